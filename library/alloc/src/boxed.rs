@@ -195,6 +195,7 @@
 use core::fmt;
 use core::future::Future;
 use core::hash::{Hash, Hasher};
+use core::io::{AsyncRead, AsyncWrite, BorrowedCursor};
 use core::iter::FusedIterator;
 use core::marker::Tuple;
 use core::marker::Unsize;
@@ -2348,6 +2349,40 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<R: ?Sized + AsyncRead + Unpin, A: Allocator> AsyncRead for Box<R, A> {
+    type Error = R::Error;
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: BorrowedCursor<'_>,
+    ) -> Poll<Result<(), R::Error>> {
+        R::poll_read(Pin::new(&mut **self), cx, buf)
+    }
+}
+
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<W: ?Sized + AsyncWrite + Unpin, A: Allocator> AsyncWrite for Box<W, A> {
+    type Error = W::Error;
+
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, W::Error>> {
+        W::poll_write(Pin::new(&mut **self), cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        W::poll_flush(Pin::new(&mut **self), cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        W::poll_close(Pin::new(&mut **self), cx)
+    }
+}
+
 impl dyn Error {
     #[inline]
     #[stable(feature = "error_downcast", since = "1.3.0")]
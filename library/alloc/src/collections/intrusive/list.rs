@@ -0,0 +1,642 @@
+//! An intrusive doubly-linked list.
+//!
+//! See the [module-level documentation](super) for how elements and
+//! adapters fit together.
+
+use core::cell::Cell;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+use super::{link_of, value_of, Adapter};
+
+#[cfg(test)]
+mod tests;
+
+/// The link an element embeds to be a member of a [`List`].
+///
+/// A link can be a member of at most one list at a time.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct ListLink {
+    next: Cell<Option<NonNull<ListLink>>>,
+    prev: Cell<Option<NonNull<ListLink>>>,
+    linked: Cell<bool>,
+    _pin: PhantomPinned,
+}
+
+// SAFETY: an unlinked `ListLink` holds no pointers, and a linked one cannot
+// be moved because the list borrows the element that contains it.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl Send for ListLink {}
+
+impl ListLink {
+    /// Creates a new, unlinked link.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub const fn new() -> Self {
+        ListLink {
+            next: Cell::new(None),
+            prev: Cell::new(None),
+            linked: Cell::new(false),
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns `true` if this link is currently a member of a list.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn is_linked(&self) -> bool {
+        self.linked.get()
+    }
+
+    fn unlink(&self) {
+        self.next.set(None);
+        self.prev.set(None);
+        self.linked.set(false);
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl Default for ListLink {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl fmt::Debug for ListLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListLink").field("linked", &self.is_linked()).finish_non_exhaustive()
+    }
+}
+
+/// Dereferences a link pointer owned by a list.
+///
+/// # Safety
+///
+/// `link` must point at a link that is a member of a live list.
+#[inline]
+unsafe fn link<'l>(link: NonNull<ListLink>) -> &'l ListLink {
+    // SAFETY: guaranteed by the caller.
+    unsafe { link.as_ref() }
+}
+
+/// An intrusive doubly-linked list.
+///
+/// The list borrows its elements for `'a` and never allocates. Pushing and
+/// popping at either end, and removing an element through a [`CursorMut`],
+/// take constant time.
+///
+/// Dropping the list unlinks all of its elements, so they can be inserted
+/// into another list afterwards.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct List<'a, A: Adapter<ListLink>> {
+    head: Option<NonNull<ListLink>>,
+    tail: Option<NonNull<ListLink>>,
+    len: usize,
+    marker: PhantomData<(Pin<&'a A::Value>, A)>,
+}
+
+// SAFETY: the list only hands out shared references to its elements, so it
+// can be sent to another thread as long as those references can.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl<A: Adapter<ListLink>> Send for List<'_, A> where A::Value: Sync {}
+
+// SAFETY: a shared list only reads the links of its elements.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl<A: Adapter<ListLink>> Sync for List<'_, A> where A::Value: Sync {}
+
+impl<'a, A: Adapter<ListLink>> List<'a, A> {
+    /// Creates an empty list.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub const fn new() -> Self {
+        List { head: None, tail: None, len: 0, marker: PhantomData }
+    }
+
+    /// Returns `true` if the list contains no elements.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is
+    /// empty.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn front(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `head` is a member of this list.
+        self.head.map(|head| unsafe { value_of::<A, _>(head) })
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is
+    /// empty.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn back(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `tail` is a member of this list.
+        self.tail.map(|tail| unsafe { value_of::<A, _>(tail) })
+    }
+
+    /// Adds an element first in the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element's link is already a member of a list.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn push_front(&mut self, value: Pin<&'a A::Value>) {
+        let new = Self::claim(value);
+        // SAFETY: `new` was just claimed, and `head` is a member of this list.
+        unsafe {
+            link(new).next.set(self.head);
+            match self.head {
+                Some(head) => link(head).prev.set(Some(new)),
+                None => self.tail = Some(new),
+            }
+        }
+        self.head = Some(new);
+        self.len += 1;
+    }
+
+    /// Appends an element to the back of the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element's link is already a member of a list.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn push_back(&mut self, value: Pin<&'a A::Value>) {
+        let new = Self::claim(value);
+        // SAFETY: `new` was just claimed, and `tail` is a member of this list.
+        unsafe {
+            link(new).prev.set(self.tail);
+            match self.tail {
+                Some(tail) => link(tail).next.set(Some(new)),
+                None => self.head = Some(new),
+            }
+        }
+        self.tail = Some(new);
+        self.len += 1;
+    }
+
+    /// Removes the first element and returns it, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `head` is a member of this list.
+        self.head.map(|head| unsafe { self.unlink_node(head) })
+    }
+
+    /// Removes the last element and returns it, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `tail` is a member of this list.
+        self.tail.map(|tail| unsafe { self.unlink_node(tail) })
+    }
+
+    /// Removes `value` from the list, returning `true` if it was linked.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    ///
+    /// If the element's link is a member of a list, that list must be `self`.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub unsafe fn remove(&mut self, value: Pin<&A::Value>) -> bool {
+        let node = link_of::<A, _>(value);
+        // SAFETY: `node` points into `value`, which is alive.
+        if !unsafe { link(node) }.is_linked() {
+            return false;
+        }
+        // SAFETY: the caller guarantees that a linked `node` is in this list.
+        unsafe { self.unlink_node(node) };
+        true
+    }
+
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// This reuses all the links from `other` and leaves it empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn append(&mut self, other: &mut Self) {
+        let Some(other_head) = other.head.take() else { return };
+        let other_tail = other.tail.take();
+        match self.tail {
+            // SAFETY: both nodes are members of the lists being joined.
+            Some(tail) => unsafe {
+                link(tail).next.set(Some(other_head));
+                link(other_head).prev.set(Some(tail));
+            },
+            None => self.head = Some(other_head),
+        }
+        self.tail = other_tail;
+        self.len += core::mem::replace(&mut other.len, 0);
+    }
+
+    /// Unlinks all elements from the list.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn clear(&mut self) {
+        let mut cur = self.head.take();
+        self.tail = None;
+        self.len = 0;
+        while let Some(node) = cur {
+            // SAFETY: `node` was a member of this list until now.
+            let node = unsafe { link(node) };
+            cur = node.next.get();
+            node.unlink();
+        }
+    }
+
+    /// Provides a forward iterator.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, 'a, A> {
+        Iter { head: self.head, tail: self.tail, len: self.len, marker: PhantomData }
+    }
+
+    /// Provides a cursor with editing operations at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is
+    /// empty.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, 'a, A> {
+        CursorMut { current: self.head, list: self }
+    }
+
+    /// Provides a cursor with editing operations at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is
+    /// empty.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, 'a, A> {
+        CursorMut { current: self.tail, list: self }
+    }
+
+    /// Provides a cursor with editing operations at `value`.
+    ///
+    /// # Safety
+    ///
+    /// The element's link must be a member of `self`.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub unsafe fn cursor_mut_from_value(&mut self, value: Pin<&A::Value>) -> CursorMut<'_, 'a, A> {
+        CursorMut { current: Some(link_of::<A, _>(value)), list: self }
+    }
+
+    /// Marks the link of `value` as linked and returns a pointer to it.
+    fn claim(value: Pin<&'a A::Value>) -> NonNull<ListLink> {
+        let node = link_of::<A, _>(value);
+        // SAFETY: `node` points into `value`, which is alive for `'a`.
+        let l = unsafe { link(node) };
+        assert!(!l.is_linked(), "element is already linked into a list");
+        l.linked.set(true);
+        node
+    }
+
+    /// Links an unlinked `new` node between `prev` and `next`.
+    ///
+    /// # Safety
+    ///
+    /// `prev` and `next` must be adjacent members of this list, or `None`
+    /// for the corresponding end.
+    unsafe fn link_between(
+        &mut self,
+        new: NonNull<ListLink>,
+        prev: Option<NonNull<ListLink>>,
+        next: Option<NonNull<ListLink>>,
+    ) {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            link(new).prev.set(prev);
+            link(new).next.set(next);
+            match prev {
+                Some(prev) => link(prev).next.set(Some(new)),
+                None => self.head = Some(new),
+            }
+            match next {
+                Some(next) => link(next).prev.set(Some(new)),
+                None => self.tail = Some(new),
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks `node` from the list and returns its element.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a member of this list.
+    unsafe fn unlink_node(&mut self, node: NonNull<ListLink>) -> Pin<&'a A::Value> {
+        // SAFETY: guaranteed by the caller.
+        let n = unsafe { link(node) };
+        let (prev, next) = (n.prev.get(), n.next.get());
+        // SAFETY: the neighbours of `node` are members of this list.
+        unsafe {
+            match prev {
+                Some(prev) => link(prev).next.set(next),
+                None => self.head = next,
+            }
+            match next {
+                Some(next) => link(next).prev.set(prev),
+                None => self.tail = prev,
+            }
+        }
+        n.unlink();
+        self.len -= 1;
+        // SAFETY: `node` was a member of this list, so its element lives for `'a`.
+        unsafe { value_of::<A, _>(node) }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> Default for List<'_, A> {
+    /// Creates an empty `List`.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> Drop for List<'_, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> fmt::Debug for List<'_, A>
+where
+    A::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'l, 'a, A: Adapter<ListLink>> IntoIterator for &'l List<'a, A> {
+    type Item = Pin<&'a A::Value>;
+    type IntoIter = Iter<'l, 'a, A>;
+
+    fn into_iter(self) -> Iter<'l, 'a, A> {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`List`].
+///
+/// This `struct` is created by [`List::iter()`]. See its documentation for
+/// more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct Iter<'l, 'a, A: Adapter<ListLink>> {
+    head: Option<NonNull<ListLink>>,
+    tail: Option<NonNull<ListLink>>,
+    len: usize,
+    marker: PhantomData<&'l List<'a, A>>,
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> Clone for Iter<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> fmt::Debug for Iter<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.len).finish()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'a, A: Adapter<ListLink>> Iterator for Iter<'_, 'a, A> {
+    type Item = Pin<&'a A::Value>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Pin<&'a A::Value>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is a member of the borrowed list.
+            unsafe {
+                self.head = link(node).next.get();
+                value_of::<A, _>(node)
+            }
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'a, A: Adapter<ListLink>> DoubleEndedIterator for Iter<'_, 'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            self.len -= 1;
+            // SAFETY: `node` is a member of the borrowed list.
+            unsafe {
+                self.tail = link(node).prev.get();
+                value_of::<A, _>(node)
+            }
+        })
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> ExactSizeIterator for Iter<'_, '_, A> {}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> FusedIterator for Iter<'_, '_, A> {}
+
+/// A cursor over a [`List`] with editing operations.
+///
+/// Like the cursors of [`LinkedList`](crate::collections::LinkedList), a
+/// `CursorMut` always rests between two elements, and points at a "ghost"
+/// non-element between the back and the front of the list.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct CursorMut<'l, 'a, A: Adapter<ListLink>> {
+    current: Option<NonNull<ListLink>>,
+    list: &'l mut List<'a, A>,
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: Adapter<ListLink>> fmt::Debug for CursorMut<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorMut").field(&self.list.len).finish()
+    }
+}
+
+impl<'l, 'a, A: Adapter<ListLink>> CursorMut<'l, 'a, A> {
+    /// Returns the element that the cursor is currently pointing to, or
+    /// `None` if the cursor is pointing to the "ghost" non-element.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn current(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `current` is a member of the list.
+        self.current.map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Returns the next element, or `None` if the cursor is pointing to the
+    /// back element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the front element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn peek_next(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the successor of `current` is a member of the list.
+        self.next_node().map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Returns the previous element, or `None` if the cursor is pointing to
+    /// the front element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the back element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn peek_prev(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the predecessor of `current` is a member of the list.
+        self.prev_node().map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Moves the cursor to the next element of the list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the front element. If it is pointing to the back element
+    /// then this will move it to the "ghost" non-element.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_next(&mut self) {
+        self.current = self.next_node();
+    }
+
+    /// Moves the cursor to the previous element of the list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the back element. If it is pointing to the front element
+    /// then this will move it to the "ghost" non-element.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_prev(&mut self) {
+        self.current = self.prev_node();
+    }
+
+    /// Removes the current element from the list and returns it.
+    ///
+    /// The cursor is moved to point to the next element. If the cursor is
+    /// pointing to the "ghost" non-element then nothing is removed and
+    /// `None` is returned.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn remove_current(&mut self) -> Option<Pin<&'a A::Value>> {
+        let node = self.current?;
+        // SAFETY: `node` is a member of the list.
+        self.current = unsafe { link(node) }.next.get();
+        // SAFETY: `node` is a member of the list.
+        Some(unsafe { self.list.unlink_node(node) })
+    }
+
+    /// Inserts a new element into the list after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the front of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element's link is already a member of a list.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn insert_after(&mut self, value: Pin<&'a A::Value>) {
+        let new = List::<A>::claim(value);
+        let next = self.next_node();
+        // SAFETY: `current` and its successor are adjacent in the list.
+        unsafe { self.list.link_between(new, self.current, next) };
+    }
+
+    /// Inserts a new element into the list before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element's link is already a member of a list.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn insert_before(&mut self, value: Pin<&'a A::Value>) {
+        let new = List::<A>::claim(value);
+        let prev = self.prev_node();
+        // SAFETY: the predecessor of `current` and `current` are adjacent.
+        unsafe { self.list.link_between(new, prev, self.current) };
+    }
+
+    /// Provides a read-only reference to the cursor's parent list.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn as_list(&self) -> &List<'a, A> {
+        self.list
+    }
+
+    fn next_node(&self) -> Option<NonNull<ListLink>> {
+        match self.current {
+            // SAFETY: `node` is a member of the list.
+            Some(node) => unsafe { link(node) }.next.get(),
+            None => self.list.head,
+        }
+    }
+
+    fn prev_node(&self) -> Option<NonNull<ListLink>> {
+        match self.current {
+            // SAFETY: `node` is a member of the list.
+            Some(node) => unsafe { link(node) }.prev.get(),
+            None => self.list.tail,
+        }
+    }
+}
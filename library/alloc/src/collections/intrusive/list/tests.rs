@@ -0,0 +1,180 @@
+use super::*;
+use crate::vec::Vec;
+
+use std::mem::offset_of;
+use std::pin::pin;
+
+struct Item {
+    value: u32,
+    link: ListLink,
+}
+
+impl Item {
+    fn new(value: u32) -> Self {
+        Item { value, link: ListLink::new() }
+    }
+}
+
+struct ItemAdapter;
+
+unsafe impl Adapter<ListLink> for ItemAdapter {
+    type Value = Item;
+    const OFFSET: usize = offset_of!(Item, link);
+}
+
+fn values(list: &List<'_, ItemAdapter>) -> Vec<u32> {
+    list.iter().map(|item| item.value).collect()
+}
+
+#[test]
+fn test_basic() {
+    let a = pin!(Item::new(1));
+    let b = pin!(Item::new(2));
+    let c = pin!(Item::new(3));
+
+    let mut list = List::<ItemAdapter>::new();
+    assert!(list.is_empty());
+    assert!(list.pop_front().is_none());
+    assert!(list.pop_back().is_none());
+
+    list.push_back(b.as_ref());
+    list.push_front(a.as_ref());
+    list.push_back(c.as_ref());
+    assert_eq!(list.len(), 3);
+    assert_eq!(values(&list), [1, 2, 3]);
+    assert_eq!(list.iter().rev().map(|item| item.value).collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(list.front().unwrap().value, 1);
+    assert_eq!(list.back().unwrap().value, 3);
+    assert!(b.link.is_linked());
+
+    assert_eq!(list.pop_front().unwrap().value, 1);
+    assert_eq!(list.pop_back().unwrap().value, 3);
+    assert!(!a.link.is_linked());
+    assert!(!c.link.is_linked());
+    assert_eq!(values(&list), [2]);
+
+    assert_eq!(list.pop_back().unwrap().value, 2);
+    assert!(list.is_empty());
+    assert!(list.front().is_none());
+    assert!(list.back().is_none());
+}
+
+#[test]
+fn test_remove() {
+    let items = pin!([Item::new(0), Item::new(1), Item::new(2), Item::new(3)]);
+    let items = items.as_ref().get_ref();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut list = List::<ItemAdapter>::new();
+    for i in 0..4 {
+        list.push_back(item(i));
+    }
+
+    unsafe {
+        assert!(list.remove(item(2)));
+        assert!(!list.remove(item(2)));
+        assert_eq!(values(&list), [0, 1, 3]);
+        assert!(list.remove(item(0)));
+        assert!(list.remove(item(3)));
+        assert_eq!(values(&list), [1]);
+        assert!(list.remove(item(1)));
+    }
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+
+    // Elements can be reinserted after they were removed.
+    list.push_back(item(3));
+    list.push_back(item(0));
+    assert_eq!(values(&list), [3, 0]);
+}
+
+#[test]
+#[should_panic = "already linked"]
+fn test_double_insert() {
+    let a = pin!(Item::new(1));
+    let mut list = List::<ItemAdapter>::new();
+    list.push_back(a.as_ref());
+    list.push_front(a.as_ref());
+}
+
+#[test]
+fn test_drop_unlinks() {
+    let a = pin!(Item::new(1));
+    let b = pin!(Item::new(2));
+    {
+        let mut list = List::<ItemAdapter>::new();
+        list.push_back(a.as_ref());
+        list.push_back(b.as_ref());
+    }
+    assert!(!a.link.is_linked());
+    assert!(!b.link.is_linked());
+
+    let mut list = List::<ItemAdapter>::new();
+    list.push_back(b.as_ref());
+    list.clear();
+    assert!(list.is_empty());
+    assert!(!b.link.is_linked());
+}
+
+#[test]
+fn test_append() {
+    let items = pin!([Item::new(0), Item::new(1), Item::new(2)]);
+    let items = items.as_ref().get_ref();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut a = List::<ItemAdapter>::new();
+    let mut b = List::<ItemAdapter>::new();
+    a.append(&mut b);
+    assert!(a.is_empty());
+
+    b.push_back(item(1));
+    b.push_back(item(2));
+    a.append(&mut b);
+    assert!(b.is_empty());
+    assert_eq!(values(&a), [1, 2]);
+
+    b.push_back(item(0));
+    b.append(&mut a);
+    assert_eq!(values(&b), [0, 1, 2]);
+    assert_eq!(b.len(), 3);
+    assert_eq!(a.len(), 0);
+}
+
+#[test]
+fn test_cursor() {
+    let items = pin!([Item::new(0), Item::new(1), Item::new(2), Item::new(3), Item::new(4)]);
+    let items = items.as_ref().get_ref();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut list = List::<ItemAdapter>::new();
+    list.push_back(item(1));
+    list.push_back(item(3));
+
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.current().unwrap().value, 1);
+    assert!(cursor.peek_prev().is_none());
+    assert_eq!(cursor.peek_next().unwrap().value, 3);
+    cursor.insert_before(item(0));
+    cursor.insert_after(item(2));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current().unwrap().value, 3);
+    cursor.move_next();
+    assert!(cursor.current().is_none());
+    cursor.insert_before(item(4));
+    assert_eq!(values(cursor.as_list()), [0, 1, 2, 3, 4]);
+
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.remove_current().unwrap().value, 3);
+    assert_eq!(cursor.current().unwrap().value, 4);
+    assert_eq!(cursor.remove_current().unwrap().value, 4);
+    assert!(cursor.current().is_none());
+    assert!(cursor.remove_current().is_none());
+    assert_eq!(values(&list), [0, 1, 2]);
+
+    let mut cursor = unsafe { list.cursor_mut_from_value(item(1)) };
+    assert_eq!(cursor.remove_current().unwrap().value, 1);
+    assert_eq!(values(&list), [0, 2]);
+    assert_eq!(list.back().unwrap().value, 2);
+}
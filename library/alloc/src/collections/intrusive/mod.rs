@@ -0,0 +1,105 @@
+//! Intrusive collections.
+//!
+//! Unlike [`LinkedList`] or [`BTreeMap`], the collections in this module do
+//! not own their elements and never allocate. Instead, every element embeds
+//! the link fields the collection needs (a [`ListLink`] or an
+//! [`RBTreeLink`]), and the collection only threads pointers through them.
+//! This makes them suitable for code that must not allocate, such as wait
+//! queues, timer wheels or interval trees in a kernel.
+//!
+//! Elements are inserted as [`Pin<&'a T>`](Pin) and must outlive the
+//! collection, so an element cannot be moved or dropped while it is linked.
+//! Which link inside the element a collection uses is described by an
+//! [`Adapter`], which allows the same element to be a member of several
+//! collections at once through different links.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(intrusive_collections)]
+//!
+//! use std::collections::intrusive::{Adapter, List, ListLink};
+//! use std::mem::offset_of;
+//! use std::pin::pin;
+//!
+//! struct Task {
+//!     id: u32,
+//!     link: ListLink,
+//! }
+//!
+//! struct TaskAdapter;
+//!
+//! // SAFETY: `OFFSET` is the offset of the `ListLink` field in `Task`.
+//! unsafe impl Adapter<ListLink> for TaskAdapter {
+//!     type Value = Task;
+//!     const OFFSET: usize = offset_of!(Task, link);
+//! }
+//!
+//! let a = pin!(Task { id: 1, link: ListLink::new() });
+//! let b = pin!(Task { id: 2, link: ListLink::new() });
+//!
+//! let mut queue = List::<TaskAdapter>::new();
+//! queue.push_back(a.as_ref());
+//! queue.push_back(b.as_ref());
+//!
+//! assert_eq!(queue.pop_front().map(|t| t.id), Some(1));
+//! assert_eq!(queue.pop_front().map(|t| t.id), Some(2));
+//! assert!(queue.is_empty());
+//! ```
+//!
+//! [`LinkedList`]: super::LinkedList
+//! [`BTreeMap`]: super::BTreeMap
+
+#![unstable(feature = "intrusive_collections", issue = "none")]
+
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+pub mod list;
+pub mod rbtree;
+
+#[doc(no_inline)]
+pub use list::{List, ListLink};
+#[doc(no_inline)]
+pub use rbtree::{KeyAdapter, RBTree, RBTreeLink};
+
+/// Describes where a link of type `L` lives inside an element.
+///
+/// An intrusive collection is parameterized by an adapter rather than by
+/// its element type, so that one element type can embed several links and
+/// be a member of several collections at the same time.
+///
+/// # Safety
+///
+/// `OFFSET` must be the byte offset of a field of type `L` inside
+/// `Self::Value`, as computed by [`offset_of!`](core::mem::offset_of).
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub unsafe trait Adapter<L> {
+    /// The type of the elements stored in the collection.
+    type Value;
+
+    /// The byte offset of the link inside [`Self::Value`](Adapter::Value).
+    const OFFSET: usize;
+}
+
+/// Returns a pointer to the link of type `L` embedded in `value`.
+#[inline]
+fn link_of<A: Adapter<L>, L>(value: Pin<&A::Value>) -> NonNull<L> {
+    let value: *const A::Value = value.get_ref();
+    // SAFETY: the `Adapter` contract guarantees that `OFFSET` is in bounds
+    // and points at a field of type `L`.
+    unsafe { NonNull::new_unchecked(value.byte_add(A::OFFSET).cast::<L>().cast_mut()) }
+}
+
+/// Returns the element that embeds `link`.
+///
+/// # Safety
+///
+/// `link` must have been obtained from [`link_of`] with the same adapter,
+/// and the element must still be alive for `'a`.
+#[inline]
+unsafe fn value_of<'a, A: Adapter<L>, L>(link: NonNull<L>) -> Pin<&'a A::Value> {
+    // SAFETY: the caller guarantees that `link` is embedded in a live
+    // element at `OFFSET`, and elements are pinned while they are linked.
+    unsafe { Pin::new_unchecked(&*link.as_ptr().byte_sub(A::OFFSET).cast::<A::Value>()) }
+}
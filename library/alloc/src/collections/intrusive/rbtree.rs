@@ -0,0 +1,1023 @@
+//! An intrusive red-black tree.
+//!
+//! See the [module-level documentation](super) for how elements and
+//! adapters fit together.
+
+use core::borrow::Borrow;
+use core::cell::Cell;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+use super::{link_of, value_of, Adapter};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Color {
+    Unlinked,
+    Red,
+    Black,
+}
+
+/// The link an element embeds to be a member of an [`RBTree`].
+///
+/// A link can be a member of at most one tree at a time.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct RBTreeLink {
+    parent: Cell<Option<NonNull<RBTreeLink>>>,
+    left: Cell<Option<NonNull<RBTreeLink>>>,
+    right: Cell<Option<NonNull<RBTreeLink>>>,
+    color: Cell<Color>,
+    _pin: PhantomPinned,
+}
+
+// SAFETY: an unlinked `RBTreeLink` holds no pointers, and a linked one
+// cannot be moved because the tree borrows the element that contains it.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl Send for RBTreeLink {}
+
+impl RBTreeLink {
+    /// Creates a new, unlinked link.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub const fn new() -> Self {
+        RBTreeLink {
+            parent: Cell::new(None),
+            left: Cell::new(None),
+            right: Cell::new(None),
+            color: Cell::new(Color::Unlinked),
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns `true` if this link is currently a member of a tree.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn is_linked(&self) -> bool {
+        self.color.get() != Color::Unlinked
+    }
+
+    fn unlink(&self) {
+        self.parent.set(None);
+        self.left.set(None);
+        self.right.set(None);
+        self.color.set(Color::Unlinked);
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl Default for RBTreeLink {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl fmt::Debug for RBTreeLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RBTreeLink").field("linked", &self.is_linked()).finish_non_exhaustive()
+    }
+}
+
+/// An [`Adapter`] that also knows how to extract the key an [`RBTree`] is
+/// ordered by.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub trait KeyAdapter: Adapter<RBTreeLink> {
+    /// The type of the key the tree is ordered by.
+    type Key: Ord;
+
+    /// Returns the key of `value`.
+    ///
+    /// The key of an element must not change while it is linked into a tree.
+    fn key(value: &Self::Value) -> Self::Key;
+}
+
+type Ptr = Option<NonNull<RBTreeLink>>;
+
+/// Dereferences a link pointer owned by a tree.
+///
+/// # Safety
+///
+/// `link` must point at a link that is a member of a live tree.
+#[inline]
+unsafe fn link<'l>(link: NonNull<RBTreeLink>) -> &'l RBTreeLink {
+    // SAFETY: guaranteed by the caller.
+    unsafe { link.as_ref() }
+}
+
+/// Returns the color of a possibly-absent node; absent leaves are black.
+#[inline]
+fn color(node: Ptr) -> Color {
+    // SAFETY: callers only pass members of a live tree.
+    node.map_or(Color::Black, |n| unsafe { link(n) }.color.get())
+}
+
+/// Returns the leftmost node of the subtree rooted at `node`.
+///
+/// # Safety
+///
+/// `node` must be a member of a live tree.
+unsafe fn first_in(mut node: NonNull<RBTreeLink>) -> NonNull<RBTreeLink> {
+    // SAFETY: every descendant of `node` is a member of the same tree.
+    while let Some(left) = unsafe { link(node) }.left.get() {
+        node = left;
+    }
+    node
+}
+
+/// Returns the rightmost node of the subtree rooted at `node`.
+///
+/// # Safety
+///
+/// `node` must be a member of a live tree.
+unsafe fn last_in(mut node: NonNull<RBTreeLink>) -> NonNull<RBTreeLink> {
+    // SAFETY: every descendant of `node` is a member of the same tree.
+    while let Some(right) = unsafe { link(node) }.right.get() {
+        node = right;
+    }
+    node
+}
+
+/// Returns the in-order successor of `node`.
+///
+/// # Safety
+///
+/// `node` must be a member of a live tree.
+unsafe fn next_of(node: NonNull<RBTreeLink>) -> Ptr {
+    // SAFETY: all nodes reached are members of the same tree.
+    unsafe {
+        if let Some(right) = link(node).right.get() {
+            return Some(first_in(right));
+        }
+        let mut node = node;
+        while let Some(parent) = link(node).parent.get() {
+            if link(parent).left.get() == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+}
+
+/// Returns the in-order predecessor of `node`.
+///
+/// # Safety
+///
+/// `node` must be a member of a live tree.
+unsafe fn prev_of(node: NonNull<RBTreeLink>) -> Ptr {
+    // SAFETY: all nodes reached are members of the same tree.
+    unsafe {
+        if let Some(left) = link(node).left.get() {
+            return Some(last_in(left));
+        }
+        let mut node = node;
+        while let Some(parent) = link(node).parent.get() {
+            if link(parent).right.get() == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+}
+
+/// An intrusive red-black tree.
+///
+/// The tree borrows its elements for `'a` and never allocates. Elements are
+/// kept in the order of their [`KeyAdapter::key`]; elements with equal keys
+/// are allowed and are kept in insertion order. Insertion, lookup and
+/// removal take *O*(log *n*) time.
+///
+/// Dropping the tree unlinks all of its elements, so they can be inserted
+/// into another tree afterwards.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(intrusive_collections)]
+///
+/// use std::collections::intrusive::{Adapter, KeyAdapter, RBTree, RBTreeLink};
+/// use std::mem::offset_of;
+/// use std::pin::pin;
+///
+/// struct Area {
+///     start: usize,
+///     link: RBTreeLink,
+/// }
+///
+/// struct AreaAdapter;
+///
+/// // SAFETY: `OFFSET` is the offset of the `RBTreeLink` field in `Area`.
+/// unsafe impl Adapter<RBTreeLink> for AreaAdapter {
+///     type Value = Area;
+///     const OFFSET: usize = offset_of!(Area, link);
+/// }
+///
+/// impl KeyAdapter for AreaAdapter {
+///     type Key = usize;
+///     fn key(area: &Area) -> usize {
+///         area.start
+///     }
+/// }
+///
+/// let low = pin!(Area { start: 0x1000, link: RBTreeLink::new() });
+/// let high = pin!(Area { start: 0x8000, link: RBTreeLink::new() });
+///
+/// let mut areas = RBTree::<AreaAdapter>::new();
+/// areas.insert(high.as_ref());
+/// areas.insert(low.as_ref());
+///
+/// // Find the last area starting at or below an address.
+/// let area = areas.upper_bound(&0x2000).peek_prev().unwrap();
+/// assert_eq!(area.start, 0x1000);
+/// ```
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct RBTree<'a, A: KeyAdapter> {
+    root: Ptr,
+    len: usize,
+    marker: PhantomData<(Pin<&'a A::Value>, A)>,
+}
+
+// SAFETY: the tree only hands out shared references to its elements, so it
+// can be sent to another thread as long as those references can.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl<A: KeyAdapter> Send for RBTree<'_, A> where A::Value: Sync {}
+
+// SAFETY: a shared tree only reads the links of its elements.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+unsafe impl<A: KeyAdapter> Sync for RBTree<'_, A> where A::Value: Sync {}
+
+impl<'a, A: KeyAdapter> RBTree<'a, A> {
+    /// Creates an empty tree.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub const fn new() -> Self {
+        RBTree { root: None, len: 0, marker: PhantomData }
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the number of elements in the tree.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the element with the smallest key, or `None` if the tree is
+    /// empty.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn first(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `root` and its descendants are members of this tree.
+        self.root.map(|root| unsafe { value_of::<A, _>(first_in(root)) })
+    }
+
+    /// Returns the element with the largest key, or `None` if the tree is
+    /// empty.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn last(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `root` and its descendants are members of this tree.
+        self.root.map(|root| unsafe { value_of::<A, _>(last_in(root)) })
+    }
+
+    /// Returns an element whose key is equal to `key`, or `None` if there
+    /// is no such element.
+    ///
+    /// If several elements have an equal key, the first of them is returned.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn find<Q>(&self, key: &Q) -> Option<Pin<&'a A::Value>>
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.lower_bound_node(key)?;
+        // SAFETY: `node` is a member of this tree.
+        let value = unsafe { value_of::<A, _>(node) };
+        if A::key(&value).borrow() == key { Some(value) } else { None }
+    }
+
+    /// Returns a cursor pointing at the first element whose key is greater
+    /// than or equal to `key`.
+    ///
+    /// The cursor points at the "ghost" non-element if there is no such
+    /// element.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn lower_bound<Q>(&self, key: &Q) -> Cursor<'_, 'a, A>
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        Cursor { current: self.lower_bound_node(key), tree: self }
+    }
+
+    /// Returns a cursor pointing at the first element whose key is greater
+    /// than `key`.
+    ///
+    /// The cursor points at the "ghost" non-element if there is no such
+    /// element.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn upper_bound<Q>(&self, key: &Q) -> Cursor<'_, 'a, A>
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        Cursor { current: self.upper_bound_node(key), tree: self }
+    }
+
+    /// Inserts an element into the tree.
+    ///
+    /// If the tree already contains elements with an equal key, the new
+    /// element is placed after them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element's link is already a member of a tree.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn insert(&mut self, value: Pin<&'a A::Value>) {
+        let new = link_of::<A, _>(value);
+        // SAFETY: `new` points into `value`, which is alive for `'a`.
+        let n = unsafe { link(new) };
+        assert!(!n.is_linked(), "element is already linked into a tree");
+
+        let key = A::key(&value);
+        let mut parent = None;
+        let mut go_left = false;
+        let mut cur = self.root;
+        while let Some(node) = cur {
+            parent = Some(node);
+            // SAFETY: `node` is a member of this tree.
+            let (node_value, node) = unsafe { (value_of::<A, _>(node), link(node)) };
+            go_left = key < A::key(&node_value);
+            cur = if go_left { node.left.get() } else { node.right.get() };
+        }
+
+        n.parent.set(parent);
+        n.left.set(None);
+        n.right.set(None);
+        n.color.set(Color::Red);
+        match parent {
+            // SAFETY: `parent` is a member of this tree.
+            Some(parent) if go_left => unsafe { link(parent) }.left.set(Some(new)),
+            // SAFETY: `parent` is a member of this tree.
+            Some(parent) => unsafe { link(parent) }.right.set(Some(new)),
+            None => self.root = Some(new),
+        }
+        self.len += 1;
+        // SAFETY: `new` was just linked into this tree.
+        unsafe { self.insert_fixup(new) };
+    }
+
+    /// Removes `value` from the tree, returning `true` if it was linked.
+    ///
+    /// # Safety
+    ///
+    /// If the element's link is a member of a tree, that tree must be `self`.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub unsafe fn remove(&mut self, value: Pin<&A::Value>) -> bool {
+        let node = link_of::<A, _>(value);
+        // SAFETY: `node` points into `value`, which is alive.
+        if !unsafe { link(node) }.is_linked() {
+            return false;
+        }
+        // SAFETY: the caller guarantees that a linked `node` is in this tree.
+        unsafe { self.remove_node(node) };
+        true
+    }
+
+    /// Removes the element with the smallest key and returns it, or `None`
+    /// if the tree is empty.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn pop_first(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `root` and its descendants are members of this tree.
+        self.root.map(|root| unsafe { self.remove_node(first_in(root)) })
+    }
+
+    /// Removes the element with the largest key and returns it, or `None`
+    /// if the tree is empty.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn pop_last(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `root` and its descendants are members of this tree.
+        self.root.map(|root| unsafe { self.remove_node(last_in(root)) })
+    }
+
+    /// Unlinks all elements from the tree.
+    ///
+    /// This operation takes *O*(*n*) time.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn clear(&mut self) {
+        // Unlink the nodes in post-order, without recursion: descend to a
+        // leaf, unlink it and continue from its parent.
+        let mut cur = self.root.take();
+        self.len = 0;
+        while let Some(node) = cur {
+            // SAFETY: `node` was a member of this tree until now, and only
+            // leaves are unlinked.
+            let n = unsafe { link(node) };
+            if let Some(left) = n.left.take() {
+                cur = Some(left);
+            } else if let Some(right) = n.right.take() {
+                cur = Some(right);
+            } else {
+                cur = n.parent.get();
+                n.unlink();
+            }
+        }
+    }
+
+    /// Gets an iterator over the elements of the tree, in key order.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, 'a, A> {
+        // SAFETY: `root` and its descendants are members of this tree.
+        let (head, tail) = match self.root {
+            Some(root) => unsafe { (Some(first_in(root)), Some(last_in(root))) },
+            None => (None, None),
+        };
+        Iter { head, tail, len: self.len, marker: PhantomData }
+    }
+
+    /// Provides a cursor at the first element.
+    ///
+    /// The cursor points at the "ghost" non-element if the tree is empty.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn cursor_front(&self) -> Cursor<'_, 'a, A> {
+        // SAFETY: `root` is a member of this tree.
+        Cursor { current: self.root.map(|root| unsafe { first_in(root) }), tree: self }
+    }
+
+    /// Provides a cursor at the last element.
+    ///
+    /// The cursor points at the "ghost" non-element if the tree is empty.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn cursor_back(&self) -> Cursor<'_, 'a, A> {
+        // SAFETY: `root` is a member of this tree.
+        Cursor { current: self.root.map(|root| unsafe { last_in(root) }), tree: self }
+    }
+
+    /// Provides a cursor with editing operations at the first element whose
+    /// key is greater than or equal to `key`.
+    ///
+    /// The cursor points at the "ghost" non-element if there is no such
+    /// element.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn lower_bound_mut<Q>(&mut self, key: &Q) -> CursorMut<'_, 'a, A>
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        CursorMut { current: self.lower_bound_node(key), tree: self }
+    }
+
+    /// Provides a cursor with editing operations at `value`.
+    ///
+    /// # Safety
+    ///
+    /// The element's link must be a member of `self`.
+    #[inline]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub unsafe fn cursor_mut_from_value(&mut self, value: Pin<&A::Value>) -> CursorMut<'_, 'a, A> {
+        CursorMut { current: Some(link_of::<A, _>(value)), tree: self }
+    }
+
+    fn lower_bound_node<Q>(&self, key: &Q) -> Ptr
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.bound_node(|node_key| node_key.borrow() >= key)
+    }
+
+    fn upper_bound_node<Q>(&self, key: &Q) -> Ptr
+    where
+        A::Key: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.bound_node(|node_key| node_key.borrow() > key)
+    }
+
+    /// Returns the first node for which `go_left` holds, assuming that it is
+    /// monotonic over the key order.
+    fn bound_node(&self, mut go_left: impl FnMut(&A::Key) -> bool) -> Ptr {
+        let mut found = None;
+        let mut cur = self.root;
+        while let Some(node) = cur {
+            // SAFETY: `node` is a member of this tree.
+            let (node_value, n) = unsafe { (value_of::<A, _>(node), link(node)) };
+            if go_left(&A::key(&node_value)) {
+                found = Some(node);
+                cur = n.left.get();
+            } else {
+                cur = n.right.get();
+            }
+        }
+        found
+    }
+
+    /// Replaces `old` with `new` in the parent of `old`.
+    ///
+    /// # Safety
+    ///
+    /// `old` must be a member of this tree, and `new` a member or `None`.
+    unsafe fn replace_child(&mut self, old: NonNull<RBTreeLink>, new: Ptr) {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            let parent = link(old).parent.get();
+            match parent {
+                Some(p) if link(p).left.get() == Some(old) => link(p).left.set(new),
+                Some(p) => link(p).right.set(new),
+                None => self.root = new,
+            }
+            if let Some(new) = new {
+                link(new).parent.set(parent);
+            }
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `x` must be a member of this tree with a right child.
+    unsafe fn rotate_left(&mut self, x: NonNull<RBTreeLink>) {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            let y = link(x).right.get().unwrap_unchecked();
+            let y_left = link(y).left.get();
+            link(x).right.set(y_left);
+            if let Some(y_left) = y_left {
+                link(y_left).parent.set(Some(x));
+            }
+            self.replace_child(x, Some(y));
+            link(y).left.set(Some(x));
+            link(x).parent.set(Some(y));
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `x` must be a member of this tree with a left child.
+    unsafe fn rotate_right(&mut self, x: NonNull<RBTreeLink>) {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            let y = link(x).left.get().unwrap_unchecked();
+            let y_right = link(y).right.get();
+            link(x).left.set(y_right);
+            if let Some(y_right) = y_right {
+                link(y_right).parent.set(Some(x));
+            }
+            self.replace_child(x, Some(y));
+            link(y).right.set(Some(x));
+            link(x).parent.set(Some(y));
+        }
+    }
+
+    /// Restores the red-black invariants after inserting the red node `z`.
+    ///
+    /// # Safety
+    ///
+    /// `z` must be a member of this tree.
+    unsafe fn insert_fixup(&mut self, mut z: NonNull<RBTreeLink>) {
+        // SAFETY: all nodes reached are members of this tree. A red parent is
+        // never the root, so it always has a parent of its own.
+        unsafe {
+            while let Some(mut p) = link(z).parent.get() {
+                if link(p).color.get() != Color::Red {
+                    break;
+                }
+                let g = link(p).parent.get().unwrap_unchecked();
+                if link(g).left.get() == Some(p) {
+                    let uncle = link(g).right.get();
+                    if color(uncle) == Color::Red {
+                        link(p).color.set(Color::Black);
+                        link(uncle.unwrap_unchecked()).color.set(Color::Black);
+                        link(g).color.set(Color::Red);
+                        z = g;
+                        continue;
+                    }
+                    if link(p).right.get() == Some(z) {
+                        self.rotate_left(p);
+                        p = z;
+                    }
+                    link(p).color.set(Color::Black);
+                    link(g).color.set(Color::Red);
+                    self.rotate_right(g);
+                } else {
+                    let uncle = link(g).left.get();
+                    if color(uncle) == Color::Red {
+                        link(p).color.set(Color::Black);
+                        link(uncle.unwrap_unchecked()).color.set(Color::Black);
+                        link(g).color.set(Color::Red);
+                        z = g;
+                        continue;
+                    }
+                    if link(p).left.get() == Some(z) {
+                        self.rotate_right(p);
+                        p = z;
+                    }
+                    link(p).color.set(Color::Black);
+                    link(g).color.set(Color::Red);
+                    self.rotate_left(g);
+                }
+                break;
+            }
+            if let Some(root) = self.root {
+                link(root).color.set(Color::Black);
+            }
+        }
+    }
+
+    /// Unlinks `z` from the tree and returns its element.
+    ///
+    /// # Safety
+    ///
+    /// `z` must be a member of this tree.
+    unsafe fn remove_node(&mut self, z: NonNull<RBTreeLink>) -> Pin<&'a A::Value> {
+        // SAFETY: all nodes reached are members of this tree.
+        unsafe {
+            let zl = link(z);
+            let removed_color;
+            let x;
+            let x_parent;
+            match (zl.left.get(), zl.right.get()) {
+                (None, child) | (child, None) => {
+                    removed_color = zl.color.get();
+                    x = child;
+                    x_parent = zl.parent.get();
+                    self.replace_child(z, child);
+                }
+                (Some(left), Some(right)) => {
+                    // Splice out the successor `y` and put it in place of `z`.
+                    let y = first_in(right);
+                    let yl = link(y);
+                    removed_color = yl.color.get();
+                    x = yl.right.get();
+                    if y == right {
+                        x_parent = Some(y);
+                    } else {
+                        x_parent = yl.parent.get();
+                        self.replace_child(y, x);
+                        yl.right.set(Some(right));
+                        link(right).parent.set(Some(y));
+                    }
+                    self.replace_child(z, Some(y));
+                    yl.left.set(Some(left));
+                    link(left).parent.set(Some(y));
+                    yl.color.set(zl.color.get());
+                }
+            }
+            if removed_color == Color::Black {
+                self.remove_fixup(x, x_parent);
+            }
+            zl.unlink();
+            self.len -= 1;
+            value_of::<A, _>(z)
+        }
+    }
+
+    /// Restores the red-black invariants after removing a black node, where
+    /// `x` is the node that took its place and carries an extra black.
+    ///
+    /// # Safety
+    ///
+    /// `x` must be a member of this tree or `None`, and `parent` its parent.
+    unsafe fn remove_fixup(&mut self, mut x: Ptr, mut parent: Ptr) {
+        // SAFETY: all nodes reached are members of this tree. Since `x`
+        // carries an extra black, its sibling always exists.
+        unsafe {
+            while x != self.root && color(x) == Color::Black {
+                let p = parent.unwrap_unchecked();
+                if link(p).left.get() == x {
+                    let mut w = link(p).right.get().unwrap_unchecked();
+                    if link(w).color.get() == Color::Red {
+                        link(w).color.set(Color::Black);
+                        link(p).color.set(Color::Red);
+                        self.rotate_left(p);
+                        w = link(p).right.get().unwrap_unchecked();
+                    }
+                    if color(link(w).left.get()) == Color::Black
+                        && color(link(w).right.get()) == Color::Black
+                    {
+                        link(w).color.set(Color::Red);
+                        x = Some(p);
+                        parent = link(p).parent.get();
+                        continue;
+                    }
+                    if color(link(w).right.get()) == Color::Black {
+                        link(link(w).left.get().unwrap_unchecked()).color.set(Color::Black);
+                        link(w).color.set(Color::Red);
+                        self.rotate_right(w);
+                        w = link(p).right.get().unwrap_unchecked();
+                    }
+                    link(w).color.set(link(p).color.get());
+                    link(p).color.set(Color::Black);
+                    link(link(w).right.get().unwrap_unchecked()).color.set(Color::Black);
+                    self.rotate_left(p);
+                } else {
+                    let mut w = link(p).left.get().unwrap_unchecked();
+                    if link(w).color.get() == Color::Red {
+                        link(w).color.set(Color::Black);
+                        link(p).color.set(Color::Red);
+                        self.rotate_right(p);
+                        w = link(p).left.get().unwrap_unchecked();
+                    }
+                    if color(link(w).left.get()) == Color::Black
+                        && color(link(w).right.get()) == Color::Black
+                    {
+                        link(w).color.set(Color::Red);
+                        x = Some(p);
+                        parent = link(p).parent.get();
+                        continue;
+                    }
+                    if color(link(w).left.get()) == Color::Black {
+                        link(link(w).right.get().unwrap_unchecked()).color.set(Color::Black);
+                        link(w).color.set(Color::Red);
+                        self.rotate_left(w);
+                        w = link(p).left.get().unwrap_unchecked();
+                    }
+                    link(w).color.set(link(p).color.get());
+                    link(p).color.set(Color::Black);
+                    link(link(w).left.get().unwrap_unchecked()).color.set(Color::Black);
+                    self.rotate_right(p);
+                }
+                x = self.root;
+                break;
+            }
+            if let Some(x) = x {
+                link(x).color.set(Color::Black);
+            }
+        }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> Default for RBTree<'_, A> {
+    /// Creates an empty `RBTree`.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> Drop for RBTree<'_, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> fmt::Debug for RBTree<'_, A>
+where
+    A::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'t, 'a, A: KeyAdapter> IntoIterator for &'t RBTree<'a, A> {
+    type Item = Pin<&'a A::Value>;
+    type IntoIter = Iter<'t, 'a, A>;
+
+    fn into_iter(self) -> Iter<'t, 'a, A> {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of an [`RBTree`], in key order.
+///
+/// This `struct` is created by [`RBTree::iter()`]. See its documentation for
+/// more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct Iter<'t, 'a, A: KeyAdapter> {
+    head: Ptr,
+    tail: Ptr,
+    len: usize,
+    marker: PhantomData<&'t RBTree<'a, A>>,
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> Clone for Iter<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> fmt::Debug for Iter<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.len).finish()
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'a, A: KeyAdapter> Iterator for Iter<'_, 'a, A> {
+    type Item = Pin<&'a A::Value>;
+
+    fn next(&mut self) -> Option<Pin<&'a A::Value>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: `head` is a member of the borrowed tree, and `len` was
+        // nonzero so it is `Some`.
+        unsafe {
+            let node = self.head.unwrap_unchecked();
+            self.head = next_of(node);
+            Some(value_of::<A, _>(node))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<'a, A: KeyAdapter> DoubleEndedIterator for Iter<'_, 'a, A> {
+    fn next_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: `tail` is a member of the borrowed tree, and `len` was
+        // nonzero so it is `Some`.
+        unsafe {
+            let node = self.tail.unwrap_unchecked();
+            self.tail = prev_of(node);
+            Some(value_of::<A, _>(node))
+        }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> ExactSizeIterator for Iter<'_, '_, A> {}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> FusedIterator for Iter<'_, '_, A> {}
+
+/// A cursor over an [`RBTree`].
+///
+/// A cursor points at an element of the tree, or at a "ghost" non-element
+/// that sits between the last and the first element.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct Cursor<'t, 'a, A: KeyAdapter> {
+    current: Ptr,
+    tree: &'t RBTree<'a, A>,
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> Clone for Cursor<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Cursor { ..*self }
+    }
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> fmt::Debug for Cursor<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cursor").field(&self.current.is_some()).finish()
+    }
+}
+
+impl<'t, 'a, A: KeyAdapter> Cursor<'t, 'a, A> {
+    /// Returns the element that the cursor is currently pointing to, or
+    /// `None` if the cursor is pointing to the "ghost" non-element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn current(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `current` is a member of the tree.
+        self.current.map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Returns the next element in key order.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn peek_next(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the successor of `current` is a member of the tree.
+        step_next(self.current, self.tree.root).map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Returns the previous element in key order.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn peek_prev(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the predecessor of `current` is a member of the tree.
+        step_prev(self.current, self.tree.root).map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Moves the cursor to the next element in key order.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_next(&mut self) {
+        self.current = step_next(self.current, self.tree.root);
+    }
+
+    /// Moves the cursor to the previous element in key order.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_prev(&mut self) {
+        self.current = step_prev(self.current, self.tree.root);
+    }
+}
+
+/// A cursor over an [`RBTree`] with editing operations.
+///
+/// A cursor points at an element of the tree, or at a "ghost" non-element
+/// that sits between the last and the first element.
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub struct CursorMut<'t, 'a, A: KeyAdapter> {
+    current: Ptr,
+    tree: &'t mut RBTree<'a, A>,
+}
+
+#[unstable(feature = "intrusive_collections", issue = "none")]
+impl<A: KeyAdapter> fmt::Debug for CursorMut<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorMut").field(&self.current.is_some()).finish()
+    }
+}
+
+impl<'t, 'a, A: KeyAdapter> CursorMut<'t, 'a, A> {
+    /// Returns the element that the cursor is currently pointing to, or
+    /// `None` if the cursor is pointing to the "ghost" non-element.
+    #[must_use]
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn current(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: `current` is a member of the tree.
+        self.current.map(|node| unsafe { value_of::<A, _>(node) })
+    }
+
+    /// Moves the cursor to the next element in key order.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_next(&mut self) {
+        self.current = step_next(self.current, self.tree.root);
+    }
+
+    /// Moves the cursor to the previous element in key order.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn move_prev(&mut self) {
+        self.current = step_prev(self.current, self.tree.root);
+    }
+
+    /// Removes the current element from the tree and returns it.
+    ///
+    /// The cursor is moved to point to the next element. If the cursor is
+    /// pointing to the "ghost" non-element then nothing is removed and
+    /// `None` is returned.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn remove_current(&mut self) -> Option<Pin<&'a A::Value>> {
+        let node = self.current?;
+        // SAFETY: `node` is a member of the tree. Rebalancing does not change
+        // the in-order successor of `node`.
+        unsafe {
+            self.current = next_of(node);
+            Some(self.tree.remove_node(node))
+        }
+    }
+
+    /// Provides a read-only cursor at the current element.
+    #[unstable(feature = "intrusive_collections", issue = "none")]
+    pub fn as_cursor(&self) -> Cursor<'_, 'a, A> {
+        Cursor { current: self.current, tree: self.tree }
+    }
+}
+
+fn step_next(current: Ptr, root: Ptr) -> Ptr {
+    // SAFETY: callers pass members of a live tree and its root.
+    match current {
+        Some(node) => unsafe { next_of(node) },
+        None => root.map(|root| unsafe { first_in(root) }),
+    }
+}
+
+fn step_prev(current: Ptr, root: Ptr) -> Ptr {
+    // SAFETY: callers pass members of a live tree and its root.
+    match current {
+        Some(node) => unsafe { prev_of(node) },
+        None => root.map(|root| unsafe { last_in(root) }),
+    }
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::testing::rng::DeterministicRng;
+use crate::vec::Vec;
+
+use std::mem::offset_of;
+use std::pin::pin;
+
+struct Item {
+    key: u32,
+    id: usize,
+    link: RBTreeLink,
+}
+
+impl Item {
+    fn new(key: u32, id: usize) -> Self {
+        Item { key, id, link: RBTreeLink::new() }
+    }
+}
+
+struct ItemAdapter;
+
+unsafe impl Adapter<RBTreeLink> for ItemAdapter {
+    type Value = Item;
+    const OFFSET: usize = offset_of!(Item, link);
+}
+
+impl KeyAdapter for ItemAdapter {
+    type Key = u32;
+    fn key(item: &Item) -> u32 {
+        item.key
+    }
+}
+
+fn keys(tree: &RBTree<'_, ItemAdapter>) -> Vec<u32> {
+    tree.iter().map(|item| item.key).collect()
+}
+
+/// Checks the parent links, ordering and red-black invariants of `tree`.
+fn check(tree: &RBTree<'_, ItemAdapter>) {
+    // Returns the black height of the subtree rooted at `node`.
+    fn check_node(node: Ptr, parent: Ptr) -> usize {
+        let Some(node) = node else { return 1 };
+        let n = unsafe { link(node) };
+        assert_eq!(n.parent.get(), parent);
+        assert_ne!(n.color.get(), Color::Unlinked);
+        if n.color.get() == Color::Red {
+            assert_eq!(color(n.left.get()), Color::Black);
+            assert_eq!(color(n.right.get()), Color::Black);
+        }
+        let left = check_node(n.left.get(), Some(node));
+        let right = check_node(n.right.get(), Some(node));
+        assert_eq!(left, right);
+        left + (n.color.get() == Color::Black) as usize
+    }
+
+    assert_eq!(color(tree.root), Color::Black);
+    check_node(tree.root, None);
+    let keys = keys(tree);
+    assert_eq!(keys.len(), tree.len());
+    assert!(keys.is_sorted());
+    let mut rev = tree.iter().rev().map(|item| item.key).collect::<Vec<_>>();
+    rev.reverse();
+    assert_eq!(keys, rev);
+}
+
+#[test]
+fn test_basic() {
+    let items = pin!([Item::new(5, 0), Item::new(1, 1), Item::new(3, 2), Item::new(3, 3)]);
+    let items = items.as_ref().get_ref();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut tree = RBTree::<ItemAdapter>::new();
+    assert!(tree.is_empty());
+    assert!(tree.first().is_none());
+    assert!(tree.pop_last().is_none());
+
+    for i in 0..4 {
+        tree.insert(item(i));
+        check(&tree);
+    }
+    assert_eq!(tree.len(), 4);
+    assert_eq!(keys(&tree), [1, 3, 3, 5]);
+    // Equal keys keep their insertion order.
+    assert_eq!(tree.iter().map(|item| item.id).collect::<Vec<_>>(), [1, 2, 3, 0]);
+
+    assert_eq!(tree.first().unwrap().key, 1);
+    assert_eq!(tree.last().unwrap().key, 5);
+    assert_eq!(tree.find(&3).unwrap().id, 2);
+    assert!(tree.find(&4).is_none());
+
+    assert_eq!(tree.pop_first().unwrap().key, 1);
+    assert_eq!(tree.pop_last().unwrap().key, 5);
+    check(&tree);
+    assert!(!items[0].link.is_linked());
+    assert!(items[2].link.is_linked());
+    assert_eq!(keys(&tree), [3, 3]);
+}
+
+#[test]
+fn test_bounds() {
+    let items = pin!([Item::new(10, 0), Item::new(20, 1), Item::new(30, 2)]);
+    let items = items.as_ref().get_ref();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut tree = RBTree::<ItemAdapter>::new();
+    for i in 0..3 {
+        tree.insert(item(i));
+    }
+
+    assert_eq!(tree.lower_bound(&20).current().unwrap().key, 20);
+    assert_eq!(tree.upper_bound(&20).current().unwrap().key, 30);
+    assert_eq!(tree.lower_bound(&15).current().unwrap().key, 20);
+    assert!(tree.lower_bound(&31).current().is_none());
+    assert_eq!(tree.lower_bound(&31).peek_prev().unwrap().key, 30);
+    assert_eq!(tree.upper_bound(&5).current().unwrap().key, 10);
+    assert!(tree.upper_bound(&5).peek_prev().is_none());
+
+    let mut cursor = tree.cursor_front();
+    assert_eq!(cursor.current().unwrap().key, 10);
+    cursor.move_prev();
+    assert!(cursor.current().is_none());
+    assert_eq!(cursor.peek_next().unwrap().key, 10);
+    cursor.move_prev();
+    assert_eq!(cursor.current().unwrap().key, 30);
+    assert_eq!(tree.cursor_back().current().unwrap().key, 30);
+
+    let mut cursor = tree.lower_bound_mut(&20);
+    assert_eq!(cursor.remove_current().unwrap().key, 20);
+    assert_eq!(cursor.current().unwrap().key, 30);
+    cursor.move_next();
+    assert!(cursor.remove_current().is_none());
+    assert_eq!(keys(&tree), [10, 30]);
+    check(&tree);
+}
+
+#[test]
+#[should_panic = "already linked"]
+fn test_double_insert() {
+    let a = pin!(Item::new(1, 0));
+    let mut tree = RBTree::<ItemAdapter>::new();
+    tree.insert(a.as_ref());
+    tree.insert(a.as_ref());
+}
+
+#[test]
+fn test_random() {
+    const N: usize = if cfg!(miri) { 64 } else { 1000 };
+
+    let mut rng = DeterministicRng::new();
+    let items: Vec<Item> = (0..N).map(|id| Item::new(rng.next() % 256, id)).collect();
+    let item = |i: usize| unsafe { Pin::new_unchecked(&items[i]) };
+
+    let mut tree = RBTree::<ItemAdapter>::new();
+    let mut expected = Vec::new();
+    for i in 0..N {
+        tree.insert(item(i));
+        expected.push(items[i].key);
+        if i % 16 == 0 {
+            check(&tree);
+        }
+    }
+    check(&tree);
+    expected.sort();
+    assert_eq!(keys(&tree), expected);
+
+    // Remove every element in a scrambled order.
+    for step in 0..N {
+        let i = (step * 7919) % N;
+        assert!(unsafe { tree.remove(item(i)) });
+        assert!(!unsafe { tree.remove(item(i)) });
+        let pos = expected.iter().position(|&k| k == items[i].key).unwrap();
+        expected.remove(pos);
+        if step % 16 == 0 {
+            check(&tree);
+            assert_eq!(keys(&tree), expected);
+        }
+    }
+    assert!(tree.is_empty());
+    check(&tree);
+}
+
+#[test]
+fn test_drop_unlinks() {
+    let mut rng = DeterministicRng::new();
+    let items: Vec<Item> = (0..100).map(|id| Item::new(rng.next(), id)).collect();
+    {
+        let mut tree = RBTree::<ItemAdapter>::new();
+        for item in &items {
+            tree.insert(unsafe { Pin::new_unchecked(item) });
+        }
+        check(&tree);
+    }
+    assert!(items.iter().all(|item| !item.link.is_linked()));
+}
@@ -6,6 +6,7 @@
 pub mod binary_heap;
 #[cfg(not(no_global_oom_handling))]
 mod btree;
+pub mod intrusive;
 #[cfg(not(no_global_oom_handling))]
 pub mod linked_list;
 #[cfg(not(no_global_oom_handling))]
@@ -118,6 +118,8 @@
 #![feature(const_size_of_val)]
 #![feature(const_waker)]
 #![feature(core_intrinsics)]
+#![feature(core_io_async)]
+#![feature(core_io_borrowed_buf)]
 #![feature(deprecated_suggestion)]
 #![feature(deref_pure_trait)]
 #![feature(dispatch_from_dyn)]
@@ -148,6 +150,7 @@
 #![feature(slice_index_methods)]
 #![feature(slice_ptr_get)]
 #![feature(slice_range)]
+#![feature(slice_sort_by_radix_key)]
 #![feature(std_internals)]
 #![feature(str_internals)]
 #![feature(strict_provenance)]
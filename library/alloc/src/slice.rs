@@ -67,6 +67,8 @@
 pub use core::slice::{RSplit, RSplitMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{RSplitN, RSplitNMut, SplitN, SplitNMut};
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+pub use core::slice::RadixKey;
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use core::slice::{SplitInclusive, SplitInclusiveMut};
 
@@ -416,6 +418,57 @@ macro_rules! sort_by_key {
         sort_by_key!(usize, self, f)
     }
 
+    /// Sorts the slice with a key extraction function, preserving initial order of equal elements,
+    /// using a radix sort.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n*)
+    /// worst-case, where *m* is the length in bytes of the longest key. Unlike the other sorts, it
+    /// does not compare keys, but distributes the elements by one byte of their keys at a time,
+    /// which makes it considerably faster for large slices with short keys, such as integers. The
+    /// key function is called up to 2 \* *m* + 1 times per element, so it should be cheap.
+    ///
+    /// See [`RadixKey`] for the types of keys that are supported. Byte slices and string slices
+    /// are sorted lexicographically.
+    ///
+    /// If `f` returns different keys for the same element, the implementation may panic. All
+    /// original elements will remain in the slice, in an unspecified order. Same is true if `f`
+    /// panics.
+    ///
+    /// # Current implementation
+    ///
+    /// The current implementation is a least significant digit radix sort, which allocates
+    /// `self.len()` elements of auxiliary memory. Bytes that are the same in all keys are
+    /// skipped. To sort without allocating, use
+    /// [`sort_by_radix_key_with_scratch`](slice::sort_by_radix_key_with_scratch).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_by_radix_key)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    /// v.sort_by_radix_key(|&k| k);
+    /// assert!(v == [-5, -3, 1, 2, 4]);
+    ///
+    /// let mut v = [("b", 1), ("ab", 2), ("a", 3), ("b", 4)];
+    /// v.sort_by_radix_key(|&(k, _)| k);
+    /// assert!(v == [("a", 3), ("ab", 2), ("b", 1), ("b", 4)]);
+    /// ```
+    ///
+    /// [`RadixKey`]: core::slice::RadixKey
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+    #[inline]
+    pub fn sort_by_radix_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: RadixKey,
+    {
+        let mut scratch = Vec::with_capacity(self.len());
+        sort::radix::sort(self, scratch.spare_capacity_mut(), &mut f);
+    }
+
     /// Copies `self` into a new `Vec`.
     ///
     /// # Examples
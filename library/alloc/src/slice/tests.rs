@@ -1,6 +1,6 @@
 use crate::borrow::ToOwned;
 use crate::rc::Rc;
-use crate::string::ToString;
+use crate::string::{String, ToString};
 use crate::test_helpers::test_rng;
 use crate::vec::Vec;
 
@@ -363,3 +363,114 @@ fn test_sort_stability() {
         }
     }
 }
+
+#[test]
+fn test_sort_by_radix_key() {
+    let mut rng = test_rng();
+    let lens = if cfg!(miri) { (0..20).chain(0..0) } else { (0..25).chain(500..510) };
+    let rounds = if cfg!(miri) { 1 } else { 10 };
+
+    for len in lens {
+        for _ in 0..rounds {
+            // The second item of each tuple is its index, so that comparing whole tuples
+            // checks that the sort is stable.
+            let orig: Vec<(u64, usize)> =
+                (0..len).map(|i| (rng.gen::<u64>() >> (rng.gen::<u32>() % 64), i)).collect();
+            let mut expected = orig.clone();
+            expected.sort();
+
+            let mut v = orig.clone();
+            v.sort_by_radix_key(|&(x, _)| x);
+            assert_eq!(v, expected);
+
+            let mut v = orig.clone();
+            let mut scratch = Vec::with_capacity(len);
+            v.sort_by_radix_key_with_scratch(scratch.spare_capacity_mut(), |&(x, _)| x);
+            assert_eq!(v, expected);
+
+            let mut v: Vec<(i16, usize)> = orig.iter().map(|&(x, i)| (x as i16, i)).collect();
+            let mut expected = v.clone();
+            expected.sort();
+            v.sort_by_radix_key(|&(x, _)| x);
+            assert_eq!(v, expected);
+
+            let strings: Vec<String> = orig.iter().map(|&(x, _)| (x % 4096).to_string()).collect();
+            let mut v: Vec<(&str, usize)> =
+                strings.iter().zip(0..).map(|(x, i)| (x.as_str(), i)).collect();
+            let mut expected = v.clone();
+            expected.sort();
+            v.sort_by_radix_key(|&(x, _)| x);
+            assert_eq!(v, expected);
+            v.reverse();
+            v.sort_by_radix_key(|&(x, _)| x.len());
+            assert!(v.windows(2).all(|w| w[0].0.len() <= w[1].0.len()));
+        }
+    }
+
+    let mut v = [-1i8, i8::MIN, 0, i8::MAX, 1];
+    v.sort_by_radix_key(|&x| x);
+    assert_eq!(v, [i8::MIN, -1, 0, 1, i8::MAX]);
+
+    let mut v = ['\u{10000}', 'b', '\u{e9}', 'a'];
+    v.sort_by_radix_key(|&x| x);
+    assert_eq!(v, ['a', 'b', '\u{e9}', '\u{10000}']);
+
+    let mut v = ["b", "", "ab", "a", "ba"];
+    v.sort_by_radix_key(|&x| x);
+    assert_eq!(v, ["", "a", "ab", "b", "ba"]);
+
+    // Should not panic.
+    [0u8; 0].sort_by_radix_key(|&x| x);
+    [(); 10].sort_by_radix_key(|_| 0u32);
+}
+
+#[test]
+#[should_panic]
+fn test_sort_by_radix_key_with_short_scratch() {
+    let mut v = [3, 2, 1];
+    let mut scratch = [mem::MaybeUninit::uninit(); 2];
+    v.sort_by_radix_key_with_scratch(&mut scratch, |&x| x);
+}
+
+#[test]
+#[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_sort_by_radix_key_panic_safe() {
+    let orig: Vec<String> = (0..100u32).rev().map(|i| (i * 7919 % 1000).to_string()).collect();
+    let mut expected = orig.clone();
+    expected.sort();
+
+    // Panic on every call of the key function in turn, and make sure every element is still
+    // there afterwards.
+    for panic_countdown in 0.. {
+        let mut v = orig.clone();
+        let calls = Cell::new(0);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            v.sort_by_radix_key(|x| {
+                calls.set(calls.get() + 1);
+                if calls.get() > panic_countdown {
+                    panic!();
+                }
+                x.parse::<u32>().unwrap()
+            })
+        }));
+        v.sort();
+        assert_eq!(v, expected);
+        if result.is_ok() {
+            break;
+        }
+    }
+
+    // A key function that returns different keys for the same element either leaves the slice
+    // in some order, or panics, but never loses elements.
+    let mut v = orig.clone();
+    let calls = Cell::new(0u32);
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        v.sort_by_radix_key(|_| {
+            calls.set(calls.get() + 1);
+            calls.get().wrapping_mul(2654435761)
+        })
+    }));
+    v.sort();
+    assert_eq!(v, expected);
+}
@@ -12,7 +12,7 @@
 use core::mem;
 use core::ptr;
 use core::str::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
-use core::unicode::conversions;
+use core::unicode::{conversions, normalization};
 
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
@@ -40,6 +40,8 @@
 pub use core::str::{EscapeDebug, EscapeDefault, EscapeUnicode};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{FromStr, Utf8Error};
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub use core::str::Graphemes;
 #[allow(deprecated)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{Lines, LinesAny};
@@ -486,6 +488,76 @@ pub fn to_uppercase(&self) -> String {
         s
     }
 
+    /// Returns the Normalization Form D of this string slice, as a new [`String`].
+    ///
+    /// In NFD, every character is replaced by its canonical decomposition, and
+    /// combining marks are put in a canonical order. Two strings that are
+    /// canonically equivalent, such as `"é"` written as one precomposed
+    /// character or as `"e"` followed by a combining acute accent, have the
+    /// same NFD.
+    ///
+    /// See [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+    /// for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// assert_eq!("\u{e9}".to_nfd(), "e\u{301}");
+    /// // Marks below come before marks above.
+    /// assert_eq!("q\u{307}\u{323}".to_nfd(), "q\u{323}\u{307}");
+    /// // Hangul syllables are decomposed into their jamo.
+    /// assert_eq!("\u{d55c}".to_nfd(), "\u{1112}\u{1161}\u{11ab}");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfd(&self) -> String {
+        if self.is_ascii() {
+            return self.to_owned();
+        }
+        canonical_decomposition(self).into_iter().collect()
+    }
+
+    /// Returns the Normalization Form C of this string slice, as a new [`String`].
+    ///
+    /// NFC is obtained by canonically decomposing the string as in
+    /// [`to_nfd`], and then recomposing characters wherever possible. It is
+    /// the form usually produced by keyboards, and the one recommended for
+    /// comparing and storing text.
+    ///
+    /// See [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+    /// for details.
+    ///
+    /// [`to_nfd`]: str::to_nfd
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_normalization)]
+    ///
+    /// assert_eq!("e\u{301}".to_nfc(), "\u{e9}");
+    /// assert_eq!("\u{1112}\u{1161}\u{11ab}".to_nfc(), "\u{d55c}");
+    /// // Some characters never appear in NFC.
+    /// assert_eq!("\u{212b}".to_nfc(), "\u{c5}");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the normalized string as a new String, \
+                  without modifying the original"]
+    #[unstable(feature = "str_normalization", issue = "none")]
+    pub fn to_nfc(&self) -> String {
+        if self.is_ascii() {
+            return self.to_owned();
+        }
+        let mut chars = canonical_decomposition(self);
+        canonical_composition(&mut chars);
+        chars.into_iter().collect()
+    }
+
     /// Converts a [`Box<str>`] into a [`String`] without copying or allocating.
     ///
     /// # Examples
@@ -663,3 +735,120 @@ fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8) -> Vec<u8> {
 
     out
 }
+
+/// Constants for the algorithmic (de)composition of Hangul syllables, see
+/// section 3.12 of the Unicode Standard.
+#[cfg(not(test))]
+#[cfg(not(no_global_oom_handling))]
+mod hangul {
+    pub(super) const S_BASE: u32 = 0xAC00;
+    pub(super) const L_BASE: u32 = 0x1100;
+    pub(super) const V_BASE: u32 = 0x1161;
+    pub(super) const T_BASE: u32 = 0x11A7;
+    pub(super) const L_COUNT: u32 = 19;
+    pub(super) const V_COUNT: u32 = 21;
+    pub(super) const T_COUNT: u32 = 28;
+    pub(super) const N_COUNT: u32 = V_COUNT * T_COUNT;
+    pub(super) const S_COUNT: u32 = L_COUNT * N_COUNT;
+}
+
+/// Returns the characters of `s` in canonically decomposed and ordered form.
+#[cfg(not(test))]
+#[cfg(not(no_global_oom_handling))]
+fn canonical_decomposition(s: &str) -> Vec<char> {
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        let s_index = (c as u32).wrapping_sub(hangul::S_BASE);
+        if s_index < hangul::S_COUNT {
+            // Hangul syllables decompose algorithmically.
+            let l = hangul::L_BASE + s_index / hangul::N_COUNT;
+            let v = hangul::V_BASE + (s_index % hangul::N_COUNT) / hangul::T_COUNT;
+            let t = hangul::T_BASE + s_index % hangul::T_COUNT;
+            // SAFETY: These are all in the Hangul Jamo block.
+            unsafe {
+                chars.push(char::from_u32_unchecked(l));
+                chars.push(char::from_u32_unchecked(v));
+                if t != hangul::T_BASE {
+                    chars.push(char::from_u32_unchecked(t));
+                }
+            }
+        } else if let Some(decomposed) = normalization::canonical_decomposition(c) {
+            chars.extend_from_slice(decomposed);
+        } else {
+            chars.push(c);
+        }
+    }
+
+    // Put every run of non-starters in canonical order. The sort is stable,
+    // so marks with the same combining class keep their relative order.
+    let mut start = 0;
+    while start < chars.len() {
+        let len = chars[start..]
+            .iter()
+            .take_while(|&&c| normalization::canonical_combining_class(c) != 0)
+            .count();
+        chars[start..start + len].sort_by_key(|&c| normalization::canonical_combining_class(c));
+        start += len + 1;
+    }
+    chars
+}
+
+/// Applies the canonical composition algorithm to canonically decomposed and
+/// ordered characters.
+#[cfg(not(test))]
+#[cfg(not(no_global_oom_handling))]
+fn canonical_composition(chars: &mut Vec<char>) {
+    // The index of the last starter that later characters may compose with.
+    let mut starter = None;
+    // The combining class of the last character since that starter that did
+    // not compose with it, if any.
+    let mut last_class = None;
+    let mut len = 0;
+    for i in 0..chars.len() {
+        let c = chars[i];
+        let class = normalization::canonical_combining_class(c);
+        if let Some(starter) = starter {
+            // A character is blocked from the starter by any character in
+            // between with a class of zero or at least its own.
+            let blocked = last_class.is_some_and(|last| last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose(chars[starter], c) {
+                    chars[starter] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(len);
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        chars[len] = c;
+        len += 1;
+    }
+    chars.truncate(len);
+}
+
+#[cfg(not(test))]
+#[cfg(not(no_global_oom_handling))]
+fn compose(a: char, b: char) -> Option<char> {
+    let l_index = (a as u32).wrapping_sub(hangul::L_BASE);
+    let v_index = (b as u32).wrapping_sub(hangul::V_BASE);
+    if l_index < hangul::L_COUNT && v_index < hangul::V_COUNT {
+        let s = hangul::S_BASE + (l_index * hangul::V_COUNT + v_index) * hangul::T_COUNT;
+        // SAFETY: This is in the Hangul Syllables block.
+        return Some(unsafe { char::from_u32_unchecked(s) });
+    }
+    let s_index = (a as u32).wrapping_sub(hangul::S_BASE);
+    let t_index = (b as u32).wrapping_sub(hangul::T_BASE);
+    if s_index < hangul::S_COUNT
+        && s_index % hangul::T_COUNT == 0
+        && 0 < t_index
+        && t_index < hangul::T_COUNT
+    {
+        // SAFETY: This is in the Hangul Syllables block.
+        return Some(unsafe { char::from_u32_unchecked(a as u32 + t_index) });
+    }
+    normalization::compose(a, b)
+}
@@ -56,15 +56,23 @@
 #[cfg(not(no_global_oom_handling))]
 use core::cmp;
 use core::cmp::Ordering;
+#[cfg(not(no_global_oom_handling))]
+use core::convert::Infallible;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 #[cfg(not(no_global_oom_handling))]
+use core::io::AsyncWrite;
+#[cfg(not(no_global_oom_handling))]
 use core::iter;
 use core::marker::PhantomData;
 use core::mem::{self, ManuallyDrop, MaybeUninit, SizedTypeProperties};
 use core::ops::{self, Index, IndexMut, Range, RangeBounds};
+#[cfg(not(no_global_oom_handling))]
+use core::pin::Pin;
 use core::ptr::{self, NonNull};
 use core::slice::{self, SliceIndex};
+#[cfg(not(no_global_oom_handling))]
+use core::task::{Context, Poll};
 
 use crate::alloc::{Allocator, Global};
 use crate::borrow::{Cow, ToOwned};
@@ -3266,6 +3274,31 @@ unsafe fn extend_one_unchecked(&mut self, &item: &'a T) {
     }
 }
 
+/// Write is implemented for `Vec<u8>` by appending to the vector.
+/// The vector will grow as needed, so writes never return `Poll::Pending`.
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<A: Allocator + Unpin> AsyncWrite for Vec<u8, A> {
+    type Error = Infallible;
+
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Infallible>> {
+        self.get_mut().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
 /// Implements comparison of vectors, [lexicographically](Ord#lexicographical-comparison).
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, A1, A2> PartialOrd<Vec<T, A2>> for Vec<T, A1>
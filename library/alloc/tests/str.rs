@@ -925,6 +925,22 @@ fn from_utf8_mostly_ascii() {
     }
 }
 
+#[test]
+fn from_utf8_error_in_long_ascii() {
+    // the error is reported at the right place wherever the ascii fast path stops
+    for start in 0..8 {
+        for i in start..128 {
+            let mut data = [b'a'; 128];
+            data[i] = 0xFF;
+            let err = from_utf8(&data[start..]).unwrap_err();
+            assert_eq!(err.valid_up_to(), i - start);
+            data[i] = 0xC3;
+            data[127] = 0xA9;
+            assert_eq!(from_utf8(&data[start..]).is_ok(), i == 126);
+        }
+    }
+}
+
 #[test]
 fn const_from_utf8_mostly_ascii() {
     const _: () = {
@@ -1397,6 +1413,33 @@ fn test_splitn_char_iterator() {
     assert_eq!(split, ["\nM", "ry h", "d ", " little lämb\nLittle lämb\n"]);
 }
 
+#[test]
+fn test_split_ascii_char_array() {
+    let data = "Gira, la Tierra; y el Sol:\nné, \u{1F600};sí,fin.".repeat(5);
+    let is_sep2 = |c| c == ',' || c == ';';
+    let is_sep3 = |c| c == ',' || c == ';' || c == '\n';
+
+    let split: Vec<&str> = data.split([',', ';']).collect();
+    let expected: Vec<&str> = data.split(is_sep2).collect();
+    assert_eq!(split, expected);
+    let split: Vec<&str> = data.rsplit(&[',', ';']).collect();
+    let expected: Vec<&str> = data.rsplit(is_sep2).collect();
+    assert_eq!(split, expected);
+
+    let split: Vec<&str> = data.split(&[',', ';', '\n'][..]).collect();
+    let expected: Vec<&str> = data.split(is_sep3).collect();
+    assert_eq!(split, expected);
+    let split: Vec<&str> = data.rsplit([',', ';', '\n']).collect();
+    let expected: Vec<&str> = data.rsplit(is_sep3).collect();
+    assert_eq!(split, expected);
+
+    let mut iter = data.match_indices([',', ';']);
+    assert_eq!(iter.next(), Some((4, ",")));
+    assert_eq!(iter.next_back(), Some((data.len() - 5, ",")));
+    assert_eq!(data.find([';', '\n']), Some(15));
+    assert_eq!(data.rfind(['x', 'y']), Some(data.len() - 28));
+}
+
 #[test]
 fn test_split_char_iterator_no_trailing() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1872,6 +1915,78 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn to_nfd() {
+    assert_eq!("".to_nfd(), "");
+    assert_eq!("abc".to_nfd(), "abc");
+    assert_eq!("\u{e9}".to_nfd(), "e\u{301}");
+    // Decompositions are applied recursively.
+    assert_eq!("\u{1e69}".to_nfd(), "s\u{323}\u{307}");
+    assert_eq!("\u{212b}".to_nfd(), "A\u{30a}");
+    // Marks are sorted by combining class, keeping the order of equal classes.
+    assert_eq!("a\u{301}\u{316}\u{300}".to_nfd(), "a\u{316}\u{301}\u{300}");
+    assert_eq!("\u{1e0b}\u{323}".to_nfd(), "d\u{323}\u{307}");
+    assert_eq!("\u{ac00}\u{d55c}".to_nfd(), "\u{1100}\u{1161}\u{1112}\u{1161}\u{11ab}");
+}
+
+#[test]
+fn to_nfc() {
+    assert_eq!("".to_nfc(), "");
+    assert_eq!("abc".to_nfc(), "abc");
+    assert_eq!("e\u{301}".to_nfc(), "\u{e9}");
+    assert_eq!("\u{e9}".to_nfc(), "\u{e9}");
+    assert_eq!("\u{212b}".to_nfc(), "\u{c5}");
+    assert_eq!("s\u{307}\u{323}".to_nfc(), "\u{1e69}");
+    assert_eq!("\u{1e0b}\u{323}".to_nfc(), "\u{1e0d}\u{307}");
+    // A mark is not blocked by a mark of a lower class, but is by an equal one.
+    assert_eq!("a\u{316}\u{301}".to_nfc(), "\u{e1}\u{316}");
+    assert_eq!("a\u{305}\u{301}".to_nfc(), "a\u{305}\u{301}");
+    // Composition exclusions are never produced.
+    assert_eq!("\u{915}\u{93c}".to_nfc(), "\u{915}\u{93c}");
+    assert_eq!("\u{958}".to_nfc(), "\u{915}\u{93c}");
+    // Hangul syllables compose algorithmically.
+    assert_eq!("\u{1112}\u{1161}\u{11ab}".to_nfc(), "\u{d55c}");
+    assert_eq!("\u{1100}\u{1161}\u{11a7}".to_nfc(), "\u{ac00}\u{11a7}");
+}
+
+#[test]
+fn graphemes() {
+    fn graphemes(s: &str) -> Vec<&str> {
+        s.graphemes().collect()
+    }
+
+    assert_eq!(graphemes(""), Vec::<&str>::new());
+    assert_eq!(graphemes("abc"), ["a", "b", "c"]);
+    assert_eq!(graphemes("a\r\nb\n\r"), ["a", "\r\n", "b", "\n", "\r"]);
+    assert_eq!(graphemes("e\u{301}\u{316}x"), ["e\u{301}\u{316}", "x"]);
+    // Hangul jamo form syllables.
+    assert_eq!(
+        graphemes("\u{1112}\u{1161}\u{11ab}\u{ac00}"),
+        ["\u{1112}\u{1161}\u{11ab}", "\u{ac00}"]
+    );
+    assert_eq!(
+        graphemes("\u{1100}\u{ac00}\u{11a8}\u{ac01}\u{1161}"),
+        ["\u{1100}\u{ac00}\u{11a8}", "\u{ac01}", "\u{1161}"]
+    );
+    // Regional indicators pair up into flags.
+    assert_eq!(
+        graphemes("\u{1f1e9}\u{1f1ea}\u{1f1eb}\u{1f1f7}\u{1f1ee}"),
+        ["\u{1f1e9}\u{1f1ea}", "\u{1f1eb}\u{1f1f7}", "\u{1f1ee}"]
+    );
+    // Emoji ZWJ sequences stay together, but a lone ZWJ does not join.
+    assert_eq!(
+        graphemes("\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}!"),
+        ["\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", "!"]
+    );
+    assert_eq!(graphemes("a\u{200d}\u{1f52c}"), ["a\u{200d}", "\u{1f52c}"]);
+    // Indic conjuncts: consonant, virama, consonant.
+    assert_eq!(graphemes("\u{915}\u{94d}\u{937}\u{93f}"), ["\u{915}\u{94d}\u{937}\u{93f}"]);
+
+    let mut iter = "ab".graphemes();
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.as_str(), "b");
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
@@ -2714,3 +2714,17 @@ fn max_swap_remove() {
     let mut v = vec![0];
     v.swap_remove(usize::MAX);
 }
+
+#[test]
+fn test_async_write() {
+    use core::io::AsyncWrite;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut v = vec![1u8];
+    assert_eq!(Pin::new(&mut v).poll_write(&mut cx, &[2, 3]), Poll::Ready(Ok(2)));
+    assert_eq!(Pin::new(&mut v).poll_write(&mut cx, &[]), Poll::Ready(Ok(0)));
+    assert_eq!(Pin::new(&mut v).poll_flush(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(v, [1, 2, 3]);
+}
@@ -0,0 +1,13 @@
+//! Adapters that transform an [`AsyncIterator`](super::AsyncIterator) into
+//! another one, mirroring the adapters of [`Iterator`].
+
+mod filter;
+mod map;
+mod take;
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub use self::filter::Filter;
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub use self::map::Map;
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub use self::take::Take;
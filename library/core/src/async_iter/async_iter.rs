@@ -1,3 +1,4 @@
+use crate::async_iter::{Collect, Filter, Map, Next, Take};
 use crate::ops::DerefMut;
 use crate::pin::Pin;
 use crate::task::{Context, Poll};
@@ -80,6 +81,136 @@ pub trait AsyncIterator {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Returns a future that resolves to the next value of the async
+    /// iterator, or `None` when it is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, block_on)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::thread;
+    ///
+    /// let mut iter = async_iter::from_iter([1, 2]);
+    /// thread::block_on(async {
+    ///     assert_eq!(iter.next().await, Some(1));
+    ///     assert_eq!(iter.next().await, Some(2));
+    ///     assert_eq!(iter.next().await, None);
+    /// });
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Takes a closure and creates an async iterator which calls that closure
+    /// on each value.
+    ///
+    /// This is the asynchronous counterpart of [`Iterator::map`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, block_on)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::thread;
+    ///
+    /// let doubled = async_iter::from_iter([1, 2, 3]).map(|x| x * 2);
+    /// let v: Vec<i32> = thread::block_on(doubled.collect());
+    /// assert_eq!(v, [2, 4, 6]);
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map::new(self, f)
+    }
+
+    /// Creates an async iterator which uses a closure to determine if a value
+    /// should be yielded.
+    ///
+    /// This is the asynchronous counterpart of [`Iterator::filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, block_on)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::thread;
+    ///
+    /// let even = async_iter::from_iter(0..6).filter(|x| x % 2 == 0);
+    /// let v: Vec<i32> = thread::block_on(even.collect());
+    /// assert_eq!(v, [0, 2, 4]);
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Creates an async iterator that yields the first `n` values, or fewer
+    /// if the underlying async iterator ends sooner.
+    ///
+    /// This is the asynchronous counterpart of [`Iterator::take`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, block_on)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::thread;
+    ///
+    /// let first = async_iter::from_iter(1..).take(3);
+    /// let v: Vec<i32> = thread::block_on(first.collect());
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Returns a future that collects all values of the async iterator into a
+    /// collection.
+    ///
+    /// Unlike [`Iterator::collect`], the collection is built by extending a
+    /// [`Default`] value, so any collection implementing [`Extend`] can be
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, block_on)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::thread;
+    ///
+    /// let s: String = thread::block_on(async_iter::from_iter(['a', 'b', 'c']).collect());
+    /// assert_eq!(s, "abc");
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn collect<B>(self) -> Collect<Self, B>
+    where
+        Self: Sized,
+        B: Default + Extend<Self::Item>,
+    {
+        Collect::new(self)
+    }
 }
 
 #[unstable(feature = "async_iterator", issue = "79024")]
@@ -0,0 +1,92 @@
+//! Futures returned by the consuming methods of [`AsyncIterator`].
+
+use crate::async_iter::AsyncIterator;
+use crate::fmt;
+use crate::future::Future;
+use crate::mem;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// A future that resolves to the next value of an async iterator.
+///
+/// This `struct` is created by the [`next`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`next`]: AsyncIterator::next
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+#[derive(Debug)]
+pub struct Next<'a, I: ?Sized> {
+    iter: &'a mut I,
+}
+
+impl<'a, I: ?Sized> Next<'a, I> {
+    pub(super) fn new(iter: &'a mut I) -> Next<'a, I> {
+        Next { iter }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator + Unpin + ?Sized> Future for Next<'_, I> {
+    type Output = Option<I::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        Pin::new(&mut *self.iter).poll_next(cx)
+    }
+}
+
+/// A future that collects the values of an async iterator into a collection.
+///
+/// This `struct` is created by the [`collect`] method on [`AsyncIterator`].
+/// See its documentation for more.
+///
+/// [`collect`]: AsyncIterator::collect
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Collect<I, B> {
+    iter: I,
+    collection: B,
+}
+
+impl<I, B: Default> Collect<I, B> {
+    pub(super) fn new(iter: I) -> Collect<I, B> {
+        Collect { iter, collection: B::default() }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: fmt::Debug, B: fmt::Debug> fmt::Debug for Collect<I, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Collect")
+            .field("iter", &self.iter)
+            .field("collection", &self.collection)
+            .finish()
+    }
+}
+
+// `collection` is never pinned, so only `iter` needs to be `Unpin`.
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: Unpin, B> Unpin for Collect<I, B> {}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator, B> Future for Collect<I, B>
+where
+    B: Default + Extend<I::Item>,
+{
+    type Output = B;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<B> {
+        // SAFETY: `this` is only used to project to the fields below and is
+        // never moved out of.
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `iter` is structurally pinned and never moved out of `self`,
+        // while `collection` is never pinned.
+        let mut iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        loop {
+            match ready!(iter.as_mut().poll_next(cx)) {
+                Some(item) => this.collection.extend_one(item),
+                None => return Poll::Ready(mem::take(&mut this.collection)),
+            }
+        }
+    }
+}
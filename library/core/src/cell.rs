@@ -2267,12 +2267,32 @@ pub(crate) const fn primitive_into_inner(self) -> $primitive {
     u32 "32"
     i64 "64"
     u64 "64"
-    i128 "128"
-    u128 "128"
     isize "ptr"
     usize "ptr"
 }
 
+// 128-bit atomics are also available on x86_64 with `cmpxchg16b`, which
+// `target_has_atomic_load_store` doesn't reflect.
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+impl UnsafeCell<i128> {
+    pub(crate) const fn primitive_into_inner(self) -> i128 {
+        self.value
+    }
+}
+
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+impl UnsafeCell<u128> {
+    pub(crate) const fn primitive_into_inner(self) -> u128 {
+        self.value
+    }
+}
+
 #[cfg(target_has_atomic_load_store = "ptr")]
 impl<T> UnsafeCell<*mut T> {
     pub(crate) const fn primitive_into_inner(self) -> *mut T {
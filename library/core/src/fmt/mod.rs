@@ -1361,6 +1361,23 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
             if let Some(prefix) = prefix { f.buf.write_str(prefix) } else { Ok(()) }
         }
 
+        if cfg!(feature = "optimize_for_size") {
+            // A single path for every width and flag, without the fast paths
+            // below.
+            let padding = self.width.map_or(0, |min| min.saturating_sub(width));
+            if self.sign_aware_zero_pad() {
+                write_prefix(self, sign, prefix)?;
+                for _ in 0..padding {
+                    self.buf.write_char('0')?;
+                }
+                return self.buf.write_str(buf);
+            }
+            let post_padding = self.padding(padding, Alignment::Right)?;
+            write_prefix(self, sign, prefix)?;
+            self.buf.write_str(buf)?;
+            return post_padding.write(self);
+        }
+
         // The `width` field is more of a `min-width` parameter at this point.
         match self.width {
             // If there's no minimum length requirements then we can just
@@ -1428,6 +1445,19 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn pad(&mut self, s: &str) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            // A single path for every width and precision, without the fast
+            // paths below.
+            let s = match self.precision.and_then(|max| s.char_indices().nth(max)) {
+                Some((i, _)) => s.get(..i).unwrap_or(s),
+                None => s,
+            };
+            let padding = self.width.map_or(0, |min| min.saturating_sub(s.chars().count()));
+            let post_padding = self.padding(padding, Alignment::Left)?;
+            self.buf.write_str(s)?;
+            return post_padding.write(self);
+        }
+
         // Make sure there's a fast path up front
         if self.width.is_none() && self.precision.is_none() {
             return self.buf.write_str(s);
@@ -1977,6 +2007,9 @@ pub fn debug_struct_field1_finish<'b>(
         name1: &str,
         value1: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_struct_fields_finish(name, &[name1], &[value1]);
+        }
         let mut builder = builders::debug_struct_new(self, name);
         builder.field(name1, value1);
         builder.finish()
@@ -1994,6 +2027,9 @@ pub fn debug_struct_field2_finish<'b>(
         name2: &str,
         value2: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_struct_fields_finish(name, &[name1, name2], &[value1, value2]);
+        }
         let mut builder = builders::debug_struct_new(self, name);
         builder.field(name1, value1);
         builder.field(name2, value2);
@@ -2014,6 +2050,13 @@ pub fn debug_struct_field3_finish<'b>(
         name3: &str,
         value3: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_struct_fields_finish(
+                name,
+                &[name1, name2, name3],
+                &[value1, value2, value3],
+            );
+        }
         let mut builder = builders::debug_struct_new(self, name);
         builder.field(name1, value1);
         builder.field(name2, value2);
@@ -2037,6 +2080,13 @@ pub fn debug_struct_field4_finish<'b>(
         name4: &str,
         value4: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_struct_fields_finish(
+                name,
+                &[name1, name2, name3, name4],
+                &[value1, value2, value3, value4],
+            );
+        }
         let mut builder = builders::debug_struct_new(self, name);
         builder.field(name1, value1);
         builder.field(name2, value2);
@@ -2063,6 +2113,13 @@ pub fn debug_struct_field5_finish<'b>(
         name5: &str,
         value5: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_struct_fields_finish(
+                name,
+                &[name1, name2, name3, name4, name5],
+                &[value1, value2, value3, value4, value5],
+            );
+        }
         let mut builder = builders::debug_struct_new(self, name);
         builder.field(name1, value1);
         builder.field(name2, value2);
@@ -2126,6 +2183,9 @@ pub fn debug_tuple<'b>(&'b mut self, name: &str) -> DebugTuple<'b, 'a> {
     #[doc(hidden)]
     #[unstable(feature = "fmt_helpers_for_derive", issue = "none")]
     pub fn debug_tuple_field1_finish<'b>(&'b mut self, name: &str, value1: &dyn Debug) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_tuple_fields_finish(name, &[value1]);
+        }
         let mut builder = builders::debug_tuple_new(self, name);
         builder.field(value1);
         builder.finish()
@@ -2141,6 +2201,9 @@ pub fn debug_tuple_field2_finish<'b>(
         value1: &dyn Debug,
         value2: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_tuple_fields_finish(name, &[value1, value2]);
+        }
         let mut builder = builders::debug_tuple_new(self, name);
         builder.field(value1);
         builder.field(value2);
@@ -2158,6 +2221,9 @@ pub fn debug_tuple_field3_finish<'b>(
         value2: &dyn Debug,
         value3: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_tuple_fields_finish(name, &[value1, value2, value3]);
+        }
         let mut builder = builders::debug_tuple_new(self, name);
         builder.field(value1);
         builder.field(value2);
@@ -2177,6 +2243,9 @@ pub fn debug_tuple_field4_finish<'b>(
         value3: &dyn Debug,
         value4: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_tuple_fields_finish(name, &[value1, value2, value3, value4]);
+        }
         let mut builder = builders::debug_tuple_new(self, name);
         builder.field(value1);
         builder.field(value2);
@@ -2198,6 +2267,9 @@ pub fn debug_tuple_field5_finish<'b>(
         value4: &dyn Debug,
         value5: &dyn Debug,
     ) -> Result {
+        if cfg!(feature = "optimize_for_size") {
+            return self.debug_tuple_fields_finish(name, &[value1, value2, value3, value4, value5]);
+        }
         let mut builder = builders::debug_tuple_new(self, name);
         builder.field(value1);
         builder.field(value2);
@@ -15,7 +15,11 @@ trait DisplayInt:
     fn zero() -> Self;
     fn from_u8(u: u8) -> Self;
     fn to_u8(&self) -> u8;
-    #[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+    #[cfg(not(any(
+        target_pointer_width = "64",
+        target_arch = "wasm32",
+        feature = "optimize_for_size"
+    )))]
     fn to_u32(&self) -> u32;
     fn to_u64(&self) -> u64;
     fn to_u128(&self) -> u128;
@@ -27,7 +31,11 @@ macro_rules! impl_int {
           fn zero() -> Self { 0 }
           fn from_u8(u: u8) -> Self { u as Self }
           fn to_u8(&self) -> u8 { *self as u8 }
-          #[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+          #[cfg(not(any(
+              target_pointer_width = "64",
+              target_arch = "wasm32",
+              feature = "optimize_for_size"
+          )))]
           fn to_u32(&self) -> u32 { *self as u32 }
           fn to_u64(&self) -> u64 { *self as u64 }
           fn to_u128(&self) -> u128 { *self as u128 }
@@ -40,7 +48,11 @@ macro_rules! impl_uint {
           fn zero() -> Self { 0 }
           fn from_u8(u: u8) -> Self { u as Self }
           fn to_u8(&self) -> u8 { *self as u8 }
-          #[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+          #[cfg(not(any(
+              target_pointer_width = "64",
+              target_arch = "wasm32",
+              feature = "optimize_for_size"
+          )))]
           fn to_u32(&self) -> u32 { *self as u32 }
           fn to_u64(&self) -> u64 { *self as u64 }
           fn to_u128(&self) -> u128 { *self as u128 }
@@ -497,8 +509,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 // Include wasm32 in here since it doesn't reflect the native pointer size, and
-// often cares strongly about getting a smaller code size.
-#[cfg(any(target_pointer_width = "64", target_arch = "wasm32"))]
+// often cares strongly about getting a smaller code size. When optimizing for
+// size, all integers up to 64 bits share a single decimal loop on every target.
+#[cfg(any(target_pointer_width = "64", target_arch = "wasm32", feature = "optimize_for_size"))]
 mod imp {
     use super::*;
     impl_Display!(
@@ -511,7 +524,11 @@ mod imp {
     );
 }
 
-#[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
+#[cfg(not(any(
+    target_pointer_width = "64",
+    target_arch = "wasm32",
+    feature = "optimize_for_size"
+)))]
 mod imp {
     use super::*;
     impl_Display!(i8, u8, i16, u16, i32, u32, isize, usize as u32 via to_u32 named fmt_u32);
@@ -521,7 +538,12 @@ mod imp {
 }
 impl_Exp!(i128, u128 as u128 via to_u128 named exp_u128);
 
+// The specialized `u128` implementation below is fast, but large.
+#[cfg(feature = "optimize_for_size")]
+impl_Display!(i128, u128 as u128 via to_u128 named fmt_u128);
+
 /// Helper function for writing a u64 into `buf` going from last to first, with `curr`.
+#[cfg(not(feature = "optimize_for_size"))]
 fn parse_u64_into<const N: usize>(mut n: u64, buf: &mut [MaybeUninit<u8>; N], curr: &mut usize) {
     let buf_ptr = MaybeUninit::slice_as_mut_ptr(buf);
     let lut_ptr = DEC_DIGITS_LUT.as_ptr();
@@ -608,6 +630,7 @@ mod imp {
     }
 }
 
+#[cfg(not(feature = "optimize_for_size"))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl fmt::Display for u128 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -615,6 +638,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[cfg(not(feature = "optimize_for_size"))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl fmt::Display for i128 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -633,6 +657,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// into at most 2 u64s, and then chunks by 10e16, 10e8, 10e4, 10e2, and then 10e1.
 /// It also has to handle 1 last item, as 10^40 > 2^128 > 10^39, whereas
 /// 10^20 > 2^64 > 10^19.
+#[cfg(not(feature = "optimize_for_size"))]
 fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     // 2^128 is about 3*10^38, so 39 gives an extra byte of space
     let mut buf = [MaybeUninit::<u8>::uninit(); 39];
@@ -692,6 +717,7 @@ fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::R
 ///   in Proc. of the SIGPLAN94 Conference on Programming Language Design and
 ///   Implementation, 1994, pp. 61–72
 ///
+#[cfg(not(feature = "optimize_for_size"))]
 fn udiv_1e19(n: u128) -> (u128, u64) {
     const DIV: u64 = 1e19 as u64;
     const FACTOR: u128 = 156927543384667019095894735580191660403;
@@ -707,6 +733,7 @@ fn udiv_1e19(n: u128) -> (u128, u64) {
 }
 
 /// Multiply unsigned 128 bit integers, return upper 128 bits of the result
+#[cfg(not(feature = "optimize_for_size"))]
 #[inline]
 fn u128_mulhi(x: u128, y: u128) -> u128 {
     let x_lo = x as u64;
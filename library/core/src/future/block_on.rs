@@ -0,0 +1,88 @@
+use crate::future::{Future, IntoFuture};
+use crate::pin::pin;
+use crate::task::{Context, Poll, StaticWake, Waker};
+
+/// A hook that lets [`block_on`] suspend the current execution context until
+/// the future it is polling is woken.
+///
+/// Waking is done through the [`StaticWake`] supertrait, which plays the role
+/// of "unpark": the [`Waker`] passed to the future calls
+/// [`StaticWake::wake`] on the parker.
+///
+/// Implementations must behave like [`thread::park`] and
+/// [`Thread::unpark`]: `wake` makes a token available, and `park` consumes
+/// the token, returning immediately if it is already available. `park` may
+/// also return spuriously, in which case the future is simply polled again.
+///
+/// In a kernel, `park` might halt the CPU until the next interrupt, and
+/// `wake` might send an inter-processor interrupt.
+///
+/// [`thread::park`]: ../../std/thread/fn.park.html
+/// [`Thread::unpark`]: ../../std/thread/struct.Thread.html#method.unpark
+#[unstable(feature = "block_on", issue = "none")]
+pub trait Park: StaticWake {
+    /// Blocks until the parker is woken, or returns spuriously.
+    #[unstable(feature = "block_on", issue = "none")]
+    fn park(&'static self);
+}
+
+/// Polls a future to completion on the current execution context, using
+/// `parker` to wait between polls.
+///
+/// The future is polled with a [`Waker`] that wakes `parker`, and
+/// [`Park::park`] is called whenever the future returns [`Poll::Pending`].
+///
+/// This function does not allocate, which makes it usable in `no_std`
+/// environments. With the standard library, `std::thread::block_on` parks
+/// the current thread instead.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(block_on, static_waker)]
+///
+/// use std::future::{self, Park};
+/// use std::hint;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::task::StaticWake;
+///
+/// /// A parker that spins until it is woken.
+/// struct SpinParker {
+///     token: AtomicBool,
+/// }
+///
+/// impl StaticWake for SpinParker {
+///     fn wake(&'static self) {
+///         self.token.store(true, Ordering::Release);
+///     }
+/// }
+///
+/// impl Park for SpinParker {
+///     fn park(&'static self) {
+///         while !self.token.swap(false, Ordering::Acquire) {
+///             hint::spin_loop();
+///         }
+///     }
+/// }
+///
+/// static PARKER: SpinParker = SpinParker { token: AtomicBool::new(false) };
+///
+/// let value = future::block_on(async { 40 + 2 }, &PARKER);
+/// assert_eq!(value, 42);
+/// ```
+#[unstable(feature = "block_on", issue = "none")]
+pub fn block_on<F, P>(future: F, parker: &'static P) -> F::Output
+where
+    F: IntoFuture,
+    P: Park,
+{
+    let mut future = pin!(future.into_future());
+    let waker = Waker::from(parker);
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => parker.park(),
+        }
+    }
+}
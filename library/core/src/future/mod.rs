@@ -13,6 +13,7 @@
 use crate::task::Context;
 
 mod async_drop;
+mod block_on;
 mod future;
 mod into_future;
 mod join;
@@ -26,6 +27,9 @@
 #[unstable(feature = "future_join", issue = "91642")]
 pub use self::join::join;
 
+#[unstable(feature = "block_on", issue = "none")]
+pub use self::block_on::{block_on, Park};
+
 #[stable(feature = "into_future", since = "1.64.0")]
 pub use into_future::IntoFuture;
 
@@ -0,0 +1,220 @@
+//! A fast, non-cryptographic hash function.
+//!
+//! This is the multiply-and-rotate construction of the `FxHasher` used inside
+//! the compiler: every word of input is added to the state, which is then
+//! multiplied by a large odd constant. A final rotation moves the well-mixed
+//! high bits down, since hash tables typically use the low bits to pick a
+//! bucket.
+
+use crate::fmt;
+
+/// The multiplier used to mix each word into the state.
+///
+/// An odd constant with an irregular bit pattern, so that every input bit
+/// affects many high bits of the product.
+const K: u64 = 0xf135_7aea_2e62_a9c5;
+
+/// A fast, non-cryptographic [`Hasher`](super::Hasher).
+///
+/// `FastHasher` processes a word of input with a single addition and
+/// multiplication, which makes it several times faster than [`SipHasher`]
+/// for small keys such as integers, pointers or interned IDs.
+///
+/// # HashDoS
+///
+/// `FastHasher` offers **no protection against HashDoS attacks**. Even with a
+/// secret seed, an attacker who controls the keys of a hash table can easily
+/// produce many keys with the same hash, degrading lookups to linear time.
+/// Only use it for keys that are not chosen by an untrusted party; otherwise,
+/// use the default hasher of [`HashMap`].
+///
+/// The hashes are also not guaranteed to be the same across releases, so they
+/// should not be stored or sent elsewhere.
+///
+/// [`SipHasher`]: super::SipHasher
+/// [`HashMap`]: ../../std/collections/struct.HashMap.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fast_hash)]
+/// use std::hash::{FastHasher, Hasher};
+///
+/// let mut hasher = FastHasher::new();
+/// hasher.write_u32(42);
+/// let a = hasher.finish();
+///
+/// let mut hasher = FastHasher::new();
+/// hasher.write_u32(42);
+/// assert_eq!(a, hasher.finish());
+/// ```
+#[unstable(feature = "fast_hash", issue = "none")]
+#[derive(Clone)]
+pub struct FastHasher {
+    hash: u64,
+}
+
+impl FastHasher {
+    /// Creates a new `FastHasher` with a seed of zero.
+    #[unstable(feature = "fast_hash", issue = "none")]
+    #[rustc_const_unstable(feature = "fast_hash", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn new() -> FastHasher {
+        FastHasher::with_seed(0)
+    }
+
+    /// Creates a new `FastHasher` with the given seed.
+    ///
+    /// Hashers with different seeds produce different hashes for the same
+    /// input, but see the [type-level documentation](FastHasher#hashdos) for
+    /// why this does not make them safe to use with untrusted keys.
+    #[unstable(feature = "fast_hash", issue = "none")]
+    #[rustc_const_unstable(feature = "fast_hash", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> FastHasher {
+        FastHasher { hash: seed }
+    }
+
+    #[inline]
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = self.hash.wrapping_add(word).wrapping_mul(K);
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl Default for FastHasher {
+    #[inline]
+    fn default() -> FastHasher {
+        FastHasher::new()
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl fmt::Debug for FastHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FastHasher").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl super::Hasher for FastHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_to_hash(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        let mut rest = chunks.remainder();
+        if rest.len() >= 4 {
+            self.add_to_hash(u32::from_le_bytes(rest[..4].try_into().unwrap()) as u64);
+            rest = &rest[4..];
+        }
+        if rest.len() >= 2 {
+            self.add_to_hash(u16::from_le_bytes(rest[..2].try_into().unwrap()) as u64);
+            rest = &rest[2..];
+        }
+        if let Some(&byte) = rest.first() {
+            self.add_to_hash(byte as u64);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.add_to_hash(i as u64);
+        self.add_to_hash((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        // The multiplication leaves the best-mixed bits at the top, while hash
+        // tables mostly look at the bottom ones.
+        self.hash.rotate_left(26)
+    }
+}
+
+/// A deterministic [`BuildHasher`](super::BuildHasher) for [`FastHasher`].
+///
+/// Every `FixedState` with the same seed creates hashers that produce the
+/// same hashes, on every run of the program. This makes the iteration order
+/// of hash maps that use it reproducible, which is useful for deterministic
+/// builds and tests.
+///
+/// Since the seed is not secret, this is even more exposed to HashDoS attacks
+/// than [`FastHasher`] on its own: never use it for keys that are chosen by an
+/// untrusted party.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fast_hash)]
+/// use std::collections::HashMap;
+/// use std::hash::FixedState;
+///
+/// let mut map = HashMap::with_hasher(FixedState::new());
+/// map.insert(1, "one");
+/// map.insert(2, "two");
+/// assert_eq!(map[&2], "two");
+/// ```
+#[unstable(feature = "fast_hash", issue = "none")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixedState {
+    seed: u64,
+}
+
+impl FixedState {
+    /// Creates a new `FixedState` with a seed of zero.
+    #[unstable(feature = "fast_hash", issue = "none")]
+    #[rustc_const_unstable(feature = "fast_hash", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn new() -> FixedState {
+        FixedState::with_seed(0)
+    }
+
+    /// Creates a new `FixedState` whose hashers use the given seed.
+    #[unstable(feature = "fast_hash", issue = "none")]
+    #[rustc_const_unstable(feature = "fast_hash", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> FixedState {
+        FixedState { seed }
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl super::BuildHasher for FixedState {
+    type Hasher = FastHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> FastHasher {
+        FastHasher::with_seed(self.seed)
+    }
+}
@@ -95,6 +95,10 @@
 #[doc(hidden)]
 pub use self::sip::SipHasher13;
 
+#[unstable(feature = "fast_hash", issue = "none")]
+pub use self::fast::{FastHasher, FixedState};
+
+mod fast;
 mod sip;
 
 /// A hashable type.
@@ -0,0 +1,247 @@
+use crate::convert::Infallible;
+use crate::io::BorrowedCursor;
+use crate::mem;
+use crate::ops::DerefMut;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Read bytes asynchronously.
+///
+/// This is the asynchronous counterpart of `std::io::Read`. Instead of
+/// blocking, [`poll_read`] returns [`Poll::Pending`] and arranges for the
+/// current task to be woken when more data may be available.
+///
+/// Since `core` has no I/O error type, each implementation chooses its own
+/// [`Error`] type. Readers that cannot fail, such as the one for `&[u8]`,
+/// use [`Infallible`].
+///
+/// [`poll_read`]: AsyncRead::poll_read
+/// [`Error`]: AsyncRead::Error
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_io_async, core_io_borrowed_buf, noop_waker)]
+///
+/// use std::io::{AsyncRead, BorrowedBuf};
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// let mut reader: &[u8] = b"hello";
+/// let mut storage = [0; 3];
+/// let mut buf = BorrowedBuf::from(&mut storage[..]);
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let poll = Pin::new(&mut reader).poll_read(&mut cx, buf.unfilled());
+/// assert_eq!(poll, Poll::Ready(Ok(())));
+/// assert_eq!(buf.filled(), b"hel");
+/// assert_eq!(reader, b"lo");
+/// ```
+#[unstable(feature = "core_io_async", issue = "none")]
+pub trait AsyncRead {
+    /// The type of errors returned when reading fails.
+    #[unstable(feature = "core_io_async", issue = "none")]
+    type Error;
+
+    /// Attempts to pull some bytes from this source into the cursor.
+    ///
+    /// On success, returns `Poll::Ready(Ok(()))` after appending data to
+    /// `buf`. If no bytes were appended while `buf` had spare capacity, the
+    /// reader has reached its "end of file" and will likely no longer be able
+    /// to produce bytes.
+    ///
+    /// If no data is available for reading, the method returns
+    /// `Poll::Pending` and arranges for the current task to receive a
+    /// notification when the object becomes readable or is closed.
+    ///
+    /// As with `std::io::Read::read_buf`, data in `buf` that was already
+    /// filled is never touched, and implementations should append to it
+    /// with [`BorrowedCursor::append`] or by writing and then calling
+    /// [`BorrowedCursor::advance`].
+    #[unstable(feature = "core_io_async", issue = "none")]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: BorrowedCursor<'_>,
+    ) -> Poll<Result<(), Self::Error>>;
+}
+
+/// Write bytes asynchronously.
+///
+/// This is the asynchronous counterpart of `std::io::Write`. Instead of
+/// blocking, each method returns [`Poll::Pending`] and arranges for the
+/// current task to be woken when the operation may make progress.
+///
+/// Since `core` has no I/O error type, each implementation chooses its own
+/// [`Error`] type. Writers that cannot fail, such as the one for `&mut [u8]`,
+/// use [`Infallible`].
+///
+/// [`Error`]: AsyncWrite::Error
+#[unstable(feature = "core_io_async", issue = "none")]
+pub trait AsyncWrite {
+    /// The type of errors returned when writing fails.
+    #[unstable(feature = "core_io_async", issue = "none")]
+    type Error;
+
+    /// Attempts to write bytes from `buf` into the object.
+    ///
+    /// On success, returns `Poll::Ready(Ok(n))`, where `n` is the number of
+    /// bytes written from the start of `buf`. A return value of `0` for a
+    /// non-empty `buf` typically means that the underlying object is no longer
+    /// able to accept bytes.
+    ///
+    /// If the object is not ready for writing, the method returns
+    /// `Poll::Pending` and arranges for the current task to receive a
+    /// notification when the object becomes writable or is closed.
+    #[unstable(feature = "core_io_async", issue = "none")]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Self::Error>>;
+
+    /// Attempts to flush the object, ensuring that any buffered data reaches
+    /// its destination.
+    ///
+    /// If the flush cannot complete immediately, the method returns
+    /// `Poll::Pending` and arranges for the current task to receive a
+    /// notification when it may make progress.
+    #[unstable(feature = "core_io_async", issue = "none")]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+
+    /// Attempts to close the object, flushing any buffered data first.
+    ///
+    /// Once `poll_close` has returned `Poll::Ready(Ok(()))`, the object
+    /// should not be written to again.
+    #[unstable(feature = "core_io_async", issue = "none")]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+}
+
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<R: ?Sized + AsyncRead + Unpin> AsyncRead for &mut R {
+    type Error = R::Error;
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: BorrowedCursor<'_>,
+    ) -> Poll<Result<(), R::Error>> {
+        R::poll_read(Pin::new(&mut **self), cx, buf)
+    }
+}
+
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<P> AsyncRead for Pin<P>
+where
+    P: DerefMut,
+    P::Target: AsyncRead,
+{
+    type Error = <P::Target as AsyncRead>::Error;
+
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: BorrowedCursor<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        <P::Target as AsyncRead>::poll_read(self.as_deref_mut(), cx, buf)
+    }
+}
+
+/// Read is implemented for `&[u8]` by copying from the slice.
+///
+/// Note that reading updates the slice to point to the yet unread part.
+/// The slice will be empty when EOF is reached.
+#[unstable(feature = "core_io_async", issue = "none")]
+impl AsyncRead for &[u8] {
+    type Error = Infallible;
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        mut buf: BorrowedCursor<'_>,
+    ) -> Poll<Result<(), Infallible>> {
+        let data: &[u8] = *self;
+        let amt = data.len().min(buf.capacity());
+        let (a, b) = data.split_at(amt);
+        buf.append(a);
+        *self = b;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<W: ?Sized + AsyncWrite + Unpin> AsyncWrite for &mut W {
+    type Error = W::Error;
+
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, W::Error>> {
+        W::poll_write(Pin::new(&mut **self), cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        W::poll_flush(Pin::new(&mut **self), cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        W::poll_close(Pin::new(&mut **self), cx)
+    }
+}
+
+#[unstable(feature = "core_io_async", issue = "none")]
+impl<P> AsyncWrite for Pin<P>
+where
+    P: DerefMut,
+    P::Target: AsyncWrite,
+{
+    type Error = <P::Target as AsyncWrite>::Error;
+
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Self::Error>> {
+        <P::Target as AsyncWrite>::poll_write(self.as_deref_mut(), cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        <P::Target as AsyncWrite>::poll_flush(self.as_deref_mut(), cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        <P::Target as AsyncWrite>::poll_close(self.as_deref_mut(), cx)
+    }
+}
+
+/// Write is implemented for `&mut [u8]` by copying into the slice,
+/// overwriting its data.
+///
+/// Note that writing updates the slice to point to the yet unwritten part.
+/// The slice will be empty when it has been completely overwritten, after
+/// which writes return `Ok(0)`.
+#[unstable(feature = "core_io_async", issue = "none")]
+impl AsyncWrite for &mut [u8] {
+    type Error = Infallible;
+
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        data: &[u8],
+    ) -> Poll<Result<usize, Infallible>> {
+        let amt = data.len().min(self.len());
+        let (a, b) = mem::take(&mut *self).split_at_mut(amt);
+        a.copy_from_slice(&data[..amt]);
+        *self = b;
+        Poll::Ready(Ok(amt))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+}
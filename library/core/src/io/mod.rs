@@ -1,6 +1,9 @@
 //! Traits, helpers, and type definitions for core I/O functionality.
 
+mod async_rw;
 mod borrowed_buf;
 
+#[unstable(feature = "core_io_async", issue = "none")]
+pub use self::async_rw::{AsyncRead, AsyncWrite};
 #[unstable(feature = "core_io_borrowed_buf", issue = "117693")]
 pub use self::borrowed_buf::{BorrowedBuf, BorrowedCursor};
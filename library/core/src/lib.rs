@@ -108,6 +108,8 @@
 // tidy-alphabetical-start
 #![feature(array_ptr_get)]
 #![feature(asm_experimental_arch)]
+#![feature(bigint)]
+#![feature(bigint_helper_methods)]
 #![feature(char_indices_offset)]
 #![feature(const_align_of_val)]
 #![feature(const_align_of_val_raw)]
@@ -167,8 +169,10 @@
 #![feature(const_waker)]
 #![feature(coverage_attribute)]
 #![feature(duration_consts_float)]
+#![feature(fast_hash)]
 #![feature(internal_impls_macro)]
 #![feature(ip)]
+#![feature(ip_prefix)]
 #![feature(is_ascii_octdigit)]
 #![feature(isqrt)]
 #![feature(link_cfg)]
@@ -189,6 +193,7 @@
 #![feature(utf16_extra)]
 #![feature(utf16_extra_const)]
 #![feature(variant_count)]
+#![feature(volatile_ptr)]
 // tidy-alphabetical-end
 //
 // Language features:
@@ -0,0 +1,536 @@
+use super::display_buffer::DisplayBuffer;
+use crate::fmt::{self, Write};
+use crate::iter::FusedIterator;
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use crate::ops::RangeInclusive;
+
+/// An IP network prefix, either IPv4 or IPv6.
+///
+/// See [`Ipv4Prefix`] and [`Ipv6Prefix`] for details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_prefix)]
+/// use std::net::{IpAddr, IpPrefix, Ipv4Addr};
+///
+/// let prefix: IpPrefix = "10.0.0.0/8".parse().unwrap();
+/// assert!(prefix.contains(IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))));
+/// assert!(!prefix.contains("::1".parse().unwrap()));
+/// assert_eq!(prefix.to_string(), "10.0.0.0/8");
+/// ```
+#[unstable(feature = "ip_prefix", issue = "none")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum IpPrefix {
+    /// An IPv4 prefix.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    V4(#[unstable(feature = "ip_prefix", issue = "none")] Ipv4Prefix),
+    /// An IPv6 prefix.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    V6(#[unstable(feature = "ip_prefix", issue = "none")] Ipv6Prefix),
+}
+
+/// An IPv4 network prefix, such as `192.168.0.0/16`, in CIDR notation.
+///
+/// A prefix is an address together with a prefix length: the number of
+/// leading bits of the address that identify the network. The remaining
+/// bits identify a host within the network, and are kept as they were given,
+/// so `192.168.1.1/24` describes the address `192.168.1.1` in the network
+/// `192.168.1.0/24`. Use [`network`] to clear them.
+///
+/// [`network`]: Ipv4Prefix::network
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_prefix)]
+/// use std::net::{Ipv4Addr, Ipv4Prefix};
+///
+/// let prefix: Ipv4Prefix = "192.168.1.1/24".parse().unwrap();
+/// assert_eq!(prefix.network(), Ipv4Prefix::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap());
+/// assert_eq!(prefix.broadcast(), Ipv4Addr::new(192, 168, 1, 255));
+/// assert!(prefix.contains(Ipv4Addr::new(192, 168, 1, 200)));
+/// assert_eq!(prefix.hosts().count(), 254);
+/// ```
+#[unstable(feature = "ip_prefix", issue = "none")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Ipv4Prefix {
+    addr: Ipv4Addr,
+    len: u8,
+}
+
+/// An IPv6 network prefix, such as `2001:db8::/32`, in CIDR notation.
+///
+/// See [`Ipv4Prefix`] for details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_prefix)]
+/// use std::net::{Ipv6Addr, Ipv6Prefix};
+///
+/// let prefix: Ipv6Prefix = "2001:db8::/32".parse().unwrap();
+/// assert!(prefix.contains(Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6)));
+/// assert_eq!(prefix.subnets(34).unwrap().count(), 4);
+/// ```
+#[unstable(feature = "ip_prefix", issue = "none")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Ipv6Prefix {
+    addr: Ipv6Addr,
+    len: u8,
+}
+
+impl IpPrefix {
+    /// Creates a prefix from an address and a prefix length.
+    ///
+    /// Returns [`None`] if `len` is larger than the number of bits of the
+    /// address.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn new(addr: IpAddr, len: u8) -> Option<IpPrefix> {
+        match addr {
+            IpAddr::V4(addr) => match Ipv4Prefix::new(addr, len) {
+                Some(prefix) => Some(IpPrefix::V4(prefix)),
+                None => None,
+            },
+            IpAddr::V6(addr) => match Ipv6Prefix::new(addr, len) {
+                Some(prefix) => Some(IpPrefix::V6(prefix)),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns the address of this prefix, including its host bits.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn addr(&self) -> IpAddr {
+        match self {
+            IpPrefix::V4(prefix) => IpAddr::V4(prefix.addr()),
+            IpPrefix::V6(prefix) => IpAddr::V6(prefix.addr()),
+        }
+    }
+
+    /// Returns the prefix length.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        match self {
+            IpPrefix::V4(prefix) => prefix.prefix_len(),
+            IpPrefix::V6(prefix) => prefix.prefix_len(),
+        }
+    }
+
+    /// Returns the prefix with its host bits cleared.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn network(&self) -> IpPrefix {
+        match self {
+            IpPrefix::V4(prefix) => IpPrefix::V4(prefix.network()),
+            IpPrefix::V6(prefix) => IpPrefix::V6(prefix.network()),
+        }
+    }
+
+    /// Returns `true` if `addr` is in the network of this prefix.
+    ///
+    /// An IPv4 address is never contained in an IPv6 prefix, nor the other
+    /// way around.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, addr: IpAddr) -> bool {
+        match (self, addr) {
+            (IpPrefix::V4(prefix), IpAddr::V4(addr)) => prefix.contains(addr),
+            (IpPrefix::V6(prefix), IpAddr::V6(addr)) => prefix.contains(addr),
+            _ => false,
+        }
+    }
+
+    /// Returns the prefix one bit shorter, or [`None`] if the prefix length
+    /// is zero.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn supernet(&self) -> Option<IpPrefix> {
+        match self {
+            IpPrefix::V4(prefix) => match prefix.supernet() {
+                Some(prefix) => Some(IpPrefix::V4(prefix)),
+                None => None,
+            },
+            IpPrefix::V6(prefix) => match prefix.supernet() {
+                Some(prefix) => Some(IpPrefix::V6(prefix)),
+                None => None,
+            },
+        }
+    }
+}
+
+macro_rules! prefix_impl {
+    (
+        $variant:ident, $prefix:ident, $addr:ident, $bits:ty, $max_len:literal,
+        $hosts:ident, $subnets:ident,
+        $example_prefix:literal, $example_addr:literal, $example_other:literal
+    ) => {
+        impl $prefix {
+            /// Creates a prefix from an address and a prefix length.
+            ///
+            /// Returns [`None`] if `len` is larger than the number of bits of
+            /// the address.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(ip_prefix)]
+            #[doc = concat!("use std::net::{", stringify!($addr), ", ", stringify!($prefix), "};")]
+            ///
+            #[doc = concat!(
+                "let addr: ", stringify!($addr), " = \"", $example_addr, "\".parse().unwrap();"
+            )]
+            #[doc = concat!(
+                "assert!(", stringify!($prefix), "::new(addr, ", $max_len, ").is_some());"
+            )]
+            #[doc = concat!(
+                "assert!(", stringify!($prefix), "::new(addr, ", $max_len, " + 1).is_none());"
+            )]
+            /// ```
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn new(addr: $addr, len: u8) -> Option<$prefix> {
+                if len as u32 <= <$bits>::BITS { Some($prefix { addr, len }) } else { None }
+            }
+
+            /// Returns the address of this prefix, including its host bits.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn addr(&self) -> $addr {
+                self.addr
+            }
+
+            /// Returns the prefix length.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn prefix_len(&self) -> u8 {
+                self.len
+            }
+
+            /// Returns the network mask: an address with the first
+            /// [`prefix_len`](Self::prefix_len) bits set.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn netmask(&self) -> $addr {
+                $addr::from_bits(self.mask())
+            }
+
+            /// Returns the host mask: an address with all bits after the first
+            /// [`prefix_len`](Self::prefix_len) bits set.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn hostmask(&self) -> $addr {
+                $addr::from_bits(!self.mask())
+            }
+
+            /// Returns the prefix with its host bits cleared.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(ip_prefix)]
+            #[doc = concat!("use std::net::", stringify!($prefix), ";")]
+            ///
+            #[doc = concat!(
+                "let prefix: ", stringify!($prefix), " = \"", $example_prefix,
+                "\".parse().unwrap();"
+            )]
+            #[doc = concat!("assert_eq!(prefix.network().to_string(), \"", $example_other, "\");")]
+            /// ```
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn network(&self) -> $prefix {
+                $prefix { addr: $addr::from_bits(self.first()), len: self.len }
+            }
+
+            /// Returns `true` if `addr` is in the network of this prefix, that
+            /// is, if its first [`prefix_len`](Self::prefix_len) bits are the
+            /// same as those of the prefix.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn contains(&self, addr: $addr) -> bool {
+                addr.to_bits() & self.mask() == self.first()
+            }
+
+            /// Returns `true` if the network of `other` is the same as, or
+            /// part of, the network of this prefix.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn contains_prefix(&self, other: &$prefix) -> bool {
+                other.len >= self.len && self.contains(other.addr)
+            }
+
+            /// Returns the network prefix one bit shorter, which contains this
+            /// one, or [`None`] if the prefix length is zero.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn supernet(&self) -> Option<$prefix> {
+                if self.len == 0 {
+                    return None;
+                }
+                let supernet = $prefix { addr: self.addr, len: self.len - 1 };
+                Some(supernet.network())
+            }
+
+            /// Returns an iterator over the networks of length `len` that this
+            /// network is divided into, or [`None`] if `len` is shorter than the
+            /// prefix length or longer than the address.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(ip_prefix)]
+            #[doc = concat!("use std::net::", stringify!($prefix), ";")]
+            ///
+            #[doc = concat!(
+                "let prefix: ", stringify!($prefix), " = \"", $example_other,
+                "\".parse().unwrap();"
+            )]
+            /// let mut subnets = prefix.subnets(prefix.prefix_len() + 1).unwrap();
+            /// assert_eq!(subnets.next().unwrap().supernet(), Some(prefix));
+            /// assert_eq!(subnets.next().unwrap().supernet(), Some(prefix));
+            /// assert_eq!(subnets.next(), None);
+            /// ```
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub fn subnets(&self, len: u8) -> Option<$subnets> {
+                let subnet = $prefix::new(self.addr, len)?;
+                if len < self.len {
+                    return None;
+                }
+                Some($subnets {
+                    next: self.first(),
+                    last: self.last() & subnet.mask(),
+                    len,
+                    done: false,
+                })
+            }
+
+            /// Returns an iterator over the usable host addresses in the
+            /// network.
+            ///
+            /// This is every address of the network, except for the first and
+            /// the last ones in IPv4 networks with a prefix length of 30 or less,
+            /// which are reserved for the network and broadcast addresses.
+            #[unstable(feature = "ip_prefix", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub fn hosts(&self) -> $hosts {
+                let (first, last) = (self.first(), self.last());
+                let reserved = <$bits>::BITS == 32 && self.len <= 30;
+                let range = if reserved { first + 1..=last - 1 } else { first..=last };
+                $hosts { range }
+            }
+
+            const fn mask(&self) -> $bits {
+                // A shift by the full width of the integer would overflow.
+                match <$bits>::MAX.checked_shl(<$bits>::BITS - self.len as u32) {
+                    Some(mask) => mask,
+                    None => 0,
+                }
+            }
+
+            const fn first(&self) -> $bits {
+                self.addr.to_bits() & self.mask()
+            }
+
+            const fn last(&self) -> $bits {
+                self.addr.to_bits() | !self.mask()
+            }
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl fmt::Debug for $prefix {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, fmt)
+            }
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl From<$prefix> for IpPrefix {
+            #[inline]
+            fn from(prefix: $prefix) -> IpPrefix {
+                IpPrefix::$variant(prefix)
+            }
+        }
+
+        /// An iterator over the host addresses of a network.
+        ///
+        #[doc = concat!("This struct is created by [`", stringify!($prefix), "::hosts`].")]
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        #[derive(Clone, Debug)]
+        pub struct $hosts {
+            range: RangeInclusive<$bits>,
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl Iterator for $hosts {
+            type Item = $addr;
+
+            #[inline]
+            fn next(&mut self) -> Option<$addr> {
+                self.range.next().map($addr::from_bits)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.range.size_hint()
+            }
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl DoubleEndedIterator for $hosts {
+            #[inline]
+            fn next_back(&mut self) -> Option<$addr> {
+                self.range.next_back().map($addr::from_bits)
+            }
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl FusedIterator for $hosts {}
+
+        /// An iterator over the subnets of a network.
+        ///
+        #[doc = concat!("This struct is created by [`", stringify!($prefix), "::subnets`].")]
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        #[derive(Clone, Debug)]
+        pub struct $subnets {
+            next: $bits,
+            last: $bits,
+            len: u8,
+            done: bool,
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl Iterator for $subnets {
+            type Item = $prefix;
+
+            fn next(&mut self) -> Option<$prefix> {
+                if self.done {
+                    return None;
+                }
+                let addr = self.next;
+                if addr == self.last {
+                    self.done = true;
+                } else {
+                    // `len` is not zero, since there would be a single subnet
+                    // otherwise.
+                    self.next = addr + (1 << (<$bits>::BITS - self.len as u32));
+                }
+                Some($prefix { addr: $addr::from_bits(addr), len: self.len })
+            }
+        }
+
+        #[unstable(feature = "ip_prefix", issue = "none")]
+        impl FusedIterator for $subnets {}
+    };
+}
+
+prefix_impl! {
+    V4, Ipv4Prefix, Ipv4Addr, u32, "32", Ipv4Hosts, Ipv4Subnets,
+    "192.168.1.1/24", "192.168.1.1", "192.168.1.0/24"
+}
+prefix_impl! {
+    V6, Ipv6Prefix, Ipv6Addr, u128, "128", Ipv6Hosts, Ipv6Subnets,
+    "2001:db8::1/64", "2001:db8::1", "2001:db8::/64"
+}
+
+impl Ipv4Prefix {
+    /// Returns the broadcast address of the network: its last address.
+    #[unstable(feature = "ip_prefix", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_prefix", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn broadcast(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(self.last())
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl fmt::Display for IpPrefix {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpPrefix::V4(prefix) => prefix.fmt(fmt),
+            IpPrefix::V6(prefix) => prefix.fmt(fmt),
+        }
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl fmt::Debug for IpPrefix {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl fmt::Display for Ipv4Prefix {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there are no alignment requirements, write the prefix directly to `f`.
+        // Otherwise, write it to a local buffer and then use `f.pad`.
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.len)
+        } else {
+            const LONGEST_IPV4_PREFIX: &str = "255.255.255.255/32";
+
+            let mut buf = DisplayBuffer::<{ LONGEST_IPV4_PREFIX.len() }>::new();
+            // Buffer is long enough for the longest possible IPv4 prefix, so this never fails.
+            write!(buf, "{}/{}", self.addr, self.len).unwrap();
+
+            fmt.pad(buf.as_str())
+        }
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl fmt::Display for Ipv6Prefix {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there are no alignment requirements, write the prefix directly to `f`.
+        // Otherwise, write it to a local buffer and then use `f.pad`.
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.len)
+        } else {
+            const LONGEST_IPV6_PREFIX: &str = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128";
+
+            let mut buf = DisplayBuffer::<{ LONGEST_IPV6_PREFIX.len() }>::new();
+            // Buffer is long enough for the longest possible IPv6 prefix, so this never fails.
+            write!(buf, "{}/{}", self.addr, self.len).unwrap();
+
+            fmt.pad(buf.as_str())
+        }
+    }
+}
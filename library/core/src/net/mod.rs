@@ -8,11 +8,17 @@
 //!   [`Ipv6Addr`] are respectively IPv4 and IPv6 addresses
 //! * [`SocketAddr`] represents socket addresses of either IPv4 or IPv6; [`SocketAddrV4`]
 //!   and [`SocketAddrV6`] are respectively IPv4 and IPv6 socket addresses
+//! * [`IpPrefix`] represents network prefixes of either IPv4 or IPv6, such as `10.0.0.0/8`;
+//!   [`Ipv4Prefix`] and [`Ipv6Prefix`] are respectively IPv4 and IPv6 prefixes
 
 #![stable(feature = "ip_in_core", since = "1.77.0")]
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::ip_addr::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
+#[unstable(feature = "ip_prefix", issue = "none")]
+pub use self::ip_prefix::{
+    IpPrefix, Ipv4Hosts, Ipv4Prefix, Ipv4Subnets, Ipv6Hosts, Ipv6Prefix, Ipv6Subnets,
+};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::parser::AddrParseError;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -20,5 +26,6 @@
 
 mod display_buffer;
 mod ip_addr;
+mod ip_prefix;
 mod parser;
 mod socket_addr;
@@ -5,7 +5,10 @@
 
 use crate::error::Error;
 use crate::fmt;
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    IpAddr, IpPrefix, Ipv4Addr, Ipv4Prefix, Ipv6Addr, Ipv6Prefix, SocketAddr, SocketAddrV4,
+    SocketAddrV6,
+};
 use crate::str::FromStr;
 
 trait ReadNumberHelper: Sized {
@@ -298,6 +301,39 @@ fn read_socket_addr(&mut self) -> Option<SocketAddr> {
             .map(SocketAddr::V4)
             .or_else(|| self.read_socket_addr_v6().map(SocketAddr::V6))
     }
+
+    /// Read a `/` followed by a prefix length in base 10.
+    fn read_prefix_len(&mut self) -> Option<u8> {
+        self.read_atomically(|p| {
+            p.read_given_char('/')?;
+            p.read_number(10, Some(3), false)
+        })
+    }
+
+    /// Read an IPv4 address with a prefix length.
+    fn read_ipv4_prefix(&mut self) -> Option<Ipv4Prefix> {
+        self.read_atomically(|p| {
+            let ip = p.read_ipv4_addr()?;
+            let len = p.read_prefix_len()?;
+            Ipv4Prefix::new(ip, len)
+        })
+    }
+
+    /// Read an IPv6 address with a prefix length.
+    fn read_ipv6_prefix(&mut self) -> Option<Ipv6Prefix> {
+        self.read_atomically(|p| {
+            let ip = p.read_ipv6_addr()?;
+            let len = p.read_prefix_len()?;
+            Ipv6Prefix::new(ip, len)
+        })
+    }
+
+    /// Read an IP address with a prefix length, either IPv4 or IPv6.
+    fn read_ip_prefix(&mut self) -> Option<IpPrefix> {
+        self.read_ipv4_prefix()
+            .map(IpPrefix::V4)
+            .or_else(|| self.read_ipv6_prefix().map(IpPrefix::V6))
+    }
 }
 
 impl IpAddr {
@@ -465,6 +501,86 @@ fn from_str(s: &str) -> Result<SocketAddr, AddrParseError> {
     }
 }
 
+impl IpPrefix {
+    /// Parse an IP prefix from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_prefix)]
+    ///
+    /// use std::net::{IpPrefix, Ipv4Addr, Ipv4Prefix, Ipv6Addr, Ipv6Prefix};
+    ///
+    /// let prefix_v4 = Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    /// let prefix_v6 = Ipv6Prefix::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10).unwrap();
+    ///
+    /// assert_eq!(IpPrefix::parse_ascii(b"10.0.0.0/8"), Ok(IpPrefix::V4(prefix_v4)));
+    /// assert_eq!(IpPrefix::parse_ascii(b"fe80::/10"), Ok(IpPrefix::V6(prefix_v6)));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        Parser::new(b).parse_with(|p| p.read_ip_prefix(), AddrKind::IpPrefix)
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl FromStr for IpPrefix {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<IpPrefix, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
+impl Ipv4Prefix {
+    /// Parse an IPv4 prefix from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_prefix)]
+    ///
+    /// use std::net::{Ipv4Addr, Ipv4Prefix};
+    ///
+    /// let prefix = Ipv4Prefix::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+    ///
+    /// assert_eq!(Ipv4Prefix::parse_ascii(b"192.168.0.0/16"), Ok(prefix));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        Parser::new(b).parse_with(|p| p.read_ipv4_prefix(), AddrKind::Ipv4Prefix)
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl FromStr for Ipv4Prefix {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv4Prefix, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
+impl Ipv6Prefix {
+    /// Parse an IPv6 prefix from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_prefix)]
+    ///
+    /// use std::net::{Ipv6Addr, Ipv6Prefix};
+    ///
+    /// let prefix = Ipv6Prefix::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    ///
+    /// assert_eq!(Ipv6Prefix::parse_ascii(b"2001:db8::/32"), Ok(prefix));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        Parser::new(b).parse_with(|p| p.read_ipv6_prefix(), AddrKind::Ipv6Prefix)
+    }
+}
+
+#[unstable(feature = "ip_prefix", issue = "none")]
+impl FromStr for Ipv6Prefix {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv6Prefix, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum AddrKind {
     Ip,
@@ -473,13 +589,16 @@ enum AddrKind {
     Socket,
     SocketV4,
     SocketV6,
+    IpPrefix,
+    Ipv4Prefix,
+    Ipv6Prefix,
 }
 
-/// An error which can be returned when parsing an IP address or a socket address.
+/// An error which can be returned when parsing an IP address, a socket address or an IP prefix.
 ///
 /// This error is used as the error type for the [`FromStr`] implementation for
-/// [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`], [`SocketAddr`], [`SocketAddrV4`], and
-/// [`SocketAddrV6`].
+/// [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`], [`SocketAddr`], [`SocketAddrV4`],
+/// [`SocketAddrV6`], [`IpPrefix`], [`Ipv4Prefix`], and [`Ipv6Prefix`].
 ///
 /// # Potential causes
 ///
@@ -522,6 +641,9 @@ fn description(&self) -> &str {
             AddrKind::Socket => "invalid socket address syntax",
             AddrKind::SocketV4 => "invalid IPv4 socket address syntax",
             AddrKind::SocketV6 => "invalid IPv6 socket address syntax",
+            AddrKind::IpPrefix => "invalid IP prefix syntax",
+            AddrKind::Ipv4Prefix => "invalid IPv4 prefix syntax",
+            AddrKind::Ipv6Prefix => "invalid IPv6 prefix syntax",
         }
     }
 }
@@ -0,0 +1,940 @@
+use super::uint::{bit_op, checked_op, shift_op};
+use super::Uint;
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::num::{overflow_panic, IntErrorKind, ParseIntError, TryFromIntError};
+use crate::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+    DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign,
+};
+use crate::str::FromStr;
+
+/// A signed integer of `64 * LIMBS` bits.
+///
+/// `Int` is the two's complement counterpart of [`Uint`], with the same arithmetic as the
+/// primitive signed integers. `LIMBS` must not be zero.
+///
+/// Unlike the primitive integers, the arithmetic operators always panic on overflow, as the
+/// standard library cannot tell whether overflow checks are enabled in the calling crate. Use
+/// the `wrapping_*` methods for modular arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(bigint)]
+/// use std::num::I256;
+///
+/// let a = I256::from(-3i64).pow(100);
+/// assert_eq!(a.to_string(), "515377520732011331036461129765621272702107522001");
+/// assert_eq!((-a).to_string(), "-515377520732011331036461129765621272702107522001");
+/// assert_eq!(I256::MIN.checked_neg(), None);
+/// ```
+#[unstable(feature = "bigint", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Int<const LIMBS: usize> {
+    bits: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    /// The smallest value that can be represented by this integer type,
+    /// -2<sup>`BITS` - 1</sup>.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const MIN: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[LIMBS - 1] = 1 << 63;
+        Self::from_limbs(limbs)
+    };
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>`BITS` - 1</sup> - 1.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const MAX: Self = Self::from_bits(Uint::MAX.shr_unchecked(1));
+
+    /// The size of this integer type in bits, `64 * LIMBS`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const BITS: u32 = Uint::<LIMBS>::BITS;
+
+    const ONE: Self = Self::from_i64(1);
+
+    #[inline]
+    pub(super) const fn from_bits(bits: Uint<LIMBS>) -> Self {
+        Self { bits }
+    }
+
+    /// Creates an integer from the limbs of its two's complement representation, from least to
+    /// most significant.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self::from_bits(Uint::from_limbs(limbs))
+    }
+
+    /// Returns the limbs of the two's complement representation of this integer, from least to
+    /// most significant.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_limbs(self) -> [u64; LIMBS] {
+        self.bits.to_limbs()
+    }
+
+    /// Creates an integer with the value of `n`.
+    ///
+    /// This is the same as `Int::from(n)`, but can be used in constants.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_i64(n: i64) -> Self {
+        let mut limbs = [if n < 0 { u64::MAX } else { 0 }; LIMBS];
+        limbs[0] = n as u64;
+        Self::from_limbs(limbs)
+    }
+
+    /// Returns the value of `self` as an unsigned integer with the same bits.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn cast_unsigned(self) -> Uint<LIMBS> {
+        self.bits
+    }
+
+    /// Returns `true` if `self` is negative and `false` if the number is zero or positive.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        (self.bits.to_limbs()[LIMBS - 1] as i64) < 0
+    }
+
+    /// Returns `true` if `self` is positive and `false` if the number is zero or negative.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        !self.is_negative() && !self.bits.is_zero()
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        self.bits.count_ones()
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        self.bits.count_zeros()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        self.bits.leading_zeros()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        self.bits.trailing_zeros()
+    }
+
+    /// Computes the absolute value of `self` without any wrapping or panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::{I256, U256};
+    ///
+    /// assert_eq!(I256::from(-100i64).unsigned_abs(), U256::from(100u64));
+    /// assert_eq!(I256::MIN.unsigned_abs(), U256::from(1u64) << 255);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn unsigned_abs(self) -> Uint<LIMBS> {
+        self.wrapping_abs().bits
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is `Self::MIN`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn abs(self) -> Self {
+        match self.checked_abs() {
+            Some(n) => n,
+            None => overflow_panic::neg(),
+        }
+    }
+
+    /// Computes the absolute difference between `self` and `other`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> Uint<LIMBS> {
+        if self.const_cmp(&other).is_lt() {
+            other.bits.wrapping_sub(self.bits)
+        } else {
+            self.bits.wrapping_sub(other.bits)
+        }
+    }
+
+    #[inline]
+    const fn const_cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Two's complement preserves the order of numbers with the same sign.
+            _ => self.bits.const_cmp(&other.bits),
+        }
+    }
+
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_add(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow
+    /// occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_sub(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow
+    /// occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_mul(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the
+    /// division results in overflow.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.bits.is_zero() {
+            return None;
+        }
+        let (a, b) = self.overflowing_div(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the
+    /// division results in overflow.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.bits.is_zero() {
+            return None;
+        }
+        let (a, b) = self.overflowing_rem(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` if `self == MIN`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        let (a, b) = self.overflowing_neg();
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked absolute value. Computes `self.abs()`, returning `None` if `self == MIN`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        let (a, b) = self.overflowing_abs();
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(Self::from_bits(self.bits.shl_unchecked(rhs))) } else { None }
+    }
+
+    /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(self.shr_unchecked(rhs)) } else { None }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        let (a, b) = self.overflowing_pow(exp);
+        if b { None } else { Some(a) }
+    }
+
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(n) => n,
+            None if rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(n) => n,
+            None if rhs.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric
+    /// bounds instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(n) => n,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric
+    /// bounds instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(n) => n,
+            None if self.is_negative() && exp % 2 == 1 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of
+    /// the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.wrapping_add(rhs.bits))
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary
+    /// of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.wrapping_sub(rhs.bits))
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the
+    /// boundary of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        // The low half of a two's complement product does not depend on the signs.
+        Self::from_bits(self.bits.wrapping_mul(rhs.bits))
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the boundary of
+    /// the type.
+    ///
+    /// The only case where such wrapping can occur is when one divides `MIN / -1`, which is
+    /// equivalent to `-MIN`, a positive value that is too large to represent in the type. In
+    /// such a case, this function returns `MIN` itself.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the boundary of
+    /// the type.
+    ///
+    /// Such wrap-around never actually occurs mathematically; implementation artifacts make
+    /// `x % y` invalid for `MIN / -1`. In such a case, this function returns `0`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the
+    /// type.
+    ///
+    /// The only case where such wrapping can occur is when one negates `MIN`, which is
+    /// returned unchanged.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        Self::from_bits(self.bits.wrapping_neg())
+    }
+
+    /// Wrapping (modular) absolute value. Computes `self.abs()`, wrapping around at the
+    /// boundary of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_abs(self) -> Self {
+        if self.is_negative() { self.wrapping_neg() } else { self }
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << (rhs % Self::BITS)`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        Self::from_bits(self.bits.wrapping_shl(rhs))
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> (rhs % Self::BITS)`.
+    ///
+    /// This is an arithmetic shift, which fills in copies of the sign bit.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.shr_unchecked(rhs % Self::BITS)
+    }
+
+    /// Shifts right by `rhs`, which must be less than `Self::BITS`, filling in the sign bit.
+    #[inline]
+    const fn shr_unchecked(self, rhs: u32) -> Self {
+        if self.is_negative() {
+            // Shifting zeros into the complement shifts ones into the value itself.
+            let complement = Uint::MAX.wrapping_sub(self.bits);
+            Self::from_bits(Uint::MAX.wrapping_sub(complement.shr_unchecked(rhs)))
+        } else {
+            Self::from_bits(self.bits.shr_unchecked(rhs))
+        }
+    }
+
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the
+    /// boundary of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is
+    /// returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let sum = self.wrapping_add(rhs);
+        // Adding numbers of the same sign overflows if the sign of the sum differs from theirs.
+        let overflow = self.is_negative() == rhs.is_negative()
+            && sum.is_negative() != self.is_negative();
+        (sum, overflow)
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is
+    /// returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let difference = self.wrapping_sub(rhs);
+        let overflow = self.is_negative() != rhs.is_negative()
+            && difference.is_negative() != self.is_negative();
+        (difference, overflow)
+    }
+
+    /// Calculates the multiplication of `self` and `rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an
+    /// arithmetic overflow would occur. If an overflow would have occurred then the wrapped
+    /// value is returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (low, high) = self.unsigned_abs().widening_mul(rhs.unsigned_abs());
+        // The magnitude of the product must be at most that of `MIN` if it is negative, and
+        // less than that otherwise.
+        let overflow = !high.is_zero()
+            || match low.const_cmp(&Self::MIN.bits) {
+                Ordering::Less => false,
+                Ordering::Equal => self.is_negative() == rhs.is_negative(),
+                Ordering::Greater => true,
+            };
+        (self.wrapping_mul(rhs), overflow)
+    }
+
+    /// Calculates the quotient when `self` is divided by `rhs`.
+    ///
+    /// Returns a tuple of the quotient along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would occur then `self` is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        if self.is_min_div_minus_one(rhs) {
+            return (self, true);
+        }
+        let quotient = Self::from_bits(self.unsigned_abs().div_rem(rhs.unsigned_abs()).0);
+        if self.is_negative() != rhs.is_negative() {
+            (quotient.wrapping_neg(), false)
+        } else {
+            (quotient, false)
+        }
+    }
+
+    /// Calculates the remainder when `self` is divided by `rhs`.
+    ///
+    /// Returns a tuple of the remainder after dividing along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would occur then 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        if self.is_min_div_minus_one(rhs) {
+            return (Self::from_bits(Uint::MIN), true);
+        }
+        // The remainder has the sign of the dividend, like for the primitive integers.
+        let remainder = Self::from_bits(self.unsigned_abs().div_rem(rhs.unsigned_abs()).1);
+        if self.is_negative() { (remainder.wrapping_neg(), false) } else { (remainder, false) }
+    }
+
+    #[inline]
+    const fn is_min_div_minus_one(self, rhs: Self) -> bool {
+        self.bits.const_cmp(&Self::MIN.bits).is_eq()
+            && rhs.bits.const_cmp(&Uint::MAX).is_eq()
+    }
+
+    /// Negates self, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns a tuple of the negated version of self along with a boolean indicating whether
+    /// an overflow happened. If `self` is the minimum value, then the minimum value will be
+    /// returned again and `true` will be returned for an overflow happening.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), self.bits.const_cmp(&Self::MIN.bits).is_eq())
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// Returns a tuple of the absolute version of self along with a boolean indicating whether
+    /// an overflow happened. If `self` is the minimum value, then the minimum value will be
+    /// returned again and `true` will be returned for an overflow happening.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_abs(self) -> (Self, bool) {
+        if self.is_negative() { self.overflowing_neg() } else { (self, false) }
+    }
+
+    /// Shifts self left by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean indicating whether
+    /// the shift value was larger than or equal to the number of bits. If the shift value is
+    /// too large, then value is masked (`rhs % Self::BITS`) and this value is then used to
+    /// perform the shift.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        (self.wrapping_shl(rhs), rhs >= Self::BITS)
+    }
+
+    /// Shifts self right by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean indicating whether
+    /// the shift value was larger than or equal to the number of bits. If the shift value is
+    /// too large, then value is masked (`rhs % Self::BITS`) and this value is then used to
+    /// perform the shift.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (self.wrapping_shr(rhs), rhs >= Self::BITS)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Returns a tuple of the exponentiation along with a bool indicating whether an overflow
+    /// happened.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        let mut overflown = false;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let (a, o) = acc.overflowing_mul(base);
+                acc = a;
+                overflown |= o;
+            }
+            exp /= 2;
+            if exp > 0 {
+                let (b, o) = base.overflowing_mul(base);
+                base = b;
+                overflown |= o;
+            }
+        }
+        (acc, overflown)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn pow(self, exp: u32) -> Self {
+        let (a, b) = self.overflowing_pow(exp);
+        if b { overflow_panic::mul() } else { a }
+    }
+
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// The string is expected to be an optional `+` or `-` sign followed by digits. Leading and
+    /// trailing whitespace represent an error. Digits are a subset of these characters,
+    /// depending on `radix`:
+    ///
+    /// * `0-9`
+    /// * `a-z`
+    /// * `A-Z`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::I256;
+    ///
+    /// assert_eq!(I256::from_str_radix("-ff", 16), Ok(I256::from(-255i64)));
+    /// assert_eq!(I256::from_str_radix(&format!("-8{}", "0".repeat(63)), 16), Ok(I256::MIN));
+    /// assert!(I256::from_str_radix(&format!("8{}", "0".repeat(63)), 16).is_err());
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        let (is_negative, digits) = match src.as_bytes() {
+            [b'+' | b'-'] => return Err(ParseIntError { kind: IntErrorKind::InvalidDigit }),
+            [b'+', rest @ ..] => (false, rest),
+            [b'-', rest @ ..] => (true, rest),
+            digits => (false, digits),
+        };
+        let magnitude = match Uint::<LIMBS>::from_digits(digits, radix) {
+            Ok(n) => n,
+            Err(IntErrorKind::PosOverflow) if is_negative => {
+                return Err(ParseIntError { kind: IntErrorKind::NegOverflow });
+            }
+            Err(kind) => return Err(ParseIntError { kind }),
+        };
+        match (is_negative, magnitude.const_cmp(&Self::MIN.bits)) {
+            (false, Ordering::Less) => Ok(Self::from_bits(magnitude)),
+            (true, Ordering::Less | Ordering::Equal) => {
+                Ok(Self::from_bits(magnitude.wrapping_neg()))
+            }
+            (false, _) => Err(ParseIntError { kind: IntErrorKind::PosOverflow }),
+            (true, _) => Err(ParseIntError { kind: IntErrorKind::NegOverflow }),
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Default for Int<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_bits(Uint::MIN)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> PartialOrd for Int<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Ord for Int<LIMBS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(other)
+    }
+}
+
+macro_rules! from_signed {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> From<$t> for Int<LIMBS> {
+            #[inline]
+            fn from(n: $t) -> Self {
+                Self::from_i64(n as i64)
+            }
+        }
+    )*};
+}
+
+from_signed! { i8 i16 i32 i64 }
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> TryFrom<Int<LIMBS>> for i64 {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(n: Int<LIMBS>) -> Result<i64, TryFromIntError> {
+        let low = n.to_limbs()[0] as i64;
+        if n == Int::from_i64(low) { Ok(low) } else { Err(TryFromIntError(())) }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> FromStr for Int<LIMBS> {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::Display for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_decimal(self.unsigned_abs(), !self.is_negative(), f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::Debug for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Formats the two's complement representation, like the primitive signed integers do.
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::LowerHex for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(self.bits, false, f)
+    }
+}
+
+/// Formats the two's complement representation, like the primitive signed integers do.
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::UpperHex for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(self.bits, true, f)
+    }
+}
+
+checked_op! { Int, Add add, AddAssign add_assign, overflowing_add, add }
+checked_op! { Int, Sub sub, SubAssign sub_assign, overflowing_sub, sub }
+checked_op! { Int, Mul mul, MulAssign mul_assign, overflowing_mul, mul }
+checked_op! { Int, Div div, DivAssign div_assign, overflowing_div, div }
+checked_op! { Int, Rem rem, RemAssign rem_assign, overflowing_rem, rem }
+bit_op! { Int, BitAnd bitand, BitAndAssign bitand_assign, &= }
+bit_op! { Int, BitOr bitor, BitOrAssign bitor_assign, |= }
+bit_op! { Int, BitXor bitxor, BitXorAssign bitxor_assign, ^= }
+shift_op! { Int, Shl shl, ShlAssign shl_assign, checked_shl, shl }
+shift_op! { Int, Shr shr, ShrAssign shr_assign, checked_shr, shr }
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Neg for Int<LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self {
+        match self.checked_neg() {
+            Some(n) => n,
+            None => overflow_panic::neg(),
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Not for Int<LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self::from_bits(!self.bits)
+    }
+}
@@ -0,0 +1,95 @@
+//! Fixed-width integers wider than `u128`, built from 64-bit limbs.
+//!
+//! [`Uint<LIMBS>`] and [`Int<LIMBS>`] are unsigned and two's complement signed integers of
+//! `64 * LIMBS` bits. They offer the same checked, wrapping, overflowing and saturating
+//! arithmetic as the primitive integers, implemented with the `carrying_add`, `borrowing_sub`
+//! and `carrying_mul` methods of `u64`.
+
+use crate::fmt;
+use crate::str;
+
+mod int;
+mod uint;
+
+#[unstable(feature = "bigint", issue = "none")]
+pub use int::Int;
+#[unstable(feature = "bigint", issue = "none")]
+pub use uint::Uint;
+
+/// A 256-bit unsigned integer.
+#[unstable(feature = "bigint", issue = "none")]
+pub type U256 = Uint<4>;
+
+/// A 512-bit unsigned integer.
+#[unstable(feature = "bigint", issue = "none")]
+pub type U512 = Uint<8>;
+
+/// A 256-bit signed integer.
+#[unstable(feature = "bigint", issue = "none")]
+pub type I256 = Int<4>;
+
+/// A 512-bit signed integer.
+#[unstable(feature = "bigint", issue = "none")]
+pub type I512 = Int<8>;
+
+/// Formats the magnitude `n` in decimal, with the sign given by `is_nonnegative`.
+fn fmt_decimal<const LIMBS: usize>(
+    mut n: Uint<LIMBS>,
+    is_nonnegative: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    // `64 * LIMBS` bits take at most `19.3 * LIMBS + 1` decimal digits.
+    let mut buf = [[0u8; 20]; LIMBS];
+    let buf = buf.as_flattened_mut();
+    let mut curr = buf.len();
+
+    // Divide by the largest power of ten that fits in a `u64`, so that most of the work is done
+    // with primitive division.
+    const CHUNK: u64 = 10_u64.pow(19);
+    loop {
+        let (q, mut r) = n.div_rem_u64(CHUNK);
+        n = q;
+        // Write all 19 digits of every chunk but the most significant one.
+        let mut digits = 0;
+        loop {
+            curr -= 1;
+            buf[curr] = b'0' + (r % 10) as u8;
+            r /= 10;
+            digits += 1;
+            if digits == 19 || (r == 0 && n.is_zero()) {
+                break;
+            }
+        }
+        if n.is_zero() {
+            break;
+        }
+    }
+
+    // SAFETY: Only ASCII digits have been written to `buf[curr..]`.
+    let digits = unsafe { str::from_utf8_unchecked(&buf[curr..]) };
+    f.pad_integral(is_nonnegative, "", digits)
+}
+
+/// Formats the bits of `n` in hexadecimal.
+fn fmt_hex<const LIMBS: usize>(
+    n: Uint<LIMBS>,
+    upper: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let mut buf = [[0u8; 16]; LIMBS];
+    let buf = buf.as_flattened_mut();
+    let len = ((Uint::<LIMBS>::BITS - n.leading_zeros()).div_ceil(4) as usize).max(1);
+    let limbs = n.to_limbs();
+    for i in 0..len {
+        let nibble = (limbs[i / 16] >> (4 * (i % 16))) & 0xf;
+        buf[len - 1 - i] = match nibble {
+            0..=9 => b'0' + nibble as u8,
+            _ if upper => b'A' + (nibble - 10) as u8,
+            _ => b'a' + (nibble - 10) as u8,
+        };
+    }
+
+    // SAFETY: Only ASCII digits have been written to `buf[..len]`.
+    let digits = unsafe { str::from_utf8_unchecked(&buf[..len]) };
+    f.pad_integral(true, "0x", digits)
+}
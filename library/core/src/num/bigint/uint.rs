@@ -0,0 +1,1360 @@
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::num::{overflow_panic, IntErrorKind, ParseIntError, TryFromIntError};
+use crate::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+    DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign,
+};
+use crate::str::FromStr;
+
+/// An unsigned integer of `64 * LIMBS` bits.
+///
+/// `Uint` provides the same arithmetic as the primitive unsigned integers, for widths that they
+/// do not cover, such as the 256-bit [`U256`]. It is stored as an array of `LIMBS` 64-bit
+/// limbs, from least to most significant. `LIMBS` must not be zero.
+///
+/// Unlike the primitive integers, the arithmetic operators always panic on overflow, as the
+/// standard library cannot tell whether overflow checks are enabled in the calling crate. Use
+/// the `wrapping_*` methods for modular arithmetic.
+///
+/// [`U256`]: crate::num::U256
+///
+/// # Examples
+///
+/// ```
+/// #![feature(bigint)]
+/// use std::num::U256;
+///
+/// let a: U256 = "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(a, U256::MAX);
+///
+/// let b = U256::from(u64::MAX);
+/// assert_eq!((b * b).to_string(), "340282366920938463426481119284349108225");
+/// assert_eq!(a.checked_add(b), None);
+/// ```
+#[unstable(feature = "bigint", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    const ASSERT_LIMBS: () = assert!(LIMBS > 0, "`Uint` and `Int` must have at least one limb");
+
+    /// The smallest value that can be represented by this integer type, zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const MIN: Self = Self::from_limbs([0; LIMBS]);
+
+    /// The largest value that can be represented by this integer type, 2<sup>`BITS`</sup> - 1.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const MAX: Self = Self::from_limbs([u64::MAX; LIMBS]);
+
+    /// The size of this integer type in bits, `64 * LIMBS`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const BITS: u32 = LIMBS as u32 * u64::BITS;
+
+    const ONE: Self = Self::from_u64(1);
+
+    /// Creates an integer from its limbs, from least to most significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// let n = U256::from_limbs([0, 1, 0, 0]);
+    /// assert_eq!(n, U256::from(u64::MAX) + U256::from(1u64));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        let () = Self::ASSERT_LIMBS;
+        Self { limbs }
+    }
+
+    /// Returns the limbs of this integer, from least to most significant.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn to_limbs(self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    /// Creates an integer with the value of `n`.
+    ///
+    /// This is the same as `Uint::from(n)`, but can be used in constants.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn from_u64(n: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n;
+        Self::from_limbs(limbs)
+    }
+
+    #[inline]
+    pub(super) const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[inline]
+    pub(super) const fn const_cmp(&self, other: &Self) -> Ordering {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.limbs[i] < other.limbs[i] {
+                return Ordering::Less;
+            } else if self.limbs[i] > other.limbs[i] {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            count += self.limbs[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// assert_eq!(U256::from(1u64).leading_zeros(), 255);
+    /// assert_eq!(U256::MIN.leading_zeros(), 256);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        let mut count = 0;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            count += self.limbs[i].leading_zeros();
+            if self.limbs[i] != 0 {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            count += self.limbs[i].trailing_zeros();
+            if self.limbs[i] != 0 {
+                break;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns the number of leading ones in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn leading_ones(self) -> u32 {
+        self.const_not().leading_zeros()
+    }
+
+    /// Returns the number of trailing ones in the binary representation of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn trailing_ones(self) -> u32 {
+        self.const_not().trailing_zeros()
+    }
+
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_power_of_two(self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn ilog2(self) -> u32 {
+        match self.checked_ilog2() {
+            Some(log) => log,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// Returns `None` if the number is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_ilog2(self) -> Option<u32> {
+        if self.is_zero() { None } else { Some(Self::BITS - 1 - self.leading_zeros()) }
+    }
+
+    #[inline]
+    const fn const_not(self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = !limbs[i];
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Calculates `self + rhs + carry` and returns a tuple containing the sum and the output
+    /// carry.
+    ///
+    /// This is the same as [`u64::carrying_add`], and can be used to chain additions into even
+    /// wider integers.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let mut limbs = self.limbs;
+        let mut carry = carry;
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], carry) = limbs[i].carrying_add(rhs.limbs[i], carry);
+            i += 1;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// Calculates `self - rhs - borrow` and returns a tuple containing the difference and the
+    /// output borrow.
+    ///
+    /// This is the same as [`u64::borrowing_sub`], and can be used to chain subtractions into
+    /// even wider integers.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let mut limbs = self.limbs;
+        let mut borrow = borrow;
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], borrow) = limbs[i].borrowing_sub(rhs.limbs[i], borrow);
+            i += 1;
+        }
+        (Self { limbs }, borrow)
+    }
+
+    /// Calculates the complete product `self * rhs` without the possibility to overflow.
+    ///
+    /// This returns the low-order (wrapping) bits and the high-order (overflow) bits of the
+    /// result as two separate values, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// let (low, high) = U256::MAX.widening_mul(U256::MAX);
+    /// assert_eq!(low, U256::from(1u64));
+    /// assert_eq!(high, U256::MAX - U256::from(1u64));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let mut low = [0; LIMBS];
+        let mut high = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                let k = i + j;
+                let acc = if k < LIMBS { low[k] } else { high[k - LIMBS] };
+                // This cannot overflow: `(2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1`.
+                let (product, mut product_high) = self.limbs[i].carrying_mul(rhs.limbs[j], carry);
+                let (sum, overflow) = product.overflowing_add(acc);
+                product_high += overflow as u64;
+                if k < LIMBS {
+                    low[k] = sum;
+                } else {
+                    high[k - LIMBS] = sum;
+                }
+                carry = product_high;
+                j += 1;
+            }
+            high[i] = carry;
+            i += 1;
+        }
+        (Self { limbs: low }, Self { limbs: high })
+    }
+
+    /// Multiplies by a single limb, returning the low `LIMBS` limbs of the product and the
+    /// limb above them.
+    #[inline]
+    const fn widening_mul_u64(self, rhs: u64) -> (Self, u64) {
+        let mut limbs = self.limbs;
+        let mut carry = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], carry) = limbs[i].carrying_mul(rhs, carry);
+            i += 1;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// Divides by a single non-zero limb, returning the quotient and the remainder.
+    #[inline]
+    pub(super) const fn div_rem_u64(self, rhs: u64) -> (Self, u64) {
+        let mut limbs = self.limbs;
+        let mut rem = 0;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            let n = (rem as u128) << 64 | limbs[i] as u128;
+            limbs[i] = (n / rhs as u128) as u64;
+            rem = (n % rhs as u128) as u64;
+        }
+        (Self { limbs }, rem)
+    }
+
+    /// Divides by a non-zero `rhs`, returning the quotient and the remainder.
+    ///
+    /// This is Algorithm D from section 4.3.1 of Knuth's *The Art of Computer Programming*,
+    /// which estimates each limb of the quotient from the top two limbs of the remainder and the
+    /// top limb of the divisor, and corrects the estimate if needed.
+    pub(super) const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        // The number of significant limbs of the divisor.
+        let mut n = LIMBS;
+        while n > 0 && rhs.limbs[n - 1] == 0 {
+            n -= 1;
+        }
+        assert!(n > 0, "attempt to divide by zero");
+        if n == 1 {
+            let (q, r) = self.div_rem_u64(rhs.limbs[0]);
+            return (q, Self::from_u64(r));
+        }
+        if self.const_cmp(&rhs).is_lt() {
+            return (Self::MIN, self);
+        }
+
+        // Normalize, so that the top limb of the divisor has its high bit set. The dividend
+        // gets an extra limb above the others, which is `u_top`.
+        let shift = rhs.limbs[n - 1].leading_zeros();
+        let v = rhs.shl_unchecked(shift).limbs;
+        let mut u = self.shl_unchecked(shift).limbs;
+        let mut u_top = if shift == 0 { 0 } else { self.limbs[LIMBS - 1] >> (64 - shift) };
+        // The number of significant limbs of the dividend, at least `n`.
+        let mut m = LIMBS;
+        while self.limbs[m - 1] == 0 {
+            m -= 1;
+        }
+
+        let mut q = [0; LIMBS];
+        let v_top = v[n - 1] as u128;
+        let v_next = v[n - 2] as u128;
+        let mut j = m - n + 1;
+        while j > 0 {
+            j -= 1;
+            // The limbs `u[j..=j + n]` are the current remainder, with index `LIMBS` meaning
+            // `u_top`.
+            let u_hi = if j + n == LIMBS { u_top } else { u[j + n] };
+            let num = (u_hi as u128) << 64 | u[j + n - 1] as u128;
+            let mut q_hat = num / v_top;
+            let mut r_hat = num % v_top;
+            while q_hat > u64::MAX as u128
+                || (r_hat <= u64::MAX as u128
+                    && q_hat * v_next > (r_hat << 64 | u[j + n - 2] as u128))
+            {
+                q_hat -= 1;
+                r_hat += v_top;
+            }
+
+            // Subtract `q_hat * v` from the remainder.
+            let q_hat = q_hat as u64;
+            let mut mul_carry = 0;
+            let mut borrow = false;
+            let mut i = 0;
+            while i < n {
+                let product;
+                (product, mul_carry) = v[i].carrying_mul(q_hat, mul_carry);
+                (u[j + i], borrow) = u[j + i].borrowing_sub(product, borrow);
+                i += 1;
+            }
+            let (top, underflow) = u_hi.borrowing_sub(mul_carry, borrow);
+
+            // The estimate was one too large, so add the divisor back once.
+            let mut q_limb = q_hat;
+            let top = if underflow {
+                q_limb -= 1;
+                let mut carry = false;
+                let mut i = 0;
+                while i < n {
+                    (u[j + i], carry) = u[j + i].carrying_add(v[i], carry);
+                    i += 1;
+                }
+                top.wrapping_add(carry as u64)
+            } else {
+                top
+            };
+            if j + n == LIMBS {
+                u_top = top;
+            } else {
+                u[j + n] = top;
+            }
+            q[j] = q_limb;
+        }
+
+        // Denormalize the remainder, which is in `u[..n]`.
+        let mut i = n;
+        while i < LIMBS {
+            u[i] = 0;
+            i += 1;
+        }
+        (Self { limbs: q }, Self { limbs: u }.shr_unchecked(shift))
+    }
+
+    /// Shifts left by `rhs`, which must be less than `Self::BITS`.
+    #[inline]
+    pub(super) const fn shl_unchecked(self, rhs: u32) -> Self {
+        let (limb_shift, bit_shift) = ((rhs / 64) as usize, rhs % 64);
+        let mut limbs = [0; LIMBS];
+        let mut i = LIMBS;
+        while i > limb_shift {
+            i -= 1;
+            let src = i - limb_shift;
+            limbs[i] = self.limbs[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                limbs[i] |= self.limbs[src - 1] >> (64 - bit_shift);
+            }
+        }
+        Self { limbs }
+    }
+
+    /// Shifts right by `rhs`, which must be less than `Self::BITS`, filling in zeros.
+    #[inline]
+    pub(super) const fn shr_unchecked(self, rhs: u32) -> Self {
+        let (limb_shift, bit_shift) = ((rhs / 64) as usize, rhs % 64);
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i + limb_shift < LIMBS {
+            let src = i + limb_shift;
+            limbs[i] = self.limbs[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < LIMBS {
+                limbs[i] |= self.limbs[src + 1] << (64 - bit_shift);
+            }
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// let one = U256::from(1u64);
+    /// assert_eq!((U256::MAX - one - one).checked_add(one), Some(U256::MAX - one));
+    /// assert_eq!((U256::MAX - one - one).checked_add(one + one + one), None);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_add(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow
+    /// occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_sub(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow
+    /// occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = self.overflowing_mul(rhs);
+        if b { None } else { Some(a) }
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self.div_rem(rhs).0) }
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self.div_rem(rhs).1) }
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` unless `self == 0`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.is_zero() { Some(self) } else { None }
+    }
+
+    /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(self.shl_unchecked(rhs)) } else { None }
+    }
+
+    /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs` is larger than or
+    /// equal to the number of bits in `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(self.shr_unchecked(rhs)) } else { None }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        let (a, b) = self.overflowing_pow(exp);
+        if b { None } else { Some(a) }
+    }
+
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(n) => n,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(n) => n,
+            None => Self::MIN,
+        }
+    }
+
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric
+    /// bounds instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(n) => n,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric
+    /// bounds instead of overflowing.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(n) => n,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of
+    /// the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary
+    /// of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the
+    /// boundary of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        // Only compute the low half of the product.
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let mut carry = 0;
+            let mut j = 0;
+            while i + j < LIMBS {
+                let (product, product_high) = self.limbs[i].carrying_mul(rhs.limbs[j], carry);
+                let (sum, overflow) = product.overflowing_add(limbs[i + j]);
+                limbs[i + j] = sum;
+                carry = product_high + overflow as u64;
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`. Wrapped division on unsigned types
+    /// is just normal division; this function exists so that all operations are accounted for
+    /// in the wrapping operations.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`. Wrapped remainder calculation on
+    /// unsigned types is just the regular remainder calculation; this function exists so that
+    /// all operations are accounted for in the wrapping operations.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the
+    /// type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        Self::MIN.wrapping_sub(self)
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << (rhs % Self::BITS)`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        self.shl_unchecked(rhs % Self::BITS)
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> (rhs % Self::BITS)`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.shr_unchecked(rhs % Self::BITS)
+    }
+
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the
+    /// boundary of the type.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is
+    /// returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        self.carrying_add(rhs, false)
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is
+    /// returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+
+    /// Calculates the multiplication of `self` and `rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an
+    /// arithmetic overflow would occur. If an overflow would have occurred then the wrapped
+    /// value is returned.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (low, high) = self.widening_mul(rhs);
+        (low, !high.is_zero())
+    }
+
+    /// Negates self in a wrapping fashion.
+    ///
+    /// Returns `!self + 1` using wrapping operations to return the value that represents the
+    /// negation of this unsigned value. Note that for positive unsigned values overflow always
+    /// occurs, but negating 0 does not overflow.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), !self.is_zero())
+    }
+
+    /// Shifts self left by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean indicating whether
+    /// the shift value was larger than or equal to the number of bits. If the shift value is
+    /// too large, then value is masked (`rhs % Self::BITS`) and this value is then used to
+    /// perform the shift.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        (self.wrapping_shl(rhs), rhs >= Self::BITS)
+    }
+
+    /// Shifts self right by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of self along with a boolean indicating whether
+    /// the shift value was larger than or equal to the number of bits. If the shift value is
+    /// too large, then value is masked (`rhs % Self::BITS`) and this value is then used to
+    /// perform the shift.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (self.wrapping_shr(rhs), rhs >= Self::BITS)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Returns a tuple of the exponentiation along with a bool indicating whether an overflow
+    /// happened.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        let mut overflown = false;
+        // Only the squarings that are still needed count as overflow.
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let (a, o) = acc.overflowing_mul(base);
+                acc = a;
+                overflown |= o;
+            }
+            exp /= 2;
+            if exp > 0 {
+                let (b, o) = base.overflowing_mul(base);
+                base = b;
+                overflown |= o;
+            }
+        }
+        (acc, overflown)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// let n = U256::from(2u64).pow(255);
+    /// assert_eq!(n.trailing_zeros(), 255);
+    /// assert_eq!(U256::from(10u64).pow(77).to_string(), format!("1{}", "0".repeat(77)));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn pow(self, exp: u32) -> Self {
+        let (a, b) = self.overflowing_pow(exp);
+        if b { overflow_panic::mul() } else { a }
+    }
+
+    /// Computes the absolute difference between `self` and `other`.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        if self.const_cmp(&other).is_lt() {
+            other.wrapping_sub(self)
+        } else {
+            self.wrapping_sub(other)
+        }
+    }
+
+    /// Shifts the bits to the left by a specified amount, `n`, wrapping the truncated bits to
+    /// the end of the resulting integer.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn rotate_left(self, n: u32) -> Self {
+        let n = n % Self::BITS;
+        if n == 0 {
+            return self;
+        }
+        self.shl_unchecked(n).const_bitor(self.shr_unchecked(Self::BITS - n))
+    }
+
+    /// Shifts the bits to the right by a specified amount, `n`, wrapping the truncated bits to
+    /// the beginning of the resulting integer.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn rotate_right(self, n: u32) -> Self {
+        self.rotate_left(Self::BITS - n % Self::BITS)
+    }
+
+    #[inline]
+    const fn const_bitor(self, rhs: Self) -> Self {
+        let mut limbs = self.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] |= rhs.limbs[i];
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// Returns the value of `self` as a signed integer with the same bits.
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn cast_signed(self) -> super::Int<LIMBS> {
+        super::Int::from_bits(self)
+    }
+
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// The string is expected to be an optional `+` sign followed by digits. Leading and
+    /// trailing whitespace represent an error. Digits are a subset of these characters,
+    /// depending on `radix`:
+    ///
+    /// * `0-9`
+    /// * `a-z`
+    /// * `A-Z`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    /// use std::num::U256;
+    ///
+    /// assert_eq!(U256::from_str_radix("A", 16), Ok(U256::from(10u64)));
+    /// assert_eq!(U256::from_str_radix(&"f".repeat(64), 16), Ok(U256::MAX));
+    /// assert!(U256::from_str_radix(&"f".repeat(65), 16).is_err());
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    #[rustc_const_unstable(feature = "bigint", issue = "none")]
+    pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        let digits = match src.as_bytes() {
+            [b'+' | b'-'] => return Err(ParseIntError { kind: IntErrorKind::InvalidDigit }),
+            [b'+', rest @ ..] => rest,
+            digits => digits,
+        };
+        match Self::from_digits(digits, radix) {
+            Ok(n) => Ok(n),
+            Err(kind) => Err(ParseIntError { kind }),
+        }
+    }
+
+    /// Parses unsigned `digits`, returning `PosOverflow` if they do not fit.
+    pub(super) const fn from_digits(mut digits: &[u8], radix: u32) -> Result<Self, IntErrorKind> {
+        assert!(
+            2 <= radix && radix <= 36,
+            "from_str_radix_int: must lie in the range `[2, 36]`"
+        );
+        if digits.is_empty() {
+            return Err(IntErrorKind::Empty);
+        }
+
+        let mut result = Self::MIN;
+        while let [c, rest @ ..] = digits {
+            let digit = match (*c as char).to_digit(radix) {
+                Some(digit) => digit,
+                None => return Err(IntErrorKind::InvalidDigit),
+            };
+            let (product, high) = result.widening_mul_u64(radix as u64);
+            let (sum, overflow) = product.overflowing_add(Self::from_u64(digit as u64));
+            if high != 0 || overflow {
+                return Err(IntErrorKind::PosOverflow);
+            }
+            result = sum;
+            digits = rest;
+        }
+        Ok(result)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(other)
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> From<$t> for Uint<LIMBS> {
+            #[inline]
+            fn from(n: $t) -> Self {
+                Self::from_u64(n as u64)
+            }
+        }
+    )*};
+}
+
+from_unsigned! { u8 u16 u32 u64 }
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> TryFrom<u128> for Uint<LIMBS> {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(n: u128) -> Result<Self, TryFromIntError> {
+        let mut limbs = Self::MIN.limbs;
+        limbs[0] = n as u64;
+        match limbs.get_mut(1) {
+            Some(limb) => *limb = (n >> 64) as u64,
+            None if n > u64::MAX as u128 => return Err(TryFromIntError(())),
+            None => {}
+        }
+        Ok(Self { limbs })
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> TryFrom<Uint<LIMBS>> for u64 {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(n: Uint<LIMBS>) -> Result<u64, TryFromIntError> {
+        match *n.limbs.as_slice() {
+            [low, ref high @ ..] if high.iter().all(|&limb| limb == 0) => Ok(low),
+            _ => Err(TryFromIntError(())),
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> TryFrom<Uint<LIMBS>> for u128 {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(n: Uint<LIMBS>) -> Result<u128, TryFromIntError> {
+        match *n.limbs.as_slice() {
+            [low] => Ok(low as u128),
+            [low, high, ref rest @ ..] if rest.iter().all(|&limb| limb == 0) => {
+                Ok((high as u128) << 64 | low as u128)
+            }
+            _ => Err(TryFromIntError(())),
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = ParseIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::Display for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_decimal(*self, true, f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::Debug for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::LowerHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(*self, false, f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> fmt::UpperHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_hex(*self, true, f)
+    }
+}
+
+/// Implements an arithmetic operator and its assigning version with a method that returns
+/// whether it overflowed, panicking if it did.
+macro_rules! checked_op {
+    (
+        $Ty:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident,
+        $method:ident, $panic:ident
+    ) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $Op for $Ty<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $op(self, rhs: Self) -> Self {
+                let (a, b) = self.$method(rhs);
+                if b { overflow_panic::$panic() } else { a }
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $OpAssign for $Ty<LIMBS> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+pub(super) use checked_op;
+
+/// Implements a bitwise operator and its assigning version, limb by limb.
+macro_rules! bit_op {
+    ($Ty:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $operator:tt) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $Op for $Ty<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                let mut limbs = self.to_limbs();
+                let rhs = rhs.to_limbs();
+                for (a, b) in limbs.iter_mut().zip(rhs) {
+                    *a $operator b;
+                }
+                Self::from_limbs(limbs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $OpAssign for $Ty<LIMBS> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+pub(super) use bit_op;
+
+/// Implements a shift operator and its assigning version, panicking if the shift amount is
+/// too large.
+macro_rules! shift_op {
+    (
+        $Ty:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident,
+        $method:ident, $panic:ident
+    ) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $Op<u32> for $Ty<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $op(self, rhs: u32) -> Self {
+                match self.$method(rhs) {
+                    Some(n) => n,
+                    None => overflow_panic::$panic(),
+                }
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl<const LIMBS: usize> $OpAssign<u32> for $Ty<LIMBS> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: u32) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+pub(super) use shift_op;
+
+checked_op! { Uint, Add add, AddAssign add_assign, overflowing_add, add }
+checked_op! { Uint, Sub sub, SubAssign sub_assign, overflowing_sub, sub }
+checked_op! { Uint, Mul mul, MulAssign mul_assign, overflowing_mul, mul }
+bit_op! { Uint, BitAnd bitand, BitAndAssign bitand_assign, &= }
+bit_op! { Uint, BitOr bitor, BitOrAssign bitor_assign, |= }
+bit_op! { Uint, BitXor bitxor, BitXorAssign bitxor_assign, ^= }
+shift_op! { Uint, Shl shl, ShlAssign shl_assign, checked_shl, shl }
+shift_op! { Uint, Shr shr, ShrAssign shr_assign, checked_shr, shr }
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Div for Uint<LIMBS> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero.
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> DivAssign for Uint<LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Rem for Uint<LIMBS> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero.
+    #[inline]
+    #[track_caller]
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> RemAssign for Uint<LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<const LIMBS: usize> Not for Uint<LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.const_not()
+    }
+}
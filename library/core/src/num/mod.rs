@@ -42,6 +42,7 @@ macro_rules! unlikely {
 #[macro_use]
 mod uint_macros; // import uint_impl!
 
+mod bigint;
 mod error;
 mod int_log10;
 mod nonzero;
@@ -83,6 +84,9 @@ macro_rules! unlikely {
 #[stable(feature = "int_error_matching", since = "1.55.0")]
 pub use error::IntErrorKind;
 
+#[unstable(feature = "bigint", issue = "none")]
+pub use bigint::{Int, Uint, I256, I512, U256, U512};
+
 macro_rules! usize_isize_to_xe_bytes_doc {
     () => {
         "
@@ -216,7 +216,10 @@ impl RefUnwindSafe for crate::sync::atomic::AtomicI32 {}
 #[cfg(target_has_atomic_load_store = "64")]
 #[stable(feature = "integer_atomics_stable", since = "1.34.0")]
 impl RefUnwindSafe for crate::sync::atomic::AtomicI64 {}
-#[cfg(target_has_atomic_load_store = "128")]
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
 #[unstable(feature = "integer_atomics", issue = "99069")]
 impl RefUnwindSafe for crate::sync::atomic::AtomicI128 {}
 
@@ -235,7 +238,10 @@ impl RefUnwindSafe for crate::sync::atomic::AtomicU32 {}
 #[cfg(target_has_atomic_load_store = "64")]
 #[stable(feature = "integer_atomics_stable", since = "1.34.0")]
 impl RefUnwindSafe for crate::sync::atomic::AtomicU64 {}
-#[cfg(target_has_atomic_load_store = "128")]
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
 #[unstable(feature = "integer_atomics", issue = "99069")]
 impl RefUnwindSafe for crate::sync::atomic::AtomicU128 {}
 
@@ -445,6 +445,10 @@
 #[unstable(feature = "ptr_internals", issue = "none")]
 pub use unique::Unique;
 
+mod volatile;
+#[unstable(feature = "volatile_ptr", issue = "none")]
+pub use volatile::{access, VolatilePtr};
+
 mod const_ptr;
 mod mut_ptr;
 
@@ -0,0 +1,359 @@
+use self::access::{ReadOnly, ReadWrite, Readable, Register, Writable, WriteOnly};
+use crate::marker::PhantomData;
+use crate::ops::Range;
+use crate::ptr::{self, NonNull};
+use crate::{fmt, hash};
+
+/// Marker types and traits describing how a [`VolatilePtr`] may be accessed.
+#[unstable(feature = "volatile_ptr", issue = "none")]
+pub mod access {
+    mod private {
+        pub trait Sealed {}
+    }
+
+    /// The pointee can be read but not written, like a status register.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct ReadOnly;
+
+    /// The pointee can be written but not read, like a command register.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct WriteOnly;
+
+    /// The pointee can be both read and written.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct ReadWrite;
+
+    /// Access types that allow reading.
+    ///
+    /// This trait is sealed and cannot be implemented outside of `core`.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    pub trait Readable: private::Sealed {}
+
+    /// Access types that allow writing.
+    ///
+    /// This trait is sealed and cannot be implemented outside of `core`.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    pub trait Writable: private::Sealed {}
+
+    impl private::Sealed for ReadOnly {}
+    impl private::Sealed for WriteOnly {}
+    impl private::Sealed for ReadWrite {}
+
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    impl Readable for ReadOnly {}
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    impl Readable for ReadWrite {}
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    impl Writable for WriteOnly {}
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    impl Writable for ReadWrite {}
+
+    /// Types that are read and written with a single instruction.
+    ///
+    /// These are the primitive integers no wider than a pointer on the
+    /// current target. A volatile access to one of them is never split into
+    /// several smaller accesses, merged with neighbouring accesses, or turned
+    /// into a call to `memcpy`, so it does not tear.
+    ///
+    /// This trait is sealed and cannot be implemented outside of `core`.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    pub trait Register: Copy + private::Sealed {}
+
+    macro_rules! register {
+        ($($int:ty)*) => {$(
+            impl private::Sealed for $int {}
+            #[unstable(feature = "volatile_ptr", issue = "none")]
+            impl Register for $int {}
+        )*};
+    }
+
+    register! { u8 i8 u16 i16 usize isize }
+    #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+    register! { u32 i32 }
+    #[cfg(target_pointer_width = "64")]
+    register! { u64 i64 }
+}
+
+/// A pointer to memory that must only be accessed with volatile operations,
+/// such as a memory-mapped I/O register.
+///
+/// Unlike [`read_volatile`] and [`write_volatile`], which work on any type,
+/// `VolatilePtr` only reads and writes [`Register`] types: primitive integers
+/// no wider than a pointer. Each access is a single instruction of the
+/// register's width, which devices usually require. Blocks of registers are
+/// described with a `#[repr(C)]` struct or an array, and a pointer to one of
+/// its registers is obtained with [`map`] or [`index`].
+///
+/// The access type `A` restricts which operations are available, so that a
+/// read-only status register cannot be written by accident. It is one of
+/// [`ReadOnly`], [`WriteOnly`] or [`ReadWrite`].
+///
+/// `VolatilePtr` is `Copy`, like a raw pointer, and performs no
+/// synchronization: two threads accessing the same register at the same time
+/// is a data race unless the device allows it.
+///
+/// [`read_volatile`]: crate::ptr::read_volatile
+/// [`write_volatile`]: crate::ptr::write_volatile
+/// [`Register`]: access::Register
+/// [`map`]: VolatilePtr::map
+/// [`index`]: VolatilePtr::index
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(volatile_ptr)]
+/// use std::ptr::{self, NonNull, VolatilePtr};
+///
+/// #[repr(C)]
+/// struct Uart {
+///     data: u32,
+///     status: u32,
+/// }
+///
+/// const TX_READY: u32 = 1 << 5;
+///
+/// // SAFETY: the device's registers are mapped at this address.
+/// let uart: VolatilePtr<Uart> =
+///     unsafe { VolatilePtr::new(NonNull::new(0x1000_0000 as *mut Uart).unwrap()) };
+/// // SAFETY: the fields are within the register block.
+/// let (data, status) = unsafe {
+///     let data = uart.map(|p| NonNull::new_unchecked(ptr::addr_of_mut!((*p.as_ptr()).data)));
+///     let status = uart.map(|p| NonNull::new_unchecked(ptr::addr_of_mut!((*p.as_ptr()).status)));
+///     (data, status.read_only())
+/// };
+///
+/// for byte in b"hello\n" {
+///     while status.read() & TX_READY == 0 {}
+///     data.write(*byte as u32);
+/// }
+/// ```
+#[unstable(feature = "volatile_ptr", issue = "none")]
+#[repr(transparent)]
+pub struct VolatilePtr<T: ?Sized, A = ReadWrite> {
+    ptr: NonNull<T>,
+    access: PhantomData<A>,
+}
+
+impl<T: ?Sized, A> VolatilePtr<T, A> {
+    /// Creates a volatile pointer to `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned pointer or any pointer derived from it is
+    /// used, `ptr` must be [valid] for volatile accesses of the kinds allowed
+    /// by `A`, and properly aligned. Like with [`read_volatile`], memory that
+    /// is accessed through a `VolatilePtr` should not be accessed any other
+    /// way, except through other volatile operations.
+    ///
+    /// [valid]: crate::ptr#safety
+    /// [`read_volatile`]: crate::ptr::read_volatile
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[rustc_const_unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub const unsafe fn new(ptr: NonNull<T>) -> Self {
+        VolatilePtr { ptr, access: PhantomData }
+    }
+
+    /// Returns the underlying pointer.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[rustc_const_unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub const fn as_non_null(self) -> NonNull<T> {
+        self.ptr
+    }
+
+    /// Returns a volatile pointer to part of the pointee, such as a field of a
+    /// register block, with the same access type.
+    ///
+    /// `f` is given the underlying pointer, and usually offsets it with
+    /// [`addr_of_mut!`](crate::ptr::addr_of_mut).
+    ///
+    /// # Safety
+    ///
+    /// The pointer returned by `f` must be valid as required by
+    /// [`new`](VolatilePtr::new). It is if it points within the pointee of
+    /// `self`.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub unsafe fn map<U: ?Sized, F>(self, f: F) -> VolatilePtr<U, A>
+    where
+        F: FnOnce(NonNull<T>) -> NonNull<U>,
+    {
+        // SAFETY: guaranteed by the caller.
+        unsafe { VolatilePtr::new(f(self.ptr)) }
+    }
+
+    /// Restricts the pointer to reading.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[rustc_const_unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub const fn read_only(self) -> VolatilePtr<T, ReadOnly>
+    where
+        A: Readable,
+    {
+        // SAFETY: reads were allowed already.
+        unsafe { VolatilePtr::new(self.ptr) }
+    }
+
+    /// Restricts the pointer to writing.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[rustc_const_unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub const fn write_only(self) -> VolatilePtr<T, WriteOnly>
+    where
+        A: Writable,
+    {
+        // SAFETY: writes were allowed already.
+        unsafe { VolatilePtr::new(self.ptr) }
+    }
+}
+
+impl<T: Register, A: Readable> VolatilePtr<T, A> {
+    /// Reads the register with a single volatile load.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub fn read(self) -> T {
+        // SAFETY: the pointer is valid for reads, as promised to `new`.
+        unsafe { self.ptr.as_ptr().read_volatile() }
+    }
+}
+
+impl<T: Register, A: Writable> VolatilePtr<T, A> {
+    /// Writes `value` to the register with a single volatile store.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub fn write(self, value: T) {
+        // SAFETY: the pointer is valid for writes, as promised to `new`.
+        unsafe { self.ptr.as_ptr().write_volatile(value) }
+    }
+}
+
+impl<T: Register> VolatilePtr<T, ReadWrite> {
+    /// Reads the register, and writes back the value returned by `f`.
+    ///
+    /// This is a volatile load followed by a volatile store, not an atomic
+    /// operation.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub fn modify<F: FnOnce(T) -> T>(self, f: F) {
+        self.write(f(self.read()));
+    }
+}
+
+impl<T, A, const N: usize> VolatilePtr<[T; N], A> {
+    /// Returns a volatile pointer to the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[unstable(feature = "volatile_ptr", issue = "none")]
+    #[inline]
+    pub fn index(self, index: usize) -> VolatilePtr<T, A> {
+        assert!(index < N, "index out of bounds");
+        // SAFETY: the element is within the array.
+        unsafe { self.map(|array| array.cast::<T>().add(index)) }
+    }
+}
+
+macro_rules! field_mask {
+    ($int:ty, $bits:expr) => {{
+        let bits = &$bits;
+        assert!(
+            bits.start < bits.end && bits.end <= <$int>::BITS,
+            "bit field out of range of the register",
+        );
+        (<$int>::MAX >> (<$int>::BITS - (bits.end - bits.start))) << bits.start
+    }};
+}
+
+macro_rules! bitfield_impl {
+    ($($int:ty)*) => {$(
+        impl<A: Readable> VolatilePtr<$int, A> {
+            /// Reads the register, and returns the bits in `bits`, shifted
+            /// down to the least significant bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bits` is empty or extends past the width of the
+            /// register.
+            #[unstable(feature = "volatile_ptr", issue = "none")]
+            #[inline]
+            pub fn read_bits(self, bits: Range<u32>) -> $int {
+                (self.read() & field_mask!($int, bits)) >> bits.start
+            }
+        }
+
+        impl VolatilePtr<$int, ReadWrite> {
+            /// Replaces the bits in `bits` of the register with the least
+            /// significant bits of `value`, leaving the other bits unchanged.
+            ///
+            /// This reads the register, and then writes it back.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bits` is empty or extends past the width of the
+            /// register, or if `value` does not fit in `bits`.
+            #[unstable(feature = "volatile_ptr", issue = "none")]
+            #[inline]
+            pub fn write_bits(self, bits: Range<u32>, value: $int) {
+                let mask = field_mask!($int, bits);
+                assert!(value & !(mask >> bits.start) == 0, "value does not fit in the bit field");
+                self.modify(|old| old & !mask | value << bits.start);
+            }
+        }
+    )*};
+}
+
+bitfield_impl! { u8 u16 usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+bitfield_impl! { u32 }
+#[cfg(target_pointer_width = "64")]
+bitfield_impl! { u64 }
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> Clone for VolatilePtr<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> Copy for VolatilePtr<T, A> {}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> PartialEq for VolatilePtr<T, A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Compare the metadata as well as the address, matching `Hash`.
+        ptr::eq(self.ptr.as_ptr(), other.ptr.as_ptr())
+    }
+}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> Eq for VolatilePtr<T, A> {}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> hash::Hash for VolatilePtr<T, A> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state)
+    }
+}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> fmt::Debug for VolatilePtr<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr, f)
+    }
+}
+
+#[unstable(feature = "volatile_ptr", issue = "none")]
+impl<T: ?Sized, A> fmt::Pointer for VolatilePtr<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr, f)
+    }
+}
@@ -1,12 +1,36 @@
 // Original implementation taken from rust-memchr.
 // Copyright 2015 Andrew Gallant, bluss and Nicolas Koch
 
+use crate::intrinsics;
 use crate::mem;
+use crate::simd::cmp::SimdPartialEq;
+use crate::simd::{Mask, Simd};
 
 const LO_USIZE: usize = usize::repeat_u8(0x01);
 const HI_USIZE: usize = usize::repeat_u8(0x80);
 const USIZE_BYTES: usize = mem::size_of::<usize>();
 
+/// Whether byte searches should use vector registers.
+///
+/// `core` cannot detect CPU features at runtime, so vector instructions are only used when they
+/// are enabled at compile time, which SSE2 and NEON are by default on `x86_64` and `aarch64`.
+pub(crate) const USE_SIMD: bool = cfg!(any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+));
+
+cfg_if! {
+    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2"))] {
+        /// The number of bytes in a vector register.
+        pub(crate) const LANES: usize = 32;
+    } else {
+        /// The number of bytes in a vector register.
+        pub(crate) const LANES: usize = 16;
+    }
+}
+
+type Block = Simd<u8, LANES>;
+
 /// Returns `true` if `x` contains any zero byte.
 ///
 /// From *Matters Computational*, J. Arndt:
@@ -24,13 +48,56 @@ const fn contains_zero_byte(x: usize) -> bool {
 #[inline]
 #[must_use]
 #[rustc_const_stable(feature = "const_memchr", since = "1.65.0")]
+#[rustc_allow_const_fn_unstable(const_eval_select)]
 pub const fn memchr(x: u8, text: &[u8]) -> Option<usize> {
     // Fast path for small slices.
     if text.len() < 2 * USIZE_BYTES {
         return memchr_naive(x, text);
     }
 
-    memchr_aligned(x, text)
+    intrinsics::const_eval_select((x, text), memchr_aligned, memchr_simd)
+}
+
+#[inline]
+fn memchr_simd(x: u8, text: &[u8]) -> Option<usize> {
+    if !USE_SIMD || text.len() < LANES {
+        return memchr_aligned(x, text);
+    }
+
+    let x = Block::splat(x);
+    find_simd(text, |block| block.simd_eq(x))
+}
+
+/// Returns the first index matching either of the bytes `x1` and `x2` in `text`.
+#[inline]
+#[must_use]
+pub fn memchr2(x1: u8, x2: u8, text: &[u8]) -> Option<usize> {
+    if USE_SIMD && text.len() >= LANES {
+        let (x1, x2) = (Block::splat(x1), Block::splat(x2));
+        return find_simd(text, |block| block.simd_eq(x1) | block.simd_eq(x2));
+    }
+
+    let (r1, r2) = (usize::repeat_u8(x1), usize::repeat_u8(x2));
+    let offset = skip_words(text, |u| contains_zero_byte(u ^ r1) || contains_zero_byte(u ^ r2));
+    text[offset..].iter().position(|&b| b == x1 || b == x2).map(|i| offset + i)
+}
+
+/// Returns the first index matching any of the bytes `x1`, `x2` and `x3` in `text`.
+#[inline]
+#[must_use]
+pub fn memchr3(x1: u8, x2: u8, x3: u8, text: &[u8]) -> Option<usize> {
+    if USE_SIMD && text.len() >= LANES {
+        let (x1, x2, x3) = (Block::splat(x1), Block::splat(x2), Block::splat(x3));
+        return find_simd(text, |block| {
+            block.simd_eq(x1) | block.simd_eq(x2) | block.simd_eq(x3)
+        });
+    }
+
+    let (r1, r2, r3) = (usize::repeat_u8(x1), usize::repeat_u8(x2), usize::repeat_u8(x3));
+    let offset = skip_words(text, |u| {
+        contains_zero_byte(u ^ r1) || contains_zero_byte(u ^ r2) || contains_zero_byte(u ^ r3)
+    });
+    text[offset..].iter().position(|&b| b == x1 || b == x2 || b == x3).map(|i| offset + i)
 }
 
 #[inline]
@@ -108,6 +175,11 @@ const fn memchr_aligned(x: u8, text: &[u8]) -> Option<usize> {
 /// Returns the last index matching the byte `x` in `text`.
 #[must_use]
 pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
+    if USE_SIMD && text.len() >= LANES {
+        let x = Block::splat(x);
+        return rfind_simd(text, |block| block.simd_eq(x));
+    }
+
     // Scan for a single byte value by reading two `usize` words at a time.
     //
     // Split `text` in three parts:
@@ -158,3 +230,132 @@ pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
     // Find the byte before the point the body loop stopped.
     text[..offset].iter().rposition(|elt| *elt == x)
 }
+
+/// Returns the last index matching either of the bytes `x1` and `x2` in `text`.
+#[inline]
+#[must_use]
+pub fn memrchr2(x1: u8, x2: u8, text: &[u8]) -> Option<usize> {
+    if USE_SIMD && text.len() >= LANES {
+        let (x1, x2) = (Block::splat(x1), Block::splat(x2));
+        return rfind_simd(text, |block| block.simd_eq(x1) | block.simd_eq(x2));
+    }
+
+    let (r1, r2) = (usize::repeat_u8(x1), usize::repeat_u8(x2));
+    let end = rskip_words(text, |u| contains_zero_byte(u ^ r1) || contains_zero_byte(u ^ r2));
+    text[..end].iter().rposition(|&b| b == x1 || b == x2)
+}
+
+/// Returns the last index matching any of the bytes `x1`, `x2` and `x3` in `text`.
+#[inline]
+#[must_use]
+pub fn memrchr3(x1: u8, x2: u8, x3: u8, text: &[u8]) -> Option<usize> {
+    if USE_SIMD && text.len() >= LANES {
+        let (x1, x2, x3) = (Block::splat(x1), Block::splat(x2), Block::splat(x3));
+        return rfind_simd(text, |block| {
+            block.simd_eq(x1) | block.simd_eq(x2) | block.simd_eq(x3)
+        });
+    }
+
+    let (r1, r2, r3) = (usize::repeat_u8(x1), usize::repeat_u8(x2), usize::repeat_u8(x3));
+    let end = rskip_words(text, |u| {
+        contains_zero_byte(u ^ r1) || contains_zero_byte(u ^ r2) || contains_zero_byte(u ^ r3)
+    });
+    text[..end].iter().rposition(|&b| b == x1 || b == x2 || b == x3)
+}
+
+/// Returns the offset of the first word of `text` for which `matches` returns `true`, or of the
+/// last bytes that do not fill a whole word.
+#[inline(always)]
+fn skip_words(text: &[u8], matches: impl Fn(usize) -> bool) -> usize {
+    let mut offset = 0;
+    while offset + USIZE_BYTES <= text.len() {
+        // SAFETY: There are at least `USIZE_BYTES` bytes left after `offset`.
+        let u = unsafe { text.as_ptr().add(offset).cast::<usize>().read_unaligned() };
+        if matches(u) {
+            break;
+        }
+        offset += USIZE_BYTES;
+    }
+    offset
+}
+
+/// Returns the end of the last word of `text` for which `matches` returns `true`, or of the first
+/// bytes that do not fill a whole word.
+#[inline(always)]
+fn rskip_words(text: &[u8], matches: impl Fn(usize) -> bool) -> usize {
+    let mut end = text.len();
+    while end >= USIZE_BYTES {
+        // SAFETY: There are at least `USIZE_BYTES` bytes before `end`.
+        let u = unsafe { text.as_ptr().add(end - USIZE_BYTES).cast::<usize>().read_unaligned() };
+        if matches(u) {
+            break;
+        }
+        end -= USIZE_BYTES;
+    }
+    end
+}
+
+/// Returns the index of the first byte of `text` whose lane `matches` sets, scanning a vector at a
+/// time. `text` must be at least `LANES` bytes long.
+#[inline(always)]
+fn find_simd(text: &[u8], matches: impl Fn(Block) -> Mask<i8, LANES>) -> Option<usize> {
+    debug_assert!(text.len() >= LANES);
+    let load = |offset: usize| {
+        // SAFETY: Callers make sure that there are at least `LANES` bytes after `offset`.
+        let block = unsafe { text.as_ptr().add(offset).cast::<Block>().read_unaligned() };
+        matches(block).to_bitmask()
+    };
+
+    let mut offset = 0;
+    while offset + LANES <= text.len() {
+        let mask = load(offset);
+        if mask != 0 {
+            return Some(offset + mask.trailing_zeros() as usize);
+        }
+        offset += LANES;
+    }
+
+    // Check the remaining bytes with a final vector that overlaps the previous one, whose bytes
+    // are already known not to match.
+    if offset < text.len() {
+        let offset = text.len() - LANES;
+        let mask = load(offset);
+        if mask != 0 {
+            return Some(offset + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+/// Returns the index of the last byte of `text` whose lane `matches` sets, scanning a vector at a
+/// time. `text` must be at least `LANES` bytes long.
+#[inline(always)]
+fn rfind_simd(text: &[u8], matches: impl Fn(Block) -> Mask<i8, LANES>) -> Option<usize> {
+    debug_assert!(text.len() >= LANES);
+    let load = |offset: usize| {
+        // SAFETY: Callers make sure that there are at least `LANES` bytes after `offset`.
+        let block = unsafe { text.as_ptr().add(offset).cast::<Block>().read_unaligned() };
+        matches(block).to_bitmask()
+    };
+    let last_lane = |mask: u64| 63 - mask.leading_zeros() as usize;
+
+    let mut end = text.len();
+    while end >= LANES {
+        let offset = end - LANES;
+        let mask = load(offset);
+        if mask != 0 {
+            return Some(offset + last_lane(mask));
+        }
+        end = offset;
+    }
+
+    // Check the remaining bytes with a final vector that overlaps the previous one, as in
+    // `find_simd`.
+    if end > 0 {
+        let mask = load(0);
+        if mask != 0 {
+            return Some(last_lane(mask));
+        }
+    }
+    None
+}
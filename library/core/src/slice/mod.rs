@@ -73,6 +73,9 @@
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::{SplitInclusive, SplitInclusiveMut};
 
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+pub use sort::radix::RadixKey;
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use raw::{from_raw_parts, from_raw_parts_mut};
 
@@ -3021,6 +3024,52 @@ pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
         sort::unstable::sort(self, &mut |a, b| f(a).lt(&f(b)));
     }
 
+    /// Sorts the slice with a key extraction function, preserving initial order of equal elements,
+    /// using `scratch` as auxiliary memory.
+    ///
+    /// This is the same radix sort as [`sort_by_radix_key`], but instead of allocating, it moves
+    /// the elements back and forth between the slice and `scratch`, which makes it usable without
+    /// an allocator. The contents of `scratch` are unspecified afterwards.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n*)
+    /// worst-case, where *m* is the length in bytes of the longest key. The key function is called
+    /// up to 2 \* *m* + 1 times per element.
+    ///
+    /// If `f` returns different keys for the same element, the implementation may panic. All
+    /// original elements will remain in the slice, in an unspecified order. Same is true if `f`
+    /// panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is shorter than the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_sort_by_radix_key)]
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut v = [(3u32, 'a'), (1, 'b'), (3, 'c'), (2, 'd')];
+    /// let mut scratch = [MaybeUninit::uninit(); 4];
+    ///
+    /// v.sort_by_radix_key_with_scratch(&mut scratch, |&(k, _)| k);
+    /// assert_eq!(v, [(1, 'b'), (2, 'd'), (3, 'a'), (3, 'c')]);
+    /// ```
+    ///
+    /// [`sort_by_radix_key`]: ../../std/primitive.slice.html#method.sort_by_radix_key
+    #[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+    #[inline]
+    pub fn sort_by_radix_key_with_scratch<K, F>(
+        &mut self,
+        scratch: &mut [mem::MaybeUninit<T>],
+        mut f: F,
+    ) where
+        F: FnMut(&T) -> K,
+        K: RadixKey,
+    {
+        sort::radix::sort(self, scratch, &mut f);
+    }
+
     /// Reorder the slice such that the element at `index` after the reordering is at its final
     /// sorted position.
     ///
@@ -0,0 +1,246 @@
+//! This module contains the implementation of `slice::sort_by_radix_key`, a stable least
+//! significant digit radix sort.
+
+use crate::mem::{self, MaybeUninit, SizedTypeProperties};
+use crate::ptr;
+use crate::slice;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A key that a slice can be sorted by with [`sort_by_radix_key`].
+///
+/// Keys are ordered like their [`Ord`] implementation. This trait is implemented for the integer
+/// types, [`char`], [`bool`], byte arrays, byte slices and string slices, and for references to
+/// any of them. It is sealed, and cannot be implemented outside of the standard library.
+///
+/// [`sort_by_radix_key`]: ../../std/primitive.slice.html#method.sort_by_radix_key
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+pub trait RadixKey: private::Sealed {
+    /// Returns the length of the key, in bytes.
+    #[doc(hidden)]
+    fn radix_len(&self) -> usize;
+
+    /// Returns byte `i` of the key, counting from the most significant one. Keys are ordered
+    /// lexicographically by these bytes, so that a key that is a prefix of another comes first.
+    ///
+    /// `i` is always less than `self.radix_len()`.
+    #[doc(hidden)]
+    fn radix_byte(&self, i: usize) -> u8;
+}
+
+macro_rules! int_radix_key {
+    ($($t:ty as $u:ty),*) => {$(
+        impl private::Sealed for $t {}
+
+        #[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+        impl RadixKey for $t {
+            #[inline]
+            fn radix_len(&self) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            #[inline]
+            fn radix_byte(&self, i: usize) -> u8 {
+                // Flipping the sign bit of signed integers puts negative numbers first.
+                let key = (*self as $u) ^ (<$t>::MIN as $u);
+                (key >> (8 * (mem::size_of::<$t>() - 1 - i))) as u8
+            }
+        }
+    )*};
+}
+
+int_radix_key! {
+    u8 as u8, u16 as u16, u32 as u32, u64 as u64, u128 as u128, usize as usize,
+    i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128, isize as usize
+}
+
+impl private::Sealed for char {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl RadixKey for char {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        mem::size_of::<char>()
+    }
+
+    #[inline]
+    fn radix_byte(&self, i: usize) -> u8 {
+        (*self as u32).radix_byte(i)
+    }
+}
+
+impl private::Sealed for bool {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl RadixKey for bool {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn radix_byte(&self, _: usize) -> u8 {
+        *self as u8
+    }
+}
+
+impl<const N: usize> private::Sealed for [u8; N] {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl<const N: usize> RadixKey for [u8; N] {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn radix_byte(&self, i: usize) -> u8 {
+        self[i]
+    }
+}
+
+impl private::Sealed for [u8] {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl RadixKey for [u8] {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn radix_byte(&self, i: usize) -> u8 {
+        self[i]
+    }
+}
+
+impl private::Sealed for str {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl RadixKey for str {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn radix_byte(&self, i: usize) -> u8 {
+        self.as_bytes()[i]
+    }
+}
+
+impl<K: RadixKey + ?Sized> private::Sealed for &K {}
+
+#[unstable(feature = "slice_sort_by_radix_key", issue = "none")]
+impl<K: RadixKey + ?Sized> RadixKey for &K {
+    #[inline]
+    fn radix_len(&self) -> usize {
+        (**self).radix_len()
+    }
+
+    #[inline]
+    fn radix_byte(&self, i: usize) -> u8 {
+        (**self).radix_byte(i)
+    }
+}
+
+/// Sorts `v` by the keys returned by `f`, using `scratch` as auxiliary memory.
+///
+/// Every pass is a stable counting sort by one byte of the keys, going from the least significant
+/// byte to the most significant one, and moving the elements back and forth between `v` and
+/// `scratch`. Passes over bytes that all keys share are skipped, so that e.g. small integers in
+/// `u64` keys only cost as many passes as they have significant bytes.
+///
+/// Panics if `scratch` is shorter than `v`.
+pub fn sort<T, K, F>(v: &mut [T], scratch: &mut [MaybeUninit<T>], f: &mut F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let len = v.len();
+    assert!(scratch.len() >= len, "scratch buffer is shorter than the slice");
+
+    // Arrays of zero-sized types are always all-equal, and thus sorted.
+    if T::IS_ZST || len < 2 {
+        return;
+    }
+
+    let key_len = v.iter().map(|x| f(x).radix_len()).max().unwrap_or(0);
+
+    // Invariant: `guard.src` points to all `len` elements of `v`, in the order they are sorted in
+    // so far. The guard copies them back into `v` when it is dropped, even if `f` panics.
+    let mut guard = CopyOnDrop { src: v.as_mut_ptr(), dst: v.as_mut_ptr(), len };
+    let mut dst: *mut T = MaybeUninit::slice_as_mut_ptr(scratch);
+
+    for i in (0..key_len).rev() {
+        // SAFETY: See the invariant above.
+        let src = unsafe { slice::from_raw_parts(guard.src, len) };
+
+        // Bucket 0 is for keys that are too short to have byte `i`, which come first.
+        let mut counts = [0usize; 257];
+        for x in src {
+            counts[digit(&f(x), i)] += 1;
+        }
+        if counts.contains(&len) {
+            // All keys fall into the same bucket, so this pass would not move anything.
+            continue;
+        }
+
+        let mut offsets = [0usize; 257];
+        let mut offset = 0;
+        for (o, &count) in offsets.iter_mut().zip(&counts) {
+            *o = offset;
+            offset += count;
+        }
+
+        for x in src {
+            let d = digit(&f(x), i);
+            // `f` may not return the same keys as in the counting loop above. Writing more
+            // elements to a bucket than were counted for it would overwrite the next bucket.
+            if counts[d] == 0 {
+                panic_on_inconsistent_keys();
+            }
+            counts[d] -= 1;
+            // SAFETY: `offsets[d]` stays within the range of bucket `d`, which is within
+            // `0..len`, as checked above. `dst` is valid for `len` writes, and does not overlap
+            // with `src`.
+            unsafe { ptr::copy_nonoverlapping(x, dst.add(offsets[d]), 1) };
+            offsets[d] += 1;
+        }
+
+        // Every bucket received exactly as many elements as were counted for it, so `dst` now
+        // holds a permutation of the elements of `src`, which is left as stale copies.
+        let src = mem::replace(&mut guard.src, dst);
+        dst = src;
+    }
+}
+
+#[inline]
+fn digit<K: RadixKey>(key: &K, i: usize) -> usize {
+    if i < key.radix_len() { key.radix_byte(i) as usize + 1 } else { 0 }
+}
+
+#[cold]
+#[inline(never)]
+fn panic_on_inconsistent_keys() -> ! {
+    panic!("key function returned different keys for the same element");
+}
+
+/// Copies `len` elements from `src` to `dst` when dropped, unless they are the same.
+struct CopyOnDrop<T> {
+    src: *mut T,
+    dst: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for CopyOnDrop<T> {
+    fn drop(&mut self) {
+        if self.src != self.dst {
+            // SAFETY: `src` and `dst` are either the same, or point into `v` and `scratch`, both
+            // of which are valid for `len` elements and do not overlap.
+            unsafe { ptr::copy_nonoverlapping(self.src, self.dst, self.len) };
+        }
+    }
+}
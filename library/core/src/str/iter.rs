@@ -9,6 +9,7 @@
 use crate::ops::Try;
 use crate::option;
 use crate::slice::{self, Split as SliceSplit};
+use crate::unicode;
 
 use super::from_utf8_unchecked;
 use super::pattern::Pattern;
@@ -1600,3 +1601,59 @@ impl<'a> FusedIterator for $Name<'a> {}
 }
 
 escape_types_impls!(EscapeDebug, EscapeDefault, EscapeUnicode);
+
+/// An iterator over the extended grapheme clusters of a string slice.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: str::graphemes
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub struct Graphemes<'a> {
+    pub(super) rest: &'a str,
+}
+
+impl<'a> Graphemes<'a> {
+    /// Views the underlying data as a subslice of the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let mut graphemes = "ne\u{301}e".graphemes();
+    /// assert_eq!(graphemes.next(), Some("n"));
+    /// assert_eq!(graphemes.as_str(), "e\u{301}e");
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "str_graphemes", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (cluster, rest) = self.rest.split_at(unicode::grapheme::first_cluster_len(self.rest));
+        self.rest = rest;
+        Some(cluster)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (!self.rest.is_empty() as usize, Some(self.rest.len()))
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl FusedIterator for Graphemes<'_> {}
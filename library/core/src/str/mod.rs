@@ -73,6 +73,9 @@
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::SplitInclusive;
 
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub use iter::Graphemes;
+
 #[unstable(feature = "str_internals", issue = "none")]
 pub use validations::{next_code_point, utf8_char_width};
 
@@ -1037,6 +1040,38 @@ pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
         SplitAsciiWhitespace { inner }
     }
 
+    /// Returns an iterator over the extended grapheme clusters of a string
+    /// slice.
+    ///
+    /// A grapheme cluster is what a user would usually think of as a single
+    /// character, even though it may be made of several [`char`]s, such as a
+    /// letter followed by combining accents, a Hangul syllable written as its
+    /// jamo, or an emoji sequence joined by zero width joiners. Clusters are
+    /// found using the rules for extended grapheme clusters from
+    /// [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// Note that the boundaries of grapheme clusters may change with new
+    /// versions of Unicode; see [`char::UNICODE_VERSION`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let graphemes: Vec<&str> = "e\u{301}\r\n\u{1F469}\u{200D}\u{1F52C}!".graphemes().collect();
+    /// assert_eq!(graphemes, ["e\u{301}", "\r\n", "\u{1F469}\u{200D}\u{1F52C}", "!"]);
+    ///
+    /// // A flag is made of two regional indicators.
+    /// assert_eq!("\u{1F1EB}\u{1F1F7}\u{1F1EE}".graphemes().count(), 2);
+    /// ```
+    #[must_use = "this returns the clusters as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_graphemes", issue = "none")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes { rest: self }
+    }
+
     /// An iterator over the lines of a string, as string slices.
     ///
     /// Lines are split at line endings that are either newlines (`\n`) or
@@ -609,6 +609,13 @@ fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
 #[doc(hidden)]
 trait MultiCharEq {
     fn matches(&mut self, c: char) -> bool;
+
+    /// Returns the characters that match, if the pattern is a set of characters rather than a
+    /// predicate.
+    #[inline]
+    fn chars(&self) -> Option<&[char]> {
+        None
+    }
 }
 
 impl<F> MultiCharEq for F
@@ -626,6 +633,11 @@ fn matches(&mut self, c: char) -> bool {
     fn matches(&mut self, c: char) -> bool {
         self.iter().any(|&m| m == c)
     }
+
+    #[inline]
+    fn chars(&self) -> Option<&[char]> {
+        Some(self)
+    }
 }
 
 impl<const N: usize> MultiCharEq for &[char; N] {
@@ -633,6 +645,11 @@ fn matches(&mut self, c: char) -> bool {
     fn matches(&mut self, c: char) -> bool {
         self.iter().any(|&m| m == c)
     }
+
+    #[inline]
+    fn chars(&self) -> Option<&[char]> {
+        Some(*self)
+    }
 }
 
 impl MultiCharEq for &[char] {
@@ -640,6 +657,11 @@ impl MultiCharEq for &[char] {
     fn matches(&mut self, c: char) -> bool {
         self.iter().any(|&m| m == c)
     }
+
+    #[inline]
+    fn chars(&self) -> Option<&[char]> {
+        Some(*self)
+    }
 }
 
 struct MultiCharEqPattern<C: MultiCharEq>(C);
@@ -651,6 +673,31 @@ struct MultiCharEqSearcher<'a, C: MultiCharEq> {
     char_indices: super::CharIndices<'a>,
 }
 
+impl<'a, C: MultiCharEq> MultiCharEqSearcher<'a, C> {
+    /// Returns the index of the first (or, if `reverse` is set, last) match in `bytes`, found
+    /// with `memchr2` or `memchr3`, if the pattern is a set of two or three ASCII characters.
+    /// Returns `None` for any other pattern.
+    #[inline]
+    fn find_ascii(&self, bytes: &[u8], reverse: bool) -> Option<Option<usize>> {
+        let index = match *self.char_eq.chars()? {
+            [a, b] if a.is_ascii() && b.is_ascii() => {
+                let (a, b) = (a as u8, b as u8);
+                if reverse { memchr::memrchr2(a, b, bytes) } else { memchr::memchr2(a, b, bytes) }
+            }
+            [a, b, c] if a.is_ascii() && b.is_ascii() && c.is_ascii() => {
+                let (a, b, c) = (a as u8, b as u8, c as u8);
+                if reverse {
+                    memchr::memrchr3(a, b, c, bytes)
+                } else {
+                    memchr::memchr3(a, b, c, bytes)
+                }
+            }
+            _ => return None,
+        };
+        Some(index)
+    }
+}
+
 impl<'a, C: MultiCharEq> Pattern<'a> for MultiCharEqPattern<C> {
     type Searcher = MultiCharEqSearcher<'a, C>;
 
@@ -683,6 +730,27 @@ fn next(&mut self) -> SearchStep {
         }
         SearchStep::Done
     }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let bytes = self.char_indices.iter.iter.as_slice();
+        let Some(index) = self.find_ascii(bytes, false) else {
+            loop {
+                match self.next() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    _ => continue,
+                }
+            }
+        };
+        let s = &mut self.char_indices;
+        // The match is a single ASCII byte, so the remaining characters start right after it.
+        let end = index.map_or(bytes.len(), |i| i + 1);
+        let start = s.front_offset;
+        s.front_offset += end;
+        s.iter.iter = bytes[end..].iter();
+        index.map(|i| (start + i, start + i + 1))
+    }
 }
 
 unsafe impl<'a, C: MultiCharEq> ReverseSearcher<'a> for MultiCharEqSearcher<'a, C> {
@@ -703,6 +771,24 @@ fn next_back(&mut self) -> SearchStep {
         }
         SearchStep::Done
     }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let bytes = self.char_indices.iter.iter.as_slice();
+        let Some(index) = self.find_ascii(bytes, true) else {
+            loop {
+                match self.next_back() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    _ => continue,
+                }
+            }
+        };
+        let s = &mut self.char_indices;
+        // The match is a single ASCII byte, so the remaining characters end right before it.
+        s.iter.iter = bytes[..index.unwrap_or(0)].iter();
+        index.map(|i| (s.front_offset + i, s.front_offset + i + 1))
+    }
 }
 
 impl<'a, C: MultiCharEq> DoubleEndedSearcher<'a> for MultiCharEqSearcher<'a, C> {}
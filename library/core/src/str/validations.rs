@@ -128,10 +128,7 @@ pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
     let mut index = 0;
     let len = v.len();
 
-    let usize_bytes = mem::size_of::<usize>();
-    let ascii_block_size = 2 * usize_bytes;
-    let blocks_end = if len >= ascii_block_size { len - ascii_block_size + 1 } else { 0 };
-    let align = v.as_ptr().align_offset(usize_bytes);
+    let align = v.as_ptr().align_offset(mem::size_of::<usize>());
 
     while index < len {
         let old_offset = index;
@@ -208,37 +205,97 @@ macro_rules! next {
             index += 1;
         } else {
             // Ascii case, try to skip forward quickly.
-            // When the pointer is aligned, read 2 words of data per iteration
-            // until we find a word containing a non-ascii byte.
-            if align != usize::MAX && align.wrapping_sub(index) % usize_bytes == 0 {
-                let ptr = v.as_ptr();
-                while index < blocks_end {
-                    // SAFETY: since `align - index` and `ascii_block_size` are
-                    // multiples of `usize_bytes`, `block = ptr.add(index)` is
-                    // always aligned with a `usize` so it's safe to dereference
-                    // both `block` and `block.add(1)`.
-                    unsafe {
-                        let block = ptr.add(index) as *const usize;
-                        // break if there is a nonascii byte
-                        let zu = contains_nonascii(*block);
-                        let zv = contains_nonascii(*block.add(1));
-                        if zu || zv {
-                            break;
-                        }
-                    }
-                    index += ascii_block_size;
-                }
-                // step from the point where the wordwise loop stopped
-                while index < len && v[index] < 128 {
-                    index += 1;
+            index = skip_ascii(v, index, align);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the index of the first non-ASCII byte in `v` at or after `index`, or `v.len()` if
+/// there is none. `v[index]` must be ASCII. This may stop early at an ASCII byte, which the caller
+/// then handles one byte at a time.
+///
+/// `align` is the offset of the first `usize`-aligned byte in `v`.
+#[inline(always)]
+#[rustc_const_unstable(feature = "str_internals", issue = "none")]
+#[rustc_allow_const_fn_unstable(const_eval_select)]
+const fn skip_ascii(v: &[u8], index: usize, align: usize) -> usize {
+    crate::intrinsics::const_eval_select((v, index, align), skip_ascii_words, skip_ascii_simd)
+}
+
+/// The portable implementation of [`skip_ascii`].
+#[inline(always)]
+#[rustc_const_unstable(feature = "str_internals", issue = "none")]
+const fn skip_ascii_words(v: &[u8], mut index: usize, align: usize) -> usize {
+    let len = v.len();
+    let usize_bytes = mem::size_of::<usize>();
+    let ascii_block_size = 2 * usize_bytes;
+    let blocks_end = if len >= ascii_block_size { len - ascii_block_size + 1 } else { 0 };
+
+    // When the pointer is aligned, read 2 words of data per iteration
+    // until we find a word containing a non-ascii byte.
+    if align != usize::MAX && align.wrapping_sub(index) % usize_bytes == 0 {
+        let ptr = v.as_ptr();
+        while index < blocks_end {
+            // SAFETY: since `align - index` and `ascii_block_size` are
+            // multiples of `usize_bytes`, `block = ptr.add(index)` is
+            // always aligned with a `usize` so it's safe to dereference
+            // both `block` and `block.add(1)`.
+            unsafe {
+                let block = ptr.add(index) as *const usize;
+                // break if there is a nonascii byte
+                let zu = contains_nonascii(*block);
+                let zv = contains_nonascii(*block.add(1));
+                if zu || zv {
+                    break;
                 }
-            } else {
-                index += 1;
             }
+            index += ascii_block_size;
+        }
+        // step from the point where the wordwise loop stopped
+        while index < len && v[index] < 128 {
+            index += 1;
         }
+        index
+    } else {
+        index + 1
     }
+}
 
-    Ok(())
+/// The implementation of [`skip_ascii`] for targets with vector registers, which reads two
+/// vectors per iteration, without any alignment requirements.
+#[inline(always)]
+fn skip_ascii_simd(v: &[u8], mut index: usize, align: usize) -> usize {
+    use crate::simd::cmp::SimdPartialOrd;
+    use crate::simd::Simd;
+    use crate::slice::memchr::{LANES, USE_SIMD};
+
+    if !USE_SIMD {
+        return skip_ascii_words(v, index, align);
+    }
+
+    // Non-ASCII bytes are exactly the negative ones, which lets the comparison compile to a
+    // single instruction that collects the sign bits.
+    type Block = Simd<i8, LANES>;
+    let zero = Block::splat(0);
+    let ptr = v.as_ptr();
+    while index + 2 * LANES <= v.len() {
+        // SAFETY: There are at least `2 * LANES` bytes after `index`.
+        let (a, b) = unsafe {
+            let block = ptr.add(index).cast::<Block>();
+            (block.read_unaligned(), block.add(1).read_unaligned())
+        };
+        let mask = a.simd_lt(zero).to_bitmask() | b.simd_lt(zero).to_bitmask() << LANES;
+        if mask != 0 {
+            return index + mask.trailing_zeros() as usize;
+        }
+        index += 2 * LANES;
+    }
+    while index < v.len() && v[index] < 128 {
+        index += 1;
+    }
+    index
 }
 
 // https://tools.ietf.org/html/rfc3629
@@ -133,6 +133,12 @@
 //! option set for each supported size, with values "8", "16", "32", "64",
 //! "128", and "ptr" for pointer-sized atomics.
 //!
+//! The set of widths is fixed per target and does not depend on enabled target
+//! features. As an exception, `AtomicI128` and `AtomicU128` are also available
+//! on x86_64 when compiling with `-C target-feature=+cmpxchg16b`, even though
+//! `target_has_atomic = "128"` is not set there. Every access to them, loads
+//! included, is then performed with `cmpxchg16b`.
+//!
 //! [lock-free]: https://en.wikipedia.org/wiki/Non-blocking_algorithm
 //!
 //! # Atomic accesses to read-only memory
@@ -3171,10 +3177,19 @@ pub const fn as_ptr(&self) -> *mut $int_type {
     8,
     u64 AtomicU64
 }
-#[cfg(target_has_atomic_load_store = "128")]
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
 atomic_int! {
-    cfg(target_has_atomic = "128"),
-    cfg(target_has_atomic_equal_alignment = "128"),
+    cfg(any(
+        target_has_atomic = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    )),
+    cfg(any(
+        target_has_atomic_equal_alignment = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    )),
     unstable(feature = "integer_atomics", issue = "99069"),
     unstable(feature = "integer_atomics", issue = "99069"),
     unstable(feature = "integer_atomics", issue = "99069"),
@@ -3189,10 +3204,19 @@ pub const fn as_ptr(&self) -> *mut $int_type {
     16,
     i128 AtomicI128
 }
-#[cfg(target_has_atomic_load_store = "128")]
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
 atomic_int! {
-    cfg(target_has_atomic = "128"),
-    cfg(target_has_atomic_equal_alignment = "128"),
+    cfg(any(
+        target_has_atomic = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    )),
+    cfg(any(
+        target_has_atomic_equal_alignment = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    )),
     unstable(feature = "integer_atomics", issue = "99069"),
     unstable(feature = "integer_atomics", issue = "99069"),
     unstable(feature = "integer_atomics", issue = "99069"),
@@ -3277,6 +3301,467 @@ macro_rules! atomic_int_ptr_sized {
     "64" 8
 }
 
+#[cfg(target_has_atomic_load_store)]
+macro_rules! atomic_float {
+    ($cfg_cas:meta,
+     $s_float_type:literal,
+     $align:expr,
+     $float_type:ident $atomic_type:ident $bits_atomic_type:ident) => {
+        /// A floating point type which can be safely shared between threads.
+        ///
+        #[doc = concat!("This type has the same size and bit validity as [`", $s_float_type, "`]. ")]
+        #[doc = concat!("Its alignment is always ", $align, " bytes.")]
+        ///
+        /// The value is stored as its bits, so operations which compare the
+        /// current value, such as [`compare_exchange`], compare it bitwise:
+        /// `0.0` and `-0.0` are different, and a NaN matches itself if its
+        /// payload is the same.
+        ///
+        /// For more about the differences between atomic types and
+        /// non-atomic types as well as information about the portability of
+        /// this type, please see the [module-level documentation].
+        ///
+        /// **Note:** This type is only available on platforms that support
+        #[doc = concat!("atomic loads and stores of [`", $s_float_type, "`].")]
+        ///
+        #[doc = concat!("[`compare_exchange`]: ", stringify!($atomic_type), "::compare_exchange")]
+        /// [module-level documentation]: crate::sync::atomic
+        #[unstable(feature = "atomic_float", issue = "none")]
+        #[repr(transparent)]
+        pub struct $atomic_type {
+            bits: $bits_atomic_type,
+        }
+
+        #[unstable(feature = "atomic_float", issue = "none")]
+        impl Default for $atomic_type {
+            #[inline]
+            fn default() -> Self {
+                Self::new(0.0)
+            }
+        }
+
+        #[unstable(feature = "atomic_float", issue = "none")]
+        impl From<$float_type> for $atomic_type {
+            #[doc = concat!("Converts an `", stringify!($float_type), "` into an `", stringify!($atomic_type), "`.")]
+            #[inline]
+            fn from(v: $float_type) -> Self {
+                Self::new(v)
+            }
+        }
+
+        #[unstable(feature = "atomic_float", issue = "none")]
+        impl fmt::Debug for $atomic_type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+
+        // Send and Sync are implicitly implemented through the integer atomic.
+
+        impl $atomic_type {
+            /// Creates a new atomic float.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_float)]
+            #[doc = concat!("use std::sync::atomic::", stringify!($atomic_type), ";")]
+            ///
+            #[doc = concat!("let atomic_pi = ", stringify!($atomic_type), "::new(3.14);")]
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[rustc_const_unstable(feature = "atomic_float", issue = "none")]
+            #[must_use]
+            pub const fn new(v: $float_type) -> Self {
+                Self { bits: $bits_atomic_type::new(v.to_bits()) }
+            }
+
+            /// Creates a new reference to an atomic float from a pointer.
+            ///
+            /// # Safety
+            ///
+            /// * `ptr` must be aligned to
+            #[doc = concat!("  `align_of::<", stringify!($atomic_type), ">()`")]
+            #[doc = concat!("  (note that on some platforms this can be bigger than `align_of::<", stringify!($float_type), ">()`).")]
+            /// * `ptr` must be [valid] for both reads and writes for the whole lifetime `'a`.
+            /// * You must adhere to the [Memory model for atomic accesses]. In particular, it is not
+            ///   allowed to mix atomic and non-atomic accesses, or atomic accesses of different sizes,
+            ///   without synchronization.
+            ///
+            /// [valid]: crate::ptr#safety
+            /// [Memory model for atomic accesses]: self#memory-model-for-atomic-accesses
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[rustc_const_unstable(feature = "atomic_float", issue = "none")]
+            pub const unsafe fn from_ptr<'a>(ptr: *mut $float_type) -> &'a $atomic_type {
+                // SAFETY: guaranteed by the caller
+                unsafe { &*ptr.cast() }
+            }
+
+            /// Returns a mutable reference to the underlying float.
+            ///
+            /// This is safe because the mutable reference guarantees that no other threads are
+            /// concurrently accessing the atomic data.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            pub fn get_mut(&mut self) -> &mut $float_type {
+                // SAFETY: the float and its bits have the same size and any bit
+                // pattern is a valid float, and the mutable reference
+                // guarantees unique access.
+                unsafe { &mut *self.bits.as_ptr().cast::<$float_type>() }
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            ///
+            /// This is safe because passing `self` by value guarantees that no other threads are
+            /// concurrently accessing the atomic data.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            pub fn into_inner(self) -> $float_type {
+                $float_type::from_bits(self.bits.into_inner())
+            }
+
+            /// Loads a value from the atomic float.
+            ///
+            /// `load` takes an [`Ordering`] argument which describes the memory ordering of this
+            /// operation. Possible values are [`SeqCst`], [`Acquire`] and [`Relaxed`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Release`] or [`AcqRel`].
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            pub fn load(&self, order: Ordering) -> $float_type {
+                $float_type::from_bits(self.bits.load(order))
+            }
+
+            /// Stores a value into the atomic float.
+            ///
+            /// `store` takes an [`Ordering`] argument which describes the memory ordering of this
+            /// operation.  Possible values are [`SeqCst`], [`Release`] and [`Relaxed`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Acquire`] or [`AcqRel`].
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            pub fn store(&self, val: $float_type, order: Ordering) {
+                self.bits.store(val.to_bits(), order)
+            }
+
+            /// Stores a value into the atomic float, returning the previous value.
+            ///
+            /// `swap` takes an [`Ordering`] argument which describes the memory ordering
+            /// of this operation. All ordering modes are possible. Note that using
+            /// [`Acquire`] makes the store part of this operation [`Relaxed`], and
+            /// using [`Release`] makes the load part [`Relaxed`].
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn swap(&self, val: $float_type, order: Ordering) -> $float_type {
+                $float_type::from_bits(self.bits.swap(val.to_bits(), order))
+            }
+
+            /// Stores a value into the atomic float if the current value is bitwise equal to
+            /// the `current` value.
+            ///
+            /// The return value is a result indicating whether the new value was written and
+            /// containing the previous value. On success this value is guaranteed to be
+            /// bitwise equal to `current`.
+            ///
+            /// `compare_exchange` takes two [`Ordering`] arguments to describe the memory
+            /// ordering of this operation, as for the integer atomics.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_float)]
+            #[doc = concat!("use std::sync::atomic::", stringify!($atomic_type), ";")]
+            /// use std::sync::atomic::Ordering::SeqCst;
+            ///
+            #[doc = concat!("let some_var = ", stringify!($atomic_type), "::new(0.0);")]
+            ///
+            /// assert_eq!(some_var.compare_exchange(-0.0, 1.0, SeqCst, SeqCst), Err(0.0));
+            /// assert_eq!(some_var.compare_exchange(0.0, 1.0, SeqCst, SeqCst), Ok(0.0));
+            /// assert_eq!(some_var.load(SeqCst), 1.0);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn compare_exchange(
+                &self,
+                current: $float_type,
+                new: $float_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$float_type, $float_type> {
+                self.bits
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map($float_type::from_bits)
+                    .map_err($float_type::from_bits)
+            }
+
+            /// Stores a value into the atomic float if the current value is bitwise equal to
+            /// the `current` value.
+            ///
+            #[doc = concat!("Unlike [`", stringify!($atomic_type), "::compare_exchange`],")]
+            /// this function is allowed to spuriously fail even
+            /// when the comparison succeeds, which can result in more efficient code on some
+            /// platforms. The return value is a result indicating whether the new value was
+            /// written and containing the previous value.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $float_type,
+                new: $float_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$float_type, $float_type> {
+                self.bits
+                    .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+                    .map($float_type::from_bits)
+                    .map_err($float_type::from_bits)
+            }
+
+            /// Fetches the value, and applies a function to it that returns an optional
+            /// new value. Returns a `Result` of `Ok(previous_value)` if the function returned
+            /// `Some(_)`, else `Err(previous_value)`.
+            ///
+            /// Note: This may call the function multiple times if the value has been changed
+            /// from other threads in the meantime, as long as the function returns `Some(_)`,
+            /// but the function will have been applied only once to the stored value.
+            ///
+            /// `fetch_update` takes two [`Ordering`] arguments to describe the memory ordering
+            /// of this operation, as for the integer atomics.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$float_type, $float_type>
+            where
+                F: FnMut($float_type) -> Option<$float_type>,
+            {
+                self.bits
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f($float_type::from_bits(bits)).map($float_type::to_bits)
+                    })
+                    .map($float_type::from_bits)
+                    .map_err($float_type::from_bits)
+            }
+
+            /// Adds to the current value, returning the previous value.
+            ///
+            /// The addition is done with a compare-and-swap loop, so it follows
+            /// IEEE 754 arithmetic exactly as `+` does.
+            ///
+            /// `fetch_add` takes an [`Ordering`] argument which describes the memory ordering
+            /// of this operation. All ordering modes are possible. Note that using
+            /// [`Acquire`] makes the store part of this operation [`Relaxed`], and
+            /// using [`Release`] makes the load part [`Relaxed`].
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_float)]
+            #[doc = concat!("use std::sync::atomic::{", stringify!($atomic_type), ", Ordering};")]
+            ///
+            #[doc = concat!("let total = ", stringify!($atomic_type), "::new(0.5);")]
+            /// assert_eq!(total.fetch_add(1.0, Ordering::Relaxed), 0.5);
+            /// assert_eq!(total.load(Ordering::Relaxed), 1.5);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn fetch_add(&self, val: $float_type, order: Ordering) -> $float_type {
+                self.fetch_apply(order, |x| x + val)
+            }
+
+            /// Subtracts from the current value, returning the previous value.
+            ///
+            /// See [`fetch_add`](Self::fetch_add) for details.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn fetch_sub(&self, val: $float_type, order: Ordering) -> $float_type {
+                self.fetch_apply(order, |x| x - val)
+            }
+
+            /// Sets the current value to the maximum of it and `val`, as computed by
+            #[doc = concat!("[`", stringify!($float_type), "::max`], returning the previous value.")]
+            ///
+            /// See [`fetch_add`](Self::fetch_add) for details.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn fetch_max(&self, val: $float_type, order: Ordering) -> $float_type {
+                self.fetch_apply(order, |x| x.max(val))
+            }
+
+            /// Sets the current value to the minimum of it and `val`, as computed by
+            #[doc = concat!("[`", stringify!($float_type), "::min`], returning the previous value.")]
+            ///
+            /// See [`fetch_add`](Self::fetch_add) for details.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[$cfg_cas]
+            pub fn fetch_min(&self, val: $float_type, order: Ordering) -> $float_type {
+                self.fetch_apply(order, |x| x.min(val))
+            }
+
+            #[inline]
+            #[$cfg_cas]
+            fn fetch_apply(
+                &self,
+                order: Ordering,
+                mut f: impl FnMut($float_type) -> $float_type,
+            ) -> $float_type {
+                match self.fetch_update(order, strongest_failure_ordering(order), |x| Some(f(x))) {
+                    Ok(prev) | Err(prev) => prev,
+                }
+            }
+
+            /// Returns a mutable pointer to the underlying float.
+            ///
+            /// Doing non-atomic reads and writes on the resulting float can be a data race.
+            /// This method is mostly useful for FFI.
+            #[inline]
+            #[unstable(feature = "atomic_float", issue = "none")]
+            #[rustc_const_unstable(feature = "atomic_float", issue = "none")]
+            pub const fn as_ptr(&self) -> *mut $float_type {
+                self.bits.as_ptr().cast()
+            }
+        }
+    };
+}
+
+#[cfg(target_has_atomic_load_store = "32")]
+atomic_float! {
+    cfg(target_has_atomic = "32"),
+    "f32",
+    4,
+    f32 AtomicF32 AtomicU32
+}
+#[cfg(target_has_atomic_load_store = "64")]
+atomic_float! {
+    cfg(target_has_atomic = "64"),
+    "f64",
+    8,
+    f64 AtomicF64 AtomicU64
+}
+
+mod private {
+    /// Prevents implementing `AtomicPrimitive` outside of the standard library.
+    #[unstable(feature = "generic_atomic", issue = "none")]
+    pub trait Sealed {}
+}
+
+/// A primitive type which has an atomic counterpart.
+///
+/// This trait is implemented for the types that have an atomic type on the
+/// current target, and is used by [`Atomic`] to find it. It is sealed and
+/// cannot be implemented outside the standard library.
+#[unstable(feature = "generic_atomic", issue = "none")]
+pub trait AtomicPrimitive: Sized + Copy + private::Sealed {
+    /// The atomic type holding a value of this type.
+    #[unstable(feature = "generic_atomic", issue = "none")]
+    type AtomicInner: Sized;
+}
+
+macro_rules! impl_atomic_primitive {
+    ($($cfg:literal => [$($primitive:ty => $atomic:ident,)*])*) => {
+        $($(
+            #[cfg(target_has_atomic_load_store = $cfg)]
+            #[unstable(feature = "generic_atomic", issue = "none")]
+            impl private::Sealed for $primitive {}
+
+            #[cfg(target_has_atomic_load_store = $cfg)]
+            #[unstable(feature = "generic_atomic", issue = "none")]
+            impl AtomicPrimitive for $primitive {
+                type AtomicInner = $atomic;
+            }
+        )*)*
+    };
+}
+
+impl_atomic_primitive! {
+    "8" => [bool => AtomicBool, i8 => AtomicI8, u8 => AtomicU8,]
+    "16" => [i16 => AtomicI16, u16 => AtomicU16,]
+    "32" => [i32 => AtomicI32, u32 => AtomicU32, f32 => AtomicF32,]
+    "64" => [i64 => AtomicI64, u64 => AtomicU64, f64 => AtomicF64,]
+    "ptr" => [isize => AtomicIsize, usize => AtomicUsize,]
+}
+
+// 128-bit atomics are also available on x86_64 with `cmpxchg16b`, which
+// `target_has_atomic_load_store` doesn't reflect.
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl private::Sealed for i128 {}
+
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl AtomicPrimitive for i128 {
+    type AtomicInner = AtomicI128;
+}
+
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl private::Sealed for u128 {}
+
+#[cfg(any(
+    target_has_atomic_load_store = "128",
+    all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+))]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl AtomicPrimitive for u128 {
+    type AtomicInner = AtomicU128;
+}
+
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl<T> private::Sealed for *mut T {}
+
+#[cfg(target_has_atomic_load_store = "ptr")]
+#[unstable(feature = "generic_atomic", issue = "none")]
+impl<T> AtomicPrimitive for *mut T {
+    type AtomicInner = AtomicPtr<T>;
+}
+
+/// The atomic counterpart of the primitive type `T`.
+///
+/// `Atomic<u32>` is [`AtomicU32`], `Atomic<f64>` is [`AtomicF64`],
+/// `Atomic<*mut T>` is [`AtomicPtr<T>`], and so on. This makes it possible to
+/// write code that is generic over the width of an atomic, or to name an
+/// atomic after the type of its value.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(generic_atomic)]
+/// use std::sync::atomic::{Atomic, Ordering};
+///
+/// let counter: Atomic<u64> = Atomic::<u64>::new(0);
+/// counter.fetch_add(1, Ordering::Relaxed);
+/// assert_eq!(counter.into_inner(), 1);
+/// ```
+#[unstable(feature = "generic_atomic", issue = "none")]
+pub type Atomic<T> = <T as AtomicPrimitive>::AtomicInner;
+
 #[inline]
 #[cfg(target_has_atomic)]
 fn strongest_failure_ordering(order: Ordering) -> Ordering {
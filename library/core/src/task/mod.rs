@@ -9,6 +9,12 @@
 mod wake;
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub use self::wake::{Context, ContextBuilder, LocalWaker, RawWaker, RawWakerVTable, Waker};
+#[unstable(feature = "noop_waker", issue = "98286")]
+pub use self::wake::noop_waker;
+
+mod static_wake;
+#[unstable(feature = "static_waker", issue = "none")]
+pub use self::static_wake::StaticWake;
 
 mod ready;
 #[stable(feature = "ready_macro", since = "1.64.0")]
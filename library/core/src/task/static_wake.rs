@@ -0,0 +1,96 @@
+use crate::task::{RawWaker, RawWakerVTable, Waker};
+
+/// The implementation of waking a task through a `'static` reference.
+///
+/// This trait can be used to create a [`Waker`] without reference counting
+/// or heap allocation, for wakers whose state lives in a `static` or is
+/// otherwise leaked for the rest of the program. Cloning or dropping such a
+/// waker does nothing; waking it calls [`wake`](StaticWake::wake) on the
+/// referenced value.
+///
+/// To construct a [`Waker`] from some type `W` implementing this trait, call
+/// `Waker::from()` on a `&'static W`. It is also possible to convert to
+/// [`RawWaker`] in the same way.
+///
+/// This is the allocation-free counterpart of the `Wake` trait in `alloc`,
+/// which requires the waker state to be stored in an `Arc`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(static_waker)]
+///
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::task::{StaticWake, Waker};
+///
+/// struct Flag(AtomicBool);
+///
+/// impl StaticWake for Flag {
+///     fn wake(&'static self) {
+///         self.0.store(true, Ordering::Release);
+///     }
+/// }
+///
+/// static WOKEN: Flag = Flag(AtomicBool::new(false));
+///
+/// let waker = Waker::from(&WOKEN);
+/// waker.clone().wake();
+/// assert!(WOKEN.0.load(Ordering::Acquire));
+/// ```
+#[unstable(feature = "static_waker", issue = "none")]
+pub trait StaticWake: Sync + 'static {
+    /// Wake the task associated with this value.
+    #[unstable(feature = "static_waker", issue = "none")]
+    fn wake(&'static self);
+}
+
+#[allow(ineffective_unstable_trait_impl)]
+#[unstable(feature = "static_waker", issue = "none")]
+impl<W: StaticWake> From<&'static W> for Waker {
+    /// Use a [`StaticWake`]-able value as a `Waker`.
+    ///
+    /// No heap allocations or atomic operations are used for this conversion.
+    fn from(waker: &'static W) -> Waker {
+        // SAFETY: This is safe because raw_waker safely constructs
+        // a RawWaker from &'static W.
+        unsafe { Waker::from_raw(raw_waker(waker)) }
+    }
+}
+
+#[allow(ineffective_unstable_trait_impl)]
+#[unstable(feature = "static_waker", issue = "none")]
+impl<W: StaticWake> From<&'static W> for RawWaker {
+    /// Use a [`StaticWake`]-able value as a `RawWaker`.
+    ///
+    /// No heap allocations or atomic operations are used for this conversion.
+    fn from(waker: &'static W) -> RawWaker {
+        raw_waker(waker)
+    }
+}
+
+// NB: As with `alloc::task::raw_waker`, both `From` impls call this function
+// directly, so the safety of `From<&'static W> for Waker` does not depend on
+// trait dispatch.
+#[inline(always)]
+fn raw_waker<W: StaticWake>(waker: &'static W) -> RawWaker {
+    // The reference is `'static`, so cloning it only copies the pointer.
+    unsafe fn clone_waker<W: StaticWake>(waker: *const ()) -> RawWaker {
+        // SAFETY: `waker` was created from a `&'static W` in `raw_waker`.
+        raw_waker(unsafe { &*(waker as *const W) })
+    }
+
+    // Wake by reference; there is nothing to release afterwards.
+    unsafe fn wake<W: StaticWake>(waker: *const ()) {
+        // SAFETY: `waker` was created from a `&'static W` in `raw_waker`.
+        let waker: &'static W = unsafe { &*(waker as *const W) };
+        waker.wake();
+    }
+
+    // Nothing is owned, so dropping does nothing.
+    unsafe fn drop_waker(_: *const ()) {}
+
+    RawWaker::new(
+        waker as *const W as *const (),
+        &RawWakerVTable::new(clone_waker::<W>, wake::<W>, wake::<W>, drop_waker),
+    )
+}
@@ -567,6 +567,31 @@ pub fn as_raw(&self) -> &RawWaker {
     }
 }
 
+/// Returns a reference to a [`Waker`] that does nothing when used.
+///
+/// This is a shorthand for [`Waker::noop()`], for executors and tests that
+/// poll futures which are not expected to wake the waker.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(noop_waker)]
+///
+/// use std::future::Future;
+/// use std::task::{self, Context, Poll};
+///
+/// let mut cx = Context::from_waker(task::noop_waker());
+///
+/// let mut future = Box::pin(async { 10 });
+/// assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(10));
+/// ```
+#[inline]
+#[must_use]
+#[unstable(feature = "noop_waker", issue = "98286")]
+pub const fn noop_waker() -> &'static Waker {
+    Waker::noop()
+}
+
 #[stable(feature = "futures_api", since = "1.36.0")]
 impl Clone for Waker {
     #[inline]
@@ -0,0 +1,112 @@
+//! Extended grapheme cluster boundaries, following the rules of
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules).
+
+use super::unicode_data::grapheme_cluster_break::*;
+
+/// Returns the length in bytes of the first extended grapheme cluster of `s`,
+/// or 0 if `s` is empty.
+pub(crate) fn first_cluster_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    // Fast path: there is a boundary between two ASCII characters, except in
+    // the middle of CR LF.
+    match bytes {
+        [] => return 0,
+        [_] => return 1,
+        [first, second, ..] if first.is_ascii() && *first != b'\r' && second.is_ascii() => {
+            return 1;
+        }
+        _ => {}
+    }
+
+    let mut chars = s.char_indices();
+    let (_, first) = chars.next().unwrap();
+    let mut state = State::new(lookup(first));
+    for (i, c) in chars {
+        if state.is_boundary_before(lookup(c)) {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// Indic_Conjunct_Break states, for GB9c.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    /// After a consonant, followed by any number of extending characters.
+    Consonant,
+    /// As `Consonant`, but at least one of them was a linker.
+    Linked,
+}
+
+/// The part of the text before a possible boundary that the rules look at.
+struct State {
+    /// The value of the last character, as returned by `lookup`.
+    prev: u8,
+    /// The number of regional indicators at the end, for GB12 and GB13.
+    regional_indicators: usize,
+    /// Whether the text ends with `Extended_Pictographic Extend*`, possibly
+    /// followed by a ZWJ, for GB11.
+    pictographic: bool,
+    conjunct: Conjunct,
+}
+
+impl State {
+    fn new(first: u8) -> State {
+        let mut state = State {
+            prev: OTHER,
+            regional_indicators: 0,
+            pictographic: false,
+            conjunct: Conjunct::None,
+        };
+        state.push(first);
+        state
+    }
+
+    /// Returns whether there is a boundary before a character with the
+    /// given value, and appends the character.
+    fn is_boundary_before(&mut self, next: u8) -> bool {
+        let boundary = match (self.prev & CATEGORY_MASK, next & CATEGORY_MASK) {
+            // GB3
+            (CR, LF) => false,
+            // GB4, GB5
+            (CR | LF | CONTROL, _) | (_, CR | LF | CONTROL) => true,
+            // GB6, GB7, GB8
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+            // GB9, GB9a
+            (_, EXTEND | ZWJ | SPACING_MARK) => false,
+            // GB9b
+            (PREPEND, _) => false,
+            // GB9c
+            _ if next & INCB_MASK == INCB_CONSONANT && self.conjunct == Conjunct::Linked => false,
+            // GB11
+            (ZWJ, EXTENDED_PICTOGRAPHIC) if self.pictographic => false,
+            // GB12, GB13
+            (REGIONAL_INDICATOR, REGIONAL_INDICATOR) => self.regional_indicators % 2 == 0,
+            // GB999
+            _ => true,
+        };
+        self.push(next);
+        boundary
+    }
+
+    fn push(&mut self, next: u8) {
+        let (prev, category) = (self.prev & CATEGORY_MASK, next & CATEGORY_MASK);
+        self.regional_indicators = match category {
+            REGIONAL_INDICATOR => self.regional_indicators + 1,
+            _ => 0,
+        };
+        self.pictographic = match category {
+            EXTENDED_PICTOGRAPHIC => true,
+            EXTEND | ZWJ => self.pictographic && prev != ZWJ,
+            _ => false,
+        };
+        self.conjunct = match (next & INCB_MASK, self.conjunct) {
+            (INCB_CONSONANT, _) => Conjunct::Consonant,
+            (INCB_LINKER, Conjunct::Consonant | Conjunct::Linked) => Conjunct::Linked,
+            (INCB_EXTEND, conjunct) => conjunct,
+            _ => Conjunct::None,
+        };
+        self.prev = next;
+    }
+}
@@ -1,6 +1,7 @@
 #![unstable(feature = "unicode_internals", issue = "none")]
 #![allow(missing_docs)]
 
+pub(crate) mod grapheme;
 pub(crate) mod printable;
 mod unicode_data;
 
@@ -19,7 +20,7 @@
 
 // For use in alloc, not re-exported in std.
 pub use unicode_data::{
-    case_ignorable::lookup as Case_Ignorable, cased::lookup as Cased, conversions,
+    case_ignorable::lookup as Case_Ignorable, cased::lookup as Cased, conversions, normalization,
 };
 
 pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
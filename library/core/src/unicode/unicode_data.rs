@@ -99,6 +99,19 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
     offset_idx % 2 == 1
 }
 
+/// Looks up the value of the run containing `needle`, in a table of runs
+/// that each pack their first code point and their value as `start << 8 | value`.
+#[inline(always)]
+fn run_search<const N: usize>(needle: u32, runs: &[u32; N]) -> u8 {
+    // The first run starts at 0, so there is always a run at or before the
+    // needle, even though no packed run can be equal to it.
+    let idx = match runs.binary_search(&(needle << 8 | 0xff)) {
+        Ok(idx) => idx,
+        Err(idx) => idx - 1,
+    };
+    runs[idx] as u8
+}
+
 pub const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
 
 #[rustfmt::skip]
@@ -1385,3 +1398,1637 @@ pub mod conversions {
         ['\u{544}', '\u{53d}', '\u{0}'],
     ];
 }
+
+#[rustfmt::skip]
+pub mod grapheme_cluster_break {
+    pub const OTHER: u8 = 0;
+    pub const CR: u8 = 1;
+    pub const LF: u8 = 2;
+    pub const CONTROL: u8 = 3;
+    pub const EXTEND: u8 = 4;
+    pub const ZWJ: u8 = 5;
+    pub const REGIONAL_INDICATOR: u8 = 6;
+    pub const PREPEND: u8 = 7;
+    pub const SPACING_MARK: u8 = 8;
+    pub const L: u8 = 9;
+    pub const V: u8 = 10;
+    pub const T: u8 = 11;
+    pub const LV: u8 = 12;
+    pub const LVT: u8 = 13;
+    pub const EXTENDED_PICTOGRAPHIC: u8 = 14;
+    pub const CATEGORY_MASK: u8 = 0x0f;
+
+    pub const INCB_CONSONANT: u8 = 0x10;
+    pub const INCB_EXTEND: u8 = 0x20;
+    pub const INCB_LINKER: u8 = 0x30;
+    pub const INCB_MASK: u8 = 0x30;
+
+    static RUNS: [u32; 1158] = [
+        3, 2562, 2819, 3329, 3587, 8192, 32515, 40960, 43278, 43520, 44291, 44558, 44800, 196644,
+        216836, 217124, 225280, 295716, 296964, 297472, 364836, 376320, 376612, 376832, 377124,
+        377600, 377892, 378368, 378660, 378880, 393223, 394752, 397348, 400128, 400387, 400640,
+        412452, 417792, 421924, 422144, 448036, 449799, 450048, 450340, 451840, 452388, 452864,
+        453156, 454144, 462599, 462848, 463140, 463360, 471076, 477952, 501252, 504064, 518948,
+        521216, 523556, 523776, 529956, 530944, 531236, 533504, 533796, 534528, 534820, 536064,
+        547108, 547840, 561159, 561664, 563236, 565248, 576036, 582151, 582436, 589828, 590600,
+        590848, 595216, 604676, 604936, 605220, 605440, 605704, 606468, 608520, 609588, 609800,
+        610304, 610596, 611588, 612368, 614400, 614916, 615424, 620560, 622592, 622852, 623112,
+        623616, 627984, 633088, 633360, 635136, 635408, 635648, 636432, 637440, 637988, 638208,
+        638468, 638728, 639236, 640256, 640776, 641280, 641800, 642356, 642560, 644868, 645120,
+        646160, 646656, 646928, 647168, 647684, 648192, 651280, 651776, 654884, 655104, 655620,
+        656136, 656384, 670756, 670976, 671240, 672004, 672512, 673540, 674048, 674564, 675328,
+        676100, 676352, 684036, 684544, 685316, 685568, 688388, 688904, 689152, 693520, 698624,
+        698896, 700672, 700944, 701440, 701712, 702976, 703524, 703744, 704008, 704772, 706048,
+        706308, 706824, 707072, 707336, 707892, 708096, 713220, 713728, 719120, 719364, 720896,
+        721156, 721416, 721920, 726288, 731392, 731664, 733440, 733712, 734208, 734480, 735744,
+        736292, 736512, 736772, 737288, 737540, 738560, 739080, 739584, 740104, 740660, 740864,
+        742660, 743424, 744464, 744960, 745232, 745472, 745988, 746496, 749840, 750080, 754180,
+        754432, 769540, 769800, 770052, 770312, 770816, 771592, 772352, 772616, 773380, 773632,
+        775940, 776192, 786436, 786696, 787460, 787712, 791824, 796928, 797200, 801280, 801828,
+        802048, 802308, 803080, 804096, 804356, 805120, 805380, 806196, 806400, 808228, 808704,
+        808976, 809728, 811524, 812032, 819460, 819720, 820224, 834596, 834816, 835080, 835332,
+        835592, 836100, 836360, 836864, 837124, 837384, 837888, 838152, 838660, 839168, 840964,
+        841472, 844292, 844800, 848648, 848896, 851972, 852488, 852992, 857360, 867108, 867584,
+        867844, 868104, 868612, 869632, 869896, 870656, 870920, 871732, 871943, 872192, 874244,
+        874496, 877060, 877568, 884996, 885256, 885760, 903684, 903936, 904964, 905224, 905732,
+        906496, 906756, 907008, 907272, 909060, 909312, 913928, 914432, 930052, 930304, 930568,
+        930820, 931876, 932608, 935684, 935972, 936964, 937728, 962820, 963072, 963336, 963588,
+        964644, 965380, 965888, 968740, 969732, 970496, 989220, 989696, 996644, 996864, 997156,
+        997376, 997668, 997888, 998920, 999424, 1012004, 1012484, 1012772, 1012996, 1014308,
+        1015300, 1015560, 1015844, 1016068, 1016356, 1017088, 1017380, 1017856, 1019140, 1021952,
+        1022212, 1031424, 1033764, 1033984, 1060100, 1061128, 1061380, 1062692, 1062912, 1063204,
+        1063688, 1064196, 1064704, 1070600, 1071108, 1071616, 1072644, 1073408, 1077508, 1078528,
+        1081860, 1082112, 1082376, 1082628, 1083136, 1084708, 1084928, 1088772, 1089024, 1114121,
+        1138698, 1157131, 1179648, 1269028, 1269760, 1511940, 1512484, 1512712, 1512960, 1520132,
+        1520648, 1520896, 1528324, 1528832, 1536516, 1537024, 1553412, 1553928, 1554180, 1555976,
+        1558020, 1558280, 1558788, 1561124, 1561348, 1561600, 1563940, 1564160, 1575684, 1576451,
+        1576708, 1576960, 1606916, 1607424, 1616164, 1616384, 1646596, 1647368, 1648388, 1648904,
+        1649664, 1650696, 1651204, 1651464, 1653028, 1653760, 1709860, 1710344, 1710852, 1711104,
+        1725704, 1725956, 1726216, 1726468, 1728256, 1728548, 1728768, 1729028, 1729280, 1729796,
+        1731848, 1733380, 1733924, 1735936, 1736484, 1736704, 1749028, 1752580, 1752868, 1756928,
+        1769476, 1770504, 1770752, 1782820, 1783044, 1784584, 1784836, 1785096, 1786372, 1786632,
+        1787136, 1796900, 1799168, 1802244, 1802760, 1803008, 1810696, 1810948, 1811976, 1812484,
+        1813000, 1813284, 1813508, 1814016, 1828388, 1828616, 1828868, 1829384, 1830148, 1830408,
+        1830660, 1831432, 1831936, 1844232, 1846276, 1848328, 1848836, 1849124, 1849344, 1888292,
+        1889024, 1889316, 1892616, 1892900, 1894656, 1895716, 1895936, 1897508, 1897728, 1898248,
+        1898532, 1899008, 1949732, 1966080, 2099971, 2100228, 2100517, 2100739, 2101248, 2107395,
+        2109184, 2112526, 2112768, 2115854, 2116096, 2121731, 2125824, 2150436, 2153732, 2154788,
+        2155012, 2155812, 2158848, 2171406, 2171648, 2177294, 2177536, 2200590, 2202112, 2205966,
+        2206464, 2300430, 2300928, 2304014, 2304256, 2328590, 2328832, 2346766, 2347008, 2353422,
+        2356224, 2357262, 2358016, 2408974, 2409216, 2468366, 2468864, 2471438, 2471680, 2473998,
+        2474240, 2489102, 2490112, 2490382, 2491904, 2492174, 2495232, 2495502, 2524672, 2527246,
+        2557440, 2557966, 2560768, 2561038, 2561280, 2561550, 2561792, 2563342, 2563584, 2564366,
+        2564608, 2566158, 2566400, 2568974, 2569472, 2573326, 2573568, 2574094, 2574336, 2575374,
+        2575616, 2575886, 2576128, 2577166, 2577920, 2578190, 2578432, 2581262, 2582528, 2594062,
+        2594816, 2597134, 2597376, 2600974, 2601216, 2604814, 2605056, 2700302, 2700800, 2819342,
+        2820096, 2824974, 2825472, 2838542, 2838784, 2839822, 2840064, 2944804, 2945536, 2981668,
+        2981888, 3006500, 3014656, 3156516, 3158030, 3158272, 3161358, 3161600, 3184932, 3185408,
+        3315470, 3315712, 3315982, 3316224, 10907428, 10907652, 10908416, 10908708, 10911232,
+        10919460, 10919936, 10940452, 10940928, 11010564, 11010816, 11011588, 11011840, 11012868,
+        11013120, 11019016, 11019524, 11020040, 11020288, 11021348, 11021568, 11042824, 11043328,
+        11056136, 11060228, 11060736, 11067428, 11072000, 11075332, 11075584, 11085316, 11086628,
+        11087360, 11093764, 11096584, 11097088, 11100169, 11107584, 11108356, 11109128, 11109376,
+        11121444, 11121672, 11122180, 11123208, 11123716, 11124232, 11124992, 11134212, 11134464,
+        11151620, 11153160, 11153668, 11154184, 11154692, 11155200, 11158276, 11158528, 11160580,
+        11160840, 11161088, 11172868, 11173120, 11186212, 11186432, 11186724, 11187456, 11188004,
+        11188480, 11189796, 11190272, 11190564, 11190784, 11201288, 11201540, 11202056, 11202560,
+        11203848, 11204132, 11204352, 11264776, 11265284, 11265544, 11266052, 11266312, 11266816,
+        11267080, 11267364, 11267584, 11272205, 14132224, 14135306, 14141184, 14142219, 14154752,
+        16457252, 16457472, 16646148, 16650240, 16654372, 16658432, 16711427, 16711680, 16752132,
+        16752640, 16773123, 16776192, 16907556, 16907776, 16965668, 16965888, 17004068, 17005312,
+        17432836, 17433600, 17433860, 17434368, 17435652, 17435940, 17436164, 17436452, 17436672,
+        17446948, 17447680, 17448740, 17448960, 17491236, 17491712, 17638436, 17639424, 17738532,
+        17739008, 17759524, 17760256, 17778212, 17780992, 17793572, 17794560, 17825800, 17826052,
+        17826312, 17826560, 17840132, 17843968, 17854500, 17854720, 17855236, 17855744, 17858340,
+        17858564, 17859080, 17859328, 17870856, 17871620, 17872648, 17873156, 17873444, 17873664,
+        17874183, 17874432, 17875460, 17875712, 17878279, 17878528, 17891364, 17892096, 17901316,
+        17902600, 17902852, 17904420, 17904896, 17909000, 17909504, 17920804, 17921024, 17924100,
+        17924616, 17924864, 17937160, 17937924, 17940232, 17940736, 17940999, 17941504, 17942788,
+        17943076, 17943300, 17943808, 17944072, 17944324, 17944576, 17968136, 17968900, 17969672,
+        17970180, 17970440, 17970724, 17970948, 17971200, 17972740, 17972992, 17973508, 17973760,
+        18013956, 18014216, 18014980, 18016548, 18017024, 18022404, 18022920, 18023424, 18037540,
+        18038016, 18038276, 18038536, 18038788, 18039048, 18040064, 18040584, 18041088, 18041608,
+        18042368, 18044676, 18044928, 18047496, 18048000, 18048548, 18050304, 18051108, 18052352,
+        18101512, 18102276, 18104328, 18104836, 18105608, 18105892, 18106112, 18112036, 18112256,
+        18132996, 18133256, 18133764, 18135304, 18135556, 18135816, 18136324, 18136584, 18136836,
+        18137352, 18137604, 18137892, 18138112, 18198276, 18198536, 18199044, 18200064, 18200584,
+        18201604, 18202120, 18202372, 18202660, 18202880, 18209796, 18210304, 18231304, 18232068,
+        18234120, 18234628, 18234888, 18235140, 18235648, 18262788, 18263048, 18263300, 18263560,
+        18264068, 18265608, 18265892, 18266112, 18291972, 18292736, 18293252, 18294280, 18294532,
+        18295588, 18295808, 18361352, 18362116, 18364424, 18364676, 18364964, 18365184, 18427908,
+        18428168, 18429440, 18429704, 18430208, 18430724, 18431240, 18431524, 18431751, 18432008,
+        18432263, 18432520, 18432804, 18433024, 18469128, 18469892, 18470912, 18471428, 18471944,
+        18472964, 18473216, 18473992, 18474240, 18481412, 18483968, 18494212, 18494500, 18494724,
+        18495752, 18496007, 18496260, 18497280, 18499364, 18499584, 18501892, 18503432, 18503940,
+        18504704, 18514951, 18516484, 18519816, 18520068, 18520356, 18520576, 18624264, 18624516,
+        18626304, 18626564, 18628104, 18628356, 18628608, 18649604, 18655232, 18655496, 18655748,
+        18657544, 18657796, 18658312, 18658564, 18659072, 18690308, 18691840, 18692612, 18692864,
+        18693124, 18693632, 18693892, 18694692, 18694916, 18695204, 18695687, 18695940, 18696192,
+        18713096, 18714368, 18714628, 18715136, 18715400, 18715908, 18716168, 18716452, 18716672,
+        18805508, 18806024, 18806528, 18808836, 18809351, 18809608, 18809856, 18822152, 18822660,
+        18823936, 18824712, 18825220, 18825480, 18825764, 18825984, 20197379, 20201476, 20201728,
+        20203268, 20207104, 23785508, 23786752, 23801892, 23803648, 24071940, 24072192, 24072456,
+        24086528, 24088324, 24089344, 24110084, 24110336, 24113160, 24113664, 29138180, 29138468,
+        29138688, 29138947, 29139968, 30343172, 30354944, 30355460, 30361344, 30500132, 30500360,
+        30500644, 30501376, 30502152, 30502436, 30503683, 30505764, 30507776, 30508324, 30510080,
+        30517796, 30518784, 30556708, 30557440, 31064068, 31078144, 31079172, 31091968, 31094020,
+        31094272, 31097860, 31098112, 31103748, 31105024, 31105284, 31109120, 31457316, 31459072,
+        31459364, 31463680, 31464228, 31465984, 31466276, 31466752, 31467044, 31468288, 31493924,
+        31494144, 31535140, 31536896, 31632932, 31633152, 31648804, 31649792, 31779876, 31780864,
+        32034852, 32036608, 32064548, 32066304, 32505870, 32571392, 32574734, 32575488, 32583438,
+        32583680, 32599054, 32600576, 32603662, 32604160, 32607758, 32608000, 32608526, 32611072,
+        32615694, 32630278, 32636928, 32637198, 32641024, 32643598, 32643840, 32648974, 32649216,
+        32649742, 32652032, 32652302, 32653312, 32655630, 32766724, 32768014, 32849408, 32851470,
+        32919552, 32931854, 32964608, 32994318, 32997376, 33019150, 33030144, 33033230, 33034240,
+        33048590, 33050624, 33053198, 33054720, 33064974, 33067008, 33074702, 33095680, 33098766,
+        33110784, 33111054, 33113600, 33113870, 33226752, 33292302, 33553920, 234881027, 234889220,
+        234913795, 234946564, 235008003, 235929600,
+    ];
+
+    /// Returns the Grapheme_Cluster_Break category of `c` in the low bits, with
+    /// Extended_Pictographic as a category of its own, and its
+    /// Indic_Conjunct_Break value in the `INCB_MASK` bits.
+    #[inline]
+    pub fn lookup(c: char) -> u8 {
+        let value = super::run_search(c as u32, &RUNS);
+        // Hangul syllables alternate between LV and LVT; only LVT is stored.
+        if value == LVT && (c as u32 - 0xAC00) % 28 == 0 { LV } else { value }
+    }
+}
+
+#[rustfmt::skip]
+pub mod normalization {
+    static COMBINING_CLASS_RUNS: [u32; 581] = [
+        0, 196838, 202216, 202460, 203496, 203736, 203996, 205258, 205788, 206794, 207324, 209921,
+        211420, 212454, 214512, 214758, 215004, 215782, 216540, 216832, 217318, 218076, 219110,
+        219368, 219612, 220134, 220393, 220650, 221161, 221418, 221929, 222182, 225280, 295910,
+        296960, 365020, 365286, 366300, 366566, 367326, 367580, 367846, 369372, 370918, 371420,
+        371686, 372190, 372452, 372710, 372746, 373003, 373260, 373517, 373774, 374031, 374288,
+        374545, 374802, 375059, 375572, 375829, 376086, 376320, 376599, 376832, 377112, 377369,
+        377600, 378086, 378332, 378368, 378642, 378880, 397542, 399390, 399647, 399904, 400128,
+        412443, 412700, 412957, 413214, 413471, 413728, 413985, 414242, 414694, 415196, 415718,
+        416988, 417254, 417756, 417792, 421923, 422144, 448230, 449792, 450534, 451548, 451814,
+        451840, 452582, 452864, 453340, 453606, 454108, 454144, 463140, 463360, 471270, 471516,
+        471782, 472284, 472550, 473052, 473830, 474076, 474598, 474844, 475110, 475868, 476134,
+        476380, 476646, 476892, 477158, 477404, 477670, 477952, 519142, 520924, 521190, 521216,
+        523740, 523776, 530150, 530944, 531430, 533504, 533990, 534528, 535014, 536064, 547292,
+        547840, 563430, 563676, 564454, 565248, 576230, 577500, 578790, 582144, 582620, 582886,
+        583388, 583654, 584156, 584422, 585180, 585755, 586012, 586269, 586726, 587484, 587750,
+        588252, 588774, 589824, 605191, 605440, 609545, 609792, 610790, 611036, 611302, 611584,
+        637959, 638208, 642313, 642560, 655078, 655104, 670727, 670976, 675081, 675328, 703495,
+        703744, 707849, 708096, 736263, 736512, 740617, 740864, 773385, 773632, 801799, 802048,
+        806153, 806400, 808276, 808539, 808704, 834567, 834816, 838921, 839168, 867081, 867584,
+        871689, 871936, 903689, 903936, 931943, 932361, 932608, 936043, 936960, 964726, 965129,
+        965376, 968826, 969728, 989404, 989696, 996828, 996864, 997340, 997376, 997848, 997888,
+        1012097, 1012354, 1012480, 1012868, 1012992, 1014402, 1015296, 1015938, 1016064, 1016550,
+        1016841, 1017088, 1017574, 1017856, 1033948, 1033984, 1062663, 1062912, 1063177, 1063680,
+        1084892, 1084928, 1269222, 1269760, 1512457, 1512960, 1520649, 1520896, 1561097, 1561344,
+        1564134, 1564160, 1616356, 1616384, 1653214, 1653478, 1653724, 1653760, 1710054, 1710300,
+        1710336, 1728521, 1728768, 1734118, 1735936, 1736668, 1736704, 1749222, 1750492, 1752038,
+        1752540, 1752576, 1753052, 1753574, 1754076, 1754598, 1755868, 1756134, 1756928, 1782791,
+        1783040, 1786889, 1787136, 1797094, 1797340, 1797606, 1799168, 1813001, 1813504, 1828359,
+        1828608, 1831433, 1831936, 1849095, 1849344, 1888486, 1889024, 1889281, 1889756, 1891046,
+        1891548, 1892582, 1892608, 1892865, 1894656, 1895900, 1895936, 1897702, 1897728, 1898726,
+        1899008, 1949926, 1950428, 1950694, 1952476, 1952742, 1953258, 1953494, 1953756, 1953994,
+        1954278, 1963752, 1964004, 1964508, 1964762, 1965030, 1965289, 1965532, 1965798, 1966044,
+        1966080, 2150630, 2150913, 2151654, 2152449, 2153446, 2153728, 2154982, 2155008, 2155777,
+        2156518, 2156764, 2157030, 2157057, 2157788, 2158822, 2158848, 2944998, 2945536, 2981641,
+        2981888, 3006694, 3014656, 3156698, 3156964, 3157224, 3157470, 3157728, 3158016, 3184904,
+        3185408, 10907622, 10907648, 10908902, 10911232, 10919654, 10919936, 10940646, 10940928,
+        11011593, 11011840, 11021321, 11021568, 11060233, 11060480, 11067622, 11072000, 11086812,
+        11087360, 11096841, 11097088, 11121415, 11121664, 11124745, 11124992, 11186406, 11186432,
+        11186918, 11187420, 11187456, 11188198, 11188480, 11189990, 11190272, 11190758, 11190784,
+        11204105, 11204352, 11267337, 11267584, 16457242, 16457472, 16654566, 16656348, 16658150,
+        16658432, 16907740, 16907776, 16965852, 16965888, 17004262, 17005312, 17436124, 17436160,
+        17436646, 17436672, 17447142, 17447169, 17447644, 17447680, 17448713, 17448960, 17491430,
+        17491676, 17491712, 17638630, 17639424, 17738726, 17739008, 17759708, 17760256, 17778396,
+        17778918, 17779676, 17779942, 17780188, 17780992, 17793766, 17794012, 17794278, 17794524,
+        17794560, 17843721, 17843968, 17854473, 17854720, 17858313, 17858560, 17873161, 17873415,
+        17873664, 17891558, 17892096, 17904393, 17904896, 17920775, 17921024, 17940489, 17940736,
+        17943047, 17943296, 17970441, 17970695, 17970944, 18016519, 18016777, 18017024, 18037511,
+        18038016, 18042121, 18042368, 18048742, 18050304, 18051302, 18052352, 18104841, 18105088,
+        18105863, 18106112, 18112230, 18112256, 18137609, 18137863, 18138112, 18202377, 18202631,
+        18202880, 18235145, 18235392, 18265609, 18265863, 18266112, 18295561, 18295808, 18364681,
+        18364935, 18365184, 18431241, 18431744, 18432775, 18433024, 18472969, 18473216, 18494473,
+        18494720, 18499337, 18499584, 18520329, 18520576, 18628361, 18628608, 18694663, 18694912,
+        18695177, 18695680, 18716425, 18716672, 18825481, 18825984, 23785473, 23786752, 23802086,
+        23803648, 24113158, 24113664, 29138433, 29138688, 30500312, 30500609, 30501376, 30502370,
+        30502616, 30503680, 30505948, 30507776, 30508518, 30509788, 30510080, 30517990, 30518784,
+        30556902, 30557440, 31457510, 31459072, 31459558, 31463680, 31464422, 31465984, 31466470,
+        31466752, 31467238, 31468288, 31494118, 31494144, 31535334, 31536896, 31633126, 31633152,
+        31648998, 31649792, 31780072, 31780572, 31780838, 31780864, 32035036, 32036608, 32064742,
+        32066055, 32066304,
+    ];
+
+    static DECOMPOSITION_TABLE: &[(char, u32)] = &[
+        ('\u{c0}', 2), ('\u{c1}', 514), ('\u{c2}', 1026), ('\u{c3}', 1538), ('\u{c4}', 2050),
+        ('\u{c5}', 2562), ('\u{c7}', 3074), ('\u{c8}', 3586), ('\u{c9}', 4098), ('\u{ca}', 4610),
+        ('\u{cb}', 5122), ('\u{cc}', 5634), ('\u{cd}', 6146), ('\u{ce}', 6658), ('\u{cf}', 7170),
+        ('\u{d1}', 7682), ('\u{d2}', 8194), ('\u{d3}', 8706), ('\u{d4}', 9218), ('\u{d5}', 9730),
+        ('\u{d6}', 10242), ('\u{d9}', 10754), ('\u{da}', 11266), ('\u{db}', 11778),
+        ('\u{dc}', 12290), ('\u{dd}', 12802), ('\u{e0}', 13314), ('\u{e1}', 13826),
+        ('\u{e2}', 14338), ('\u{e3}', 14850), ('\u{e4}', 15362), ('\u{e5}', 15874),
+        ('\u{e7}', 16386), ('\u{e8}', 16898), ('\u{e9}', 17410), ('\u{ea}', 17922),
+        ('\u{eb}', 18434), ('\u{ec}', 18946), ('\u{ed}', 19458), ('\u{ee}', 19970),
+        ('\u{ef}', 20482), ('\u{f1}', 20994), ('\u{f2}', 21506), ('\u{f3}', 22018),
+        ('\u{f4}', 22530), ('\u{f5}', 23042), ('\u{f6}', 23554), ('\u{f9}', 24066),
+        ('\u{fa}', 24578), ('\u{fb}', 25090), ('\u{fc}', 25602), ('\u{fd}', 26114),
+        ('\u{ff}', 26626), ('\u{100}', 27138), ('\u{101}', 27650), ('\u{102}', 28162),
+        ('\u{103}', 28674), ('\u{104}', 29186), ('\u{105}', 29698), ('\u{106}', 30210),
+        ('\u{107}', 30722), ('\u{108}', 31234), ('\u{109}', 31746), ('\u{10a}', 32258),
+        ('\u{10b}', 32770), ('\u{10c}', 33282), ('\u{10d}', 33794), ('\u{10e}', 34306),
+        ('\u{10f}', 34818), ('\u{112}', 35330), ('\u{113}', 35842), ('\u{114}', 36354),
+        ('\u{115}', 36866), ('\u{116}', 37378), ('\u{117}', 37890), ('\u{118}', 38402),
+        ('\u{119}', 38914), ('\u{11a}', 39426), ('\u{11b}', 39938), ('\u{11c}', 40450),
+        ('\u{11d}', 40962), ('\u{11e}', 41474), ('\u{11f}', 41986), ('\u{120}', 42498),
+        ('\u{121}', 43010), ('\u{122}', 43522), ('\u{123}', 44034), ('\u{124}', 44546),
+        ('\u{125}', 45058), ('\u{128}', 45570), ('\u{129}', 46082), ('\u{12a}', 46594),
+        ('\u{12b}', 47106), ('\u{12c}', 47618), ('\u{12d}', 48130), ('\u{12e}', 48642),
+        ('\u{12f}', 49154), ('\u{130}', 49666), ('\u{134}', 50178), ('\u{135}', 50690),
+        ('\u{136}', 51202), ('\u{137}', 51714), ('\u{139}', 52226), ('\u{13a}', 52738),
+        ('\u{13b}', 53250), ('\u{13c}', 53762), ('\u{13d}', 54274), ('\u{13e}', 54786),
+        ('\u{143}', 55298), ('\u{144}', 55810), ('\u{145}', 56322), ('\u{146}', 56834),
+        ('\u{147}', 57346), ('\u{148}', 57858), ('\u{14c}', 58370), ('\u{14d}', 58882),
+        ('\u{14e}', 59394), ('\u{14f}', 59906), ('\u{150}', 60418), ('\u{151}', 60930),
+        ('\u{154}', 61442), ('\u{155}', 61954), ('\u{156}', 62466), ('\u{157}', 62978),
+        ('\u{158}', 63490), ('\u{159}', 64002), ('\u{15a}', 64514), ('\u{15b}', 65026),
+        ('\u{15c}', 65538), ('\u{15d}', 66050), ('\u{15e}', 66562), ('\u{15f}', 67074),
+        ('\u{160}', 67586), ('\u{161}', 68098), ('\u{162}', 68610), ('\u{163}', 69122),
+        ('\u{164}', 69634), ('\u{165}', 70146), ('\u{168}', 70658), ('\u{169}', 71170),
+        ('\u{16a}', 71682), ('\u{16b}', 72194), ('\u{16c}', 72706), ('\u{16d}', 73218),
+        ('\u{16e}', 73730), ('\u{16f}', 74242), ('\u{170}', 74754), ('\u{171}', 75266),
+        ('\u{172}', 75778), ('\u{173}', 76290), ('\u{174}', 76802), ('\u{175}', 77314),
+        ('\u{176}', 77826), ('\u{177}', 78338), ('\u{178}', 78850), ('\u{179}', 79362),
+        ('\u{17a}', 79874), ('\u{17b}', 80386), ('\u{17c}', 80898), ('\u{17d}', 81410),
+        ('\u{17e}', 81922), ('\u{1a0}', 82434), ('\u{1a1}', 82946), ('\u{1af}', 83458),
+        ('\u{1b0}', 83970), ('\u{1cd}', 84482), ('\u{1ce}', 84994), ('\u{1cf}', 85506),
+        ('\u{1d0}', 86018), ('\u{1d1}', 86530), ('\u{1d2}', 87042), ('\u{1d3}', 87554),
+        ('\u{1d4}', 88066), ('\u{1d5}', 88579), ('\u{1d6}', 89347), ('\u{1d7}', 90115),
+        ('\u{1d8}', 90883), ('\u{1d9}', 91651), ('\u{1da}', 92419), ('\u{1db}', 93187),
+        ('\u{1dc}', 93955), ('\u{1de}', 94723), ('\u{1df}', 95491), ('\u{1e0}', 96259),
+        ('\u{1e1}', 97027), ('\u{1e2}', 97794), ('\u{1e3}', 98306), ('\u{1e6}', 98818),
+        ('\u{1e7}', 99330), ('\u{1e8}', 99842), ('\u{1e9}', 100354), ('\u{1ea}', 100866),
+        ('\u{1eb}', 101378), ('\u{1ec}', 101891), ('\u{1ed}', 102659), ('\u{1ee}', 103426),
+        ('\u{1ef}', 103938), ('\u{1f0}', 104450), ('\u{1f4}', 104962), ('\u{1f5}', 105474),
+        ('\u{1f8}', 105986), ('\u{1f9}', 106498), ('\u{1fa}', 107011), ('\u{1fb}', 107779),
+        ('\u{1fc}', 108546), ('\u{1fd}', 109058), ('\u{1fe}', 109570), ('\u{1ff}', 110082),
+        ('\u{200}', 110594), ('\u{201}', 111106), ('\u{202}', 111618), ('\u{203}', 112130),
+        ('\u{204}', 112642), ('\u{205}', 113154), ('\u{206}', 113666), ('\u{207}', 114178),
+        ('\u{208}', 114690), ('\u{209}', 115202), ('\u{20a}', 115714), ('\u{20b}', 116226),
+        ('\u{20c}', 116738), ('\u{20d}', 117250), ('\u{20e}', 117762), ('\u{20f}', 118274),
+        ('\u{210}', 118786), ('\u{211}', 119298), ('\u{212}', 119810), ('\u{213}', 120322),
+        ('\u{214}', 120834), ('\u{215}', 121346), ('\u{216}', 121858), ('\u{217}', 122370),
+        ('\u{218}', 122882), ('\u{219}', 123394), ('\u{21a}', 123906), ('\u{21b}', 124418),
+        ('\u{21e}', 124930), ('\u{21f}', 125442), ('\u{226}', 125954), ('\u{227}', 126466),
+        ('\u{228}', 126978), ('\u{229}', 127490), ('\u{22a}', 128003), ('\u{22b}', 128771),
+        ('\u{22c}', 129539), ('\u{22d}', 130307), ('\u{22e}', 131074), ('\u{22f}', 131586),
+        ('\u{230}', 132099), ('\u{231}', 132867), ('\u{232}', 133634), ('\u{233}', 134146),
+        ('\u{340}', 134657), ('\u{341}', 134913), ('\u{343}', 135169), ('\u{344}', 135426),
+        ('\u{374}', 135937), ('\u{37e}', 136193), ('\u{385}', 136450), ('\u{386}', 136962),
+        ('\u{387}', 137473), ('\u{388}', 137730), ('\u{389}', 138242), ('\u{38a}', 138754),
+        ('\u{38c}', 139266), ('\u{38e}', 139778), ('\u{38f}', 140290), ('\u{390}', 140803),
+        ('\u{3aa}', 141570), ('\u{3ab}', 142082), ('\u{3ac}', 142594), ('\u{3ad}', 143106),
+        ('\u{3ae}', 143618), ('\u{3af}', 144130), ('\u{3b0}', 144643), ('\u{3ca}', 145410),
+        ('\u{3cb}', 145922), ('\u{3cc}', 146434), ('\u{3cd}', 146946), ('\u{3ce}', 147458),
+        ('\u{3d3}', 147970), ('\u{3d4}', 148482), ('\u{400}', 148994), ('\u{401}', 149506),
+        ('\u{403}', 150018), ('\u{407}', 150530), ('\u{40c}', 151042), ('\u{40d}', 151554),
+        ('\u{40e}', 152066), ('\u{419}', 152578), ('\u{439}', 153090), ('\u{450}', 153602),
+        ('\u{451}', 154114), ('\u{453}', 154626), ('\u{457}', 155138), ('\u{45c}', 155650),
+        ('\u{45d}', 156162), ('\u{45e}', 156674), ('\u{476}', 157186), ('\u{477}', 157698),
+        ('\u{4c1}', 158210), ('\u{4c2}', 158722), ('\u{4d0}', 159234), ('\u{4d1}', 159746),
+        ('\u{4d2}', 160258), ('\u{4d3}', 160770), ('\u{4d6}', 161282), ('\u{4d7}', 161794),
+        ('\u{4da}', 162306), ('\u{4db}', 162818), ('\u{4dc}', 163330), ('\u{4dd}', 163842),
+        ('\u{4de}', 164354), ('\u{4df}', 164866), ('\u{4e2}', 165378), ('\u{4e3}', 165890),
+        ('\u{4e4}', 166402), ('\u{4e5}', 166914), ('\u{4e6}', 167426), ('\u{4e7}', 167938),
+        ('\u{4ea}', 168450), ('\u{4eb}', 168962), ('\u{4ec}', 169474), ('\u{4ed}', 169986),
+        ('\u{4ee}', 170498), ('\u{4ef}', 171010), ('\u{4f0}', 171522), ('\u{4f1}', 172034),
+        ('\u{4f2}', 172546), ('\u{4f3}', 173058), ('\u{4f4}', 173570), ('\u{4f5}', 174082),
+        ('\u{4f8}', 174594), ('\u{4f9}', 175106), ('\u{622}', 175618), ('\u{623}', 176130),
+        ('\u{624}', 176642), ('\u{625}', 177154), ('\u{626}', 177666), ('\u{6c0}', 178178),
+        ('\u{6c2}', 178690), ('\u{6d3}', 179202), ('\u{929}', 179714), ('\u{931}', 180226),
+        ('\u{934}', 180738), ('\u{958}', 181250), ('\u{959}', 181762), ('\u{95a}', 182274),
+        ('\u{95b}', 182786), ('\u{95c}', 183298), ('\u{95d}', 183810), ('\u{95e}', 184322),
+        ('\u{95f}', 184834), ('\u{9cb}', 185346), ('\u{9cc}', 185858), ('\u{9dc}', 186370),
+        ('\u{9dd}', 186882), ('\u{9df}', 187394), ('\u{a33}', 187906), ('\u{a36}', 188418),
+        ('\u{a59}', 188930), ('\u{a5a}', 189442), ('\u{a5b}', 189954), ('\u{a5e}', 190466),
+        ('\u{b48}', 190978), ('\u{b4b}', 191490), ('\u{b4c}', 192002), ('\u{b5c}', 192514),
+        ('\u{b5d}', 193026), ('\u{b94}', 193538), ('\u{bca}', 194050), ('\u{bcb}', 194562),
+        ('\u{bcc}', 195074), ('\u{c48}', 195586), ('\u{cc0}', 196098), ('\u{cc7}', 196610),
+        ('\u{cc8}', 197122), ('\u{cca}', 197634), ('\u{ccb}', 198147), ('\u{d4a}', 198914),
+        ('\u{d4b}', 199426), ('\u{d4c}', 199938), ('\u{dda}', 200450), ('\u{ddc}', 200962),
+        ('\u{ddd}', 201475), ('\u{dde}', 202242), ('\u{f43}', 202754), ('\u{f4d}', 203266),
+        ('\u{f52}', 203778), ('\u{f57}', 204290), ('\u{f5c}', 204802), ('\u{f69}', 205314),
+        ('\u{f73}', 205826), ('\u{f75}', 206338), ('\u{f76}', 206850), ('\u{f78}', 207362),
+        ('\u{f81}', 207874), ('\u{f93}', 208386), ('\u{f9d}', 208898), ('\u{fa2}', 209410),
+        ('\u{fa7}', 209922), ('\u{fac}', 210434), ('\u{fb9}', 210946), ('\u{1026}', 211458),
+        ('\u{1b06}', 211970), ('\u{1b08}', 212482), ('\u{1b0a}', 212994), ('\u{1b0c}', 213506),
+        ('\u{1b0e}', 214018), ('\u{1b12}', 214530), ('\u{1b3b}', 215042), ('\u{1b3d}', 215554),
+        ('\u{1b40}', 216066), ('\u{1b41}', 216578), ('\u{1b43}', 217090), ('\u{1e00}', 217602),
+        ('\u{1e01}', 218114), ('\u{1e02}', 218626), ('\u{1e03}', 219138), ('\u{1e04}', 219650),
+        ('\u{1e05}', 220162), ('\u{1e06}', 220674), ('\u{1e07}', 221186), ('\u{1e08}', 221699),
+        ('\u{1e09}', 222467), ('\u{1e0a}', 223234), ('\u{1e0b}', 223746), ('\u{1e0c}', 224258),
+        ('\u{1e0d}', 224770), ('\u{1e0e}', 225282), ('\u{1e0f}', 225794), ('\u{1e10}', 226306),
+        ('\u{1e11}', 226818), ('\u{1e12}', 227330), ('\u{1e13}', 227842), ('\u{1e14}', 228355),
+        ('\u{1e15}', 229123), ('\u{1e16}', 229891), ('\u{1e17}', 230659), ('\u{1e18}', 231426),
+        ('\u{1e19}', 231938), ('\u{1e1a}', 232450), ('\u{1e1b}', 232962), ('\u{1e1c}', 233475),
+        ('\u{1e1d}', 234243), ('\u{1e1e}', 235010), ('\u{1e1f}', 235522), ('\u{1e20}', 236034),
+        ('\u{1e21}', 236546), ('\u{1e22}', 237058), ('\u{1e23}', 237570), ('\u{1e24}', 238082),
+        ('\u{1e25}', 238594), ('\u{1e26}', 239106), ('\u{1e27}', 239618), ('\u{1e28}', 240130),
+        ('\u{1e29}', 240642), ('\u{1e2a}', 241154), ('\u{1e2b}', 241666), ('\u{1e2c}', 242178),
+        ('\u{1e2d}', 242690), ('\u{1e2e}', 243203), ('\u{1e2f}', 243971), ('\u{1e30}', 244738),
+        ('\u{1e31}', 245250), ('\u{1e32}', 245762), ('\u{1e33}', 246274), ('\u{1e34}', 246786),
+        ('\u{1e35}', 247298), ('\u{1e36}', 247810), ('\u{1e37}', 248322), ('\u{1e38}', 248835),
+        ('\u{1e39}', 249603), ('\u{1e3a}', 250370), ('\u{1e3b}', 250882), ('\u{1e3c}', 251394),
+        ('\u{1e3d}', 251906), ('\u{1e3e}', 252418), ('\u{1e3f}', 252930), ('\u{1e40}', 253442),
+        ('\u{1e41}', 253954), ('\u{1e42}', 254466), ('\u{1e43}', 254978), ('\u{1e44}', 255490),
+        ('\u{1e45}', 256002), ('\u{1e46}', 256514), ('\u{1e47}', 257026), ('\u{1e48}', 257538),
+        ('\u{1e49}', 258050), ('\u{1e4a}', 258562), ('\u{1e4b}', 259074), ('\u{1e4c}', 259587),
+        ('\u{1e4d}', 260355), ('\u{1e4e}', 261123), ('\u{1e4f}', 261891), ('\u{1e50}', 262659),
+        ('\u{1e51}', 263427), ('\u{1e52}', 264195), ('\u{1e53}', 264963), ('\u{1e54}', 265730),
+        ('\u{1e55}', 266242), ('\u{1e56}', 266754), ('\u{1e57}', 267266), ('\u{1e58}', 267778),
+        ('\u{1e59}', 268290), ('\u{1e5a}', 268802), ('\u{1e5b}', 269314), ('\u{1e5c}', 269827),
+        ('\u{1e5d}', 270595), ('\u{1e5e}', 271362), ('\u{1e5f}', 271874), ('\u{1e60}', 272386),
+        ('\u{1e61}', 272898), ('\u{1e62}', 273410), ('\u{1e63}', 273922), ('\u{1e64}', 274435),
+        ('\u{1e65}', 275203), ('\u{1e66}', 275971), ('\u{1e67}', 276739), ('\u{1e68}', 277507),
+        ('\u{1e69}', 278275), ('\u{1e6a}', 279042), ('\u{1e6b}', 279554), ('\u{1e6c}', 280066),
+        ('\u{1e6d}', 280578), ('\u{1e6e}', 281090), ('\u{1e6f}', 281602), ('\u{1e70}', 282114),
+        ('\u{1e71}', 282626), ('\u{1e72}', 283138), ('\u{1e73}', 283650), ('\u{1e74}', 284162),
+        ('\u{1e75}', 284674), ('\u{1e76}', 285186), ('\u{1e77}', 285698), ('\u{1e78}', 286211),
+        ('\u{1e79}', 286979), ('\u{1e7a}', 287747), ('\u{1e7b}', 288515), ('\u{1e7c}', 289282),
+        ('\u{1e7d}', 289794), ('\u{1e7e}', 290306), ('\u{1e7f}', 290818), ('\u{1e80}', 291330),
+        ('\u{1e81}', 291842), ('\u{1e82}', 292354), ('\u{1e83}', 292866), ('\u{1e84}', 293378),
+        ('\u{1e85}', 293890), ('\u{1e86}', 294402), ('\u{1e87}', 294914), ('\u{1e88}', 295426),
+        ('\u{1e89}', 295938), ('\u{1e8a}', 296450), ('\u{1e8b}', 296962), ('\u{1e8c}', 297474),
+        ('\u{1e8d}', 297986), ('\u{1e8e}', 298498), ('\u{1e8f}', 299010), ('\u{1e90}', 299522),
+        ('\u{1e91}', 300034), ('\u{1e92}', 300546), ('\u{1e93}', 301058), ('\u{1e94}', 301570),
+        ('\u{1e95}', 302082), ('\u{1e96}', 302594), ('\u{1e97}', 303106), ('\u{1e98}', 303618),
+        ('\u{1e99}', 304130), ('\u{1e9b}', 304642), ('\u{1ea0}', 305154), ('\u{1ea1}', 305666),
+        ('\u{1ea2}', 306178), ('\u{1ea3}', 306690), ('\u{1ea4}', 307203), ('\u{1ea5}', 307971),
+        ('\u{1ea6}', 308739), ('\u{1ea7}', 309507), ('\u{1ea8}', 310275), ('\u{1ea9}', 311043),
+        ('\u{1eaa}', 311811), ('\u{1eab}', 312579), ('\u{1eac}', 313347), ('\u{1ead}', 314115),
+        ('\u{1eae}', 314883), ('\u{1eaf}', 315651), ('\u{1eb0}', 316419), ('\u{1eb1}', 317187),
+        ('\u{1eb2}', 317955), ('\u{1eb3}', 318723), ('\u{1eb4}', 319491), ('\u{1eb5}', 320259),
+        ('\u{1eb6}', 321027), ('\u{1eb7}', 321795), ('\u{1eb8}', 322562), ('\u{1eb9}', 323074),
+        ('\u{1eba}', 323586), ('\u{1ebb}', 324098), ('\u{1ebc}', 324610), ('\u{1ebd}', 325122),
+        ('\u{1ebe}', 325635), ('\u{1ebf}', 326403), ('\u{1ec0}', 327171), ('\u{1ec1}', 327939),
+        ('\u{1ec2}', 328707), ('\u{1ec3}', 329475), ('\u{1ec4}', 330243), ('\u{1ec5}', 331011),
+        ('\u{1ec6}', 331779), ('\u{1ec7}', 332547), ('\u{1ec8}', 333314), ('\u{1ec9}', 333826),
+        ('\u{1eca}', 334338), ('\u{1ecb}', 334850), ('\u{1ecc}', 335362), ('\u{1ecd}', 335874),
+        ('\u{1ece}', 336386), ('\u{1ecf}', 336898), ('\u{1ed0}', 337411), ('\u{1ed1}', 338179),
+        ('\u{1ed2}', 338947), ('\u{1ed3}', 339715), ('\u{1ed4}', 340483), ('\u{1ed5}', 341251),
+        ('\u{1ed6}', 342019), ('\u{1ed7}', 342787), ('\u{1ed8}', 343555), ('\u{1ed9}', 344323),
+        ('\u{1eda}', 345091), ('\u{1edb}', 345859), ('\u{1edc}', 346627), ('\u{1edd}', 347395),
+        ('\u{1ede}', 348163), ('\u{1edf}', 348931), ('\u{1ee0}', 349699), ('\u{1ee1}', 350467),
+        ('\u{1ee2}', 351235), ('\u{1ee3}', 352003), ('\u{1ee4}', 352770), ('\u{1ee5}', 353282),
+        ('\u{1ee6}', 353794), ('\u{1ee7}', 354306), ('\u{1ee8}', 354819), ('\u{1ee9}', 355587),
+        ('\u{1eea}', 356355), ('\u{1eeb}', 357123), ('\u{1eec}', 357891), ('\u{1eed}', 358659),
+        ('\u{1eee}', 359427), ('\u{1eef}', 360195), ('\u{1ef0}', 360963), ('\u{1ef1}', 361731),
+        ('\u{1ef2}', 362498), ('\u{1ef3}', 363010), ('\u{1ef4}', 363522), ('\u{1ef5}', 364034),
+        ('\u{1ef6}', 364546), ('\u{1ef7}', 365058), ('\u{1ef8}', 365570), ('\u{1ef9}', 366082),
+        ('\u{1f00}', 366594), ('\u{1f01}', 367106), ('\u{1f02}', 367619), ('\u{1f03}', 368387),
+        ('\u{1f04}', 369155), ('\u{1f05}', 369923), ('\u{1f06}', 370691), ('\u{1f07}', 371459),
+        ('\u{1f08}', 372226), ('\u{1f09}', 372738), ('\u{1f0a}', 373251), ('\u{1f0b}', 374019),
+        ('\u{1f0c}', 374787), ('\u{1f0d}', 375555), ('\u{1f0e}', 376323), ('\u{1f0f}', 377091),
+        ('\u{1f10}', 377858), ('\u{1f11}', 378370), ('\u{1f12}', 378883), ('\u{1f13}', 379651),
+        ('\u{1f14}', 380419), ('\u{1f15}', 381187), ('\u{1f18}', 381954), ('\u{1f19}', 382466),
+        ('\u{1f1a}', 382979), ('\u{1f1b}', 383747), ('\u{1f1c}', 384515), ('\u{1f1d}', 385283),
+        ('\u{1f20}', 386050), ('\u{1f21}', 386562), ('\u{1f22}', 387075), ('\u{1f23}', 387843),
+        ('\u{1f24}', 388611), ('\u{1f25}', 389379), ('\u{1f26}', 390147), ('\u{1f27}', 390915),
+        ('\u{1f28}', 391682), ('\u{1f29}', 392194), ('\u{1f2a}', 392707), ('\u{1f2b}', 393475),
+        ('\u{1f2c}', 394243), ('\u{1f2d}', 395011), ('\u{1f2e}', 395779), ('\u{1f2f}', 396547),
+        ('\u{1f30}', 397314), ('\u{1f31}', 397826), ('\u{1f32}', 398339), ('\u{1f33}', 399107),
+        ('\u{1f34}', 399875), ('\u{1f35}', 400643), ('\u{1f36}', 401411), ('\u{1f37}', 402179),
+        ('\u{1f38}', 402946), ('\u{1f39}', 403458), ('\u{1f3a}', 403971), ('\u{1f3b}', 404739),
+        ('\u{1f3c}', 405507), ('\u{1f3d}', 406275), ('\u{1f3e}', 407043), ('\u{1f3f}', 407811),
+        ('\u{1f40}', 408578), ('\u{1f41}', 409090), ('\u{1f42}', 409603), ('\u{1f43}', 410371),
+        ('\u{1f44}', 411139), ('\u{1f45}', 411907), ('\u{1f48}', 412674), ('\u{1f49}', 413186),
+        ('\u{1f4a}', 413699), ('\u{1f4b}', 414467), ('\u{1f4c}', 415235), ('\u{1f4d}', 416003),
+        ('\u{1f50}', 416770), ('\u{1f51}', 417282), ('\u{1f52}', 417795), ('\u{1f53}', 418563),
+        ('\u{1f54}', 419331), ('\u{1f55}', 420099), ('\u{1f56}', 420867), ('\u{1f57}', 421635),
+        ('\u{1f59}', 422402), ('\u{1f5b}', 422915), ('\u{1f5d}', 423683), ('\u{1f5f}', 424451),
+        ('\u{1f60}', 425218), ('\u{1f61}', 425730), ('\u{1f62}', 426243), ('\u{1f63}', 427011),
+        ('\u{1f64}', 427779), ('\u{1f65}', 428547), ('\u{1f66}', 429315), ('\u{1f67}', 430083),
+        ('\u{1f68}', 430850), ('\u{1f69}', 431362), ('\u{1f6a}', 431875), ('\u{1f6b}', 432643),
+        ('\u{1f6c}', 433411), ('\u{1f6d}', 434179), ('\u{1f6e}', 434947), ('\u{1f6f}', 435715),
+        ('\u{1f70}', 436482), ('\u{1f71}', 436994), ('\u{1f72}', 437506), ('\u{1f73}', 438018),
+        ('\u{1f74}', 438530), ('\u{1f75}', 439042), ('\u{1f76}', 439554), ('\u{1f77}', 440066),
+        ('\u{1f78}', 440578), ('\u{1f79}', 441090), ('\u{1f7a}', 441602), ('\u{1f7b}', 442114),
+        ('\u{1f7c}', 442626), ('\u{1f7d}', 443138), ('\u{1f80}', 443651), ('\u{1f81}', 444419),
+        ('\u{1f82}', 445188), ('\u{1f83}', 446212), ('\u{1f84}', 447236), ('\u{1f85}', 448260),
+        ('\u{1f86}', 449284), ('\u{1f87}', 450308), ('\u{1f88}', 451331), ('\u{1f89}', 452099),
+        ('\u{1f8a}', 452868), ('\u{1f8b}', 453892), ('\u{1f8c}', 454916), ('\u{1f8d}', 455940),
+        ('\u{1f8e}', 456964), ('\u{1f8f}', 457988), ('\u{1f90}', 459011), ('\u{1f91}', 459779),
+        ('\u{1f92}', 460548), ('\u{1f93}', 461572), ('\u{1f94}', 462596), ('\u{1f95}', 463620),
+        ('\u{1f96}', 464644), ('\u{1f97}', 465668), ('\u{1f98}', 466691), ('\u{1f99}', 467459),
+        ('\u{1f9a}', 468228), ('\u{1f9b}', 469252), ('\u{1f9c}', 470276), ('\u{1f9d}', 471300),
+        ('\u{1f9e}', 472324), ('\u{1f9f}', 473348), ('\u{1fa0}', 474371), ('\u{1fa1}', 475139),
+        ('\u{1fa2}', 475908), ('\u{1fa3}', 476932), ('\u{1fa4}', 477956), ('\u{1fa5}', 478980),
+        ('\u{1fa6}', 480004), ('\u{1fa7}', 481028), ('\u{1fa8}', 482051), ('\u{1fa9}', 482819),
+        ('\u{1faa}', 483588), ('\u{1fab}', 484612), ('\u{1fac}', 485636), ('\u{1fad}', 486660),
+        ('\u{1fae}', 487684), ('\u{1faf}', 488708), ('\u{1fb0}', 489730), ('\u{1fb1}', 490242),
+        ('\u{1fb2}', 490755), ('\u{1fb3}', 491522), ('\u{1fb4}', 492035), ('\u{1fb6}', 492802),
+        ('\u{1fb7}', 493315), ('\u{1fb8}', 494082), ('\u{1fb9}', 494594), ('\u{1fba}', 495106),
+        ('\u{1fbb}', 495618), ('\u{1fbc}', 496130), ('\u{1fbe}', 496641), ('\u{1fc1}', 496898),
+        ('\u{1fc2}', 497411), ('\u{1fc3}', 498178), ('\u{1fc4}', 498691), ('\u{1fc6}', 499458),
+        ('\u{1fc7}', 499971), ('\u{1fc8}', 500738), ('\u{1fc9}', 501250), ('\u{1fca}', 501762),
+        ('\u{1fcb}', 502274), ('\u{1fcc}', 502786), ('\u{1fcd}', 503298), ('\u{1fce}', 503810),
+        ('\u{1fcf}', 504322), ('\u{1fd0}', 504834), ('\u{1fd1}', 505346), ('\u{1fd2}', 505859),
+        ('\u{1fd3}', 506627), ('\u{1fd6}', 507394), ('\u{1fd7}', 507907), ('\u{1fd8}', 508674),
+        ('\u{1fd9}', 509186), ('\u{1fda}', 509698), ('\u{1fdb}', 510210), ('\u{1fdd}', 510722),
+        ('\u{1fde}', 511234), ('\u{1fdf}', 511746), ('\u{1fe0}', 512258), ('\u{1fe1}', 512770),
+        ('\u{1fe2}', 513283), ('\u{1fe3}', 514051), ('\u{1fe4}', 514818), ('\u{1fe5}', 515330),
+        ('\u{1fe6}', 515842), ('\u{1fe7}', 516355), ('\u{1fe8}', 517122), ('\u{1fe9}', 517634),
+        ('\u{1fea}', 518146), ('\u{1feb}', 518658), ('\u{1fec}', 519170), ('\u{1fed}', 519682),
+        ('\u{1fee}', 520194), ('\u{1fef}', 520705), ('\u{1ff2}', 520963), ('\u{1ff3}', 521730),
+        ('\u{1ff4}', 522243), ('\u{1ff6}', 523010), ('\u{1ff7}', 523523), ('\u{1ff8}', 524290),
+        ('\u{1ff9}', 524802), ('\u{1ffa}', 525314), ('\u{1ffb}', 525826), ('\u{1ffc}', 526338),
+        ('\u{1ffd}', 526849), ('\u{2000}', 527105), ('\u{2001}', 527361), ('\u{2126}', 527617),
+        ('\u{212a}', 527873), ('\u{212b}', 528130), ('\u{219a}', 528642), ('\u{219b}', 529154),
+        ('\u{21ae}', 529666), ('\u{21cd}', 530178), ('\u{21ce}', 530690), ('\u{21cf}', 531202),
+        ('\u{2204}', 531714), ('\u{2209}', 532226), ('\u{220c}', 532738), ('\u{2224}', 533250),
+        ('\u{2226}', 533762), ('\u{2241}', 534274), ('\u{2244}', 534786), ('\u{2247}', 535298),
+        ('\u{2249}', 535810), ('\u{2260}', 536322), ('\u{2262}', 536834), ('\u{226d}', 537346),
+        ('\u{226e}', 537858), ('\u{226f}', 538370), ('\u{2270}', 538882), ('\u{2271}', 539394),
+        ('\u{2274}', 539906), ('\u{2275}', 540418), ('\u{2278}', 540930), ('\u{2279}', 541442),
+        ('\u{2280}', 541954), ('\u{2281}', 542466), ('\u{2284}', 542978), ('\u{2285}', 543490),
+        ('\u{2288}', 544002), ('\u{2289}', 544514), ('\u{22ac}', 545026), ('\u{22ad}', 545538),
+        ('\u{22ae}', 546050), ('\u{22af}', 546562), ('\u{22e0}', 547074), ('\u{22e1}', 547586),
+        ('\u{22e2}', 548098), ('\u{22e3}', 548610), ('\u{22ea}', 549122), ('\u{22eb}', 549634),
+        ('\u{22ec}', 550146), ('\u{22ed}', 550658), ('\u{2329}', 551169), ('\u{232a}', 551425),
+        ('\u{2adc}', 551682), ('\u{304c}', 552194), ('\u{304e}', 552706), ('\u{3050}', 553218),
+        ('\u{3052}', 553730), ('\u{3054}', 554242), ('\u{3056}', 554754), ('\u{3058}', 555266),
+        ('\u{305a}', 555778), ('\u{305c}', 556290), ('\u{305e}', 556802), ('\u{3060}', 557314),
+        ('\u{3062}', 557826), ('\u{3065}', 558338), ('\u{3067}', 558850), ('\u{3069}', 559362),
+        ('\u{3070}', 559874), ('\u{3071}', 560386), ('\u{3073}', 560898), ('\u{3074}', 561410),
+        ('\u{3076}', 561922), ('\u{3077}', 562434), ('\u{3079}', 562946), ('\u{307a}', 563458),
+        ('\u{307c}', 563970), ('\u{307d}', 564482), ('\u{3094}', 564994), ('\u{309e}', 565506),
+        ('\u{30ac}', 566018), ('\u{30ae}', 566530), ('\u{30b0}', 567042), ('\u{30b2}', 567554),
+        ('\u{30b4}', 568066), ('\u{30b6}', 568578), ('\u{30b8}', 569090), ('\u{30ba}', 569602),
+        ('\u{30bc}', 570114), ('\u{30be}', 570626), ('\u{30c0}', 571138), ('\u{30c2}', 571650),
+        ('\u{30c5}', 572162), ('\u{30c7}', 572674), ('\u{30c9}', 573186), ('\u{30d0}', 573698),
+        ('\u{30d1}', 574210), ('\u{30d3}', 574722), ('\u{30d4}', 575234), ('\u{30d6}', 575746),
+        ('\u{30d7}', 576258), ('\u{30d9}', 576770), ('\u{30da}', 577282), ('\u{30dc}', 577794),
+        ('\u{30dd}', 578306), ('\u{30f4}', 578818), ('\u{30f7}', 579330), ('\u{30f8}', 579842),
+        ('\u{30f9}', 580354), ('\u{30fa}', 580866), ('\u{30fe}', 581378), ('\u{f900}', 581889),
+        ('\u{f901}', 582145), ('\u{f902}', 582401), ('\u{f903}', 582657), ('\u{f904}', 582913),
+        ('\u{f905}', 583169), ('\u{f906}', 583425), ('\u{f907}', 583681), ('\u{f908}', 583937),
+        ('\u{f909}', 584193), ('\u{f90a}', 584449), ('\u{f90b}', 584705), ('\u{f90c}', 584961),
+        ('\u{f90d}', 585217), ('\u{f90e}', 585473), ('\u{f90f}', 585729), ('\u{f910}', 585985),
+        ('\u{f911}', 586241), ('\u{f912}', 586497), ('\u{f913}', 586753), ('\u{f914}', 587009),
+        ('\u{f915}', 587265), ('\u{f916}', 587521), ('\u{f917}', 587777), ('\u{f918}', 588033),
+        ('\u{f919}', 588289), ('\u{f91a}', 588545), ('\u{f91b}', 588801), ('\u{f91c}', 589057),
+        ('\u{f91d}', 589313), ('\u{f91e}', 589569), ('\u{f91f}', 589825), ('\u{f920}', 590081),
+        ('\u{f921}', 590337), ('\u{f922}', 590593), ('\u{f923}', 590849), ('\u{f924}', 591105),
+        ('\u{f925}', 591361), ('\u{f926}', 591617), ('\u{f927}', 591873), ('\u{f928}', 592129),
+        ('\u{f929}', 592385), ('\u{f92a}', 592641), ('\u{f92b}', 592897), ('\u{f92c}', 593153),
+        ('\u{f92d}', 593409), ('\u{f92e}', 593665), ('\u{f92f}', 593921), ('\u{f930}', 594177),
+        ('\u{f931}', 594433), ('\u{f932}', 594689), ('\u{f933}', 594945), ('\u{f934}', 595201),
+        ('\u{f935}', 595457), ('\u{f936}', 595713), ('\u{f937}', 595969), ('\u{f938}', 596225),
+        ('\u{f939}', 596481), ('\u{f93a}', 596737), ('\u{f93b}', 596993), ('\u{f93c}', 597249),
+        ('\u{f93d}', 597505), ('\u{f93e}', 597761), ('\u{f93f}', 598017), ('\u{f940}', 598273),
+        ('\u{f941}', 598529), ('\u{f942}', 598785), ('\u{f943}', 599041), ('\u{f944}', 599297),
+        ('\u{f945}', 599553), ('\u{f946}', 599809), ('\u{f947}', 600065), ('\u{f948}', 600321),
+        ('\u{f949}', 600577), ('\u{f94a}', 600833), ('\u{f94b}', 601089), ('\u{f94c}', 601345),
+        ('\u{f94d}', 601601), ('\u{f94e}', 601857), ('\u{f94f}', 602113), ('\u{f950}', 602369),
+        ('\u{f951}', 602625), ('\u{f952}', 602881), ('\u{f953}', 603137), ('\u{f954}', 603393),
+        ('\u{f955}', 603649), ('\u{f956}', 603905), ('\u{f957}', 604161), ('\u{f958}', 604417),
+        ('\u{f959}', 604673), ('\u{f95a}', 604929), ('\u{f95b}', 605185), ('\u{f95c}', 605441),
+        ('\u{f95d}', 605697), ('\u{f95e}', 605953), ('\u{f95f}', 606209), ('\u{f960}', 606465),
+        ('\u{f961}', 606721), ('\u{f962}', 606977), ('\u{f963}', 607233), ('\u{f964}', 607489),
+        ('\u{f965}', 607745), ('\u{f966}', 608001), ('\u{f967}', 608257), ('\u{f968}', 608513),
+        ('\u{f969}', 608769), ('\u{f96a}', 609025), ('\u{f96b}', 609281), ('\u{f96c}', 609537),
+        ('\u{f96d}', 609793), ('\u{f96e}', 610049), ('\u{f96f}', 610305), ('\u{f970}', 610561),
+        ('\u{f971}', 610817), ('\u{f972}', 611073), ('\u{f973}', 611329), ('\u{f974}', 611585),
+        ('\u{f975}', 611841), ('\u{f976}', 612097), ('\u{f977}', 612353), ('\u{f978}', 612609),
+        ('\u{f979}', 612865), ('\u{f97a}', 613121), ('\u{f97b}', 613377), ('\u{f97c}', 613633),
+        ('\u{f97d}', 613889), ('\u{f97e}', 614145), ('\u{f97f}', 614401), ('\u{f980}', 614657),
+        ('\u{f981}', 614913), ('\u{f982}', 615169), ('\u{f983}', 615425), ('\u{f984}', 615681),
+        ('\u{f985}', 615937), ('\u{f986}', 616193), ('\u{f987}', 616449), ('\u{f988}', 616705),
+        ('\u{f989}', 616961), ('\u{f98a}', 617217), ('\u{f98b}', 617473), ('\u{f98c}', 617729),
+        ('\u{f98d}', 617985), ('\u{f98e}', 618241), ('\u{f98f}', 618497), ('\u{f990}', 618753),
+        ('\u{f991}', 619009), ('\u{f992}', 619265), ('\u{f993}', 619521), ('\u{f994}', 619777),
+        ('\u{f995}', 620033), ('\u{f996}', 620289), ('\u{f997}', 620545), ('\u{f998}', 620801),
+        ('\u{f999}', 621057), ('\u{f99a}', 621313), ('\u{f99b}', 621569), ('\u{f99c}', 621825),
+        ('\u{f99d}', 622081), ('\u{f99e}', 622337), ('\u{f99f}', 622593), ('\u{f9a0}', 622849),
+        ('\u{f9a1}', 623105), ('\u{f9a2}', 623361), ('\u{f9a3}', 623617), ('\u{f9a4}', 623873),
+        ('\u{f9a5}', 624129), ('\u{f9a6}', 624385), ('\u{f9a7}', 624641), ('\u{f9a8}', 624897),
+        ('\u{f9a9}', 625153), ('\u{f9aa}', 625409), ('\u{f9ab}', 625665), ('\u{f9ac}', 625921),
+        ('\u{f9ad}', 626177), ('\u{f9ae}', 626433), ('\u{f9af}', 626689), ('\u{f9b0}', 626945),
+        ('\u{f9b1}', 627201), ('\u{f9b2}', 627457), ('\u{f9b3}', 627713), ('\u{f9b4}', 627969),
+        ('\u{f9b5}', 628225), ('\u{f9b6}', 628481), ('\u{f9b7}', 628737), ('\u{f9b8}', 628993),
+        ('\u{f9b9}', 629249), ('\u{f9ba}', 629505), ('\u{f9bb}', 629761), ('\u{f9bc}', 630017),
+        ('\u{f9bd}', 630273), ('\u{f9be}', 630529), ('\u{f9bf}', 630785), ('\u{f9c0}', 631041),
+        ('\u{f9c1}', 631297), ('\u{f9c2}', 631553), ('\u{f9c3}', 631809), ('\u{f9c4}', 632065),
+        ('\u{f9c5}', 632321), ('\u{f9c6}', 632577), ('\u{f9c7}', 632833), ('\u{f9c8}', 633089),
+        ('\u{f9c9}', 633345), ('\u{f9ca}', 633601), ('\u{f9cb}', 633857), ('\u{f9cc}', 634113),
+        ('\u{f9cd}', 634369), ('\u{f9ce}', 634625), ('\u{f9cf}', 634881), ('\u{f9d0}', 635137),
+        ('\u{f9d1}', 635393), ('\u{f9d2}', 635649), ('\u{f9d3}', 635905), ('\u{f9d4}', 636161),
+        ('\u{f9d5}', 636417), ('\u{f9d6}', 636673), ('\u{f9d7}', 636929), ('\u{f9d8}', 637185),
+        ('\u{f9d9}', 637441), ('\u{f9da}', 637697), ('\u{f9db}', 637953), ('\u{f9dc}', 638209),
+        ('\u{f9dd}', 638465), ('\u{f9de}', 638721), ('\u{f9df}', 638977), ('\u{f9e0}', 639233),
+        ('\u{f9e1}', 639489), ('\u{f9e2}', 639745), ('\u{f9e3}', 640001), ('\u{f9e4}', 640257),
+        ('\u{f9e5}', 640513), ('\u{f9e6}', 640769), ('\u{f9e7}', 641025), ('\u{f9e8}', 641281),
+        ('\u{f9e9}', 641537), ('\u{f9ea}', 641793), ('\u{f9eb}', 642049), ('\u{f9ec}', 642305),
+        ('\u{f9ed}', 642561), ('\u{f9ee}', 642817), ('\u{f9ef}', 643073), ('\u{f9f0}', 643329),
+        ('\u{f9f1}', 643585), ('\u{f9f2}', 643841), ('\u{f9f3}', 644097), ('\u{f9f4}', 644353),
+        ('\u{f9f5}', 644609), ('\u{f9f6}', 644865), ('\u{f9f7}', 645121), ('\u{f9f8}', 645377),
+        ('\u{f9f9}', 645633), ('\u{f9fa}', 645889), ('\u{f9fb}', 646145), ('\u{f9fc}', 646401),
+        ('\u{f9fd}', 646657), ('\u{f9fe}', 646913), ('\u{f9ff}', 647169), ('\u{fa00}', 647425),
+        ('\u{fa01}', 647681), ('\u{fa02}', 647937), ('\u{fa03}', 648193), ('\u{fa04}', 648449),
+        ('\u{fa05}', 648705), ('\u{fa06}', 648961), ('\u{fa07}', 649217), ('\u{fa08}', 649473),
+        ('\u{fa09}', 649729), ('\u{fa0a}', 649985), ('\u{fa0b}', 650241), ('\u{fa0c}', 650497),
+        ('\u{fa0d}', 650753), ('\u{fa10}', 651009), ('\u{fa12}', 651265), ('\u{fa15}', 651521),
+        ('\u{fa16}', 651777), ('\u{fa17}', 652033), ('\u{fa18}', 652289), ('\u{fa19}', 652545),
+        ('\u{fa1a}', 652801), ('\u{fa1b}', 653057), ('\u{fa1c}', 653313), ('\u{fa1d}', 653569),
+        ('\u{fa1e}', 653825), ('\u{fa20}', 654081), ('\u{fa22}', 654337), ('\u{fa25}', 654593),
+        ('\u{fa26}', 654849), ('\u{fa2a}', 655105), ('\u{fa2b}', 655361), ('\u{fa2c}', 655617),
+        ('\u{fa2d}', 655873), ('\u{fa2e}', 656129), ('\u{fa2f}', 656385), ('\u{fa30}', 656641),
+        ('\u{fa31}', 656897), ('\u{fa32}', 657153), ('\u{fa33}', 657409), ('\u{fa34}', 657665),
+        ('\u{fa35}', 657921), ('\u{fa36}', 658177), ('\u{fa37}', 658433), ('\u{fa38}', 658689),
+        ('\u{fa39}', 658945), ('\u{fa3a}', 659201), ('\u{fa3b}', 659457), ('\u{fa3c}', 659713),
+        ('\u{fa3d}', 659969), ('\u{fa3e}', 660225), ('\u{fa3f}', 660481), ('\u{fa40}', 660737),
+        ('\u{fa41}', 660993), ('\u{fa42}', 661249), ('\u{fa43}', 661505), ('\u{fa44}', 661761),
+        ('\u{fa45}', 662017), ('\u{fa46}', 662273), ('\u{fa47}', 662529), ('\u{fa48}', 662785),
+        ('\u{fa49}', 663041), ('\u{fa4a}', 663297), ('\u{fa4b}', 663553), ('\u{fa4c}', 663809),
+        ('\u{fa4d}', 664065), ('\u{fa4e}', 664321), ('\u{fa4f}', 664577), ('\u{fa50}', 664833),
+        ('\u{fa51}', 665089), ('\u{fa52}', 665345), ('\u{fa53}', 665601), ('\u{fa54}', 665857),
+        ('\u{fa55}', 666113), ('\u{fa56}', 666369), ('\u{fa57}', 666625), ('\u{fa58}', 666881),
+        ('\u{fa59}', 667137), ('\u{fa5a}', 667393), ('\u{fa5b}', 667649), ('\u{fa5c}', 667905),
+        ('\u{fa5d}', 668161), ('\u{fa5e}', 668417), ('\u{fa5f}', 668673), ('\u{fa60}', 668929),
+        ('\u{fa61}', 669185), ('\u{fa62}', 669441), ('\u{fa63}', 669697), ('\u{fa64}', 669953),
+        ('\u{fa65}', 670209), ('\u{fa66}', 670465), ('\u{fa67}', 670721), ('\u{fa68}', 670977),
+        ('\u{fa69}', 671233), ('\u{fa6a}', 671489), ('\u{fa6b}', 671745), ('\u{fa6c}', 672001),
+        ('\u{fa6d}', 672257), ('\u{fa70}', 672513), ('\u{fa71}', 672769), ('\u{fa72}', 673025),
+        ('\u{fa73}', 673281), ('\u{fa74}', 673537), ('\u{fa75}', 673793), ('\u{fa76}', 674049),
+        ('\u{fa77}', 674305), ('\u{fa78}', 674561), ('\u{fa79}', 674817), ('\u{fa7a}', 675073),
+        ('\u{fa7b}', 675329), ('\u{fa7c}', 675585), ('\u{fa7d}', 675841), ('\u{fa7e}', 676097),
+        ('\u{fa7f}', 676353), ('\u{fa80}', 676609), ('\u{fa81}', 676865), ('\u{fa82}', 677121),
+        ('\u{fa83}', 677377), ('\u{fa84}', 677633), ('\u{fa85}', 677889), ('\u{fa86}', 678145),
+        ('\u{fa87}', 678401), ('\u{fa88}', 678657), ('\u{fa89}', 678913), ('\u{fa8a}', 679169),
+        ('\u{fa8b}', 679425), ('\u{fa8c}', 679681), ('\u{fa8d}', 679937), ('\u{fa8e}', 680193),
+        ('\u{fa8f}', 680449), ('\u{fa90}', 680705), ('\u{fa91}', 680961), ('\u{fa92}', 681217),
+        ('\u{fa93}', 681473), ('\u{fa94}', 681729), ('\u{fa95}', 681985), ('\u{fa96}', 682241),
+        ('\u{fa97}', 682497), ('\u{fa98}', 682753), ('\u{fa99}', 683009), ('\u{fa9a}', 683265),
+        ('\u{fa9b}', 683521), ('\u{fa9c}', 683777), ('\u{fa9d}', 684033), ('\u{fa9e}', 684289),
+        ('\u{fa9f}', 684545), ('\u{faa0}', 684801), ('\u{faa1}', 685057), ('\u{faa2}', 685313),
+        ('\u{faa3}', 685569), ('\u{faa4}', 685825), ('\u{faa5}', 686081), ('\u{faa6}', 686337),
+        ('\u{faa7}', 686593), ('\u{faa8}', 686849), ('\u{faa9}', 687105), ('\u{faaa}', 687361),
+        ('\u{faab}', 687617), ('\u{faac}', 687873), ('\u{faad}', 688129), ('\u{faae}', 688385),
+        ('\u{faaf}', 688641), ('\u{fab0}', 688897), ('\u{fab1}', 689153), ('\u{fab2}', 689409),
+        ('\u{fab3}', 689665), ('\u{fab4}', 689921), ('\u{fab5}', 690177), ('\u{fab6}', 690433),
+        ('\u{fab7}', 690689), ('\u{fab8}', 690945), ('\u{fab9}', 691201), ('\u{faba}', 691457),
+        ('\u{fabb}', 691713), ('\u{fabc}', 691969), ('\u{fabd}', 692225), ('\u{fabe}', 692481),
+        ('\u{fabf}', 692737), ('\u{fac0}', 692993), ('\u{fac1}', 693249), ('\u{fac2}', 693505),
+        ('\u{fac3}', 693761), ('\u{fac4}', 694017), ('\u{fac5}', 694273), ('\u{fac6}', 694529),
+        ('\u{fac7}', 694785), ('\u{fac8}', 695041), ('\u{fac9}', 695297), ('\u{faca}', 695553),
+        ('\u{facb}', 695809), ('\u{facc}', 696065), ('\u{facd}', 696321), ('\u{face}', 696577),
+        ('\u{facf}', 696833), ('\u{fad0}', 697089), ('\u{fad1}', 697345), ('\u{fad2}', 697601),
+        ('\u{fad3}', 697857), ('\u{fad4}', 698113), ('\u{fad5}', 698369), ('\u{fad6}', 698625),
+        ('\u{fad7}', 698881), ('\u{fad8}', 699137), ('\u{fad9}', 699393), ('\u{fb1d}', 699650),
+        ('\u{fb1f}', 700162), ('\u{fb2a}', 700674), ('\u{fb2b}', 701186), ('\u{fb2c}', 701699),
+        ('\u{fb2d}', 702467), ('\u{fb2e}', 703234), ('\u{fb2f}', 703746), ('\u{fb30}', 704258),
+        ('\u{fb31}', 704770), ('\u{fb32}', 705282), ('\u{fb33}', 705794), ('\u{fb34}', 706306),
+        ('\u{fb35}', 706818), ('\u{fb36}', 707330), ('\u{fb38}', 707842), ('\u{fb39}', 708354),
+        ('\u{fb3a}', 708866), ('\u{fb3b}', 709378), ('\u{fb3c}', 709890), ('\u{fb3e}', 710402),
+        ('\u{fb40}', 710914), ('\u{fb41}', 711426), ('\u{fb43}', 711938), ('\u{fb44}', 712450),
+        ('\u{fb46}', 712962), ('\u{fb47}', 713474), ('\u{fb48}', 713986), ('\u{fb49}', 714498),
+        ('\u{fb4a}', 715010), ('\u{fb4b}', 715522), ('\u{fb4c}', 716034), ('\u{fb4d}', 716546),
+        ('\u{fb4e}', 717058), ('\u{1109a}', 717570), ('\u{1109c}', 718082), ('\u{110ab}', 718594),
+        ('\u{1112e}', 719106), ('\u{1112f}', 719618), ('\u{1134b}', 720130), ('\u{1134c}', 720642),
+        ('\u{114bb}', 721154), ('\u{114bc}', 721666), ('\u{114be}', 722178), ('\u{115ba}', 722690),
+        ('\u{115bb}', 723202), ('\u{11938}', 723714), ('\u{1d15e}', 724226), ('\u{1d15f}', 724738),
+        ('\u{1d160}', 725251), ('\u{1d161}', 726019), ('\u{1d162}', 726787), ('\u{1d163}', 727555),
+        ('\u{1d164}', 728323), ('\u{1d1bb}', 729090), ('\u{1d1bc}', 729602), ('\u{1d1bd}', 730115),
+        ('\u{1d1be}', 730883), ('\u{1d1bf}', 731651), ('\u{1d1c0}', 732419), ('\u{2f800}', 733185),
+        ('\u{2f801}', 733441), ('\u{2f802}', 733697), ('\u{2f803}', 733953), ('\u{2f804}', 734209),
+        ('\u{2f805}', 734465), ('\u{2f806}', 734721), ('\u{2f807}', 734977), ('\u{2f808}', 735233),
+        ('\u{2f809}', 735489), ('\u{2f80a}', 735745), ('\u{2f80b}', 736001), ('\u{2f80c}', 736257),
+        ('\u{2f80d}', 736513), ('\u{2f80e}', 736769), ('\u{2f80f}', 737025), ('\u{2f810}', 737281),
+        ('\u{2f811}', 737537), ('\u{2f812}', 737793), ('\u{2f813}', 738049), ('\u{2f814}', 738305),
+        ('\u{2f815}', 738561), ('\u{2f816}', 738817), ('\u{2f817}', 739073), ('\u{2f818}', 739329),
+        ('\u{2f819}', 739585), ('\u{2f81a}', 739841), ('\u{2f81b}', 740097), ('\u{2f81c}', 740353),
+        ('\u{2f81d}', 740609), ('\u{2f81e}', 740865), ('\u{2f81f}', 741121), ('\u{2f820}', 741377),
+        ('\u{2f821}', 741633), ('\u{2f822}', 741889), ('\u{2f823}', 742145), ('\u{2f824}', 742401),
+        ('\u{2f825}', 742657), ('\u{2f826}', 742913), ('\u{2f827}', 743169), ('\u{2f828}', 743425),
+        ('\u{2f829}', 743681), ('\u{2f82a}', 743937), ('\u{2f82b}', 744193), ('\u{2f82c}', 744449),
+        ('\u{2f82d}', 744705), ('\u{2f82e}', 744961), ('\u{2f82f}', 745217), ('\u{2f830}', 745473),
+        ('\u{2f831}', 745729), ('\u{2f832}', 745985), ('\u{2f833}', 746241), ('\u{2f834}', 746497),
+        ('\u{2f835}', 746753), ('\u{2f836}', 747009), ('\u{2f837}', 747265), ('\u{2f838}', 747521),
+        ('\u{2f839}', 747777), ('\u{2f83a}', 748033), ('\u{2f83b}', 748289), ('\u{2f83c}', 748545),
+        ('\u{2f83d}', 748801), ('\u{2f83e}', 749057), ('\u{2f83f}', 749313), ('\u{2f840}', 749569),
+        ('\u{2f841}', 749825), ('\u{2f842}', 750081), ('\u{2f843}', 750337), ('\u{2f844}', 750593),
+        ('\u{2f845}', 750849), ('\u{2f846}', 751105), ('\u{2f847}', 751361), ('\u{2f848}', 751617),
+        ('\u{2f849}', 751873), ('\u{2f84a}', 752129), ('\u{2f84b}', 752385), ('\u{2f84c}', 752641),
+        ('\u{2f84d}', 752897), ('\u{2f84e}', 753153), ('\u{2f84f}', 753409), ('\u{2f850}', 753665),
+        ('\u{2f851}', 753921), ('\u{2f852}', 754177), ('\u{2f853}', 754433), ('\u{2f854}', 754689),
+        ('\u{2f855}', 754945), ('\u{2f856}', 755201), ('\u{2f857}', 755457), ('\u{2f858}', 755713),
+        ('\u{2f859}', 755969), ('\u{2f85a}', 756225), ('\u{2f85b}', 756481), ('\u{2f85c}', 756737),
+        ('\u{2f85d}', 756993), ('\u{2f85e}', 757249), ('\u{2f85f}', 757505), ('\u{2f860}', 757761),
+        ('\u{2f861}', 758017), ('\u{2f862}', 758273), ('\u{2f863}', 758529), ('\u{2f864}', 758785),
+        ('\u{2f865}', 759041), ('\u{2f866}', 759297), ('\u{2f867}', 759553), ('\u{2f868}', 759809),
+        ('\u{2f869}', 760065), ('\u{2f86a}', 760321), ('\u{2f86b}', 760577), ('\u{2f86c}', 760833),
+        ('\u{2f86d}', 761089), ('\u{2f86e}', 761345), ('\u{2f86f}', 761601), ('\u{2f870}', 761857),
+        ('\u{2f871}', 762113), ('\u{2f872}', 762369), ('\u{2f873}', 762625), ('\u{2f874}', 762881),
+        ('\u{2f875}', 763137), ('\u{2f876}', 763393), ('\u{2f877}', 763649), ('\u{2f878}', 763905),
+        ('\u{2f879}', 764161), ('\u{2f87a}', 764417), ('\u{2f87b}', 764673), ('\u{2f87c}', 764929),
+        ('\u{2f87d}', 765185), ('\u{2f87e}', 765441), ('\u{2f87f}', 765697), ('\u{2f880}', 765953),
+        ('\u{2f881}', 766209), ('\u{2f882}', 766465), ('\u{2f883}', 766721), ('\u{2f884}', 766977),
+        ('\u{2f885}', 767233), ('\u{2f886}', 767489), ('\u{2f887}', 767745), ('\u{2f888}', 768001),
+        ('\u{2f889}', 768257), ('\u{2f88a}', 768513), ('\u{2f88b}', 768769), ('\u{2f88c}', 769025),
+        ('\u{2f88d}', 769281), ('\u{2f88e}', 769537), ('\u{2f88f}', 769793), ('\u{2f890}', 770049),
+        ('\u{2f891}', 770305), ('\u{2f892}', 770561), ('\u{2f893}', 770817), ('\u{2f894}', 771073),
+        ('\u{2f895}', 771329), ('\u{2f896}', 771585), ('\u{2f897}', 771841), ('\u{2f898}', 772097),
+        ('\u{2f899}', 772353), ('\u{2f89a}', 772609), ('\u{2f89b}', 772865), ('\u{2f89c}', 773121),
+        ('\u{2f89d}', 773377), ('\u{2f89e}', 773633), ('\u{2f89f}', 773889), ('\u{2f8a0}', 774145),
+        ('\u{2f8a1}', 774401), ('\u{2f8a2}', 774657), ('\u{2f8a3}', 774913), ('\u{2f8a4}', 775169),
+        ('\u{2f8a5}', 775425), ('\u{2f8a6}', 775681), ('\u{2f8a7}', 775937), ('\u{2f8a8}', 776193),
+        ('\u{2f8a9}', 776449), ('\u{2f8aa}', 776705), ('\u{2f8ab}', 776961), ('\u{2f8ac}', 777217),
+        ('\u{2f8ad}', 777473), ('\u{2f8ae}', 777729), ('\u{2f8af}', 777985), ('\u{2f8b0}', 778241),
+        ('\u{2f8b1}', 778497), ('\u{2f8b2}', 778753), ('\u{2f8b3}', 779009), ('\u{2f8b4}', 779265),
+        ('\u{2f8b5}', 779521), ('\u{2f8b6}', 779777), ('\u{2f8b7}', 780033), ('\u{2f8b8}', 780289),
+        ('\u{2f8b9}', 780545), ('\u{2f8ba}', 780801), ('\u{2f8bb}', 781057), ('\u{2f8bc}', 781313),
+        ('\u{2f8bd}', 781569), ('\u{2f8be}', 781825), ('\u{2f8bf}', 782081), ('\u{2f8c0}', 782337),
+        ('\u{2f8c1}', 782593), ('\u{2f8c2}', 782849), ('\u{2f8c3}', 783105), ('\u{2f8c4}', 783361),
+        ('\u{2f8c5}', 783617), ('\u{2f8c6}', 783873), ('\u{2f8c7}', 784129), ('\u{2f8c8}', 784385),
+        ('\u{2f8c9}', 784641), ('\u{2f8ca}', 784897), ('\u{2f8cb}', 785153), ('\u{2f8cc}', 785409),
+        ('\u{2f8cd}', 785665), ('\u{2f8ce}', 785921), ('\u{2f8cf}', 786177), ('\u{2f8d0}', 786433),
+        ('\u{2f8d1}', 786689), ('\u{2f8d2}', 786945), ('\u{2f8d3}', 787201), ('\u{2f8d4}', 787457),
+        ('\u{2f8d5}', 787713), ('\u{2f8d6}', 787969), ('\u{2f8d7}', 788225), ('\u{2f8d8}', 788481),
+        ('\u{2f8d9}', 788737), ('\u{2f8da}', 788993), ('\u{2f8db}', 789249), ('\u{2f8dc}', 789505),
+        ('\u{2f8dd}', 789761), ('\u{2f8de}', 790017), ('\u{2f8df}', 790273), ('\u{2f8e0}', 790529),
+        ('\u{2f8e1}', 790785), ('\u{2f8e2}', 791041), ('\u{2f8e3}', 791297), ('\u{2f8e4}', 791553),
+        ('\u{2f8e5}', 791809), ('\u{2f8e6}', 792065), ('\u{2f8e7}', 792321), ('\u{2f8e8}', 792577),
+        ('\u{2f8e9}', 792833), ('\u{2f8ea}', 793089), ('\u{2f8eb}', 793345), ('\u{2f8ec}', 793601),
+        ('\u{2f8ed}', 793857), ('\u{2f8ee}', 794113), ('\u{2f8ef}', 794369), ('\u{2f8f0}', 794625),
+        ('\u{2f8f1}', 794881), ('\u{2f8f2}', 795137), ('\u{2f8f3}', 795393), ('\u{2f8f4}', 795649),
+        ('\u{2f8f5}', 795905), ('\u{2f8f6}', 796161), ('\u{2f8f7}', 796417), ('\u{2f8f8}', 796673),
+        ('\u{2f8f9}', 796929), ('\u{2f8fa}', 797185), ('\u{2f8fb}', 797441), ('\u{2f8fc}', 797697),
+        ('\u{2f8fd}', 797953), ('\u{2f8fe}', 798209), ('\u{2f8ff}', 798465), ('\u{2f900}', 798721),
+        ('\u{2f901}', 798977), ('\u{2f902}', 799233), ('\u{2f903}', 799489), ('\u{2f904}', 799745),
+        ('\u{2f905}', 800001), ('\u{2f906}', 800257), ('\u{2f907}', 800513), ('\u{2f908}', 800769),
+        ('\u{2f909}', 801025), ('\u{2f90a}', 801281), ('\u{2f90b}', 801537), ('\u{2f90c}', 801793),
+        ('\u{2f90d}', 802049), ('\u{2f90e}', 802305), ('\u{2f90f}', 802561), ('\u{2f910}', 802817),
+        ('\u{2f911}', 803073), ('\u{2f912}', 803329), ('\u{2f913}', 803585), ('\u{2f914}', 803841),
+        ('\u{2f915}', 804097), ('\u{2f916}', 804353), ('\u{2f917}', 804609), ('\u{2f918}', 804865),
+        ('\u{2f919}', 805121), ('\u{2f91a}', 805377), ('\u{2f91b}', 805633), ('\u{2f91c}', 805889),
+        ('\u{2f91d}', 806145), ('\u{2f91e}', 806401), ('\u{2f91f}', 806657), ('\u{2f920}', 806913),
+        ('\u{2f921}', 807169), ('\u{2f922}', 807425), ('\u{2f923}', 807681), ('\u{2f924}', 807937),
+        ('\u{2f925}', 808193), ('\u{2f926}', 808449), ('\u{2f927}', 808705), ('\u{2f928}', 808961),
+        ('\u{2f929}', 809217), ('\u{2f92a}', 809473), ('\u{2f92b}', 809729), ('\u{2f92c}', 809985),
+        ('\u{2f92d}', 810241), ('\u{2f92e}', 810497), ('\u{2f92f}', 810753), ('\u{2f930}', 811009),
+        ('\u{2f931}', 811265), ('\u{2f932}', 811521), ('\u{2f933}', 811777), ('\u{2f934}', 812033),
+        ('\u{2f935}', 812289), ('\u{2f936}', 812545), ('\u{2f937}', 812801), ('\u{2f938}', 813057),
+        ('\u{2f939}', 813313), ('\u{2f93a}', 813569), ('\u{2f93b}', 813825), ('\u{2f93c}', 814081),
+        ('\u{2f93d}', 814337), ('\u{2f93e}', 814593), ('\u{2f93f}', 814849), ('\u{2f940}', 815105),
+        ('\u{2f941}', 815361), ('\u{2f942}', 815617), ('\u{2f943}', 815873), ('\u{2f944}', 816129),
+        ('\u{2f945}', 816385), ('\u{2f946}', 816641), ('\u{2f947}', 816897), ('\u{2f948}', 817153),
+        ('\u{2f949}', 817409), ('\u{2f94a}', 817665), ('\u{2f94b}', 817921), ('\u{2f94c}', 818177),
+        ('\u{2f94d}', 818433), ('\u{2f94e}', 818689), ('\u{2f94f}', 818945), ('\u{2f950}', 819201),
+        ('\u{2f951}', 819457), ('\u{2f952}', 819713), ('\u{2f953}', 819969), ('\u{2f954}', 820225),
+        ('\u{2f955}', 820481), ('\u{2f956}', 820737), ('\u{2f957}', 820993), ('\u{2f958}', 821249),
+        ('\u{2f959}', 821505), ('\u{2f95a}', 821761), ('\u{2f95b}', 822017), ('\u{2f95c}', 822273),
+        ('\u{2f95d}', 822529), ('\u{2f95e}', 822785), ('\u{2f95f}', 823041), ('\u{2f960}', 823297),
+        ('\u{2f961}', 823553), ('\u{2f962}', 823809), ('\u{2f963}', 824065), ('\u{2f964}', 824321),
+        ('\u{2f965}', 824577), ('\u{2f966}', 824833), ('\u{2f967}', 825089), ('\u{2f968}', 825345),
+        ('\u{2f969}', 825601), ('\u{2f96a}', 825857), ('\u{2f96b}', 826113), ('\u{2f96c}', 826369),
+        ('\u{2f96d}', 826625), ('\u{2f96e}', 826881), ('\u{2f96f}', 827137), ('\u{2f970}', 827393),
+        ('\u{2f971}', 827649), ('\u{2f972}', 827905), ('\u{2f973}', 828161), ('\u{2f974}', 828417),
+        ('\u{2f975}', 828673), ('\u{2f976}', 828929), ('\u{2f977}', 829185), ('\u{2f978}', 829441),
+        ('\u{2f979}', 829697), ('\u{2f97a}', 829953), ('\u{2f97b}', 830209), ('\u{2f97c}', 830465),
+        ('\u{2f97d}', 830721), ('\u{2f97e}', 830977), ('\u{2f97f}', 831233), ('\u{2f980}', 831489),
+        ('\u{2f981}', 831745), ('\u{2f982}', 832001), ('\u{2f983}', 832257), ('\u{2f984}', 832513),
+        ('\u{2f985}', 832769), ('\u{2f986}', 833025), ('\u{2f987}', 833281), ('\u{2f988}', 833537),
+        ('\u{2f989}', 833793), ('\u{2f98a}', 834049), ('\u{2f98b}', 834305), ('\u{2f98c}', 834561),
+        ('\u{2f98d}', 834817), ('\u{2f98e}', 835073), ('\u{2f98f}', 835329), ('\u{2f990}', 835585),
+        ('\u{2f991}', 835841), ('\u{2f992}', 836097), ('\u{2f993}', 836353), ('\u{2f994}', 836609),
+        ('\u{2f995}', 836865), ('\u{2f996}', 837121), ('\u{2f997}', 837377), ('\u{2f998}', 837633),
+        ('\u{2f999}', 837889), ('\u{2f99a}', 838145), ('\u{2f99b}', 838401), ('\u{2f99c}', 838657),
+        ('\u{2f99d}', 838913), ('\u{2f99e}', 839169), ('\u{2f99f}', 839425), ('\u{2f9a0}', 839681),
+        ('\u{2f9a1}', 839937), ('\u{2f9a2}', 840193), ('\u{2f9a3}', 840449), ('\u{2f9a4}', 840705),
+        ('\u{2f9a5}', 840961), ('\u{2f9a6}', 841217), ('\u{2f9a7}', 841473), ('\u{2f9a8}', 841729),
+        ('\u{2f9a9}', 841985), ('\u{2f9aa}', 842241), ('\u{2f9ab}', 842497), ('\u{2f9ac}', 842753),
+        ('\u{2f9ad}', 843009), ('\u{2f9ae}', 843265), ('\u{2f9af}', 843521), ('\u{2f9b0}', 843777),
+        ('\u{2f9b1}', 844033), ('\u{2f9b2}', 844289), ('\u{2f9b3}', 844545), ('\u{2f9b4}', 844801),
+        ('\u{2f9b5}', 845057), ('\u{2f9b6}', 845313), ('\u{2f9b7}', 845569), ('\u{2f9b8}', 845825),
+        ('\u{2f9b9}', 846081), ('\u{2f9ba}', 846337), ('\u{2f9bb}', 846593), ('\u{2f9bc}', 846849),
+        ('\u{2f9bd}', 847105), ('\u{2f9be}', 847361), ('\u{2f9bf}', 847617), ('\u{2f9c0}', 847873),
+        ('\u{2f9c1}', 848129), ('\u{2f9c2}', 848385), ('\u{2f9c3}', 848641), ('\u{2f9c4}', 848897),
+        ('\u{2f9c5}', 849153), ('\u{2f9c6}', 849409), ('\u{2f9c7}', 849665), ('\u{2f9c8}', 849921),
+        ('\u{2f9c9}', 850177), ('\u{2f9ca}', 850433), ('\u{2f9cb}', 850689), ('\u{2f9cc}', 850945),
+        ('\u{2f9cd}', 851201), ('\u{2f9ce}', 851457), ('\u{2f9cf}', 851713), ('\u{2f9d0}', 851969),
+        ('\u{2f9d1}', 852225), ('\u{2f9d2}', 852481), ('\u{2f9d3}', 852737), ('\u{2f9d4}', 852993),
+        ('\u{2f9d5}', 853249), ('\u{2f9d6}', 853505), ('\u{2f9d7}', 853761), ('\u{2f9d8}', 854017),
+        ('\u{2f9d9}', 854273), ('\u{2f9da}', 854529), ('\u{2f9db}', 854785), ('\u{2f9dc}', 855041),
+        ('\u{2f9dd}', 855297), ('\u{2f9de}', 855553), ('\u{2f9df}', 855809), ('\u{2f9e0}', 856065),
+        ('\u{2f9e1}', 856321), ('\u{2f9e2}', 856577), ('\u{2f9e3}', 856833), ('\u{2f9e4}', 857089),
+        ('\u{2f9e5}', 857345), ('\u{2f9e6}', 857601), ('\u{2f9e7}', 857857), ('\u{2f9e8}', 858113),
+        ('\u{2f9e9}', 858369), ('\u{2f9ea}', 858625), ('\u{2f9eb}', 858881), ('\u{2f9ec}', 859137),
+        ('\u{2f9ed}', 859393), ('\u{2f9ee}', 859649), ('\u{2f9ef}', 859905), ('\u{2f9f0}', 860161),
+        ('\u{2f9f1}', 860417), ('\u{2f9f2}', 860673), ('\u{2f9f3}', 860929), ('\u{2f9f4}', 861185),
+        ('\u{2f9f5}', 861441), ('\u{2f9f6}', 861697), ('\u{2f9f7}', 861953), ('\u{2f9f8}', 862209),
+        ('\u{2f9f9}', 862465), ('\u{2f9fa}', 862721), ('\u{2f9fb}', 862977), ('\u{2f9fc}', 863233),
+        ('\u{2f9fd}', 863489), ('\u{2f9fe}', 863745), ('\u{2f9ff}', 864001), ('\u{2fa00}', 864257),
+        ('\u{2fa01}', 864513), ('\u{2fa02}', 864769), ('\u{2fa03}', 865025), ('\u{2fa04}', 865281),
+        ('\u{2fa05}', 865537), ('\u{2fa06}', 865793), ('\u{2fa07}', 866049), ('\u{2fa08}', 866305),
+        ('\u{2fa09}', 866561), ('\u{2fa0a}', 866817), ('\u{2fa0b}', 867073), ('\u{2fa0c}', 867329),
+        ('\u{2fa0d}', 867585), ('\u{2fa0e}', 867841), ('\u{2fa0f}', 868097), ('\u{2fa10}', 868353),
+        ('\u{2fa11}', 868609), ('\u{2fa12}', 868865), ('\u{2fa13}', 869121), ('\u{2fa14}', 869377),
+        ('\u{2fa15}', 869633), ('\u{2fa16}', 869889), ('\u{2fa17}', 870145), ('\u{2fa18}', 870401),
+        ('\u{2fa19}', 870657), ('\u{2fa1a}', 870913), ('\u{2fa1b}', 871169), ('\u{2fa1c}', 871425),
+        ('\u{2fa1d}', 871681),
+    ];
+
+    static DECOMPOSITION_CHARS: &[char] = &[
+        'A', '\u{300}', 'A', '\u{301}', 'A', '\u{302}', 'A', '\u{303}', 'A', '\u{308}', 'A',
+        '\u{30a}', 'C', '\u{327}', 'E', '\u{300}', 'E', '\u{301}', 'E', '\u{302}', 'E', '\u{308}',
+        'I', '\u{300}', 'I', '\u{301}', 'I', '\u{302}', 'I', '\u{308}', 'N', '\u{303}', 'O',
+        '\u{300}', 'O', '\u{301}', 'O', '\u{302}', 'O', '\u{303}', 'O', '\u{308}', 'U', '\u{300}',
+        'U', '\u{301}', 'U', '\u{302}', 'U', '\u{308}', 'Y', '\u{301}', 'a', '\u{300}', 'a',
+        '\u{301}', 'a', '\u{302}', 'a', '\u{303}', 'a', '\u{308}', 'a', '\u{30a}', 'c', '\u{327}',
+        'e', '\u{300}', 'e', '\u{301}', 'e', '\u{302}', 'e', '\u{308}', 'i', '\u{300}', 'i',
+        '\u{301}', 'i', '\u{302}', 'i', '\u{308}', 'n', '\u{303}', 'o', '\u{300}', 'o', '\u{301}',
+        'o', '\u{302}', 'o', '\u{303}', 'o', '\u{308}', 'u', '\u{300}', 'u', '\u{301}', 'u',
+        '\u{302}', 'u', '\u{308}', 'y', '\u{301}', 'y', '\u{308}', 'A', '\u{304}', 'a', '\u{304}',
+        'A', '\u{306}', 'a', '\u{306}', 'A', '\u{328}', 'a', '\u{328}', 'C', '\u{301}', 'c',
+        '\u{301}', 'C', '\u{302}', 'c', '\u{302}', 'C', '\u{307}', 'c', '\u{307}', 'C', '\u{30c}',
+        'c', '\u{30c}', 'D', '\u{30c}', 'd', '\u{30c}', 'E', '\u{304}', 'e', '\u{304}', 'E',
+        '\u{306}', 'e', '\u{306}', 'E', '\u{307}', 'e', '\u{307}', 'E', '\u{328}', 'e', '\u{328}',
+        'E', '\u{30c}', 'e', '\u{30c}', 'G', '\u{302}', 'g', '\u{302}', 'G', '\u{306}', 'g',
+        '\u{306}', 'G', '\u{307}', 'g', '\u{307}', 'G', '\u{327}', 'g', '\u{327}', 'H', '\u{302}',
+        'h', '\u{302}', 'I', '\u{303}', 'i', '\u{303}', 'I', '\u{304}', 'i', '\u{304}', 'I',
+        '\u{306}', 'i', '\u{306}', 'I', '\u{328}', 'i', '\u{328}', 'I', '\u{307}', 'J', '\u{302}',
+        'j', '\u{302}', 'K', '\u{327}', 'k', '\u{327}', 'L', '\u{301}', 'l', '\u{301}', 'L',
+        '\u{327}', 'l', '\u{327}', 'L', '\u{30c}', 'l', '\u{30c}', 'N', '\u{301}', 'n', '\u{301}',
+        'N', '\u{327}', 'n', '\u{327}', 'N', '\u{30c}', 'n', '\u{30c}', 'O', '\u{304}', 'o',
+        '\u{304}', 'O', '\u{306}', 'o', '\u{306}', 'O', '\u{30b}', 'o', '\u{30b}', 'R', '\u{301}',
+        'r', '\u{301}', 'R', '\u{327}', 'r', '\u{327}', 'R', '\u{30c}', 'r', '\u{30c}', 'S',
+        '\u{301}', 's', '\u{301}', 'S', '\u{302}', 's', '\u{302}', 'S', '\u{327}', 's', '\u{327}',
+        'S', '\u{30c}', 's', '\u{30c}', 'T', '\u{327}', 't', '\u{327}', 'T', '\u{30c}', 't',
+        '\u{30c}', 'U', '\u{303}', 'u', '\u{303}', 'U', '\u{304}', 'u', '\u{304}', 'U', '\u{306}',
+        'u', '\u{306}', 'U', '\u{30a}', 'u', '\u{30a}', 'U', '\u{30b}', 'u', '\u{30b}', 'U',
+        '\u{328}', 'u', '\u{328}', 'W', '\u{302}', 'w', '\u{302}', 'Y', '\u{302}', 'y', '\u{302}',
+        'Y', '\u{308}', 'Z', '\u{301}', 'z', '\u{301}', 'Z', '\u{307}', 'z', '\u{307}', 'Z',
+        '\u{30c}', 'z', '\u{30c}', 'O', '\u{31b}', 'o', '\u{31b}', 'U', '\u{31b}', 'u', '\u{31b}',
+        'A', '\u{30c}', 'a', '\u{30c}', 'I', '\u{30c}', 'i', '\u{30c}', 'O', '\u{30c}', 'o',
+        '\u{30c}', 'U', '\u{30c}', 'u', '\u{30c}', 'U', '\u{308}', '\u{304}', 'u', '\u{308}',
+        '\u{304}', 'U', '\u{308}', '\u{301}', 'u', '\u{308}', '\u{301}', 'U', '\u{308}', '\u{30c}',
+        'u', '\u{308}', '\u{30c}', 'U', '\u{308}', '\u{300}', 'u', '\u{308}', '\u{300}', 'A',
+        '\u{308}', '\u{304}', 'a', '\u{308}', '\u{304}', 'A', '\u{307}', '\u{304}', 'a', '\u{307}',
+        '\u{304}', '\u{c6}', '\u{304}', '\u{e6}', '\u{304}', 'G', '\u{30c}', 'g', '\u{30c}', 'K',
+        '\u{30c}', 'k', '\u{30c}', 'O', '\u{328}', 'o', '\u{328}', 'O', '\u{328}', '\u{304}', 'o',
+        '\u{328}', '\u{304}', '\u{1b7}', '\u{30c}', '\u{292}', '\u{30c}', 'j', '\u{30c}', 'G',
+        '\u{301}', 'g', '\u{301}', 'N', '\u{300}', 'n', '\u{300}', 'A', '\u{30a}', '\u{301}', 'a',
+        '\u{30a}', '\u{301}', '\u{c6}', '\u{301}', '\u{e6}', '\u{301}', '\u{d8}', '\u{301}',
+        '\u{f8}', '\u{301}', 'A', '\u{30f}', 'a', '\u{30f}', 'A', '\u{311}', 'a', '\u{311}', 'E',
+        '\u{30f}', 'e', '\u{30f}', 'E', '\u{311}', 'e', '\u{311}', 'I', '\u{30f}', 'i', '\u{30f}',
+        'I', '\u{311}', 'i', '\u{311}', 'O', '\u{30f}', 'o', '\u{30f}', 'O', '\u{311}', 'o',
+        '\u{311}', 'R', '\u{30f}', 'r', '\u{30f}', 'R', '\u{311}', 'r', '\u{311}', 'U', '\u{30f}',
+        'u', '\u{30f}', 'U', '\u{311}', 'u', '\u{311}', 'S', '\u{326}', 's', '\u{326}', 'T',
+        '\u{326}', 't', '\u{326}', 'H', '\u{30c}', 'h', '\u{30c}', 'A', '\u{307}', 'a', '\u{307}',
+        'E', '\u{327}', 'e', '\u{327}', 'O', '\u{308}', '\u{304}', 'o', '\u{308}', '\u{304}', 'O',
+        '\u{303}', '\u{304}', 'o', '\u{303}', '\u{304}', 'O', '\u{307}', 'o', '\u{307}', 'O',
+        '\u{307}', '\u{304}', 'o', '\u{307}', '\u{304}', 'Y', '\u{304}', 'y', '\u{304}', '\u{300}',
+        '\u{301}', '\u{313}', '\u{308}', '\u{301}', '\u{2b9}', ';', '\u{a8}', '\u{301}', '\u{391}',
+        '\u{301}', '\u{b7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+        '\u{39f}', '\u{301}', '\u{3a5}', '\u{301}', '\u{3a9}', '\u{301}', '\u{3b9}', '\u{308}',
+        '\u{301}', '\u{399}', '\u{308}', '\u{3a5}', '\u{308}', '\u{3b1}', '\u{301}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{301}', '\u{3c5}', '\u{308}', '\u{301}',
+        '\u{3b9}', '\u{308}', '\u{3c5}', '\u{308}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{301}',
+        '\u{3c9}', '\u{301}', '\u{3d2}', '\u{301}', '\u{3d2}', '\u{308}', '\u{415}', '\u{300}',
+        '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41a}', '\u{301}',
+        '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+        '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+        '\u{43a}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30f}',
+        '\u{475}', '\u{30f}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+        '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+        '\u{435}', '\u{306}', '\u{4d8}', '\u{308}', '\u{4d9}', '\u{308}', '\u{416}', '\u{308}',
+        '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+        '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41e}', '\u{308}',
+        '\u{43e}', '\u{308}', '\u{4e8}', '\u{308}', '\u{4e9}', '\u{308}', '\u{42d}', '\u{308}',
+        '\u{44d}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+        '\u{443}', '\u{308}', '\u{423}', '\u{30b}', '\u{443}', '\u{30b}', '\u{427}', '\u{308}',
+        '\u{447}', '\u{308}', '\u{42b}', '\u{308}', '\u{44b}', '\u{308}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{6d5}', '\u{654}', '\u{6c1}', '\u{654}', '\u{6d2}', '\u{654}', '\u{928}', '\u{93c}',
+        '\u{930}', '\u{93c}', '\u{933}', '\u{93c}', '\u{915}', '\u{93c}', '\u{916}', '\u{93c}',
+        '\u{917}', '\u{93c}', '\u{91c}', '\u{93c}', '\u{921}', '\u{93c}', '\u{922}', '\u{93c}',
+        '\u{92b}', '\u{93c}', '\u{92f}', '\u{93c}', '\u{9c7}', '\u{9be}', '\u{9c7}', '\u{9d7}',
+        '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}', '\u{9af}', '\u{9bc}', '\u{a32}', '\u{a3c}',
+        '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}', '\u{a17}', '\u{a3c}', '\u{a1c}', '\u{a3c}',
+        '\u{a2b}', '\u{a3c}', '\u{b47}', '\u{b56}', '\u{b47}', '\u{b3e}', '\u{b47}', '\u{b57}',
+        '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}', '\u{b92}', '\u{bd7}', '\u{bc6}', '\u{bbe}',
+        '\u{bc7}', '\u{bbe}', '\u{bc6}', '\u{bd7}', '\u{c46}', '\u{c56}', '\u{cbf}', '\u{cd5}',
+        '\u{cc6}', '\u{cd5}', '\u{cc6}', '\u{cd6}', '\u{cc6}', '\u{cc2}', '\u{cc6}', '\u{cc2}',
+        '\u{cd5}', '\u{d46}', '\u{d3e}', '\u{d47}', '\u{d3e}', '\u{d46}', '\u{d57}', '\u{dd9}',
+        '\u{dca}', '\u{dd9}', '\u{dcf}', '\u{dd9}', '\u{dcf}', '\u{dca}', '\u{dd9}', '\u{ddf}',
+        '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}', '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}',
+        '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}', '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}',
+        '\u{fb2}', '\u{f80}', '\u{fb3}', '\u{f80}', '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}',
+        '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}', '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}',
+        '\u{f90}', '\u{fb5}', '\u{1025}', '\u{102e}', '\u{1b05}', '\u{1b35}', '\u{1b07}',
+        '\u{1b35}', '\u{1b09}', '\u{1b35}', '\u{1b0b}', '\u{1b35}', '\u{1b0d}', '\u{1b35}',
+        '\u{1b11}', '\u{1b35}', '\u{1b3a}', '\u{1b35}', '\u{1b3c}', '\u{1b35}', '\u{1b3e}',
+        '\u{1b35}', '\u{1b3f}', '\u{1b35}', '\u{1b42}', '\u{1b35}', 'A', '\u{325}', 'a', '\u{325}',
+        'B', '\u{307}', 'b', '\u{307}', 'B', '\u{323}', 'b', '\u{323}', 'B', '\u{331}', 'b',
+        '\u{331}', 'C', '\u{327}', '\u{301}', 'c', '\u{327}', '\u{301}', 'D', '\u{307}', 'd',
+        '\u{307}', 'D', '\u{323}', 'd', '\u{323}', 'D', '\u{331}', 'd', '\u{331}', 'D', '\u{327}',
+        'd', '\u{327}', 'D', '\u{32d}', 'd', '\u{32d}', 'E', '\u{304}', '\u{300}', 'e', '\u{304}',
+        '\u{300}', 'E', '\u{304}', '\u{301}', 'e', '\u{304}', '\u{301}', 'E', '\u{32d}', 'e',
+        '\u{32d}', 'E', '\u{330}', 'e', '\u{330}', 'E', '\u{327}', '\u{306}', 'e', '\u{327}',
+        '\u{306}', 'F', '\u{307}', 'f', '\u{307}', 'G', '\u{304}', 'g', '\u{304}', 'H', '\u{307}',
+        'h', '\u{307}', 'H', '\u{323}', 'h', '\u{323}', 'H', '\u{308}', 'h', '\u{308}', 'H',
+        '\u{327}', 'h', '\u{327}', 'H', '\u{32e}', 'h', '\u{32e}', 'I', '\u{330}', 'i', '\u{330}',
+        'I', '\u{308}', '\u{301}', 'i', '\u{308}', '\u{301}', 'K', '\u{301}', 'k', '\u{301}', 'K',
+        '\u{323}', 'k', '\u{323}', 'K', '\u{331}', 'k', '\u{331}', 'L', '\u{323}', 'l', '\u{323}',
+        'L', '\u{323}', '\u{304}', 'l', '\u{323}', '\u{304}', 'L', '\u{331}', 'l', '\u{331}', 'L',
+        '\u{32d}', 'l', '\u{32d}', 'M', '\u{301}', 'm', '\u{301}', 'M', '\u{307}', 'm', '\u{307}',
+        'M', '\u{323}', 'm', '\u{323}', 'N', '\u{307}', 'n', '\u{307}', 'N', '\u{323}', 'n',
+        '\u{323}', 'N', '\u{331}', 'n', '\u{331}', 'N', '\u{32d}', 'n', '\u{32d}', 'O', '\u{303}',
+        '\u{301}', 'o', '\u{303}', '\u{301}', 'O', '\u{303}', '\u{308}', 'o', '\u{303}', '\u{308}',
+        'O', '\u{304}', '\u{300}', 'o', '\u{304}', '\u{300}', 'O', '\u{304}', '\u{301}', 'o',
+        '\u{304}', '\u{301}', 'P', '\u{301}', 'p', '\u{301}', 'P', '\u{307}', 'p', '\u{307}', 'R',
+        '\u{307}', 'r', '\u{307}', 'R', '\u{323}', 'r', '\u{323}', 'R', '\u{323}', '\u{304}', 'r',
+        '\u{323}', '\u{304}', 'R', '\u{331}', 'r', '\u{331}', 'S', '\u{307}', 's', '\u{307}', 'S',
+        '\u{323}', 's', '\u{323}', 'S', '\u{301}', '\u{307}', 's', '\u{301}', '\u{307}', 'S',
+        '\u{30c}', '\u{307}', 's', '\u{30c}', '\u{307}', 'S', '\u{323}', '\u{307}', 's', '\u{323}',
+        '\u{307}', 'T', '\u{307}', 't', '\u{307}', 'T', '\u{323}', 't', '\u{323}', 'T', '\u{331}',
+        't', '\u{331}', 'T', '\u{32d}', 't', '\u{32d}', 'U', '\u{324}', 'u', '\u{324}', 'U',
+        '\u{330}', 'u', '\u{330}', 'U', '\u{32d}', 'u', '\u{32d}', 'U', '\u{303}', '\u{301}', 'u',
+        '\u{303}', '\u{301}', 'U', '\u{304}', '\u{308}', 'u', '\u{304}', '\u{308}', 'V', '\u{303}',
+        'v', '\u{303}', 'V', '\u{323}', 'v', '\u{323}', 'W', '\u{300}', 'w', '\u{300}', 'W',
+        '\u{301}', 'w', '\u{301}', 'W', '\u{308}', 'w', '\u{308}', 'W', '\u{307}', 'w', '\u{307}',
+        'W', '\u{323}', 'w', '\u{323}', 'X', '\u{307}', 'x', '\u{307}', 'X', '\u{308}', 'x',
+        '\u{308}', 'Y', '\u{307}', 'y', '\u{307}', 'Z', '\u{302}', 'z', '\u{302}', 'Z', '\u{323}',
+        'z', '\u{323}', 'Z', '\u{331}', 'z', '\u{331}', 'h', '\u{331}', 't', '\u{308}', 'w',
+        '\u{30a}', 'y', '\u{30a}', '\u{17f}', '\u{307}', 'A', '\u{323}', 'a', '\u{323}', 'A',
+        '\u{309}', 'a', '\u{309}', 'A', '\u{302}', '\u{301}', 'a', '\u{302}', '\u{301}', 'A',
+        '\u{302}', '\u{300}', 'a', '\u{302}', '\u{300}', 'A', '\u{302}', '\u{309}', 'a', '\u{302}',
+        '\u{309}', 'A', '\u{302}', '\u{303}', 'a', '\u{302}', '\u{303}', 'A', '\u{323}', '\u{302}',
+        'a', '\u{323}', '\u{302}', 'A', '\u{306}', '\u{301}', 'a', '\u{306}', '\u{301}', 'A',
+        '\u{306}', '\u{300}', 'a', '\u{306}', '\u{300}', 'A', '\u{306}', '\u{309}', 'a', '\u{306}',
+        '\u{309}', 'A', '\u{306}', '\u{303}', 'a', '\u{306}', '\u{303}', 'A', '\u{323}', '\u{306}',
+        'a', '\u{323}', '\u{306}', 'E', '\u{323}', 'e', '\u{323}', 'E', '\u{309}', 'e', '\u{309}',
+        'E', '\u{303}', 'e', '\u{303}', 'E', '\u{302}', '\u{301}', 'e', '\u{302}', '\u{301}', 'E',
+        '\u{302}', '\u{300}', 'e', '\u{302}', '\u{300}', 'E', '\u{302}', '\u{309}', 'e', '\u{302}',
+        '\u{309}', 'E', '\u{302}', '\u{303}', 'e', '\u{302}', '\u{303}', 'E', '\u{323}', '\u{302}',
+        'e', '\u{323}', '\u{302}', 'I', '\u{309}', 'i', '\u{309}', 'I', '\u{323}', 'i', '\u{323}',
+        'O', '\u{323}', 'o', '\u{323}', 'O', '\u{309}', 'o', '\u{309}', 'O', '\u{302}', '\u{301}',
+        'o', '\u{302}', '\u{301}', 'O', '\u{302}', '\u{300}', 'o', '\u{302}', '\u{300}', 'O',
+        '\u{302}', '\u{309}', 'o', '\u{302}', '\u{309}', 'O', '\u{302}', '\u{303}', 'o', '\u{302}',
+        '\u{303}', 'O', '\u{323}', '\u{302}', 'o', '\u{323}', '\u{302}', 'O', '\u{31b}', '\u{301}',
+        'o', '\u{31b}', '\u{301}', 'O', '\u{31b}', '\u{300}', 'o', '\u{31b}', '\u{300}', 'O',
+        '\u{31b}', '\u{309}', 'o', '\u{31b}', '\u{309}', 'O', '\u{31b}', '\u{303}', 'o', '\u{31b}',
+        '\u{303}', 'O', '\u{31b}', '\u{323}', 'o', '\u{31b}', '\u{323}', 'U', '\u{323}', 'u',
+        '\u{323}', 'U', '\u{309}', 'u', '\u{309}', 'U', '\u{31b}', '\u{301}', 'u', '\u{31b}',
+        '\u{301}', 'U', '\u{31b}', '\u{300}', 'u', '\u{31b}', '\u{300}', 'U', '\u{31b}', '\u{309}',
+        'u', '\u{31b}', '\u{309}', 'U', '\u{31b}', '\u{303}', 'u', '\u{31b}', '\u{303}', 'U',
+        '\u{31b}', '\u{323}', 'u', '\u{31b}', '\u{323}', 'Y', '\u{300}', 'y', '\u{300}', 'Y',
+        '\u{323}', 'y', '\u{323}', 'Y', '\u{309}', 'y', '\u{309}', 'Y', '\u{303}', 'y', '\u{303}',
+        '\u{3b1}', '\u{313}', '\u{3b1}', '\u{314}', '\u{3b1}', '\u{313}', '\u{300}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{3b1}', '\u{313}', '\u{301}', '\u{3b1}', '\u{314}', '\u{301}',
+        '\u{3b1}', '\u{313}', '\u{342}', '\u{3b1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+        '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+        '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3b5}', '\u{313}', '\u{3b5}', '\u{314}',
+        '\u{3b5}', '\u{313}', '\u{300}', '\u{3b5}', '\u{314}', '\u{300}', '\u{3b5}', '\u{313}',
+        '\u{301}', '\u{3b5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+        '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+        '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{3b7}', '\u{314}',
+        '\u{3b7}', '\u{313}', '\u{300}', '\u{3b7}', '\u{314}', '\u{300}', '\u{3b7}', '\u{313}',
+        '\u{301}', '\u{3b7}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{342}', '\u{3b7}',
+        '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+        '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+        '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+        '\u{3b9}', '\u{313}', '\u{3b9}', '\u{314}', '\u{3b9}', '\u{313}', '\u{300}', '\u{3b9}',
+        '\u{314}', '\u{300}', '\u{3b9}', '\u{313}', '\u{301}', '\u{3b9}', '\u{314}', '\u{301}',
+        '\u{3b9}', '\u{313}', '\u{342}', '\u{3b9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+        '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+        '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+        '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3bf}', '\u{313}', '\u{3bf}', '\u{314}',
+        '\u{3bf}', '\u{313}', '\u{300}', '\u{3bf}', '\u{314}', '\u{300}', '\u{3bf}', '\u{313}',
+        '\u{301}', '\u{3bf}', '\u{314}', '\u{301}', '\u{39f}', '\u{313}', '\u{39f}', '\u{314}',
+        '\u{39f}', '\u{313}', '\u{300}', '\u{39f}', '\u{314}', '\u{300}', '\u{39f}', '\u{313}',
+        '\u{301}', '\u{39f}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{3c5}', '\u{314}',
+        '\u{3c5}', '\u{313}', '\u{300}', '\u{3c5}', '\u{314}', '\u{300}', '\u{3c5}', '\u{313}',
+        '\u{301}', '\u{3c5}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{342}', '\u{3c5}',
+        '\u{314}', '\u{342}', '\u{3a5}', '\u{314}', '\u{3a5}', '\u{314}', '\u{300}', '\u{3a5}',
+        '\u{314}', '\u{301}', '\u{3a5}', '\u{314}', '\u{342}', '\u{3c9}', '\u{313}', '\u{3c9}',
+        '\u{314}', '\u{3c9}', '\u{313}', '\u{300}', '\u{3c9}', '\u{314}', '\u{300}', '\u{3c9}',
+        '\u{313}', '\u{301}', '\u{3c9}', '\u{314}', '\u{301}', '\u{3c9}', '\u{313}', '\u{342}',
+        '\u{3c9}', '\u{314}', '\u{342}', '\u{3a9}', '\u{313}', '\u{3a9}', '\u{314}', '\u{3a9}',
+        '\u{313}', '\u{300}', '\u{3a9}', '\u{314}', '\u{300}', '\u{3a9}', '\u{313}', '\u{301}',
+        '\u{3a9}', '\u{314}', '\u{301}', '\u{3a9}', '\u{313}', '\u{342}', '\u{3a9}', '\u{314}',
+        '\u{342}', '\u{3b1}', '\u{300}', '\u{3b1}', '\u{301}', '\u{3b5}', '\u{300}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{300}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{300}', '\u{3b9}',
+        '\u{301}', '\u{3bf}', '\u{300}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{300}', '\u{3c5}',
+        '\u{301}', '\u{3c9}', '\u{300}', '\u{3c9}', '\u{301}', '\u{3b1}', '\u{313}', '\u{345}',
+        '\u{3b1}', '\u{314}', '\u{345}', '\u{3b1}', '\u{313}', '\u{300}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3b1}', '\u{313}', '\u{301}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3b1}', '\u{313}', '\u{342}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{345}', '\u{3b7}', '\u{314}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{300}', '\u{345}', '\u{3b7}', '\u{314}', '\u{300}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{301}', '\u{345}', '\u{3b7}', '\u{314}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{342}', '\u{345}', '\u{3b7}', '\u{314}', '\u{342}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}', '\u{313}', '\u{300}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3c9}', '\u{313}', '\u{345}',
+        '\u{3c9}', '\u{314}', '\u{345}', '\u{3c9}', '\u{313}', '\u{300}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3c9}', '\u{313}', '\u{301}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3c9}', '\u{313}', '\u{342}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{3a9}', '\u{313}', '\u{345}', '\u{3a9}', '\u{314}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{300}', '\u{345}', '\u{3a9}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{301}', '\u{345}', '\u{3a9}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{342}', '\u{345}', '\u{3a9}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b1}', '\u{306}', '\u{3b1}', '\u{304}', '\u{3b1}', '\u{300}', '\u{345}',
+        '\u{3b1}', '\u{345}', '\u{3b1}', '\u{301}', '\u{345}', '\u{3b1}', '\u{342}', '\u{3b1}',
+        '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}',
+        '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3b9}', '\u{a8}', '\u{342}', '\u{3b7}',
+        '\u{300}', '\u{345}', '\u{3b7}', '\u{345}', '\u{3b7}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{342}', '\u{3b7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+        '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1fbf}', '\u{300}',
+        '\u{1fbf}', '\u{301}', '\u{1fbf}', '\u{342}', '\u{3b9}', '\u{306}', '\u{3b9}', '\u{304}',
+        '\u{3b9}', '\u{308}', '\u{300}', '\u{3b9}', '\u{308}', '\u{301}', '\u{3b9}', '\u{342}',
+        '\u{3b9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}', '\u{399}',
+        '\u{300}', '\u{399}', '\u{301}', '\u{1ffe}', '\u{300}', '\u{1ffe}', '\u{301}', '\u{1ffe}',
+        '\u{342}', '\u{3c5}', '\u{306}', '\u{3c5}', '\u{304}', '\u{3c5}', '\u{308}', '\u{300}',
+        '\u{3c5}', '\u{308}', '\u{301}', '\u{3c1}', '\u{313}', '\u{3c1}', '\u{314}', '\u{3c5}',
+        '\u{342}', '\u{3c5}', '\u{308}', '\u{342}', '\u{3a5}', '\u{306}', '\u{3a5}', '\u{304}',
+        '\u{3a5}', '\u{300}', '\u{3a5}', '\u{301}', '\u{3a1}', '\u{314}', '\u{a8}', '\u{300}',
+        '\u{a8}', '\u{301}', '`', '\u{3c9}', '\u{300}', '\u{345}', '\u{3c9}', '\u{345}', '\u{3c9}',
+        '\u{301}', '\u{345}', '\u{3c9}', '\u{342}', '\u{3c9}', '\u{342}', '\u{345}', '\u{39f}',
+        '\u{300}', '\u{39f}', '\u{301}', '\u{3a9}', '\u{300}', '\u{3a9}', '\u{301}', '\u{3a9}',
+        '\u{345}', '\u{b4}', '\u{2002}', '\u{2003}', '\u{3a9}', 'K', 'A', '\u{30a}', '\u{2190}',
+        '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21d0}', '\u{338}', '\u{21d4}',
+        '\u{338}', '\u{21d2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}', '\u{338}', '\u{220b}',
+        '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223c}', '\u{338}', '\u{2243}',
+        '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '=', '\u{338}', '\u{2261}',
+        '\u{338}', '\u{224d}', '\u{338}', '<', '\u{338}', '>', '\u{338}', '\u{2264}', '\u{338}',
+        '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}', '\u{338}', '\u{2276}', '\u{338}',
+        '\u{2277}', '\u{338}', '\u{227a}', '\u{338}', '\u{227b}', '\u{338}', '\u{2282}', '\u{338}',
+        '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}', '\u{338}', '\u{22a2}', '\u{338}',
+        '\u{22a8}', '\u{338}', '\u{22a9}', '\u{338}', '\u{22ab}', '\u{338}', '\u{227c}', '\u{338}',
+        '\u{227d}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}', '\u{338}', '\u{22b2}', '\u{338}',
+        '\u{22b3}', '\u{338}', '\u{22b4}', '\u{338}', '\u{22b5}', '\u{338}', '\u{3008}', '\u{3009}',
+        '\u{2add}', '\u{338}', '\u{304b}', '\u{3099}', '\u{304d}', '\u{3099}', '\u{304f}',
+        '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}', '\u{3099}',
+        '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305b}', '\u{3099}', '\u{305d}',
+        '\u{3099}', '\u{305f}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}',
+        '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306f}', '\u{3099}', '\u{306f}',
+        '\u{309a}', '\u{3072}', '\u{3099}', '\u{3072}', '\u{309a}', '\u{3075}', '\u{3099}',
+        '\u{3075}', '\u{309a}', '\u{3078}', '\u{3099}', '\u{3078}', '\u{309a}', '\u{307b}',
+        '\u{3099}', '\u{307b}', '\u{309a}', '\u{3046}', '\u{3099}', '\u{309d}', '\u{3099}',
+        '\u{30ab}', '\u{3099}', '\u{30ad}', '\u{3099}', '\u{30af}', '\u{3099}', '\u{30b1}',
+        '\u{3099}', '\u{30b3}', '\u{3099}', '\u{30b5}', '\u{3099}', '\u{30b7}', '\u{3099}',
+        '\u{30b9}', '\u{3099}', '\u{30bb}', '\u{3099}', '\u{30bd}', '\u{3099}', '\u{30bf}',
+        '\u{3099}', '\u{30c1}', '\u{3099}', '\u{30c4}', '\u{3099}', '\u{30c6}', '\u{3099}',
+        '\u{30c8}', '\u{3099}', '\u{30cf}', '\u{3099}', '\u{30cf}', '\u{309a}', '\u{30d2}',
+        '\u{3099}', '\u{30d2}', '\u{309a}', '\u{30d5}', '\u{3099}', '\u{30d5}', '\u{309a}',
+        '\u{30d8}', '\u{3099}', '\u{30d8}', '\u{309a}', '\u{30db}', '\u{3099}', '\u{30db}',
+        '\u{309a}', '\u{30a6}', '\u{3099}', '\u{30ef}', '\u{3099}', '\u{30f0}', '\u{3099}',
+        '\u{30f1}', '\u{3099}', '\u{30f2}', '\u{3099}', '\u{30fd}', '\u{3099}', '\u{8c48}',
+        '\u{66f4}', '\u{8eca}', '\u{8cc8}', '\u{6ed1}', '\u{4e32}', '\u{53e5}', '\u{9f9c}',
+        '\u{9f9c}', '\u{5951}', '\u{91d1}', '\u{5587}', '\u{5948}', '\u{61f6}', '\u{7669}',
+        '\u{7f85}', '\u{863f}', '\u{87ba}', '\u{88f8}', '\u{908f}', '\u{6a02}', '\u{6d1b}',
+        '\u{70d9}', '\u{73de}', '\u{843d}', '\u{916a}', '\u{99f1}', '\u{4e82}', '\u{5375}',
+        '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}', '\u{5d50}', '\u{6feb}', '\u{85cd}',
+        '\u{8964}', '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}', '\u{6717}', '\u{6d6a}',
+        '\u{72fc}', '\u{90ce}', '\u{4f86}', '\u{51b7}', '\u{52de}', '\u{64c4}', '\u{6ad3}',
+        '\u{7210}', '\u{76e7}', '\u{8001}', '\u{8606}', '\u{865c}', '\u{8def}', '\u{9732}',
+        '\u{9b6f}', '\u{9dfa}', '\u{788c}', '\u{797f}', '\u{7da0}', '\u{83c9}', '\u{9304}',
+        '\u{9e7f}', '\u{8ad6}', '\u{58df}', '\u{5f04}', '\u{7c60}', '\u{807e}', '\u{7262}',
+        '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}', '\u{5c62}', '\u{6a13}', '\u{6dda}',
+        '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}', '\u{808b}', '\u{51dc}',
+        '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}', '\u{8b80}', '\u{62cf}',
+        '\u{6a02}', '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}', '\u{7570}',
+        '\u{5317}', '\u{78fb}', '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}', '\u{6578}',
+        '\u{7d22}', '\u{53c3}', '\u{585e}', '\u{7701}', '\u{8449}', '\u{8aaa}', '\u{6bba}',
+        '\u{8fb0}', '\u{6c88}', '\u{62fe}', '\u{82e5}', '\u{63a0}', '\u{7565}', '\u{4eae}',
+        '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}', '\u{8ad2}', '\u{91cf}',
+        '\u{52f5}', '\u{5442}', '\u{5973}', '\u{5eec}', '\u{65c5}', '\u{6ffe}', '\u{792a}',
+        '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{529b}', '\u{66c6}', '\u{6b77}',
+        '\u{8f62}', '\u{5e74}', '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}', '\u{7149}',
+        '\u{7489}', '\u{79ca}', '\u{7df4}', '\u{806f}', '\u{8f26}', '\u{84ee}', '\u{9023}',
+        '\u{934a}', '\u{5217}', '\u{52a3}', '\u{54bd}', '\u{70c8}', '\u{88c2}', '\u{8aaa}',
+        '\u{5ec9}', '\u{5ff5}', '\u{637b}', '\u{6bae}', '\u{7c3e}', '\u{7375}', '\u{4ee4}',
+        '\u{56f9}', '\u{5be7}', '\u{5dba}', '\u{601c}', '\u{73b2}', '\u{7469}', '\u{7f9a}',
+        '\u{8046}', '\u{9234}', '\u{96f6}', '\u{9748}', '\u{9818}', '\u{4f8b}', '\u{79ae}',
+        '\u{91b4}', '\u{96b8}', '\u{60e1}', '\u{4e86}', '\u{50da}', '\u{5bee}', '\u{5c3f}',
+        '\u{6599}', '\u{6a02}', '\u{71ce}', '\u{7642}', '\u{84fc}', '\u{907c}', '\u{9f8d}',
+        '\u{6688}', '\u{962e}', '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}',
+        '\u{7409}', '\u{7559}', '\u{786b}', '\u{7d10}', '\u{985e}', '\u{516d}', '\u{622e}',
+        '\u{9678}', '\u{502b}', '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}',
+        '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540f}', '\u{5c65}', '\u{6613}',
+        '\u{674e}', '\u{68a8}', '\u{6ce5}', '\u{7406}', '\u{75e2}', '\u{7f79}', '\u{88cf}',
+        '\u{88e1}', '\u{91cc}', '\u{96e2}', '\u{533f}', '\u{6eba}', '\u{541d}', '\u{71d0}',
+        '\u{7498}', '\u{85fa}', '\u{96a3}', '\u{9c57}', '\u{9e9f}', '\u{6797}', '\u{6dcb}',
+        '\u{81e8}', '\u{7acb}', '\u{7b20}', '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}',
+        '\u{4ec0}', '\u{8336}', '\u{523a}', '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}',
+        '\u{5b85}', '\u{6d1e}', '\u{66b4}', '\u{8f3b}', '\u{884c}', '\u{964d}', '\u{898b}',
+        '\u{5ed3}', '\u{5140}', '\u{55c0}', '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}',
+        '\u{76ca}', '\u{793c}', '\u{795e}', '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}',
+        '\u{7fbd}', '\u{8612}', '\u{8af8}', '\u{9038}', '\u{90fd}', '\u{98ef}', '\u{98fc}',
+        '\u{9928}', '\u{9db4}', '\u{90de}', '\u{96b7}', '\u{4fae}', '\u{50e7}', '\u{514d}',
+        '\u{52c9}', '\u{52e4}', '\u{5351}', '\u{559d}', '\u{5606}', '\u{5668}', '\u{5840}',
+        '\u{58a8}', '\u{5c64}', '\u{5c6e}', '\u{6094}', '\u{6168}', '\u{618e}', '\u{61f2}',
+        '\u{654f}', '\u{65e2}', '\u{6691}', '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}',
+        '\u{716e}', '\u{722b}', '\u{7422}', '\u{7891}', '\u{793e}', '\u{7949}', '\u{7948}',
+        '\u{7950}', '\u{7956}', '\u{795d}', '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}',
+        '\u{7bc0}', '\u{7df4}', '\u{7e09}', '\u{7e41}', '\u{7f72}', '\u{8005}', '\u{81ed}',
+        '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8b01}', '\u{8b39}',
+        '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{9038}', '\u{96e3}', '\u{97ff}', '\u{983b}',
+        '\u{6075}', '\u{242ee}', '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}', '\u{4f80}',
+        '\u{5145}', '\u{5180}', '\u{52c7}', '\u{52fa}', '\u{559d}', '\u{5555}', '\u{5599}',
+        '\u{55e2}', '\u{585a}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}', '\u{5b28}',
+        '\u{5ed2}', '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}',
+        '\u{618e}', '\u{6160}', '\u{61f2}', '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}',
+        '\u{6556}', '\u{6674}', '\u{6717}', '\u{671b}', '\u{6756}', '\u{6b79}', '\u{6bba}',
+        '\u{6d41}', '\u{6edb}', '\u{6ecb}', '\u{6f22}', '\u{701e}', '\u{716e}', '\u{77a7}',
+        '\u{7235}', '\u{72af}', '\u{732a}', '\u{7471}', '\u{7506}', '\u{753b}', '\u{761d}',
+        '\u{761f}', '\u{76ca}', '\u{76db}', '\u{76f4}', '\u{774a}', '\u{7740}', '\u{78cc}',
+        '\u{7ab1}', '\u{7bc0}', '\u{7c7b}', '\u{7d5b}', '\u{7df4}', '\u{7f3e}', '\u{8005}',
+        '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8abf}',
+        '\u{8af8}', '\u{8acb}', '\u{8b01}', '\u{8afe}', '\u{8aed}', '\u{8b39}', '\u{8b8a}',
+        '\u{8d08}', '\u{8f38}', '\u{9072}', '\u{9199}', '\u{9276}', '\u{967c}', '\u{96e3}',
+        '\u{9756}', '\u{97db}', '\u{97ff}', '\u{980b}', '\u{983b}', '\u{9b12}', '\u{9f9c}',
+        '\u{2284a}', '\u{22844}', '\u{233d5}', '\u{3b9d}', '\u{4018}', '\u{4039}', '\u{25249}',
+        '\u{25cd0}', '\u{27ed3}', '\u{9f43}', '\u{9f8e}', '\u{5d9}', '\u{5b4}', '\u{5f2}',
+        '\u{5b7}', '\u{5e9}', '\u{5c1}', '\u{5e9}', '\u{5c2}', '\u{5e9}', '\u{5bc}', '\u{5c1}',
+        '\u{5e9}', '\u{5bc}', '\u{5c2}', '\u{5d0}', '\u{5b7}', '\u{5d0}', '\u{5b8}', '\u{5d0}',
+        '\u{5bc}', '\u{5d1}', '\u{5bc}', '\u{5d2}', '\u{5bc}', '\u{5d3}', '\u{5bc}', '\u{5d4}',
+        '\u{5bc}', '\u{5d5}', '\u{5bc}', '\u{5d6}', '\u{5bc}', '\u{5d8}', '\u{5bc}', '\u{5d9}',
+        '\u{5bc}', '\u{5da}', '\u{5bc}', '\u{5db}', '\u{5bc}', '\u{5dc}', '\u{5bc}', '\u{5de}',
+        '\u{5bc}', '\u{5e0}', '\u{5bc}', '\u{5e1}', '\u{5bc}', '\u{5e3}', '\u{5bc}', '\u{5e4}',
+        '\u{5bc}', '\u{5e6}', '\u{5bc}', '\u{5e7}', '\u{5bc}', '\u{5e8}', '\u{5bc}', '\u{5e9}',
+        '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}', '\u{5b9}', '\u{5d1}', '\u{5bf}', '\u{5db}',
+        '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{11099}', '\u{110ba}', '\u{1109b}', '\u{110ba}',
+        '\u{110a5}', '\u{110ba}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}',
+        '\u{1133e}', '\u{11347}', '\u{11357}', '\u{114b9}', '\u{114ba}', '\u{114b9}', '\u{114b0}',
+        '\u{114b9}', '\u{114bd}', '\u{115b8}', '\u{115af}', '\u{115b9}', '\u{115af}', '\u{11935}',
+        '\u{11930}', '\u{1d157}', '\u{1d165}', '\u{1d158}', '\u{1d165}', '\u{1d158}', '\u{1d165}',
+        '\u{1d16e}', '\u{1d158}', '\u{1d165}', '\u{1d16f}', '\u{1d158}', '\u{1d165}', '\u{1d170}',
+        '\u{1d158}', '\u{1d165}', '\u{1d171}', '\u{1d158}', '\u{1d165}', '\u{1d172}', '\u{1d1b9}',
+        '\u{1d165}', '\u{1d1ba}', '\u{1d165}', '\u{1d1b9}', '\u{1d165}', '\u{1d16e}', '\u{1d1ba}',
+        '\u{1d165}', '\u{1d16e}', '\u{1d1b9}', '\u{1d165}', '\u{1d16f}', '\u{1d1ba}', '\u{1d165}',
+        '\u{1d16f}', '\u{4e3d}', '\u{4e38}', '\u{4e41}', '\u{20122}', '\u{4f60}', '\u{4fae}',
+        '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50e7}', '\u{50cf}', '\u{349e}',
+        '\u{2063a}', '\u{514d}', '\u{5154}', '\u{5164}', '\u{5177}', '\u{2051c}', '\u{34b9}',
+        '\u{5167}', '\u{518d}', '\u{2054b}', '\u{5197}', '\u{51a4}', '\u{4ecc}', '\u{51ac}',
+        '\u{51b5}', '\u{291df}', '\u{51f5}', '\u{5203}', '\u{34df}', '\u{523b}', '\u{5246}',
+        '\u{5272}', '\u{5277}', '\u{3515}', '\u{52c7}', '\u{52c9}', '\u{52e4}', '\u{52fa}',
+        '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535a}', '\u{5373}',
+        '\u{537d}', '\u{537f}', '\u{537f}', '\u{537f}', '\u{20a2c}', '\u{7070}', '\u{53ca}',
+        '\u{53df}', '\u{20b63}', '\u{53eb}', '\u{53f1}', '\u{5406}', '\u{549e}', '\u{5438}',
+        '\u{5448}', '\u{5468}', '\u{54a2}', '\u{54f6}', '\u{5510}', '\u{5553}', '\u{5563}',
+        '\u{5584}', '\u{5584}', '\u{5599}', '\u{55ab}', '\u{55b3}', '\u{55c2}', '\u{5716}',
+        '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{5207}', '\u{58ee}', '\u{57ce}',
+        '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}', '\u{58ac}', '\u{214e4}',
+        '\u{58f2}', '\u{58f7}', '\u{5906}', '\u{591a}', '\u{5922}', '\u{5962}', '\u{216a8}',
+        '\u{216ea}', '\u{59ec}', '\u{5a1b}', '\u{5a27}', '\u{59d8}', '\u{5a66}', '\u{36ee}',
+        '\u{36fc}', '\u{5b08}', '\u{5b3e}', '\u{5b3e}', '\u{219c8}', '\u{5bc3}', '\u{5bd8}',
+        '\u{5be7}', '\u{5bf3}', '\u{21b18}', '\u{5bff}', '\u{5c06}', '\u{5f53}', '\u{5c22}',
+        '\u{3781}', '\u{5c60}', '\u{5c6e}', '\u{5cc0}', '\u{5c8d}', '\u{21de4}', '\u{5d43}',
+        '\u{21de6}', '\u{5d6e}', '\u{5d6b}', '\u{5d7c}', '\u{5de1}', '\u{5de2}', '\u{382f}',
+        '\u{5dfd}', '\u{5e28}', '\u{5e3d}', '\u{5e69}', '\u{3862}', '\u{22183}', '\u{387c}',
+        '\u{5eb0}', '\u{5eb3}', '\u{5eb6}', '\u{5eca}', '\u{2a392}', '\u{5efe}', '\u{22331}',
+        '\u{22331}', '\u{8201}', '\u{5f22}', '\u{5f22}', '\u{38c7}', '\u{232b8}', '\u{261da}',
+        '\u{5f62}', '\u{5f6b}', '\u{38e3}', '\u{5f9a}', '\u{5fcd}', '\u{5fd7}', '\u{5ff9}',
+        '\u{6081}', '\u{393a}', '\u{391c}', '\u{6094}', '\u{226d4}', '\u{60c7}', '\u{6148}',
+        '\u{614c}', '\u{614e}', '\u{614c}', '\u{617a}', '\u{618e}', '\u{61b2}', '\u{61a4}',
+        '\u{61af}', '\u{61de}', '\u{61f2}', '\u{61f6}', '\u{6210}', '\u{621b}', '\u{625d}',
+        '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}', '\u{633d}', '\u{62fc}', '\u{6368}',
+        '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}', '\u{63a9}', '\u{3a2e}',
+        '\u{6469}', '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}', '\u{654f}', '\u{656c}',
+        '\u{2300a}', '\u{65e3}', '\u{66f8}', '\u{6649}', '\u{3b19}', '\u{6691}', '\u{3b08}',
+        '\u{3ae4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669c}', '\u{80ad}', '\u{43d9}',
+        '\u{6717}', '\u{671b}', '\u{6721}', '\u{675e}', '\u{6753}', '\u{233c3}', '\u{3b49}',
+        '\u{67fa}', '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346d}', '\u{688e}', '\u{681f}',
+        '\u{6914}', '\u{3b9d}', '\u{6942}', '\u{69a3}', '\u{69ea}', '\u{6aa8}', '\u{236a3}',
+        '\u{6adb}', '\u{3c18}', '\u{6b21}', '\u{238a7}', '\u{6b54}', '\u{3c4e}', '\u{6b72}',
+        '\u{6b9f}', '\u{6bba}', '\u{6bbb}', '\u{23a8d}', '\u{21d0b}', '\u{23afa}', '\u{6c4e}',
+        '\u{23cbc}', '\u{6cbf}', '\u{6ccd}', '\u{6c67}', '\u{6d16}', '\u{6d3e}', '\u{6d77}',
+        '\u{6d41}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}', '\u{6d34}', '\u{6e2f}',
+        '\u{6e6e}', '\u{3d33}', '\u{6ecb}', '\u{6ec7}', '\u{23ed1}', '\u{6df9}', '\u{6f6e}',
+        '\u{23f5e}', '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701e}', '\u{701b}', '\u{3d96}',
+        '\u{704a}', '\u{707d}', '\u{7077}', '\u{70ad}', '\u{20525}', '\u{7145}', '\u{24263}',
+        '\u{719c}', '\u{243ab}', '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}',
+        '\u{7295}', '\u{24735}', '\u{24814}', '\u{737a}', '\u{738b}', '\u{3eac}', '\u{73a5}',
+        '\u{3eb8}', '\u{3eb8}', '\u{7447}', '\u{745c}', '\u{7471}', '\u{7485}', '\u{74ca}',
+        '\u{3f1b}', '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}', '\u{7570}', '\u{2219f}',
+        '\u{7610}', '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}', '\u{4008}', '\u{76f4}',
+        '\u{250f3}', '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}', '\u{771f}', '\u{771f}',
+        '\u{774a}', '\u{4039}', '\u{778b}', '\u{4046}', '\u{4096}', '\u{2541d}', '\u{784e}',
+        '\u{788c}', '\u{78cc}', '\u{40e3}', '\u{25626}', '\u{7956}', '\u{2569a}', '\u{256c5}',
+        '\u{798f}', '\u{79eb}', '\u{412f}', '\u{7a40}', '\u{7a4a}', '\u{7a4f}', '\u{2597c}',
+        '\u{25aa7}', '\u{25aa7}', '\u{7aee}', '\u{4202}', '\u{25bab}', '\u{7bc6}', '\u{7bc9}',
+        '\u{4227}', '\u{25c80}', '\u{7cd2}', '\u{42a0}', '\u{7ce8}', '\u{7ce3}', '\u{7d00}',
+        '\u{25f86}', '\u{7d63}', '\u{4301}', '\u{7dc7}', '\u{7e02}', '\u{7e45}', '\u{4334}',
+        '\u{26228}', '\u{26247}', '\u{4359}', '\u{262d9}', '\u{7f7a}', '\u{2633e}', '\u{7f95}',
+        '\u{7ffa}', '\u{8005}', '\u{264da}', '\u{26523}', '\u{8060}', '\u{265a8}', '\u{8070}',
+        '\u{2335f}', '\u{43d5}', '\u{80b2}', '\u{8103}', '\u{440b}', '\u{813e}', '\u{5ab5}',
+        '\u{267a7}', '\u{267b5}', '\u{23393}', '\u{2339c}', '\u{8201}', '\u{8204}', '\u{8f9e}',
+        '\u{446b}', '\u{8291}', '\u{828b}', '\u{829d}', '\u{52b3}', '\u{82b1}', '\u{82b3}',
+        '\u{82bd}', '\u{82e6}', '\u{26b3c}', '\u{82e5}', '\u{831d}', '\u{8363}', '\u{83ad}',
+        '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8457}', '\u{8353}', '\u{83ca}', '\u{83cc}',
+        '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}', '\u{84f1}', '\u{84f3}',
+        '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}', '\u{4561}', '\u{26fb1}',
+        '\u{270d2}', '\u{456b}', '\u{8650}', '\u{865c}', '\u{8667}', '\u{8669}', '\u{86a9}',
+        '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8779}', '\u{8728}', '\u{876b}', '\u{8786}',
+        '\u{45d7}', '\u{87e1}', '\u{8801}', '\u{45f9}', '\u{8860}', '\u{8863}', '\u{27667}',
+        '\u{88d7}', '\u{88de}', '\u{4635}', '\u{88fa}', '\u{34bb}', '\u{278ae}', '\u{27966}',
+        '\u{46be}', '\u{46c7}', '\u{8aa0}', '\u{8aed}', '\u{8b8a}', '\u{8c55}', '\u{27ca8}',
+        '\u{8cab}', '\u{8cc1}', '\u{8d1b}', '\u{8d77}', '\u{27f2f}', '\u{20804}', '\u{8dcb}',
+        '\u{8dbc}', '\u{8df0}', '\u{208de}', '\u{8ed4}', '\u{8f38}', '\u{285d2}', '\u{285ed}',
+        '\u{9094}', '\u{90f1}', '\u{9111}', '\u{2872e}', '\u{911b}', '\u{9238}', '\u{92d7}',
+        '\u{92d8}', '\u{927c}', '\u{93f9}', '\u{9415}', '\u{28bfa}', '\u{958b}', '\u{4995}',
+        '\u{95b7}', '\u{28d77}', '\u{49e6}', '\u{96c3}', '\u{5db2}', '\u{9723}', '\u{29145}',
+        '\u{2921a}', '\u{4a6e}', '\u{4a76}', '\u{97e0}', '\u{2940a}', '\u{4ab2}', '\u{29496}',
+        '\u{980b}', '\u{980b}', '\u{9829}', '\u{295b6}', '\u{98e2}', '\u{4b33}', '\u{9929}',
+        '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}', '\u{29b30}', '\u{9b12}', '\u{9c40}',
+        '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}', '\u{2a0ce}', '\u{4cf8}', '\u{2a105}',
+        '\u{2a20e}', '\u{2a291}', '\u{9ebb}', '\u{4d56}', '\u{9ef9}', '\u{9efe}', '\u{9f05}',
+        '\u{9f0f}', '\u{9f16}', '\u{9f3b}', '\u{2a600}',
+    ];
+
+    static COMPOSITION_TABLE: &[((char, char), char)] = &[
+        (('<', '\u{338}'), '\u{226e}'), (('=', '\u{338}'), '\u{2260}'),
+        (('>', '\u{338}'), '\u{226f}'), (('A', '\u{300}'), '\u{c0}'), (('A', '\u{301}'), '\u{c1}'),
+        (('A', '\u{302}'), '\u{c2}'), (('A', '\u{303}'), '\u{c3}'), (('A', '\u{304}'), '\u{100}'),
+        (('A', '\u{306}'), '\u{102}'), (('A', '\u{307}'), '\u{226}'), (('A', '\u{308}'), '\u{c4}'),
+        (('A', '\u{309}'), '\u{1ea2}'), (('A', '\u{30a}'), '\u{c5}'), (('A', '\u{30c}'), '\u{1cd}'),
+        (('A', '\u{30f}'), '\u{200}'), (('A', '\u{311}'), '\u{202}'),
+        (('A', '\u{323}'), '\u{1ea0}'), (('A', '\u{325}'), '\u{1e00}'),
+        (('A', '\u{328}'), '\u{104}'), (('B', '\u{307}'), '\u{1e02}'),
+        (('B', '\u{323}'), '\u{1e04}'), (('B', '\u{331}'), '\u{1e06}'),
+        (('C', '\u{301}'), '\u{106}'), (('C', '\u{302}'), '\u{108}'), (('C', '\u{307}'), '\u{10a}'),
+        (('C', '\u{30c}'), '\u{10c}'), (('C', '\u{327}'), '\u{c7}'), (('D', '\u{307}'), '\u{1e0a}'),
+        (('D', '\u{30c}'), '\u{10e}'), (('D', '\u{323}'), '\u{1e0c}'),
+        (('D', '\u{327}'), '\u{1e10}'), (('D', '\u{32d}'), '\u{1e12}'),
+        (('D', '\u{331}'), '\u{1e0e}'), (('E', '\u{300}'), '\u{c8}'), (('E', '\u{301}'), '\u{c9}'),
+        (('E', '\u{302}'), '\u{ca}'), (('E', '\u{303}'), '\u{1ebc}'), (('E', '\u{304}'), '\u{112}'),
+        (('E', '\u{306}'), '\u{114}'), (('E', '\u{307}'), '\u{116}'), (('E', '\u{308}'), '\u{cb}'),
+        (('E', '\u{309}'), '\u{1eba}'), (('E', '\u{30c}'), '\u{11a}'),
+        (('E', '\u{30f}'), '\u{204}'), (('E', '\u{311}'), '\u{206}'),
+        (('E', '\u{323}'), '\u{1eb8}'), (('E', '\u{327}'), '\u{228}'),
+        (('E', '\u{328}'), '\u{118}'), (('E', '\u{32d}'), '\u{1e18}'),
+        (('E', '\u{330}'), '\u{1e1a}'), (('F', '\u{307}'), '\u{1e1e}'),
+        (('G', '\u{301}'), '\u{1f4}'), (('G', '\u{302}'), '\u{11c}'),
+        (('G', '\u{304}'), '\u{1e20}'), (('G', '\u{306}'), '\u{11e}'),
+        (('G', '\u{307}'), '\u{120}'), (('G', '\u{30c}'), '\u{1e6}'), (('G', '\u{327}'), '\u{122}'),
+        (('H', '\u{302}'), '\u{124}'), (('H', '\u{307}'), '\u{1e22}'),
+        (('H', '\u{308}'), '\u{1e26}'), (('H', '\u{30c}'), '\u{21e}'),
+        (('H', '\u{323}'), '\u{1e24}'), (('H', '\u{327}'), '\u{1e28}'),
+        (('H', '\u{32e}'), '\u{1e2a}'), (('I', '\u{300}'), '\u{cc}'), (('I', '\u{301}'), '\u{cd}'),
+        (('I', '\u{302}'), '\u{ce}'), (('I', '\u{303}'), '\u{128}'), (('I', '\u{304}'), '\u{12a}'),
+        (('I', '\u{306}'), '\u{12c}'), (('I', '\u{307}'), '\u{130}'), (('I', '\u{308}'), '\u{cf}'),
+        (('I', '\u{309}'), '\u{1ec8}'), (('I', '\u{30c}'), '\u{1cf}'),
+        (('I', '\u{30f}'), '\u{208}'), (('I', '\u{311}'), '\u{20a}'),
+        (('I', '\u{323}'), '\u{1eca}'), (('I', '\u{328}'), '\u{12e}'),
+        (('I', '\u{330}'), '\u{1e2c}'), (('J', '\u{302}'), '\u{134}'),
+        (('K', '\u{301}'), '\u{1e30}'), (('K', '\u{30c}'), '\u{1e8}'),
+        (('K', '\u{323}'), '\u{1e32}'), (('K', '\u{327}'), '\u{136}'),
+        (('K', '\u{331}'), '\u{1e34}'), (('L', '\u{301}'), '\u{139}'),
+        (('L', '\u{30c}'), '\u{13d}'), (('L', '\u{323}'), '\u{1e36}'),
+        (('L', '\u{327}'), '\u{13b}'), (('L', '\u{32d}'), '\u{1e3c}'),
+        (('L', '\u{331}'), '\u{1e3a}'), (('M', '\u{301}'), '\u{1e3e}'),
+        (('M', '\u{307}'), '\u{1e40}'), (('M', '\u{323}'), '\u{1e42}'),
+        (('N', '\u{300}'), '\u{1f8}'), (('N', '\u{301}'), '\u{143}'), (('N', '\u{303}'), '\u{d1}'),
+        (('N', '\u{307}'), '\u{1e44}'), (('N', '\u{30c}'), '\u{147}'),
+        (('N', '\u{323}'), '\u{1e46}'), (('N', '\u{327}'), '\u{145}'),
+        (('N', '\u{32d}'), '\u{1e4a}'), (('N', '\u{331}'), '\u{1e48}'),
+        (('O', '\u{300}'), '\u{d2}'), (('O', '\u{301}'), '\u{d3}'), (('O', '\u{302}'), '\u{d4}'),
+        (('O', '\u{303}'), '\u{d5}'), (('O', '\u{304}'), '\u{14c}'), (('O', '\u{306}'), '\u{14e}'),
+        (('O', '\u{307}'), '\u{22e}'), (('O', '\u{308}'), '\u{d6}'), (('O', '\u{309}'), '\u{1ece}'),
+        (('O', '\u{30b}'), '\u{150}'), (('O', '\u{30c}'), '\u{1d1}'), (('O', '\u{30f}'), '\u{20c}'),
+        (('O', '\u{311}'), '\u{20e}'), (('O', '\u{31b}'), '\u{1a0}'),
+        (('O', '\u{323}'), '\u{1ecc}'), (('O', '\u{328}'), '\u{1ea}'),
+        (('P', '\u{301}'), '\u{1e54}'), (('P', '\u{307}'), '\u{1e56}'),
+        (('R', '\u{301}'), '\u{154}'), (('R', '\u{307}'), '\u{1e58}'),
+        (('R', '\u{30c}'), '\u{158}'), (('R', '\u{30f}'), '\u{210}'), (('R', '\u{311}'), '\u{212}'),
+        (('R', '\u{323}'), '\u{1e5a}'), (('R', '\u{327}'), '\u{156}'),
+        (('R', '\u{331}'), '\u{1e5e}'), (('S', '\u{301}'), '\u{15a}'),
+        (('S', '\u{302}'), '\u{15c}'), (('S', '\u{307}'), '\u{1e60}'),
+        (('S', '\u{30c}'), '\u{160}'), (('S', '\u{323}'), '\u{1e62}'),
+        (('S', '\u{326}'), '\u{218}'), (('S', '\u{327}'), '\u{15e}'),
+        (('T', '\u{307}'), '\u{1e6a}'), (('T', '\u{30c}'), '\u{164}'),
+        (('T', '\u{323}'), '\u{1e6c}'), (('T', '\u{326}'), '\u{21a}'),
+        (('T', '\u{327}'), '\u{162}'), (('T', '\u{32d}'), '\u{1e70}'),
+        (('T', '\u{331}'), '\u{1e6e}'), (('U', '\u{300}'), '\u{d9}'), (('U', '\u{301}'), '\u{da}'),
+        (('U', '\u{302}'), '\u{db}'), (('U', '\u{303}'), '\u{168}'), (('U', '\u{304}'), '\u{16a}'),
+        (('U', '\u{306}'), '\u{16c}'), (('U', '\u{308}'), '\u{dc}'), (('U', '\u{309}'), '\u{1ee6}'),
+        (('U', '\u{30a}'), '\u{16e}'), (('U', '\u{30b}'), '\u{170}'), (('U', '\u{30c}'), '\u{1d3}'),
+        (('U', '\u{30f}'), '\u{214}'), (('U', '\u{311}'), '\u{216}'), (('U', '\u{31b}'), '\u{1af}'),
+        (('U', '\u{323}'), '\u{1ee4}'), (('U', '\u{324}'), '\u{1e72}'),
+        (('U', '\u{328}'), '\u{172}'), (('U', '\u{32d}'), '\u{1e76}'),
+        (('U', '\u{330}'), '\u{1e74}'), (('V', '\u{303}'), '\u{1e7c}'),
+        (('V', '\u{323}'), '\u{1e7e}'), (('W', '\u{300}'), '\u{1e80}'),
+        (('W', '\u{301}'), '\u{1e82}'), (('W', '\u{302}'), '\u{174}'),
+        (('W', '\u{307}'), '\u{1e86}'), (('W', '\u{308}'), '\u{1e84}'),
+        (('W', '\u{323}'), '\u{1e88}'), (('X', '\u{307}'), '\u{1e8a}'),
+        (('X', '\u{308}'), '\u{1e8c}'), (('Y', '\u{300}'), '\u{1ef2}'),
+        (('Y', '\u{301}'), '\u{dd}'), (('Y', '\u{302}'), '\u{176}'), (('Y', '\u{303}'), '\u{1ef8}'),
+        (('Y', '\u{304}'), '\u{232}'), (('Y', '\u{307}'), '\u{1e8e}'),
+        (('Y', '\u{308}'), '\u{178}'), (('Y', '\u{309}'), '\u{1ef6}'),
+        (('Y', '\u{323}'), '\u{1ef4}'), (('Z', '\u{301}'), '\u{179}'),
+        (('Z', '\u{302}'), '\u{1e90}'), (('Z', '\u{307}'), '\u{17b}'),
+        (('Z', '\u{30c}'), '\u{17d}'), (('Z', '\u{323}'), '\u{1e92}'),
+        (('Z', '\u{331}'), '\u{1e94}'), (('a', '\u{300}'), '\u{e0}'), (('a', '\u{301}'), '\u{e1}'),
+        (('a', '\u{302}'), '\u{e2}'), (('a', '\u{303}'), '\u{e3}'), (('a', '\u{304}'), '\u{101}'),
+        (('a', '\u{306}'), '\u{103}'), (('a', '\u{307}'), '\u{227}'), (('a', '\u{308}'), '\u{e4}'),
+        (('a', '\u{309}'), '\u{1ea3}'), (('a', '\u{30a}'), '\u{e5}'), (('a', '\u{30c}'), '\u{1ce}'),
+        (('a', '\u{30f}'), '\u{201}'), (('a', '\u{311}'), '\u{203}'),
+        (('a', '\u{323}'), '\u{1ea1}'), (('a', '\u{325}'), '\u{1e01}'),
+        (('a', '\u{328}'), '\u{105}'), (('b', '\u{307}'), '\u{1e03}'),
+        (('b', '\u{323}'), '\u{1e05}'), (('b', '\u{331}'), '\u{1e07}'),
+        (('c', '\u{301}'), '\u{107}'), (('c', '\u{302}'), '\u{109}'), (('c', '\u{307}'), '\u{10b}'),
+        (('c', '\u{30c}'), '\u{10d}'), (('c', '\u{327}'), '\u{e7}'), (('d', '\u{307}'), '\u{1e0b}'),
+        (('d', '\u{30c}'), '\u{10f}'), (('d', '\u{323}'), '\u{1e0d}'),
+        (('d', '\u{327}'), '\u{1e11}'), (('d', '\u{32d}'), '\u{1e13}'),
+        (('d', '\u{331}'), '\u{1e0f}'), (('e', '\u{300}'), '\u{e8}'), (('e', '\u{301}'), '\u{e9}'),
+        (('e', '\u{302}'), '\u{ea}'), (('e', '\u{303}'), '\u{1ebd}'), (('e', '\u{304}'), '\u{113}'),
+        (('e', '\u{306}'), '\u{115}'), (('e', '\u{307}'), '\u{117}'), (('e', '\u{308}'), '\u{eb}'),
+        (('e', '\u{309}'), '\u{1ebb}'), (('e', '\u{30c}'), '\u{11b}'),
+        (('e', '\u{30f}'), '\u{205}'), (('e', '\u{311}'), '\u{207}'),
+        (('e', '\u{323}'), '\u{1eb9}'), (('e', '\u{327}'), '\u{229}'),
+        (('e', '\u{328}'), '\u{119}'), (('e', '\u{32d}'), '\u{1e19}'),
+        (('e', '\u{330}'), '\u{1e1b}'), (('f', '\u{307}'), '\u{1e1f}'),
+        (('g', '\u{301}'), '\u{1f5}'), (('g', '\u{302}'), '\u{11d}'),
+        (('g', '\u{304}'), '\u{1e21}'), (('g', '\u{306}'), '\u{11f}'),
+        (('g', '\u{307}'), '\u{121}'), (('g', '\u{30c}'), '\u{1e7}'), (('g', '\u{327}'), '\u{123}'),
+        (('h', '\u{302}'), '\u{125}'), (('h', '\u{307}'), '\u{1e23}'),
+        (('h', '\u{308}'), '\u{1e27}'), (('h', '\u{30c}'), '\u{21f}'),
+        (('h', '\u{323}'), '\u{1e25}'), (('h', '\u{327}'), '\u{1e29}'),
+        (('h', '\u{32e}'), '\u{1e2b}'), (('h', '\u{331}'), '\u{1e96}'),
+        (('i', '\u{300}'), '\u{ec}'), (('i', '\u{301}'), '\u{ed}'), (('i', '\u{302}'), '\u{ee}'),
+        (('i', '\u{303}'), '\u{129}'), (('i', '\u{304}'), '\u{12b}'), (('i', '\u{306}'), '\u{12d}'),
+        (('i', '\u{308}'), '\u{ef}'), (('i', '\u{309}'), '\u{1ec9}'), (('i', '\u{30c}'), '\u{1d0}'),
+        (('i', '\u{30f}'), '\u{209}'), (('i', '\u{311}'), '\u{20b}'),
+        (('i', '\u{323}'), '\u{1ecb}'), (('i', '\u{328}'), '\u{12f}'),
+        (('i', '\u{330}'), '\u{1e2d}'), (('j', '\u{302}'), '\u{135}'),
+        (('j', '\u{30c}'), '\u{1f0}'), (('k', '\u{301}'), '\u{1e31}'),
+        (('k', '\u{30c}'), '\u{1e9}'), (('k', '\u{323}'), '\u{1e33}'),
+        (('k', '\u{327}'), '\u{137}'), (('k', '\u{331}'), '\u{1e35}'),
+        (('l', '\u{301}'), '\u{13a}'), (('l', '\u{30c}'), '\u{13e}'),
+        (('l', '\u{323}'), '\u{1e37}'), (('l', '\u{327}'), '\u{13c}'),
+        (('l', '\u{32d}'), '\u{1e3d}'), (('l', '\u{331}'), '\u{1e3b}'),
+        (('m', '\u{301}'), '\u{1e3f}'), (('m', '\u{307}'), '\u{1e41}'),
+        (('m', '\u{323}'), '\u{1e43}'), (('n', '\u{300}'), '\u{1f9}'),
+        (('n', '\u{301}'), '\u{144}'), (('n', '\u{303}'), '\u{f1}'), (('n', '\u{307}'), '\u{1e45}'),
+        (('n', '\u{30c}'), '\u{148}'), (('n', '\u{323}'), '\u{1e47}'),
+        (('n', '\u{327}'), '\u{146}'), (('n', '\u{32d}'), '\u{1e4b}'),
+        (('n', '\u{331}'), '\u{1e49}'), (('o', '\u{300}'), '\u{f2}'), (('o', '\u{301}'), '\u{f3}'),
+        (('o', '\u{302}'), '\u{f4}'), (('o', '\u{303}'), '\u{f5}'), (('o', '\u{304}'), '\u{14d}'),
+        (('o', '\u{306}'), '\u{14f}'), (('o', '\u{307}'), '\u{22f}'), (('o', '\u{308}'), '\u{f6}'),
+        (('o', '\u{309}'), '\u{1ecf}'), (('o', '\u{30b}'), '\u{151}'),
+        (('o', '\u{30c}'), '\u{1d2}'), (('o', '\u{30f}'), '\u{20d}'), (('o', '\u{311}'), '\u{20f}'),
+        (('o', '\u{31b}'), '\u{1a1}'), (('o', '\u{323}'), '\u{1ecd}'),
+        (('o', '\u{328}'), '\u{1eb}'), (('p', '\u{301}'), '\u{1e55}'),
+        (('p', '\u{307}'), '\u{1e57}'), (('r', '\u{301}'), '\u{155}'),
+        (('r', '\u{307}'), '\u{1e59}'), (('r', '\u{30c}'), '\u{159}'),
+        (('r', '\u{30f}'), '\u{211}'), (('r', '\u{311}'), '\u{213}'),
+        (('r', '\u{323}'), '\u{1e5b}'), (('r', '\u{327}'), '\u{157}'),
+        (('r', '\u{331}'), '\u{1e5f}'), (('s', '\u{301}'), '\u{15b}'),
+        (('s', '\u{302}'), '\u{15d}'), (('s', '\u{307}'), '\u{1e61}'),
+        (('s', '\u{30c}'), '\u{161}'), (('s', '\u{323}'), '\u{1e63}'),
+        (('s', '\u{326}'), '\u{219}'), (('s', '\u{327}'), '\u{15f}'),
+        (('t', '\u{307}'), '\u{1e6b}'), (('t', '\u{308}'), '\u{1e97}'),
+        (('t', '\u{30c}'), '\u{165}'), (('t', '\u{323}'), '\u{1e6d}'),
+        (('t', '\u{326}'), '\u{21b}'), (('t', '\u{327}'), '\u{163}'),
+        (('t', '\u{32d}'), '\u{1e71}'), (('t', '\u{331}'), '\u{1e6f}'),
+        (('u', '\u{300}'), '\u{f9}'), (('u', '\u{301}'), '\u{fa}'), (('u', '\u{302}'), '\u{fb}'),
+        (('u', '\u{303}'), '\u{169}'), (('u', '\u{304}'), '\u{16b}'), (('u', '\u{306}'), '\u{16d}'),
+        (('u', '\u{308}'), '\u{fc}'), (('u', '\u{309}'), '\u{1ee7}'), (('u', '\u{30a}'), '\u{16f}'),
+        (('u', '\u{30b}'), '\u{171}'), (('u', '\u{30c}'), '\u{1d4}'), (('u', '\u{30f}'), '\u{215}'),
+        (('u', '\u{311}'), '\u{217}'), (('u', '\u{31b}'), '\u{1b0}'),
+        (('u', '\u{323}'), '\u{1ee5}'), (('u', '\u{324}'), '\u{1e73}'),
+        (('u', '\u{328}'), '\u{173}'), (('u', '\u{32d}'), '\u{1e77}'),
+        (('u', '\u{330}'), '\u{1e75}'), (('v', '\u{303}'), '\u{1e7d}'),
+        (('v', '\u{323}'), '\u{1e7f}'), (('w', '\u{300}'), '\u{1e81}'),
+        (('w', '\u{301}'), '\u{1e83}'), (('w', '\u{302}'), '\u{175}'),
+        (('w', '\u{307}'), '\u{1e87}'), (('w', '\u{308}'), '\u{1e85}'),
+        (('w', '\u{30a}'), '\u{1e98}'), (('w', '\u{323}'), '\u{1e89}'),
+        (('x', '\u{307}'), '\u{1e8b}'), (('x', '\u{308}'), '\u{1e8d}'),
+        (('y', '\u{300}'), '\u{1ef3}'), (('y', '\u{301}'), '\u{fd}'), (('y', '\u{302}'), '\u{177}'),
+        (('y', '\u{303}'), '\u{1ef9}'), (('y', '\u{304}'), '\u{233}'),
+        (('y', '\u{307}'), '\u{1e8f}'), (('y', '\u{308}'), '\u{ff}'),
+        (('y', '\u{309}'), '\u{1ef7}'), (('y', '\u{30a}'), '\u{1e99}'),
+        (('y', '\u{323}'), '\u{1ef5}'), (('z', '\u{301}'), '\u{17a}'),
+        (('z', '\u{302}'), '\u{1e91}'), (('z', '\u{307}'), '\u{17c}'),
+        (('z', '\u{30c}'), '\u{17e}'), (('z', '\u{323}'), '\u{1e93}'),
+        (('z', '\u{331}'), '\u{1e95}'), (('\u{a8}', '\u{300}'), '\u{1fed}'),
+        (('\u{a8}', '\u{301}'), '\u{385}'), (('\u{a8}', '\u{342}'), '\u{1fc1}'),
+        (('\u{c2}', '\u{300}'), '\u{1ea6}'), (('\u{c2}', '\u{301}'), '\u{1ea4}'),
+        (('\u{c2}', '\u{303}'), '\u{1eaa}'), (('\u{c2}', '\u{309}'), '\u{1ea8}'),
+        (('\u{c4}', '\u{304}'), '\u{1de}'), (('\u{c5}', '\u{301}'), '\u{1fa}'),
+        (('\u{c6}', '\u{301}'), '\u{1fc}'), (('\u{c6}', '\u{304}'), '\u{1e2}'),
+        (('\u{c7}', '\u{301}'), '\u{1e08}'), (('\u{ca}', '\u{300}'), '\u{1ec0}'),
+        (('\u{ca}', '\u{301}'), '\u{1ebe}'), (('\u{ca}', '\u{303}'), '\u{1ec4}'),
+        (('\u{ca}', '\u{309}'), '\u{1ec2}'), (('\u{cf}', '\u{301}'), '\u{1e2e}'),
+        (('\u{d4}', '\u{300}'), '\u{1ed2}'), (('\u{d4}', '\u{301}'), '\u{1ed0}'),
+        (('\u{d4}', '\u{303}'), '\u{1ed6}'), (('\u{d4}', '\u{309}'), '\u{1ed4}'),
+        (('\u{d5}', '\u{301}'), '\u{1e4c}'), (('\u{d5}', '\u{304}'), '\u{22c}'),
+        (('\u{d5}', '\u{308}'), '\u{1e4e}'), (('\u{d6}', '\u{304}'), '\u{22a}'),
+        (('\u{d8}', '\u{301}'), '\u{1fe}'), (('\u{dc}', '\u{300}'), '\u{1db}'),
+        (('\u{dc}', '\u{301}'), '\u{1d7}'), (('\u{dc}', '\u{304}'), '\u{1d5}'),
+        (('\u{dc}', '\u{30c}'), '\u{1d9}'), (('\u{e2}', '\u{300}'), '\u{1ea7}'),
+        (('\u{e2}', '\u{301}'), '\u{1ea5}'), (('\u{e2}', '\u{303}'), '\u{1eab}'),
+        (('\u{e2}', '\u{309}'), '\u{1ea9}'), (('\u{e4}', '\u{304}'), '\u{1df}'),
+        (('\u{e5}', '\u{301}'), '\u{1fb}'), (('\u{e6}', '\u{301}'), '\u{1fd}'),
+        (('\u{e6}', '\u{304}'), '\u{1e3}'), (('\u{e7}', '\u{301}'), '\u{1e09}'),
+        (('\u{ea}', '\u{300}'), '\u{1ec1}'), (('\u{ea}', '\u{301}'), '\u{1ebf}'),
+        (('\u{ea}', '\u{303}'), '\u{1ec5}'), (('\u{ea}', '\u{309}'), '\u{1ec3}'),
+        (('\u{ef}', '\u{301}'), '\u{1e2f}'), (('\u{f4}', '\u{300}'), '\u{1ed3}'),
+        (('\u{f4}', '\u{301}'), '\u{1ed1}'), (('\u{f4}', '\u{303}'), '\u{1ed7}'),
+        (('\u{f4}', '\u{309}'), '\u{1ed5}'), (('\u{f5}', '\u{301}'), '\u{1e4d}'),
+        (('\u{f5}', '\u{304}'), '\u{22d}'), (('\u{f5}', '\u{308}'), '\u{1e4f}'),
+        (('\u{f6}', '\u{304}'), '\u{22b}'), (('\u{f8}', '\u{301}'), '\u{1ff}'),
+        (('\u{fc}', '\u{300}'), '\u{1dc}'), (('\u{fc}', '\u{301}'), '\u{1d8}'),
+        (('\u{fc}', '\u{304}'), '\u{1d6}'), (('\u{fc}', '\u{30c}'), '\u{1da}'),
+        (('\u{102}', '\u{300}'), '\u{1eb0}'), (('\u{102}', '\u{301}'), '\u{1eae}'),
+        (('\u{102}', '\u{303}'), '\u{1eb4}'), (('\u{102}', '\u{309}'), '\u{1eb2}'),
+        (('\u{103}', '\u{300}'), '\u{1eb1}'), (('\u{103}', '\u{301}'), '\u{1eaf}'),
+        (('\u{103}', '\u{303}'), '\u{1eb5}'), (('\u{103}', '\u{309}'), '\u{1eb3}'),
+        (('\u{112}', '\u{300}'), '\u{1e14}'), (('\u{112}', '\u{301}'), '\u{1e16}'),
+        (('\u{113}', '\u{300}'), '\u{1e15}'), (('\u{113}', '\u{301}'), '\u{1e17}'),
+        (('\u{14c}', '\u{300}'), '\u{1e50}'), (('\u{14c}', '\u{301}'), '\u{1e52}'),
+        (('\u{14d}', '\u{300}'), '\u{1e51}'), (('\u{14d}', '\u{301}'), '\u{1e53}'),
+        (('\u{15a}', '\u{307}'), '\u{1e64}'), (('\u{15b}', '\u{307}'), '\u{1e65}'),
+        (('\u{160}', '\u{307}'), '\u{1e66}'), (('\u{161}', '\u{307}'), '\u{1e67}'),
+        (('\u{168}', '\u{301}'), '\u{1e78}'), (('\u{169}', '\u{301}'), '\u{1e79}'),
+        (('\u{16a}', '\u{308}'), '\u{1e7a}'), (('\u{16b}', '\u{308}'), '\u{1e7b}'),
+        (('\u{17f}', '\u{307}'), '\u{1e9b}'), (('\u{1a0}', '\u{300}'), '\u{1edc}'),
+        (('\u{1a0}', '\u{301}'), '\u{1eda}'), (('\u{1a0}', '\u{303}'), '\u{1ee0}'),
+        (('\u{1a0}', '\u{309}'), '\u{1ede}'), (('\u{1a0}', '\u{323}'), '\u{1ee2}'),
+        (('\u{1a1}', '\u{300}'), '\u{1edd}'), (('\u{1a1}', '\u{301}'), '\u{1edb}'),
+        (('\u{1a1}', '\u{303}'), '\u{1ee1}'), (('\u{1a1}', '\u{309}'), '\u{1edf}'),
+        (('\u{1a1}', '\u{323}'), '\u{1ee3}'), (('\u{1af}', '\u{300}'), '\u{1eea}'),
+        (('\u{1af}', '\u{301}'), '\u{1ee8}'), (('\u{1af}', '\u{303}'), '\u{1eee}'),
+        (('\u{1af}', '\u{309}'), '\u{1eec}'), (('\u{1af}', '\u{323}'), '\u{1ef0}'),
+        (('\u{1b0}', '\u{300}'), '\u{1eeb}'), (('\u{1b0}', '\u{301}'), '\u{1ee9}'),
+        (('\u{1b0}', '\u{303}'), '\u{1eef}'), (('\u{1b0}', '\u{309}'), '\u{1eed}'),
+        (('\u{1b0}', '\u{323}'), '\u{1ef1}'), (('\u{1b7}', '\u{30c}'), '\u{1ee}'),
+        (('\u{1ea}', '\u{304}'), '\u{1ec}'), (('\u{1eb}', '\u{304}'), '\u{1ed}'),
+        (('\u{226}', '\u{304}'), '\u{1e0}'), (('\u{227}', '\u{304}'), '\u{1e1}'),
+        (('\u{228}', '\u{306}'), '\u{1e1c}'), (('\u{229}', '\u{306}'), '\u{1e1d}'),
+        (('\u{22e}', '\u{304}'), '\u{230}'), (('\u{22f}', '\u{304}'), '\u{231}'),
+        (('\u{292}', '\u{30c}'), '\u{1ef}'), (('\u{391}', '\u{300}'), '\u{1fba}'),
+        (('\u{391}', '\u{301}'), '\u{386}'), (('\u{391}', '\u{304}'), '\u{1fb9}'),
+        (('\u{391}', '\u{306}'), '\u{1fb8}'), (('\u{391}', '\u{313}'), '\u{1f08}'),
+        (('\u{391}', '\u{314}'), '\u{1f09}'), (('\u{391}', '\u{345}'), '\u{1fbc}'),
+        (('\u{395}', '\u{300}'), '\u{1fc8}'), (('\u{395}', '\u{301}'), '\u{388}'),
+        (('\u{395}', '\u{313}'), '\u{1f18}'), (('\u{395}', '\u{314}'), '\u{1f19}'),
+        (('\u{397}', '\u{300}'), '\u{1fca}'), (('\u{397}', '\u{301}'), '\u{389}'),
+        (('\u{397}', '\u{313}'), '\u{1f28}'), (('\u{397}', '\u{314}'), '\u{1f29}'),
+        (('\u{397}', '\u{345}'), '\u{1fcc}'), (('\u{399}', '\u{300}'), '\u{1fda}'),
+        (('\u{399}', '\u{301}'), '\u{38a}'), (('\u{399}', '\u{304}'), '\u{1fd9}'),
+        (('\u{399}', '\u{306}'), '\u{1fd8}'), (('\u{399}', '\u{308}'), '\u{3aa}'),
+        (('\u{399}', '\u{313}'), '\u{1f38}'), (('\u{399}', '\u{314}'), '\u{1f39}'),
+        (('\u{39f}', '\u{300}'), '\u{1ff8}'), (('\u{39f}', '\u{301}'), '\u{38c}'),
+        (('\u{39f}', '\u{313}'), '\u{1f48}'), (('\u{39f}', '\u{314}'), '\u{1f49}'),
+        (('\u{3a1}', '\u{314}'), '\u{1fec}'), (('\u{3a5}', '\u{300}'), '\u{1fea}'),
+        (('\u{3a5}', '\u{301}'), '\u{38e}'), (('\u{3a5}', '\u{304}'), '\u{1fe9}'),
+        (('\u{3a5}', '\u{306}'), '\u{1fe8}'), (('\u{3a5}', '\u{308}'), '\u{3ab}'),
+        (('\u{3a5}', '\u{314}'), '\u{1f59}'), (('\u{3a9}', '\u{300}'), '\u{1ffa}'),
+        (('\u{3a9}', '\u{301}'), '\u{38f}'), (('\u{3a9}', '\u{313}'), '\u{1f68}'),
+        (('\u{3a9}', '\u{314}'), '\u{1f69}'), (('\u{3a9}', '\u{345}'), '\u{1ffc}'),
+        (('\u{3ac}', '\u{345}'), '\u{1fb4}'), (('\u{3ae}', '\u{345}'), '\u{1fc4}'),
+        (('\u{3b1}', '\u{300}'), '\u{1f70}'), (('\u{3b1}', '\u{301}'), '\u{3ac}'),
+        (('\u{3b1}', '\u{304}'), '\u{1fb1}'), (('\u{3b1}', '\u{306}'), '\u{1fb0}'),
+        (('\u{3b1}', '\u{313}'), '\u{1f00}'), (('\u{3b1}', '\u{314}'), '\u{1f01}'),
+        (('\u{3b1}', '\u{342}'), '\u{1fb6}'), (('\u{3b1}', '\u{345}'), '\u{1fb3}'),
+        (('\u{3b5}', '\u{300}'), '\u{1f72}'), (('\u{3b5}', '\u{301}'), '\u{3ad}'),
+        (('\u{3b5}', '\u{313}'), '\u{1f10}'), (('\u{3b5}', '\u{314}'), '\u{1f11}'),
+        (('\u{3b7}', '\u{300}'), '\u{1f74}'), (('\u{3b7}', '\u{301}'), '\u{3ae}'),
+        (('\u{3b7}', '\u{313}'), '\u{1f20}'), (('\u{3b7}', '\u{314}'), '\u{1f21}'),
+        (('\u{3b7}', '\u{342}'), '\u{1fc6}'), (('\u{3b7}', '\u{345}'), '\u{1fc3}'),
+        (('\u{3b9}', '\u{300}'), '\u{1f76}'), (('\u{3b9}', '\u{301}'), '\u{3af}'),
+        (('\u{3b9}', '\u{304}'), '\u{1fd1}'), (('\u{3b9}', '\u{306}'), '\u{1fd0}'),
+        (('\u{3b9}', '\u{308}'), '\u{3ca}'), (('\u{3b9}', '\u{313}'), '\u{1f30}'),
+        (('\u{3b9}', '\u{314}'), '\u{1f31}'), (('\u{3b9}', '\u{342}'), '\u{1fd6}'),
+        (('\u{3bf}', '\u{300}'), '\u{1f78}'), (('\u{3bf}', '\u{301}'), '\u{3cc}'),
+        (('\u{3bf}', '\u{313}'), '\u{1f40}'), (('\u{3bf}', '\u{314}'), '\u{1f41}'),
+        (('\u{3c1}', '\u{313}'), '\u{1fe4}'), (('\u{3c1}', '\u{314}'), '\u{1fe5}'),
+        (('\u{3c5}', '\u{300}'), '\u{1f7a}'), (('\u{3c5}', '\u{301}'), '\u{3cd}'),
+        (('\u{3c5}', '\u{304}'), '\u{1fe1}'), (('\u{3c5}', '\u{306}'), '\u{1fe0}'),
+        (('\u{3c5}', '\u{308}'), '\u{3cb}'), (('\u{3c5}', '\u{313}'), '\u{1f50}'),
+        (('\u{3c5}', '\u{314}'), '\u{1f51}'), (('\u{3c5}', '\u{342}'), '\u{1fe6}'),
+        (('\u{3c9}', '\u{300}'), '\u{1f7c}'), (('\u{3c9}', '\u{301}'), '\u{3ce}'),
+        (('\u{3c9}', '\u{313}'), '\u{1f60}'), (('\u{3c9}', '\u{314}'), '\u{1f61}'),
+        (('\u{3c9}', '\u{342}'), '\u{1ff6}'), (('\u{3c9}', '\u{345}'), '\u{1ff3}'),
+        (('\u{3ca}', '\u{300}'), '\u{1fd2}'), (('\u{3ca}', '\u{301}'), '\u{390}'),
+        (('\u{3ca}', '\u{342}'), '\u{1fd7}'), (('\u{3cb}', '\u{300}'), '\u{1fe2}'),
+        (('\u{3cb}', '\u{301}'), '\u{3b0}'), (('\u{3cb}', '\u{342}'), '\u{1fe7}'),
+        (('\u{3ce}', '\u{345}'), '\u{1ff4}'), (('\u{3d2}', '\u{301}'), '\u{3d3}'),
+        (('\u{3d2}', '\u{308}'), '\u{3d4}'), (('\u{406}', '\u{308}'), '\u{407}'),
+        (('\u{410}', '\u{306}'), '\u{4d0}'), (('\u{410}', '\u{308}'), '\u{4d2}'),
+        (('\u{413}', '\u{301}'), '\u{403}'), (('\u{415}', '\u{300}'), '\u{400}'),
+        (('\u{415}', '\u{306}'), '\u{4d6}'), (('\u{415}', '\u{308}'), '\u{401}'),
+        (('\u{416}', '\u{306}'), '\u{4c1}'), (('\u{416}', '\u{308}'), '\u{4dc}'),
+        (('\u{417}', '\u{308}'), '\u{4de}'), (('\u{418}', '\u{300}'), '\u{40d}'),
+        (('\u{418}', '\u{304}'), '\u{4e2}'), (('\u{418}', '\u{306}'), '\u{419}'),
+        (('\u{418}', '\u{308}'), '\u{4e4}'), (('\u{41a}', '\u{301}'), '\u{40c}'),
+        (('\u{41e}', '\u{308}'), '\u{4e6}'), (('\u{423}', '\u{304}'), '\u{4ee}'),
+        (('\u{423}', '\u{306}'), '\u{40e}'), (('\u{423}', '\u{308}'), '\u{4f0}'),
+        (('\u{423}', '\u{30b}'), '\u{4f2}'), (('\u{427}', '\u{308}'), '\u{4f4}'),
+        (('\u{42b}', '\u{308}'), '\u{4f8}'), (('\u{42d}', '\u{308}'), '\u{4ec}'),
+        (('\u{430}', '\u{306}'), '\u{4d1}'), (('\u{430}', '\u{308}'), '\u{4d3}'),
+        (('\u{433}', '\u{301}'), '\u{453}'), (('\u{435}', '\u{300}'), '\u{450}'),
+        (('\u{435}', '\u{306}'), '\u{4d7}'), (('\u{435}', '\u{308}'), '\u{451}'),
+        (('\u{436}', '\u{306}'), '\u{4c2}'), (('\u{436}', '\u{308}'), '\u{4dd}'),
+        (('\u{437}', '\u{308}'), '\u{4df}'), (('\u{438}', '\u{300}'), '\u{45d}'),
+        (('\u{438}', '\u{304}'), '\u{4e3}'), (('\u{438}', '\u{306}'), '\u{439}'),
+        (('\u{438}', '\u{308}'), '\u{4e5}'), (('\u{43a}', '\u{301}'), '\u{45c}'),
+        (('\u{43e}', '\u{308}'), '\u{4e7}'), (('\u{443}', '\u{304}'), '\u{4ef}'),
+        (('\u{443}', '\u{306}'), '\u{45e}'), (('\u{443}', '\u{308}'), '\u{4f1}'),
+        (('\u{443}', '\u{30b}'), '\u{4f3}'), (('\u{447}', '\u{308}'), '\u{4f5}'),
+        (('\u{44b}', '\u{308}'), '\u{4f9}'), (('\u{44d}', '\u{308}'), '\u{4ed}'),
+        (('\u{456}', '\u{308}'), '\u{457}'), (('\u{474}', '\u{30f}'), '\u{476}'),
+        (('\u{475}', '\u{30f}'), '\u{477}'), (('\u{4d8}', '\u{308}'), '\u{4da}'),
+        (('\u{4d9}', '\u{308}'), '\u{4db}'), (('\u{4e8}', '\u{308}'), '\u{4ea}'),
+        (('\u{4e9}', '\u{308}'), '\u{4eb}'), (('\u{627}', '\u{653}'), '\u{622}'),
+        (('\u{627}', '\u{654}'), '\u{623}'), (('\u{627}', '\u{655}'), '\u{625}'),
+        (('\u{648}', '\u{654}'), '\u{624}'), (('\u{64a}', '\u{654}'), '\u{626}'),
+        (('\u{6c1}', '\u{654}'), '\u{6c2}'), (('\u{6d2}', '\u{654}'), '\u{6d3}'),
+        (('\u{6d5}', '\u{654}'), '\u{6c0}'), (('\u{928}', '\u{93c}'), '\u{929}'),
+        (('\u{930}', '\u{93c}'), '\u{931}'), (('\u{933}', '\u{93c}'), '\u{934}'),
+        (('\u{9c7}', '\u{9be}'), '\u{9cb}'), (('\u{9c7}', '\u{9d7}'), '\u{9cc}'),
+        (('\u{b47}', '\u{b3e}'), '\u{b4b}'), (('\u{b47}', '\u{b56}'), '\u{b48}'),
+        (('\u{b47}', '\u{b57}'), '\u{b4c}'), (('\u{b92}', '\u{bd7}'), '\u{b94}'),
+        (('\u{bc6}', '\u{bbe}'), '\u{bca}'), (('\u{bc6}', '\u{bd7}'), '\u{bcc}'),
+        (('\u{bc7}', '\u{bbe}'), '\u{bcb}'), (('\u{c46}', '\u{c56}'), '\u{c48}'),
+        (('\u{cbf}', '\u{cd5}'), '\u{cc0}'), (('\u{cc6}', '\u{cc2}'), '\u{cca}'),
+        (('\u{cc6}', '\u{cd5}'), '\u{cc7}'), (('\u{cc6}', '\u{cd6}'), '\u{cc8}'),
+        (('\u{cca}', '\u{cd5}'), '\u{ccb}'), (('\u{d46}', '\u{d3e}'), '\u{d4a}'),
+        (('\u{d46}', '\u{d57}'), '\u{d4c}'), (('\u{d47}', '\u{d3e}'), '\u{d4b}'),
+        (('\u{dd9}', '\u{dca}'), '\u{dda}'), (('\u{dd9}', '\u{dcf}'), '\u{ddc}'),
+        (('\u{dd9}', '\u{ddf}'), '\u{dde}'), (('\u{ddc}', '\u{dca}'), '\u{ddd}'),
+        (('\u{1025}', '\u{102e}'), '\u{1026}'), (('\u{1b05}', '\u{1b35}'), '\u{1b06}'),
+        (('\u{1b07}', '\u{1b35}'), '\u{1b08}'), (('\u{1b09}', '\u{1b35}'), '\u{1b0a}'),
+        (('\u{1b0b}', '\u{1b35}'), '\u{1b0c}'), (('\u{1b0d}', '\u{1b35}'), '\u{1b0e}'),
+        (('\u{1b11}', '\u{1b35}'), '\u{1b12}'), (('\u{1b3a}', '\u{1b35}'), '\u{1b3b}'),
+        (('\u{1b3c}', '\u{1b35}'), '\u{1b3d}'), (('\u{1b3e}', '\u{1b35}'), '\u{1b40}'),
+        (('\u{1b3f}', '\u{1b35}'), '\u{1b41}'), (('\u{1b42}', '\u{1b35}'), '\u{1b43}'),
+        (('\u{1e36}', '\u{304}'), '\u{1e38}'), (('\u{1e37}', '\u{304}'), '\u{1e39}'),
+        (('\u{1e5a}', '\u{304}'), '\u{1e5c}'), (('\u{1e5b}', '\u{304}'), '\u{1e5d}'),
+        (('\u{1e62}', '\u{307}'), '\u{1e68}'), (('\u{1e63}', '\u{307}'), '\u{1e69}'),
+        (('\u{1ea0}', '\u{302}'), '\u{1eac}'), (('\u{1ea0}', '\u{306}'), '\u{1eb6}'),
+        (('\u{1ea1}', '\u{302}'), '\u{1ead}'), (('\u{1ea1}', '\u{306}'), '\u{1eb7}'),
+        (('\u{1eb8}', '\u{302}'), '\u{1ec6}'), (('\u{1eb9}', '\u{302}'), '\u{1ec7}'),
+        (('\u{1ecc}', '\u{302}'), '\u{1ed8}'), (('\u{1ecd}', '\u{302}'), '\u{1ed9}'),
+        (('\u{1f00}', '\u{300}'), '\u{1f02}'), (('\u{1f00}', '\u{301}'), '\u{1f04}'),
+        (('\u{1f00}', '\u{342}'), '\u{1f06}'), (('\u{1f00}', '\u{345}'), '\u{1f80}'),
+        (('\u{1f01}', '\u{300}'), '\u{1f03}'), (('\u{1f01}', '\u{301}'), '\u{1f05}'),
+        (('\u{1f01}', '\u{342}'), '\u{1f07}'), (('\u{1f01}', '\u{345}'), '\u{1f81}'),
+        (('\u{1f02}', '\u{345}'), '\u{1f82}'), (('\u{1f03}', '\u{345}'), '\u{1f83}'),
+        (('\u{1f04}', '\u{345}'), '\u{1f84}'), (('\u{1f05}', '\u{345}'), '\u{1f85}'),
+        (('\u{1f06}', '\u{345}'), '\u{1f86}'), (('\u{1f07}', '\u{345}'), '\u{1f87}'),
+        (('\u{1f08}', '\u{300}'), '\u{1f0a}'), (('\u{1f08}', '\u{301}'), '\u{1f0c}'),
+        (('\u{1f08}', '\u{342}'), '\u{1f0e}'), (('\u{1f08}', '\u{345}'), '\u{1f88}'),
+        (('\u{1f09}', '\u{300}'), '\u{1f0b}'), (('\u{1f09}', '\u{301}'), '\u{1f0d}'),
+        (('\u{1f09}', '\u{342}'), '\u{1f0f}'), (('\u{1f09}', '\u{345}'), '\u{1f89}'),
+        (('\u{1f0a}', '\u{345}'), '\u{1f8a}'), (('\u{1f0b}', '\u{345}'), '\u{1f8b}'),
+        (('\u{1f0c}', '\u{345}'), '\u{1f8c}'), (('\u{1f0d}', '\u{345}'), '\u{1f8d}'),
+        (('\u{1f0e}', '\u{345}'), '\u{1f8e}'), (('\u{1f0f}', '\u{345}'), '\u{1f8f}'),
+        (('\u{1f10}', '\u{300}'), '\u{1f12}'), (('\u{1f10}', '\u{301}'), '\u{1f14}'),
+        (('\u{1f11}', '\u{300}'), '\u{1f13}'), (('\u{1f11}', '\u{301}'), '\u{1f15}'),
+        (('\u{1f18}', '\u{300}'), '\u{1f1a}'), (('\u{1f18}', '\u{301}'), '\u{1f1c}'),
+        (('\u{1f19}', '\u{300}'), '\u{1f1b}'), (('\u{1f19}', '\u{301}'), '\u{1f1d}'),
+        (('\u{1f20}', '\u{300}'), '\u{1f22}'), (('\u{1f20}', '\u{301}'), '\u{1f24}'),
+        (('\u{1f20}', '\u{342}'), '\u{1f26}'), (('\u{1f20}', '\u{345}'), '\u{1f90}'),
+        (('\u{1f21}', '\u{300}'), '\u{1f23}'), (('\u{1f21}', '\u{301}'), '\u{1f25}'),
+        (('\u{1f21}', '\u{342}'), '\u{1f27}'), (('\u{1f21}', '\u{345}'), '\u{1f91}'),
+        (('\u{1f22}', '\u{345}'), '\u{1f92}'), (('\u{1f23}', '\u{345}'), '\u{1f93}'),
+        (('\u{1f24}', '\u{345}'), '\u{1f94}'), (('\u{1f25}', '\u{345}'), '\u{1f95}'),
+        (('\u{1f26}', '\u{345}'), '\u{1f96}'), (('\u{1f27}', '\u{345}'), '\u{1f97}'),
+        (('\u{1f28}', '\u{300}'), '\u{1f2a}'), (('\u{1f28}', '\u{301}'), '\u{1f2c}'),
+        (('\u{1f28}', '\u{342}'), '\u{1f2e}'), (('\u{1f28}', '\u{345}'), '\u{1f98}'),
+        (('\u{1f29}', '\u{300}'), '\u{1f2b}'), (('\u{1f29}', '\u{301}'), '\u{1f2d}'),
+        (('\u{1f29}', '\u{342}'), '\u{1f2f}'), (('\u{1f29}', '\u{345}'), '\u{1f99}'),
+        (('\u{1f2a}', '\u{345}'), '\u{1f9a}'), (('\u{1f2b}', '\u{345}'), '\u{1f9b}'),
+        (('\u{1f2c}', '\u{345}'), '\u{1f9c}'), (('\u{1f2d}', '\u{345}'), '\u{1f9d}'),
+        (('\u{1f2e}', '\u{345}'), '\u{1f9e}'), (('\u{1f2f}', '\u{345}'), '\u{1f9f}'),
+        (('\u{1f30}', '\u{300}'), '\u{1f32}'), (('\u{1f30}', '\u{301}'), '\u{1f34}'),
+        (('\u{1f30}', '\u{342}'), '\u{1f36}'), (('\u{1f31}', '\u{300}'), '\u{1f33}'),
+        (('\u{1f31}', '\u{301}'), '\u{1f35}'), (('\u{1f31}', '\u{342}'), '\u{1f37}'),
+        (('\u{1f38}', '\u{300}'), '\u{1f3a}'), (('\u{1f38}', '\u{301}'), '\u{1f3c}'),
+        (('\u{1f38}', '\u{342}'), '\u{1f3e}'), (('\u{1f39}', '\u{300}'), '\u{1f3b}'),
+        (('\u{1f39}', '\u{301}'), '\u{1f3d}'), (('\u{1f39}', '\u{342}'), '\u{1f3f}'),
+        (('\u{1f40}', '\u{300}'), '\u{1f42}'), (('\u{1f40}', '\u{301}'), '\u{1f44}'),
+        (('\u{1f41}', '\u{300}'), '\u{1f43}'), (('\u{1f41}', '\u{301}'), '\u{1f45}'),
+        (('\u{1f48}', '\u{300}'), '\u{1f4a}'), (('\u{1f48}', '\u{301}'), '\u{1f4c}'),
+        (('\u{1f49}', '\u{300}'), '\u{1f4b}'), (('\u{1f49}', '\u{301}'), '\u{1f4d}'),
+        (('\u{1f50}', '\u{300}'), '\u{1f52}'), (('\u{1f50}', '\u{301}'), '\u{1f54}'),
+        (('\u{1f50}', '\u{342}'), '\u{1f56}'), (('\u{1f51}', '\u{300}'), '\u{1f53}'),
+        (('\u{1f51}', '\u{301}'), '\u{1f55}'), (('\u{1f51}', '\u{342}'), '\u{1f57}'),
+        (('\u{1f59}', '\u{300}'), '\u{1f5b}'), (('\u{1f59}', '\u{301}'), '\u{1f5d}'),
+        (('\u{1f59}', '\u{342}'), '\u{1f5f}'), (('\u{1f60}', '\u{300}'), '\u{1f62}'),
+        (('\u{1f60}', '\u{301}'), '\u{1f64}'), (('\u{1f60}', '\u{342}'), '\u{1f66}'),
+        (('\u{1f60}', '\u{345}'), '\u{1fa0}'), (('\u{1f61}', '\u{300}'), '\u{1f63}'),
+        (('\u{1f61}', '\u{301}'), '\u{1f65}'), (('\u{1f61}', '\u{342}'), '\u{1f67}'),
+        (('\u{1f61}', '\u{345}'), '\u{1fa1}'), (('\u{1f62}', '\u{345}'), '\u{1fa2}'),
+        (('\u{1f63}', '\u{345}'), '\u{1fa3}'), (('\u{1f64}', '\u{345}'), '\u{1fa4}'),
+        (('\u{1f65}', '\u{345}'), '\u{1fa5}'), (('\u{1f66}', '\u{345}'), '\u{1fa6}'),
+        (('\u{1f67}', '\u{345}'), '\u{1fa7}'), (('\u{1f68}', '\u{300}'), '\u{1f6a}'),
+        (('\u{1f68}', '\u{301}'), '\u{1f6c}'), (('\u{1f68}', '\u{342}'), '\u{1f6e}'),
+        (('\u{1f68}', '\u{345}'), '\u{1fa8}'), (('\u{1f69}', '\u{300}'), '\u{1f6b}'),
+        (('\u{1f69}', '\u{301}'), '\u{1f6d}'), (('\u{1f69}', '\u{342}'), '\u{1f6f}'),
+        (('\u{1f69}', '\u{345}'), '\u{1fa9}'), (('\u{1f6a}', '\u{345}'), '\u{1faa}'),
+        (('\u{1f6b}', '\u{345}'), '\u{1fab}'), (('\u{1f6c}', '\u{345}'), '\u{1fac}'),
+        (('\u{1f6d}', '\u{345}'), '\u{1fad}'), (('\u{1f6e}', '\u{345}'), '\u{1fae}'),
+        (('\u{1f6f}', '\u{345}'), '\u{1faf}'), (('\u{1f70}', '\u{345}'), '\u{1fb2}'),
+        (('\u{1f74}', '\u{345}'), '\u{1fc2}'), (('\u{1f7c}', '\u{345}'), '\u{1ff2}'),
+        (('\u{1fb6}', '\u{345}'), '\u{1fb7}'), (('\u{1fbf}', '\u{300}'), '\u{1fcd}'),
+        (('\u{1fbf}', '\u{301}'), '\u{1fce}'), (('\u{1fbf}', '\u{342}'), '\u{1fcf}'),
+        (('\u{1fc6}', '\u{345}'), '\u{1fc7}'), (('\u{1ff6}', '\u{345}'), '\u{1ff7}'),
+        (('\u{1ffe}', '\u{300}'), '\u{1fdd}'), (('\u{1ffe}', '\u{301}'), '\u{1fde}'),
+        (('\u{1ffe}', '\u{342}'), '\u{1fdf}'), (('\u{2190}', '\u{338}'), '\u{219a}'),
+        (('\u{2192}', '\u{338}'), '\u{219b}'), (('\u{2194}', '\u{338}'), '\u{21ae}'),
+        (('\u{21d0}', '\u{338}'), '\u{21cd}'), (('\u{21d2}', '\u{338}'), '\u{21cf}'),
+        (('\u{21d4}', '\u{338}'), '\u{21ce}'), (('\u{2203}', '\u{338}'), '\u{2204}'),
+        (('\u{2208}', '\u{338}'), '\u{2209}'), (('\u{220b}', '\u{338}'), '\u{220c}'),
+        (('\u{2223}', '\u{338}'), '\u{2224}'), (('\u{2225}', '\u{338}'), '\u{2226}'),
+        (('\u{223c}', '\u{338}'), '\u{2241}'), (('\u{2243}', '\u{338}'), '\u{2244}'),
+        (('\u{2245}', '\u{338}'), '\u{2247}'), (('\u{2248}', '\u{338}'), '\u{2249}'),
+        (('\u{224d}', '\u{338}'), '\u{226d}'), (('\u{2261}', '\u{338}'), '\u{2262}'),
+        (('\u{2264}', '\u{338}'), '\u{2270}'), (('\u{2265}', '\u{338}'), '\u{2271}'),
+        (('\u{2272}', '\u{338}'), '\u{2274}'), (('\u{2273}', '\u{338}'), '\u{2275}'),
+        (('\u{2276}', '\u{338}'), '\u{2278}'), (('\u{2277}', '\u{338}'), '\u{2279}'),
+        (('\u{227a}', '\u{338}'), '\u{2280}'), (('\u{227b}', '\u{338}'), '\u{2281}'),
+        (('\u{227c}', '\u{338}'), '\u{22e0}'), (('\u{227d}', '\u{338}'), '\u{22e1}'),
+        (('\u{2282}', '\u{338}'), '\u{2284}'), (('\u{2283}', '\u{338}'), '\u{2285}'),
+        (('\u{2286}', '\u{338}'), '\u{2288}'), (('\u{2287}', '\u{338}'), '\u{2289}'),
+        (('\u{2291}', '\u{338}'), '\u{22e2}'), (('\u{2292}', '\u{338}'), '\u{22e3}'),
+        (('\u{22a2}', '\u{338}'), '\u{22ac}'), (('\u{22a8}', '\u{338}'), '\u{22ad}'),
+        (('\u{22a9}', '\u{338}'), '\u{22ae}'), (('\u{22ab}', '\u{338}'), '\u{22af}'),
+        (('\u{22b2}', '\u{338}'), '\u{22ea}'), (('\u{22b3}', '\u{338}'), '\u{22eb}'),
+        (('\u{22b4}', '\u{338}'), '\u{22ec}'), (('\u{22b5}', '\u{338}'), '\u{22ed}'),
+        (('\u{3046}', '\u{3099}'), '\u{3094}'), (('\u{304b}', '\u{3099}'), '\u{304c}'),
+        (('\u{304d}', '\u{3099}'), '\u{304e}'), (('\u{304f}', '\u{3099}'), '\u{3050}'),
+        (('\u{3051}', '\u{3099}'), '\u{3052}'), (('\u{3053}', '\u{3099}'), '\u{3054}'),
+        (('\u{3055}', '\u{3099}'), '\u{3056}'), (('\u{3057}', '\u{3099}'), '\u{3058}'),
+        (('\u{3059}', '\u{3099}'), '\u{305a}'), (('\u{305b}', '\u{3099}'), '\u{305c}'),
+        (('\u{305d}', '\u{3099}'), '\u{305e}'), (('\u{305f}', '\u{3099}'), '\u{3060}'),
+        (('\u{3061}', '\u{3099}'), '\u{3062}'), (('\u{3064}', '\u{3099}'), '\u{3065}'),
+        (('\u{3066}', '\u{3099}'), '\u{3067}'), (('\u{3068}', '\u{3099}'), '\u{3069}'),
+        (('\u{306f}', '\u{3099}'), '\u{3070}'), (('\u{306f}', '\u{309a}'), '\u{3071}'),
+        (('\u{3072}', '\u{3099}'), '\u{3073}'), (('\u{3072}', '\u{309a}'), '\u{3074}'),
+        (('\u{3075}', '\u{3099}'), '\u{3076}'), (('\u{3075}', '\u{309a}'), '\u{3077}'),
+        (('\u{3078}', '\u{3099}'), '\u{3079}'), (('\u{3078}', '\u{309a}'), '\u{307a}'),
+        (('\u{307b}', '\u{3099}'), '\u{307c}'), (('\u{307b}', '\u{309a}'), '\u{307d}'),
+        (('\u{309d}', '\u{3099}'), '\u{309e}'), (('\u{30a6}', '\u{3099}'), '\u{30f4}'),
+        (('\u{30ab}', '\u{3099}'), '\u{30ac}'), (('\u{30ad}', '\u{3099}'), '\u{30ae}'),
+        (('\u{30af}', '\u{3099}'), '\u{30b0}'), (('\u{30b1}', '\u{3099}'), '\u{30b2}'),
+        (('\u{30b3}', '\u{3099}'), '\u{30b4}'), (('\u{30b5}', '\u{3099}'), '\u{30b6}'),
+        (('\u{30b7}', '\u{3099}'), '\u{30b8}'), (('\u{30b9}', '\u{3099}'), '\u{30ba}'),
+        (('\u{30bb}', '\u{3099}'), '\u{30bc}'), (('\u{30bd}', '\u{3099}'), '\u{30be}'),
+        (('\u{30bf}', '\u{3099}'), '\u{30c0}'), (('\u{30c1}', '\u{3099}'), '\u{30c2}'),
+        (('\u{30c4}', '\u{3099}'), '\u{30c5}'), (('\u{30c6}', '\u{3099}'), '\u{30c7}'),
+        (('\u{30c8}', '\u{3099}'), '\u{30c9}'), (('\u{30cf}', '\u{3099}'), '\u{30d0}'),
+        (('\u{30cf}', '\u{309a}'), '\u{30d1}'), (('\u{30d2}', '\u{3099}'), '\u{30d3}'),
+        (('\u{30d2}', '\u{309a}'), '\u{30d4}'), (('\u{30d5}', '\u{3099}'), '\u{30d6}'),
+        (('\u{30d5}', '\u{309a}'), '\u{30d7}'), (('\u{30d8}', '\u{3099}'), '\u{30d9}'),
+        (('\u{30d8}', '\u{309a}'), '\u{30da}'), (('\u{30db}', '\u{3099}'), '\u{30dc}'),
+        (('\u{30db}', '\u{309a}'), '\u{30dd}'), (('\u{30ef}', '\u{3099}'), '\u{30f7}'),
+        (('\u{30f0}', '\u{3099}'), '\u{30f8}'), (('\u{30f1}', '\u{3099}'), '\u{30f9}'),
+        (('\u{30f2}', '\u{3099}'), '\u{30fa}'), (('\u{30fd}', '\u{3099}'), '\u{30fe}'),
+        (('\u{11099}', '\u{110ba}'), '\u{1109a}'), (('\u{1109b}', '\u{110ba}'), '\u{1109c}'),
+        (('\u{110a5}', '\u{110ba}'), '\u{110ab}'), (('\u{11131}', '\u{11127}'), '\u{1112e}'),
+        (('\u{11132}', '\u{11127}'), '\u{1112f}'), (('\u{11347}', '\u{1133e}'), '\u{1134b}'),
+        (('\u{11347}', '\u{11357}'), '\u{1134c}'), (('\u{114b9}', '\u{114b0}'), '\u{114bc}'),
+        (('\u{114b9}', '\u{114ba}'), '\u{114bb}'), (('\u{114b9}', '\u{114bd}'), '\u{114be}'),
+        (('\u{115b8}', '\u{115af}'), '\u{115ba}'), (('\u{115b9}', '\u{115af}'), '\u{115bb}'),
+        (('\u{11935}', '\u{11930}'), '\u{11938}'),
+    ];
+
+    /// Returns the canonical combining class of `c`.
+    #[inline]
+    pub fn canonical_combining_class(c: char) -> u8 {
+        if (c as u32) < 0x300 {
+            0
+        } else {
+            super::run_search(c as u32, &COMBINING_CLASS_RUNS)
+        }
+    }
+
+    /// Returns the full canonical decomposition of `c`, or `None` if it does not
+    /// decompose. Hangul syllables are not included, since they decompose
+    /// algorithmically.
+    pub fn canonical_decomposition(c: char) -> Option<&'static [char]> {
+        let i = DECOMPOSITION_TABLE.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+        let packed = DECOMPOSITION_TABLE[i].1;
+        let (start, len) = ((packed >> 8) as usize, (packed & 0xff) as usize);
+        // SAFETY: Indices come from statically generated table
+        Some(unsafe { DECOMPOSITION_CHARS.get_unchecked(start..start + len) })
+    }
+
+    /// Returns the primary composite of `a` followed by `b`, if there is one.
+    /// Hangul syllables are not included, since they compose algorithmically.
+    pub fn compose(a: char, b: char) -> Option<char> {
+        let i = COMPOSITION_TABLE.binary_search_by(|&(pair, _)| pair.cmp(&(a, b))).ok()?;
+        Some(COMPOSITION_TABLE[i].1)
+    }
+}
@@ -14,3 +14,77 @@ fn into_async_iter() {
     assert_eq!(async_iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
     assert_eq!(async_iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
 }
+
+#[test]
+fn adapters() {
+    let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+
+    let iter = async_iter::from_iter(0..10).filter(|x| x % 3 != 0).map(|x| x * 10).take(4);
+    assert_eq!(iter.size_hint(), (0, Some(4)));
+    let mut iter = pin!(iter);
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(10)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(20)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(40)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(50)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    assert_eq!(async_iter::from_iter(0..3).take(10).size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn next_and_collect() {
+    use core::future::Future;
+
+    let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+
+    let mut iter = async_iter::from_iter([1, 2]);
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+
+    let collect = async_iter::from_iter(1..=4).map(|x| x * x).collect::<Vec<_>>();
+    assert_eq!(pin!(collect).poll(&mut cx), Poll::Ready(vec![1, 4, 9, 16]));
+}
+
+#[test]
+fn collect_pending() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::Context;
+
+    // Yields `0..3`, returning `Pending` before each value.
+    struct Slow {
+        next: u32,
+        ready: bool,
+    }
+
+    impl AsyncIterator for Slow {
+        type Item = u32;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+            if !self.ready {
+                self.ready = true;
+                return Poll::Pending;
+            }
+            self.ready = false;
+            if self.next == 3 {
+                return Poll::Ready(None);
+            }
+            self.next += 1;
+            Poll::Ready(Some(self.next - 1))
+        }
+    }
+
+    let mut cx = Context::from_waker(core::task::Waker::noop());
+    let mut collect = pin!(Slow { next: 0, ready: false }.collect::<Vec<_>>());
+    let mut polls = 1;
+    let v = loop {
+        match collect.as_mut().poll(&mut cx) {
+            Poll::Ready(v) => break v,
+            Poll::Pending => polls += 1,
+        }
+    };
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(polls, 5);
+}
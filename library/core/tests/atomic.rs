@@ -270,9 +270,15 @@ fn atomic_alignment() {
     assert_eq!(align_of::<AtomicU64>(), size_of::<AtomicU64>());
     #[cfg(target_has_atomic = "64")]
     assert_eq!(align_of::<AtomicI64>(), size_of::<AtomicI64>());
-    #[cfg(target_has_atomic = "128")]
+    #[cfg(any(
+        target_has_atomic = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    ))]
     assert_eq!(align_of::<AtomicU128>(), size_of::<AtomicU128>());
-    #[cfg(target_has_atomic = "128")]
+    #[cfg(any(
+        target_has_atomic = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    ))]
     assert_eq!(align_of::<AtomicI128>(), size_of::<AtomicI128>());
     #[cfg(target_has_atomic = "ptr")]
     assert_eq!(align_of::<AtomicUsize>(), size_of::<AtomicUsize>());
@@ -306,6 +312,61 @@ fn atomic_compare_exchange() {
     ATOMIC.compare_exchange_weak(0, 1, SeqCst, SeqCst).ok();
 }
 
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn float_fetch_ops() {
+    let a = AtomicF64::new(1.5);
+    assert_eq!(a.fetch_add(2.0, SeqCst), 1.5);
+    assert_eq!(a.fetch_sub(0.5, SeqCst), 3.5);
+    assert_eq!(a.fetch_max(f64::NAN, SeqCst), 3.0);
+    assert_eq!(a.fetch_min(-1.0, SeqCst), 3.0);
+    assert_eq!(a.swap(f64::INFINITY, SeqCst), -1.0);
+    assert_eq!(a.into_inner(), f64::INFINITY);
+
+    let b = AtomicF32::new(0.1);
+    b.fetch_add(0.2, SeqCst);
+    assert_eq!(b.load(SeqCst), 0.1f32 + 0.2f32);
+    assert_eq!(b.fetch_update(SeqCst, SeqCst, |_| None), Err(0.1f32 + 0.2f32));
+}
+
+#[test]
+#[cfg(target_has_atomic = "32")]
+fn float_compare_exchange_is_bitwise() {
+    let mut a = AtomicF32::new(-0.0);
+    assert!(a.compare_exchange(0.0, 1.0, SeqCst, SeqCst).is_err());
+    assert_eq!(a.compare_exchange(-0.0, 1.0, SeqCst, SeqCst), Ok(-0.0));
+
+    let nan = f32::from_bits(0x7fc0_0001);
+    a.store(nan, SeqCst);
+    assert_eq!(a.compare_exchange(nan, 2.0, SeqCst, SeqCst).map(f32::to_bits), Ok(nan.to_bits()));
+    assert_eq!(*a.get_mut(), 2.0);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn generic_atomic_alias() {
+    let a: Atomic<u64> = Atomic::<u64>::new(5);
+    assert_eq!(a.fetch_add(1, SeqCst), 5);
+    let _: &AtomicF64 = &Atomic::<f64>::default();
+    let _: &AtomicPtr<u8> = &Atomic::<*mut u8>::default();
+    let _: &AtomicBool = &Atomic::<bool>::new(true);
+}
+
+#[test]
+#[cfg(any(target_has_atomic = "128", all(target_arch = "x86_64", target_feature = "cmpxchg16b")))]
+fn atomic_128() {
+    let tagged = (7u128 << 64) | 0xdead_beef;
+    let a: Atomic<u128> = AtomicU128::new(tagged);
+    assert_eq!(a.compare_exchange(tagged, tagged + (1 << 64), SeqCst, SeqCst), Ok(tagged));
+    assert_eq!(a.fetch_add(u64::MAX as u128 + 1, SeqCst) >> 64, 8);
+    assert_eq!(a.fetch_max(u128::MAX, SeqCst), (9 << 64) | 0xdead_beef);
+    assert_eq!(a.into_inner(), u128::MAX);
+
+    let b = AtomicI128::new(i128::MIN);
+    assert_eq!(b.fetch_sub(1, SeqCst), i128::MIN);
+    assert_eq!(b.load(SeqCst), i128::MAX);
+}
+
 /* FIXME(#110395)
 #[test]
 fn atomic_const_from() {
@@ -43,3 +43,66 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 
     assert_eq!(format!("{Bar:<03}"), "1  0051  ");
 }
+
+// CI also runs these with `optimize_for_size`, under which `pad`,
+// `pad_integral`, integer `Display` and the `Debug` helpers used by derives
+// have separate, compact implementations.
+#[test]
+fn test_pad_width_precision() {
+    assert_eq!(
+        format!("[{:.3}] [{:8.2}] [{:^9.4}]", "héllo", "héllo", "héllo"),
+        "[hél] [hé      ] [  héll   ]"
+    );
+    assert_eq!(
+        format!("[{:_>7}] [{:.0}] [{:3}] [{:<4}] [{:^5}]", "ab", "xyz", "long", 'c', true),
+        "[_____ab] [] [long] [c   ] [true ]"
+    );
+    assert_eq!(
+        format!("[{:^6}] [{:>+6}] [{:+06}] [{:*^9}] [{:#010x}]", 3u8, 4i16, 6i64, -7isize, 255),
+        "[  3   ] [    +4] [+00006] [***-7****] [0x000000ff]"
+    );
+    assert_eq!(
+        format!("[{:<#6x}] [{:^#7b}] [{:#2o}]", 31u16, 5usize, 8),
+        "[0x1f  ] [ 0b101 ] [0o10]"
+    );
+    assert_eq!(
+        format!("[{:45}]", 1u128 << 100),
+        format!("[{:>45}]", "1267650600228229401496703205376")
+    );
+    assert_eq!(format!("[{:040}]", i128::MIN), "[-170141183460469231731687303715884105728]");
+    assert_eq!(format!("[{:042}]", i128::MIN), "[-00170141183460469231731687303715884105728]");
+}
+
+#[test]
+fn test_derived_debug() {
+    #[derive(Debug)]
+    struct Unit;
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        label: &'static str,
+    }
+    #[derive(Debug)]
+    struct Pair(u16, Option<i64>);
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Wide {
+        Tuple(u8, u8, u8, u8, u8),
+        Struct { a: u8, b: u8, c: u8, d: u8, e: u8 },
+    }
+
+    assert_eq!(format!("{:?}", Unit), "Unit");
+    assert_eq!(format!("{:?}", Point { x: -3, label: "é" }), r#"Point { x: -3, label: "é" }"#);
+    assert_eq!(
+        format!("{:#?}", Point { x: -3, label: "é" }),
+        "Point {\n    x: -3,\n    label: \"é\",\n}"
+    );
+    assert_eq!(format!("{:?}", Pair(7, Some(-9))), "Pair(7, Some(-9))");
+    assert_eq!(format!("{:#?}", Pair(7, None)), "Pair(\n    7,\n    None,\n)");
+    assert_eq!(format!("{:03?}", Pair(7, Some(9))), "Pair(007, Some(009))");
+    assert_eq!(format!("{:?}", Wide::Tuple(1, 2, 3, 4, 5)), "Tuple(1, 2, 3, 4, 5)");
+    assert_eq!(
+        format!("{:?}", Wide::Struct { a: 1, b: 2, c: 3, d: 4, e: 5 }),
+        "Struct { a: 1, b: 2, c: 3, d: 4, e: 5 }"
+    );
+}
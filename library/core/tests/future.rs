@@ -98,6 +98,39 @@ fn join_is_able_to_handle_temporaries() {
     }
 }
 
+#[test]
+fn test_block_on_with_parker() {
+    use std::future::Park;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::task::StaticWake;
+
+    struct CountingParker {
+        token: AtomicBool,
+        parks: AtomicUsize,
+    }
+
+    impl StaticWake for CountingParker {
+        fn wake(&'static self) {
+            self.token.store(true, Ordering::Release);
+        }
+    }
+
+    impl Park for CountingParker {
+        fn park(&'static self) {
+            assert!(self.token.swap(false, Ordering::Acquire), "parked without a wake-up");
+            self.parks.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    static PARKER: CountingParker =
+        CountingParker { token: AtomicBool::new(false), parks: AtomicUsize::new(0) };
+
+    assert_eq!(std::future::block_on(poll_n(7, 4), &PARKER), 7);
+    assert_eq!(PARKER.parks.load(Ordering::Relaxed), 3);
+    assert_eq!(std::future::block_on(async { 1 }, &PARKER), 1);
+    assert_eq!(PARKER.parks.load(Ordering::Relaxed), 3);
+}
+
 fn block_on(fut: impl Future) {
     struct Waker;
     impl Wake for Waker {
@@ -173,6 +173,34 @@ fn test_build_hasher_object_safe() {
     let _: &dyn BuildHasher<Hasher = DefaultHasher> = &RandomState::new();
 }
 
+#[test]
+fn test_fast_hasher() {
+    use std::hash::{FastHasher, FixedState};
+
+    let hash = |state: &FixedState, value: &dyn Fn(&mut FastHasher)| {
+        let mut hasher = state.build_hasher();
+        value(&mut hasher);
+        hasher.finish()
+    };
+    let (a, b) = (FixedState::new(), FixedState::with_seed(1));
+
+    assert_eq!(a.hash_one(5u32), FixedState::default().hash_one(5u32));
+    assert_ne!(a.hash_one(5u32), b.hash_one(5u32));
+    assert_ne!(a.hash_one(5u32), a.hash_one(6u32));
+    assert_ne!(a.hash_one("ab"), a.hash_one("ba"));
+    assert_ne!(a.hash_one(1u128), a.hash_one(1u128 << 64));
+
+    // Every byte of the input is taken into account, whatever its position.
+    let bytes: Vec<u8> = (1..=15).collect();
+    for len in 0..bytes.len() {
+        let full = hash(&a, &|h| h.write(&bytes[..len + 1]));
+        let mut changed = bytes[..len + 1].to_vec();
+        changed[len] ^= 0x80;
+        assert_ne!(full, hash(&a, &|h| h.write(&changed)));
+        assert_ne!(full, hash(&a, &|h| h.write(&bytes[..len])));
+    }
+}
+
 // just tests by whether or not this compiles
 fn _build_hasher_default_impl_all_auto_traits<T>() {
     use std::panic::{RefUnwindSafe, UnwindSafe};
@@ -0,0 +1,44 @@
+use core::io::{AsyncRead, AsyncWrite, BorrowedBuf};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+#[test]
+fn read_slice() {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut reader: &[u8] = &[1, 2, 3, 4, 5];
+    let buf: &mut [_] = &mut [0; 4];
+    let mut rbuf: BorrowedBuf<'_> = buf.into();
+
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, rbuf.unfilled()), Poll::Ready(Ok(())));
+    assert_eq!(rbuf.filled(), [1, 2, 3, 4]);
+    assert_eq!(reader, [5]);
+
+    // A full buffer reads nothing.
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, rbuf.unfilled()), Poll::Ready(Ok(())));
+    assert_eq!(reader, [5]);
+
+    rbuf.clear();
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, rbuf.unfilled()), Poll::Ready(Ok(())));
+    assert_eq!(rbuf.filled(), [5]);
+    assert!(reader.is_empty());
+
+    // EOF appends nothing.
+    rbuf.clear();
+    let mut reader = &mut reader;
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, rbuf.unfilled()), Poll::Ready(Ok(())));
+    assert_eq!(rbuf.len(), 0);
+}
+
+#[test]
+fn write_slice() {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut storage = [0; 5];
+    let mut writer: &mut [u8] = &mut storage;
+
+    assert_eq!(Pin::new(&mut writer).poll_write(&mut cx, &[1, 2, 3]), Poll::Ready(Ok(3)));
+    assert_eq!(Pin::new(&mut writer).poll_write(&mut cx, &[4, 5, 6]), Poll::Ready(Ok(2)));
+    assert_eq!(Pin::new(&mut writer).poll_write(&mut cx, &[7]), Poll::Ready(Ok(0)));
+    assert_eq!(Pin::new(&mut writer).poll_flush(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(Pin::new(&mut writer).poll_close(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(storage, [1, 2, 3, 4, 5]);
+}
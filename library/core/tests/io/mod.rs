@@ -1 +1,2 @@
+mod async_rw;
 mod borrowed_buf;
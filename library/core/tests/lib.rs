@@ -4,8 +4,10 @@
 #![feature(array_windows)]
 #![feature(ascii_char)]
 #![feature(ascii_char_variants)]
+#![feature(async_iter_adapters)]
 #![feature(async_iter_from_iter)]
 #![feature(async_iterator)]
+#![feature(bigint)]
 #![feature(bigint_helper_methods)]
 #![feature(cell_update)]
 #![feature(clone_to_uninit)]
@@ -14,6 +16,7 @@
 #![feature(const_black_box)]
 #![feature(const_cell_into_inner)]
 #![feature(const_hash)]
+#![feature(fast_hash)]
 #![feature(const_heap)]
 #![feature(const_intrinsic_copy)]
 #![feature(const_int_from_str)]
@@ -26,6 +29,7 @@
 #![feature(const_trait_impl)]
 #![feature(const_likely)]
 #![feature(core_intrinsics)]
+#![feature(core_io_async)]
 #![feature(core_io_borrowed_buf)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
@@ -40,6 +44,7 @@
 #![feature(fmt_internals)]
 #![feature(float_minimum_maximum)]
 #![feature(future_join)]
+#![feature(block_on)]
 #![feature(generic_assert_internals)]
 #![feature(array_try_from_fn)]
 #![feature(hasher_prefixfree_extras)]
@@ -57,6 +62,7 @@
 #![feature(maybe_uninit_uninit_array_transpose)]
 #![feature(min_specialization)]
 #![feature(noop_waker)]
+#![feature(static_waker)]
 #![feature(numfmt)]
 #![feature(num_midpoint)]
 #![feature(offset_of_nested)]
@@ -71,6 +77,7 @@
 #![feature(slice_internals)]
 #![feature(slice_partition_dedup)]
 #![feature(ip)]
+#![feature(ip_prefix)]
 #![feature(iter_advance_by)]
 #![feature(iter_array_chunks)]
 #![feature(iter_chain)]
@@ -98,7 +105,15 @@
 #![feature(const_option)]
 #![feature(const_option_ext)]
 #![feature(const_result)]
-#![cfg_attr(target_has_atomic = "128", feature(integer_atomics))]
+#![cfg_attr(
+    any(
+        target_has_atomic = "128",
+        all(target_arch = "x86_64", target_feature = "cmpxchg16b"),
+    ),
+    feature(integer_atomics)
+)]
+#![feature(atomic_float)]
+#![feature(generic_atomic)]
 #![cfg_attr(test, feature(cfg_match))]
 #![feature(int_roundings)]
 #![feature(split_array)]
@@ -106,6 +121,7 @@
 #![feature(strict_provenance_atomic_ptr)]
 #![feature(trusted_random_access)]
 #![feature(unsize)]
+#![feature(volatile_ptr)]
 #![feature(const_array_from_ref)]
 #![feature(const_slice_from_ref)]
 #![feature(waker_getters)]
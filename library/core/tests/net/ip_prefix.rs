@@ -0,0 +1,94 @@
+use core::net::{IpAddr, IpPrefix, Ipv4Addr, Ipv4Prefix, Ipv6Addr, Ipv6Prefix};
+
+#[test]
+fn parse_and_display() {
+    let prefix: Ipv4Prefix = "10.1.2.3/8".parse().unwrap();
+    assert_eq!(prefix.addr(), Ipv4Addr::new(10, 1, 2, 3));
+    assert_eq!(prefix.prefix_len(), 8);
+    assert_eq!(prefix.to_string(), "10.1.2.3/8");
+    assert_eq!(format!("{:>14}", prefix.network()), "    10.0.0.0/8");
+
+    let prefix: Ipv6Prefix = "2001:db8::1/128".parse().unwrap();
+    assert_eq!(prefix.addr(), Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(format!("{prefix:?}"), "2001:db8::1/128");
+
+    let prefix: IpPrefix = "::/0".parse().unwrap();
+    assert_eq!(prefix, IpPrefix::V6(Ipv6Prefix::new(Ipv6Addr::UNSPECIFIED, 0).unwrap()));
+
+    let invalid = ["10.0.0.0", "10.0.0.0/", "10.0.0.0/33", "10.0.0.0/08", "/8", "::/129", "::1/-1"];
+    for invalid in invalid {
+        assert!(invalid.parse::<IpPrefix>().is_err(), "{invalid}");
+    }
+    assert!("::/64".parse::<Ipv4Prefix>().is_err());
+    assert!("10.0.0.0/8".parse::<Ipv6Prefix>().is_err());
+}
+
+#[test]
+fn masks() {
+    let prefix: Ipv4Prefix = "172.16.5.4/12".parse().unwrap();
+    assert_eq!(prefix.netmask(), Ipv4Addr::new(255, 240, 0, 0));
+    assert_eq!(prefix.hostmask(), Ipv4Addr::new(0, 15, 255, 255));
+    assert_eq!(prefix.network().addr(), Ipv4Addr::new(172, 16, 0, 0));
+    assert_eq!(prefix.broadcast(), Ipv4Addr::new(172, 31, 255, 255));
+
+    let all: Ipv4Prefix = "1.2.3.4/0".parse().unwrap();
+    assert_eq!(all.netmask(), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(all.broadcast(), Ipv4Addr::BROADCAST);
+
+    let host: Ipv6Prefix = "::1/128".parse().unwrap();
+    assert_eq!(host.hostmask(), Ipv6Addr::UNSPECIFIED);
+    assert_eq!(host.network(), host);
+}
+
+#[test]
+fn contains() {
+    let prefix: IpPrefix = "192.168.0.0/16".parse().unwrap();
+    assert!(prefix.contains(IpAddr::V4(Ipv4Addr::new(192, 168, 255, 1))));
+    assert!(!prefix.contains(IpAddr::V4(Ipv4Addr::new(192, 169, 0, 0))));
+    assert!(!prefix.contains(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+
+    let prefix: Ipv6Prefix = "fe80::/10".parse().unwrap();
+    assert!(prefix.contains("febf::1".parse().unwrap()));
+    assert!(!prefix.contains("fec0::1".parse().unwrap()));
+    assert!(prefix.contains_prefix(&"fe80::/64".parse().unwrap()));
+    assert!(!prefix.contains_prefix(&"fe80::/9".parse().unwrap()));
+}
+
+#[test]
+fn hosts() {
+    let prefix: Ipv4Prefix = "10.0.0.0/30".parse().unwrap();
+    let hosts: Vec<_> = prefix.hosts().collect();
+    assert_eq!(hosts, [Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2)]);
+
+    let prefix: Ipv4Prefix = "10.0.0.0/31".parse().unwrap();
+    assert_eq!(prefix.hosts().count(), 2);
+    let prefix: Ipv4Prefix = "10.0.0.7/32".parse().unwrap();
+    assert_eq!(prefix.hosts().collect::<Vec<_>>(), [Ipv4Addr::new(10, 0, 0, 7)]);
+
+    let prefix: Ipv6Prefix = "2001:db8::/126".parse().unwrap();
+    assert_eq!(prefix.hosts().count(), 4);
+    assert_eq!(prefix.hosts().next_back(), Some("2001:db8::3".parse().unwrap()));
+}
+
+#[test]
+fn supernet_and_subnets() {
+    let prefix: Ipv4Prefix = "10.1.2.3/24".parse().unwrap();
+    assert_eq!(prefix.supernet(), Some("10.1.2.0/23".parse().unwrap()));
+    assert_eq!("0.0.0.0/0".parse::<Ipv4Prefix>().unwrap().supernet(), None);
+
+    let subnets: Vec<_> = prefix.subnets(26).unwrap().map(|p| p.to_string()).collect();
+    assert_eq!(subnets, ["10.1.2.0/26", "10.1.2.64/26", "10.1.2.128/26", "10.1.2.192/26"]);
+    assert_eq!(prefix.subnets(24).unwrap().collect::<Vec<_>>(), [prefix.network()]);
+    assert!(prefix.subnets(23).is_none());
+    assert!(prefix.subnets(33).is_none());
+
+    let all: Ipv6Prefix = "::/0".parse().unwrap();
+    let mut subnets = all.subnets(1).unwrap();
+    assert_eq!(subnets.next(), Some("::/1".parse().unwrap()));
+    assert_eq!(subnets.next(), Some("8000::/1".parse().unwrap()));
+    assert_eq!(subnets.next(), None);
+    assert_eq!(all.subnets(0).unwrap().count(), 1);
+
+    let last: Ipv4Prefix = "255.255.255.254/31".parse().unwrap();
+    assert_eq!(last.subnets(32).unwrap().count(), 2);
+}
@@ -1,6 +1,7 @@
 use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
 mod ip_addr;
+mod ip_prefix;
 mod parser;
 mod socket_addr;
 
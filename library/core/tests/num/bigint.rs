@@ -0,0 +1,309 @@
+use core::num::{Int, IntErrorKind, Uint, I256, U256, U512};
+
+// `Uint<2>` and `Int<2>` have the same width as `u128` and `i128`, which they are compared to.
+
+fn u(n: u128) -> Uint<2> {
+    Uint::from_limbs([n as u64, (n >> 64) as u64])
+}
+
+fn from_u(n: Uint<2>) -> u128 {
+    let [low, high] = n.to_limbs();
+    (high as u128) << 64 | low as u128
+}
+
+fn i(n: i128) -> Int<2> {
+    Int::from_limbs([n as u64, (n >> 64) as u64])
+}
+
+fn from_i(n: Int<2>) -> i128 {
+    let [low, high] = n.to_limbs();
+    ((high as u128) << 64 | low as u128) as i128
+}
+
+const UNSIGNED: &[u128] = &[
+    0,
+    1,
+    2,
+    3,
+    10,
+    u64::MAX as u128 - 1,
+    u64::MAX as u128,
+    u64::MAX as u128 + 1,
+    1 << 100,
+    0x1234_5678_9abc_def0_1122_3344_5566_7788,
+    0x8000_0000_0000_0000_0000_0000_0000_0001,
+    u128::MAX / 3,
+    u128::MAX - 1,
+    u128::MAX,
+];
+
+const SIGNED: &[i128] = &[
+    0,
+    1,
+    -1,
+    2,
+    -7,
+    i64::MIN as i128,
+    i64::MAX as i128 + 1,
+    -(1 << 100),
+    0x1234_5678_9abc_def0_1122_3344_5566_7788,
+    i128::MIN / 3,
+    i128::MIN + 1,
+    i128::MIN,
+    i128::MAX - 1,
+    i128::MAX,
+];
+
+const SHIFTS: &[u32] = &[0, 1, 7, 63, 64, 65, 127, 128, 129, 200];
+
+#[test]
+fn test_uint_matches_u128() {
+    for &a in UNSIGNED {
+        assert_eq!(from_u(u(a)), a);
+        assert_eq!(u(a).count_ones(), a.count_ones());
+        assert_eq!(u(a).leading_zeros(), a.leading_zeros());
+        assert_eq!(u(a).trailing_zeros(), a.trailing_zeros());
+        assert_eq!(u(a).checked_ilog2(), a.checked_ilog2());
+        assert_eq!(u(a).is_power_of_two(), a.is_power_of_two());
+        assert_eq!(u(a).to_string(), a.to_string());
+        assert_eq!(format!("{:x}", u(a)), format!("{a:x}"));
+        assert_eq!(format!("{:#X}", u(a)), format!("{a:#X}"));
+        assert_eq!(from_u(u(a).wrapping_neg()), a.wrapping_neg());
+        assert_eq!(Uint::<2>::from_str_radix(&a.to_string(), 10), Ok(u(a)));
+        assert_eq!(Uint::<2>::from_str_radix(&format!("{a:o}"), 8), Ok(u(a)));
+
+        for &s in SHIFTS {
+            assert_eq!(u(a).checked_shl(s).map(from_u), a.checked_shl(s));
+            assert_eq!(u(a).checked_shr(s).map(from_u), a.checked_shr(s));
+            assert_eq!(from_u(u(a).wrapping_shl(s)), a.wrapping_shl(s));
+            assert_eq!(from_u(u(a).wrapping_shr(s)), a.wrapping_shr(s));
+            assert_eq!(from_u(u(a).rotate_left(s)), a.rotate_left(s));
+            assert_eq!(from_u(u(a).rotate_right(s)), a.rotate_right(s));
+        }
+        for exp in [0, 1, 2, 3, 7, 64, 127, 128] {
+            let (n, o) = u(a).overflowing_pow(exp);
+            assert_eq!((from_u(n), o), a.overflowing_pow(exp));
+        }
+
+        for &b in UNSIGNED {
+            let (n, o) = u(a).overflowing_add(u(b));
+            assert_eq!((from_u(n), o), a.overflowing_add(b));
+            let (n, o) = u(a).overflowing_sub(u(b));
+            assert_eq!((from_u(n), o), a.overflowing_sub(b));
+            let (n, o) = u(a).overflowing_mul(u(b));
+            assert_eq!((from_u(n), o), a.overflowing_mul(b));
+            assert_eq!(u(a).checked_div(u(b)).map(from_u), a.checked_div(b));
+            assert_eq!(u(a).checked_rem(u(b)).map(from_u), a.checked_rem(b));
+            assert_eq!(from_u(u(a).saturating_add(u(b))), a.saturating_add(b));
+            assert_eq!(from_u(u(a).saturating_sub(u(b))), a.saturating_sub(b));
+            assert_eq!(from_u(u(a).saturating_mul(u(b))), a.saturating_mul(b));
+            assert_eq!(from_u(u(a).abs_diff(u(b))), a.abs_diff(b));
+            assert_eq!(from_u(u(a) & u(b)), a & b);
+            assert_eq!(from_u(u(a) | u(b)), a | b);
+            assert_eq!(from_u(u(a) ^ u(b)), a ^ b);
+            assert_eq!(u(a).cmp(&u(b)), a.cmp(&b));
+        }
+    }
+}
+
+#[test]
+fn test_int_matches_i128() {
+    for &a in SIGNED {
+        assert_eq!(from_i(i(a)), a);
+        assert_eq!(i(a).is_negative(), a.is_negative());
+        assert_eq!(i(a).is_positive(), a.is_positive());
+        assert_eq!(from_u(i(a).unsigned_abs()), a.unsigned_abs());
+        assert_eq!(i(a).checked_abs().map(from_i), a.checked_abs());
+        assert_eq!(i(a).checked_neg().map(from_i), a.checked_neg());
+        assert_eq!(i(a).to_string(), a.to_string());
+        assert_eq!(format!("{:+}", i(a)), format!("{a:+}"));
+        assert_eq!(format!("{:>50}", i(a)), format!("{a:>50}"));
+        assert_eq!(format!("{:x}", i(a)), format!("{a:x}"));
+        assert_eq!(Int::<2>::from_str_radix(&a.to_string(), 10), Ok(i(a)));
+        assert_eq!(Int::<2>::from_str_radix(&format!("{a:+}"), 10), Ok(i(a)));
+
+        for &s in SHIFTS {
+            assert_eq!(i(a).checked_shl(s).map(from_i), a.checked_shl(s));
+            assert_eq!(i(a).checked_shr(s).map(from_i), a.checked_shr(s));
+            assert_eq!(from_i(i(a).wrapping_shr(s)), a.wrapping_shr(s));
+        }
+        for exp in [0, 1, 2, 3, 7, 64, 127, 128] {
+            let (n, o) = i(a).overflowing_pow(exp);
+            assert_eq!((from_i(n), o), a.overflowing_pow(exp));
+            assert_eq!(from_i(i(a).saturating_pow(exp)), a.saturating_pow(exp));
+        }
+
+        for &b in SIGNED {
+            let (n, o) = i(a).overflowing_add(i(b));
+            assert_eq!((from_i(n), o), a.overflowing_add(b));
+            let (n, o) = i(a).overflowing_sub(i(b));
+            assert_eq!((from_i(n), o), a.overflowing_sub(b));
+            let (n, o) = i(a).overflowing_mul(i(b));
+            assert_eq!((from_i(n), o), a.overflowing_mul(b));
+            assert_eq!(i(a).checked_div(i(b)).map(from_i), a.checked_div(b));
+            assert_eq!(i(a).checked_rem(i(b)).map(from_i), a.checked_rem(b));
+            if b != 0 {
+                let (n, o) = i(a).overflowing_div(i(b));
+                assert_eq!((from_i(n), o), a.overflowing_div(b));
+                let (n, o) = i(a).overflowing_rem(i(b));
+                assert_eq!((from_i(n), o), a.overflowing_rem(b));
+            }
+            assert_eq!(from_i(i(a).saturating_add(i(b))), a.saturating_add(b));
+            assert_eq!(from_i(i(a).saturating_sub(i(b))), a.saturating_sub(b));
+            assert_eq!(from_i(i(a).saturating_mul(i(b))), a.saturating_mul(b));
+            assert_eq!(from_u(i(a).abs_diff(i(b))), a.abs_diff(b));
+            assert_eq!(i(a).cmp(&i(b)), a.cmp(&b));
+        }
+    }
+}
+
+#[test]
+fn test_division_identity() {
+    // A xorshift generator, to get limbs with all kinds of bit patterns.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    // Limbs that make the quotient estimates in long division wrong.
+    let special = [0, 1, u64::MAX, 1 << 63, (1 << 63) - 1];
+
+    for round in 0..500 {
+        let mut random = |limbs: usize| {
+            let mut n = [0; 8];
+            for limb in &mut n[..limbs] {
+                let x = next();
+                *limb = if round % 3 == 0 { special[x as usize % special.len()] } else { x };
+            }
+            U512::from_limbs(n)
+        };
+        let a = random(1 + round % 4);
+        let b = random(1 + round / 4 % 4);
+        if b == U512::MIN {
+            continue;
+        }
+        let r = random(4) % b;
+        let n = a * b + r;
+        assert_eq!(n / b, a, "{n} / {b}");
+        assert_eq!(n % b, r, "{n} % {b}");
+    }
+}
+
+#[test]
+fn test_widening_mul() {
+    let (low, high) = U256::MAX.widening_mul(U256::MAX);
+    assert_eq!(low, U256::from(1u8));
+    assert_eq!(high, U256::MAX - U256::from(1u8));
+
+    let x = U256::from(3u8).pow(80);
+    let (low, high) = x.widening_mul(x);
+    assert_eq!(high, U256::MIN);
+    assert_eq!(low, U256::from(3u8).pow(160));
+
+    assert_eq!(U256::MAX.carrying_add(U256::MIN, true), (U256::MIN, true));
+    assert_eq!(U256::MIN.borrowing_sub(U256::MIN, true), (U256::MAX, true));
+}
+
+#[test]
+fn test_fmt() {
+    assert_eq!(
+        U256::MAX.to_string(),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+    assert_eq!(
+        I256::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(format!("{:x}", U256::MAX), "f".repeat(64));
+    assert_eq!(format!("{:X}", I256::from(-1i8)), "F".repeat(64));
+    assert_eq!(format!("{:#06x}", U256::from(0xabu8)), "0x00ab");
+    assert_eq!(format!("{:?}", U256::from(10u8).pow(40)), format!("1{}", "0".repeat(40)));
+    assert_eq!(format!("{:05}", I256::from(-42i8)), "-0042");
+    assert_eq!(U256::MIN.to_string(), "0");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("0".parse::<U256>(), Ok(U256::MIN));
+    assert_eq!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            .parse::<U256>(),
+        Ok(U256::MAX)
+    );
+    assert_eq!(
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+            .parse::<I256>(),
+        Ok(I256::MIN)
+    );
+    assert_eq!(U256::from_str_radix("zz", 36), Ok(U256::from(1295u16)));
+
+    let kind = |s: &str| s.parse::<U256>().unwrap_err().kind().clone();
+    assert_eq!(kind(""), IntErrorKind::Empty);
+    assert_eq!(kind("+"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("-1"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("12a"), IntErrorKind::InvalidDigit);
+    assert_eq!(
+        kind("115792089237316195423570985008687907853269984665640564039457584007913129639936"),
+        IntErrorKind::PosOverflow
+    );
+
+    let kind = |s: &str| s.parse::<I256>().unwrap_err().kind().clone();
+    assert_eq!(kind("-"), IntErrorKind::InvalidDigit);
+    assert_eq!(
+        kind("57896044618658097711785492504343953926634992332820282019728792003956564819968"),
+        IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        kind("-57896044618658097711785492504343953926634992332820282019728792003956564819969"),
+        IntErrorKind::NegOverflow
+    );
+    assert_eq!(kind(&format!("-{}", "9".repeat(100))), IntErrorKind::NegOverflow);
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(u64::try_from(U256::from(u64::MAX)), Ok(u64::MAX));
+    assert!(u64::try_from(U256::from(u64::MAX) + U256::from(1u8)).is_err());
+    assert_eq!(u128::try_from(U256::try_from(u128::MAX).unwrap()), Ok(u128::MAX));
+    assert!(Uint::<1>::try_from(1u128 << 64).is_err());
+    assert_eq!(i64::try_from(I256::from(i64::MIN)), Ok(i64::MIN));
+    assert!(i64::try_from(I256::from(i64::MIN) - I256::from(1i8)).is_err());
+    assert_eq!(I256::from(-1i8).cast_unsigned(), U256::MAX);
+    assert_eq!(U256::MAX.cast_signed(), I256::from(-1i8));
+}
+
+#[test]
+fn test_const() {
+    const TEN_POW_30: U256 = U256::from_u64(1_000_000_000_000_000).wrapping_mul(
+        U256::from_u64(1_000_000_000_000_000),
+    );
+    const SHIFTED: Option<I256> = I256::from_i64(-1).checked_shl(255);
+    assert_eq!(TEN_POW_30, U256::from(10u8).pow(30));
+    assert_eq!(SHIFTED, Some(I256::MIN));
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_add_overflow() {
+    let _ = U256::MAX + U256::from(1u8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_div_overflow() {
+    let _ = I256::MIN / I256::from(-1i8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_by_zero() {
+    let _ = U256::MAX / U256::MIN;
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn test_shl_overflow() {
+    let _ = U256::from(1u8) << 256;
+}
@@ -21,6 +21,7 @@
 mod u64;
 mod u8;
 
+mod bigint;
 mod bignum;
 
 mod const_from;
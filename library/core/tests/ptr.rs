@@ -1183,3 +1183,37 @@ fn test_ptr_metadata_in_const() {
     assert_eq!(SLICE_META, 3);
     assert_eq!(DYN_META.size_of(), 42);
 }
+
+#[test]
+fn volatile_ptr_registers() {
+    #[repr(C)]
+    struct Block {
+        ctrl: u32,
+        fifo: [u16; 4],
+    }
+
+    let mut block = Block { ctrl: 0, fifo: [0; 4] };
+    let regs: VolatilePtr<Block> = unsafe { VolatilePtr::new(NonNull::from(&mut block)) };
+    let ctrl = unsafe { regs.map(|p| NonNull::new_unchecked(addr_of_mut!((*p.as_ptr()).ctrl))) };
+    let fifo = unsafe { regs.map(|p| NonNull::new_unchecked(addr_of_mut!((*p.as_ptr()).fifo))) };
+
+    ctrl.write(0xff00_00ff);
+    ctrl.modify(|v| v | 0x100);
+    assert_eq!(ctrl.read_only().read(), 0xff00_01ff);
+    assert_eq!(ctrl.read_bits(4..12), 0x1f);
+    ctrl.write_bits(24..32, 0x12);
+    assert_eq!(ctrl.read(), 0x1200_01ff);
+
+    fifo.index(3).write_only().write(7);
+    assert_eq!(fifo.index(3).read(), 7);
+    assert_eq!(block.ctrl, 0x1200_01ff);
+    assert_eq!(block.fifo, [0, 0, 0, 7]);
+}
+
+#[test]
+#[should_panic = "value does not fit in the bit field"]
+fn volatile_ptr_write_bits_overflow() {
+    let mut reg = 0u8;
+    let reg: VolatilePtr<u8> = unsafe { VolatilePtr::new(NonNull::from(&mut reg)) };
+    reg.write_bits(0..4, 0x10);
+}
@@ -1969,7 +1969,7 @@ fn select_nth_unstable_past_length() {
 }
 
 pub mod memchr {
-    use core::slice::memchr::{memchr, memrchr};
+    use core::slice::memchr::{memchr, memchr2, memchr3, memrchr, memrchr2, memrchr3};
 
     // test fallback implementations on all platforms
     #[test]
@@ -2052,6 +2052,57 @@ fn each_alignment_reversed() {
             assert_eq!(Some(pos - start), memrchr(needle, &data[start..]));
         }
     }
+
+    #[test]
+    fn matches_two_and_three() {
+        assert_eq!(Some(1), memchr2(b'b', b'c', b"abca"));
+        assert_eq!(Some(2), memrchr2(b'b', b'c', b"abca"));
+        assert_eq!(Some(0), memchr3(b'x', b'y', b'a', b"abca"));
+        assert_eq!(Some(3), memrchr3(b'x', b'y', b'a', b"abca"));
+        assert_eq!(None, memchr2(b'x', b'y', b"abca"));
+        assert_eq!(None, memrchr3(b'x', b'y', b'z', b""));
+    }
+
+    // Exercise the vector and word loops, including their last, partial blocks.
+    #[test]
+    fn each_position_and_length() {
+        let mut data = [b'a'; 100];
+        for len in 0..data.len() {
+            for pos in 0..len {
+                data[pos] = b'z';
+                let text = &data[..len];
+                assert_eq!(Some(pos), memchr(b'z', text));
+                assert_eq!(Some(pos), memrchr(b'z', text));
+                assert_eq!(Some(pos), memchr2(b'y', b'z', text));
+                assert_eq!(Some(pos), memrchr2(b'z', b'y', text));
+                assert_eq!(Some(pos), memchr3(b'x', b'y', b'z', text));
+                assert_eq!(Some(pos), memrchr3(b'z', b'x', b'y', text));
+                data[pos] = b'a';
+            }
+            let text = &data[..len];
+            assert_eq!(None, memchr(b'z', text));
+            assert_eq!(None, memrchr(b'z', text));
+            assert_eq!(None, memchr2(b'y', b'z', text));
+            assert_eq!(None, memrchr2(b'y', b'z', text));
+            assert_eq!(None, memchr3(b'x', b'y', b'z', text));
+            assert_eq!(None, memrchr3(b'x', b'y', b'z', text));
+        }
+    }
+
+    #[test]
+    fn first_and_last_of_many() {
+        let mut data = [b'a'; 100];
+        data[20] = b'y';
+        data[50] = b'z';
+        data[70] = b'y';
+        for start in 0..16 {
+            let text = &data[start..];
+            assert_eq!(Some(20 - start), memchr2(b'z', b'y', text));
+            assert_eq!(Some(70 - start), memrchr2(b'z', b'y', text));
+            assert_eq!(Some(50 - start), memchr3(b'x', b'z', b'-', text));
+            assert_eq!(Some(50 - start), memrchr3(b'x', b'z', b'-', text));
+        }
+    }
 }
 
 #[test]
@@ -20,3 +20,28 @@ fn test_waker_getters() {
     |_| {},
     |_| {},
 );
+
+#[test]
+fn test_static_waker() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::StaticWake;
+
+    struct Counter(AtomicUsize);
+
+    impl StaticWake for Counter {
+        fn wake(&'static self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    static COUNTER: Counter = Counter(AtomicUsize::new(0));
+
+    let waker = Waker::from(&COUNTER);
+    let waker2 = waker.clone();
+    assert!(waker.will_wake(&waker2));
+    waker.wake_by_ref();
+    waker2.wake();
+    drop(waker);
+    assert_eq!(COUNTER.0.load(Ordering::Relaxed), 2);
+    assert!(!Waker::from(&COUNTER).will_wake(std::task::noop_waker()));
+}
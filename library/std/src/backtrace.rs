@@ -28,6 +28,12 @@
 //! platform supports capturing backtraces you can consult the `BacktraceStatus`
 //! enum as a result of `Backtrace::status`.
 //!
+//! On targets where the unwinder cannot walk the stack, a standard library
+//! built with the `backtrace-frame-pointers` Cargo feature walks the chain of
+//! frame pointers instead. This requires all code to be compiled with
+//! `-C force-frame-pointers=yes`. Frames that cannot be symbolized on the
+//! target are reported with their addresses only, to be resolved offline.
+//!
 //! Like above with accuracy platform support is done on a best effort basis.
 //! Sometimes libraries might not be available at runtime or something may go
 //! wrong which would cause a backtrace to not be captured. Please feel free to
@@ -89,13 +95,15 @@
 // a backtrace or actually symbolizing it.
 
 use crate::backtrace_rs::{self, BytesOrWideString};
+use crate::borrow::Cow;
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
 use crate::panic::UnwindSafe;
+use crate::path::Path;
 use crate::sync::atomic::{AtomicU8, Ordering::Relaxed};
 use crate::sync::LazyLock;
-use crate::sys::backtrace::{lock, output_filename, set_image_base};
+use crate::sys::backtrace::{filename_path, lock, output_filename, set_image_base};
 
 /// A captured OS thread stack backtrace.
 ///
@@ -155,11 +163,18 @@ pub struct BacktraceFrame {
 #[derive(Debug)]
 enum RawFrame {
     Actual(backtrace_rs::Frame),
+    /// The instruction pointer of a frame found by walking frame pointers.
+    FramePointer(usize),
     #[cfg(test)]
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol that a [`BacktraceFrame`] was resolved to.
+///
+/// A frame may resolve to several symbols when functions were inlined into
+/// each other; see [`BacktraceFrame::symbols`].
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -171,6 +186,19 @@ enum BytesOrWide {
     Wide(Vec<u16>),
 }
 
+/// The name of a [`BacktraceSymbol`].
+///
+/// Its [`Display`] implementation demangles the name if possible. The
+/// alternate form (`{:#}`) omits the hash suffix of Rust symbol names, as the
+/// [`Debug`] output of a [`Backtrace`] does.
+///
+/// [`Display`]: fmt::Display
+/// [`Debug`]: fmt::Debug
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct SymbolName<'a> {
+    bytes: &'a [u8],
+}
+
 #[stable(feature = "backtrace", since = "1.65.0")]
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -207,6 +235,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "79676")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -322,6 +351,7 @@ pub const fn disabled() -> Backtrace {
 
     // Capture a backtrace which start just before the function addressed by
     // `ip`
+    #[cfg_attr(feature = "backtrace-frame-pointers", inline(never))]
     fn create(ip: usize) -> Backtrace {
         let _lock = lock();
         let mut frames = Vec::new();
@@ -338,6 +368,20 @@ fn create(ip: usize) -> Backtrace {
                 }
                 true
             });
+
+            // Fall back to frame pointers if the unwinder doesn't support this
+            // target. Symbol addresses aren't known here, so skip the frames of
+            // this function and its caller, which are never inlined.
+            if frames.is_empty() && cfg!(feature = "backtrace-frame-pointers") {
+                crate::sys::backtrace::frame_pointer::trace(|ip| {
+                    frames.push(BacktraceFrame {
+                        frame: RawFrame::FramePointer(ip.addr()),
+                        symbols: Vec::new(),
+                    });
+                    true
+                });
+                actual_start = Some(frames.len().min(2));
+            }
         }
 
         // If no frames came out assume that this is an unsupported platform
@@ -378,6 +422,95 @@ pub fn frames(&'a self) -> &'a [BacktraceFrame] {
     }
 }
 
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is usually the address of the instruction after the call that
+    /// this frame is executing, so it may not lie within the calling line.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the symbols this frame was resolved to.
+    ///
+    /// If functions were inlined at this frame, there is one symbol for each
+    /// of them, starting with the innermost. The slice is empty if the frame
+    /// could not be resolved.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the name of the function, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn name(&self) -> Option<SymbolName<'_>> {
+        self.name.as_deref().map(|bytes| SymbolName { bytes })
+    }
+
+    /// Returns the path of the source file, if known.
+    ///
+    /// The path is returned as it was recorded in the debug information, so
+    /// it may be relative to the directory the program was built in.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<Cow<'_, Path>> {
+        filename_path(match self.filename.as_ref()? {
+            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
+        })
+    }
+
+    /// Returns the line number in the source file, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number in the source file, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
+impl<'a> SymbolName<'a> {
+    /// Returns the raw, possibly mangled, bytes of the name.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the raw, possibly mangled, name if it is valid UTF-8.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn as_str(&self) -> Option<&'a str> {
+        crate::str::from_utf8(self.bytes).ok()
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+impl fmt::Display for SymbolName<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&backtrace_rs::SymbolName::new(self.bytes), fmt)
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+impl fmt::Debug for SymbolName<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "\"{self:#}\"")
+    }
+}
+
 #[stable(feature = "backtrace", since = "1.65.0")]
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -440,23 +573,27 @@ pub(super) fn lazy_resolve(mut capture: Capture) -> LazyResolve {
             let _lock = lock();
             for frame in capture.frames.iter_mut() {
                 let symbols = &mut frame.symbols;
-                let frame = match &frame.frame {
-                    RawFrame::Actual(frame) => frame,
+                let push = |symbol: &backtrace_rs::Symbol| {
+                    symbols.push(BacktraceSymbol {
+                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
+                        filename: symbol.filename_raw().map(|b| match b {
+                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+                        }),
+                        lineno: symbol.lineno(),
+                        colno: symbol.colno(),
+                    });
+                };
+                match &frame.frame {
+                    RawFrame::Actual(frame) => unsafe {
+                        backtrace_rs::resolve_frame_unsynchronized(frame, push)
+                    },
+                    RawFrame::FramePointer(ip) => unsafe {
+                        let ip = crate::ptr::without_provenance_mut(*ip);
+                        backtrace_rs::resolve_unsynchronized(ip, push)
+                    },
                     #[cfg(test)]
                     RawFrame::Fake => unimplemented!(),
-                };
-                unsafe {
-                    backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-                        symbols.push(BacktraceSymbol {
-                            name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                            filename: symbol.filename_raw().map(|b| match b {
-                                BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                                BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                            }),
-                            lineno: symbol.lineno(),
-                            colno: symbol.colno(),
-                        });
-                    });
                 }
             }
 
@@ -470,6 +607,7 @@ impl RawFrame {
     fn ip(&self) -> *mut c_void {
         match self {
             RawFrame::Actual(frame) => frame.ip(),
+            RawFrame::FramePointer(ip) => crate::ptr::without_provenance_mut(*ip),
             #[cfg(test)]
             RawFrame::Fake => crate::ptr::without_provenance_mut(1),
         }
@@ -93,6 +93,27 @@ fn test_frames() {
     assert!(iter.all(|(f, e)| format!("{f:#?}") == *e));
 }
 
+#[test]
+fn test_symbol_accessors() {
+    let frames = generate_fake_frames();
+    assert_eq!(frames[0].ip(), RawFrame::Fake.ip());
+
+    let symbols = frames[2].symbols();
+    assert_eq!(symbols.len(), 2);
+    let name = symbols[0].name().unwrap();
+    assert_eq!(name.as_bytes(), b"std::rt::lang_start_internal");
+    assert_eq!(name.as_str(), Some("std::rt::lang_start_internal"));
+    assert_eq!(name.to_string(), "std::rt::lang_start_internal");
+    assert_eq!(symbols[0].filename().as_deref(), Some(Path::new("rust/rt.rs")));
+    assert_eq!(symbols[0].lineno(), Some(300));
+    assert_eq!(symbols[0].colno(), Some(5));
+    assert_eq!(symbols[1].colno(), None);
+
+    let symbol = &frames[1].symbols()[0];
+    assert!(symbol.filename().is_none());
+    assert_eq!(symbol.lineno(), None);
+}
+
 #[test]
 fn backtrace_unwind_safe() {
     fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
@@ -415,6 +415,8 @@
 pub use alloc_crate::collections::{BTreeMap, BTreeSet, BinaryHeap};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use alloc_crate::collections::{LinkedList, VecDeque};
+#[unstable(feature = "intrusive_collections", issue = "none")]
+pub use alloc_crate::collections::intrusive;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(inline)]
@@ -14,8 +14,10 @@
 use crate::ffi::OsString;
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+use crate::ops::{Bound, Deref, DerefMut, RangeBounds};
 use crate::path::{Path, PathBuf};
 use crate::sealed::Sealed;
+use crate::slice;
 use crate::sync::Arc;
 use crate::sys::fs as fs_imp;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
@@ -229,6 +231,140 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A handle to an open directory, relative to which other paths are resolved.
+///
+/// Operations on a `Dir` keep working on the same directory even if it is
+/// renamed or replaced after it was opened, which avoids the races inherent in
+/// joining paths. Where the platform supports it, they map directly onto the
+/// `*at` family of system calls (`openat`, `mkdirat`, `unlinkat`, ...).
+/// Elsewhere, paths are joined to the absolute path the directory was opened
+/// with, and these guarantees do not hold.
+///
+/// A `Dir` is opened with [`Dir::open`] or, for more control over how paths
+/// are resolved, with [`DirOptions`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dir_handle)]
+/// use std::fs::{Dir, OpenOptions};
+/// use std::io::Write;
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = Dir::open("/var/lib/myapp")?;
+///     dir.create_dir("cache")?;
+///     let mut file = dir.open_file("cache/data", OpenOptions::new().write(true).create(true))?;
+///     file.write_all(b"hello")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "dir_handle", issue = "none")]
+pub struct Dir {
+    inner: fs_imp::Dir,
+}
+
+/// Options controlling how a [`Dir`] resolves paths.
+///
+/// The options chosen when opening a directory apply to the directory itself
+/// and are inherited by directories opened through [`Dir::open_dir`].
+#[unstable(feature = "dir_handle", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct DirOptions(crate::sys_common::fs::DirOptions);
+
+/// Options and flags which can be used to configure how a file is copied.
+///
+/// This builder exposes the ability to configure how [`copy`] copies the
+/// contents of a file: whether it must be a copy-on-write clone, whether holes
+/// are preserved, whether timestamps and ownership are kept, and how progress
+/// is reported.
+///
+/// As long as no option is set, the file is copied exactly like [`copy`]
+/// does. Once any option is set, the standard library copies the file itself
+/// instead of calling the platform's copy routine, such as `CopyFileExW` on
+/// Windows or `fcopyfile` on macOS. The file is still cloned where
+/// [`reflink_only`](CopyOptions::reflink_only) says clones are supported, but
+/// apart from the contents only the permissions and the metadata selected
+/// with [`preserve_metadata`](CopyOptions::preserve_metadata) are copied, not
+/// extended attributes or other metadata the platform's routine would copy.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_copy_options)]
+/// use std::fs::CopyOptions;
+///
+/// fn main() -> std::io::Result<()> {
+///     CopyOptions::new()
+///         .preserve_metadata(true)
+///         .progress(|bytes| eprintln!("copied {bytes} bytes"))
+///         .copy("disk.img", "backup.img")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_copy_options", issue = "none")]
+pub struct CopyOptions<'a>(crate::sys_common::fs::CopyOptions<'a>);
+
+/// Options which can be used to configure how a file is memory-mapped with
+/// [`File::map`] or [`File::map_mut`].
+#[unstable(feature = "file_map", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct MapOptions(crate::sys_common::fs::MapOptions);
+
+/// A hint about how a memory-mapped region will be accessed, given to
+/// [`Mmap::advise`] or [`MmapMut::advise`].
+#[unstable(feature = "file_map", issue = "none")]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MmapAdvice {
+    /// No particular access pattern. This is the default.
+    Normal,
+    /// The region will be read from lower to higher addresses, so pages can
+    /// be read ahead aggressively and freed soon after they were accessed.
+    Sequential,
+    /// The region will be accessed in random order, so reading ahead is not
+    /// useful.
+    Random,
+    /// The region will be accessed soon, so its pages should be read in now.
+    WillNeed,
+}
+
+/// A read-only memory-mapped region of a file.
+///
+/// The region dereferences to a byte slice, and is unmapped when dropped. It
+/// is created by [`File::map`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(file_map)]
+/// use std::fs::{File, MapOptions};
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("data.bin")?;
+///     // SAFETY: nothing else modifies or truncates `data.bin` while it is mapped.
+///     let map = unsafe { file.map(.., &MapOptions::new())? };
+///     println!("the file starts with {:?}", &map[..4]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "file_map", issue = "none")]
+pub struct Mmap {
+    inner: fs_imp::Mmap,
+}
+
+/// A writable memory-mapped region of a file.
+///
+/// The region dereferences to a mutable byte slice, and is unmapped when
+/// dropped. Unless the region was mapped copy-on-write, writes to it are
+/// written back to the file eventually, or when it is [flushed]. It is
+/// created by [`File::map_mut`].
+///
+/// [flushed]: MmapMut::flush
+#[unstable(feature = "file_map", issue = "none")]
+pub struct MmapMut {
+    inner: fs_imp::Mmap,
+}
+
 /// Read the entire contents of a file into a bytes vector.
 ///
 /// This is a convenience function for using [`File::open`] and [`read_to_end`]
@@ -537,6 +673,224 @@ pub fn sync_data(&self) -> io::Result<()> {
         self.inner.datasync()
     }
 
+    /// Acquires an exclusive advisory lock on the file, blocking until it
+    /// can be acquired.
+    ///
+    /// At most one handle may hold an exclusive lock on a file, and no handle
+    /// may hold a shared lock at the same time. The lock is released by
+    /// [`unlock`](File::unlock), or when every handle referring to the same
+    /// open file description (including those created by
+    /// [`try_clone`](File::try_clone)) is closed.
+    ///
+    /// Locks are advisory: they only exclude other callers of these methods,
+    /// and do not prevent the file from being read or written.
+    ///
+    /// If this handle already holds a lock, whether it is converted or a
+    /// second lock is taken is platform-specific, so callers should not rely
+    /// on either behavior.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX` flag, and the `LockFileEx` function on Windows with
+    /// the `LOCKFILE_EXCLUSIVE_LOCK` flag. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// On other platforms, and on Unix platforms without `flock`, this
+    /// returns an error of kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("build.lock")?;
+    ///     f.lock()?;
+    ///     // ... update the cache ...
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock", alias = "LockFileEx")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared advisory lock on the file, blocking until it can be
+    /// acquired.
+    ///
+    /// Any number of handles may hold a shared lock on a file at the same
+    /// time, as long as no handle holds an exclusive lock. See
+    /// [`lock`](File::lock) for how locks are released.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH` flag, and the `LockFileEx` function on Windows.
+    /// Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock", alias = "LockFileEx")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Attempts to acquire an exclusive advisory lock on the file without
+    /// blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired, and `Ok(false)` if another
+    /// handle holds a conflicting lock. See [`lock`](File::lock) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("build.lock")?;
+    ///     if !f.try_lock()? {
+    ///         println!("waiting for another build to finish");
+    ///         f.lock()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock", alias = "LockFileEx")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Attempts to acquire a shared advisory lock on the file without
+    /// blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired, and `Ok(false)` if another
+    /// handle holds an exclusive lock. See [`lock_shared`](File::lock_shared)
+    /// for details.
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock", alias = "LockFileEx")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the advisory lock held by this handle, if any.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_UN` flag, and the `UnlockFile` function on Windows.
+    /// Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock", alias = "UnlockFile")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
+    /// Maps the `range` of bytes of the file into memory for reading.
+    ///
+    /// An unbounded end maps the file up to its current length. The file must
+    /// have been opened for reading.
+    ///
+    /// # Safety
+    ///
+    /// The returned [`Mmap`] hands out the file's contents as a `&[u8]`, which
+    /// must not change while it is borrowed. The caller must therefore ensure
+    /// that, for as long as the mapping exists, the mapped part of the file is
+    /// not modified, whether through this process (including through other
+    /// mappings of the file) or by other processes. The file must not be
+    /// truncated either: accessing pages past the end of the file may raise
+    /// `SIGBUS` on Unix or an access violation on Windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidInput`] error if the range ends
+    /// before it starts or extends past the end of the file, or if it does not
+    /// fit in the address space. Other errors are returned when the platform
+    /// cannot map the file.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `mmap` function on Unix and
+    /// to `CreateFileMappingW` and `MapViewOfFile` on Windows. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "mmap", alias = "MapViewOfFile")]
+    pub unsafe fn map<R: RangeBounds<u64>>(
+        &self,
+        range: R,
+        options: &MapOptions,
+    ) -> io::Result<Mmap> {
+        let (offset, len) = self.map_range(range)?;
+        self.inner.map(offset, len, false, &options.0).map(|inner| Mmap { inner })
+    }
+
+    /// Maps the `range` of bytes of the file into memory for reading and
+    /// writing.
+    ///
+    /// An unbounded end maps the file up to its current length. The file must
+    /// have been opened for reading, and for writing unless the mapping is
+    /// [copy-on-write](MapOptions::copy_on_write).
+    ///
+    /// # Safety
+    ///
+    /// The returned [`MmapMut`] hands out the file's contents as a
+    /// `&mut [u8]`, which must not be accessed through any other path while it
+    /// is borrowed. The caller must therefore ensure that, for as long as the
+    /// mapping exists, the mapped part of the file is neither read nor
+    /// modified other than through it, whether through this process (including
+    /// through other mappings of the file) or by other processes. The file must
+    /// not be truncated either, as with [`map`](File::map).
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`map`](File::map).
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "mmap", alias = "MapViewOfFile")]
+    pub unsafe fn map_mut<R: RangeBounds<u64>>(
+        &self,
+        range: R,
+        options: &MapOptions,
+    ) -> io::Result<MmapMut> {
+        let (offset, len) = self.map_range(range)?;
+        self.inner.map(offset, len, true, &options.0).map(|inner| MmapMut { inner })
+    }
+
+    /// Resolves `range` against the file's length, into an offset and length.
+    fn map_range<R: RangeBounds<u64>>(&self, range: R) -> io::Result<(u64, usize)> {
+        const INVALID: io::Error = io::const_io_error!(
+            io::ErrorKind::InvalidInput,
+            "mapped range is out of bounds of the file",
+        );
+
+        let file_len = self.metadata()?.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or(INVALID)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).ok_or(INVALID)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => file_len,
+        };
+        if start > end || end > file_len {
+            return Err(INVALID);
+        }
+        let len = usize::try_from(end - start).map_err(|_| INVALID)?;
+        Ok((start, len))
+    }
+
     /// Truncates or extends the underlying file, updating the size of
     /// this file to become `size`.
     ///
@@ -2754,3 +3108,448 @@ fn as_inner_mut(&mut self) -> &mut fs_imp::DirBuilder {
 pub fn exists<P: AsRef<Path>>(path: P) -> io::Result<bool> {
     fs_imp::exists(path.as_ref())
 }
+
+impl Dir {
+    /// Opens the directory at `path` with the default [`DirOptions`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist or is not
+    /// a directory.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Dir> {
+        DirOptions::new().open(path)
+    }
+
+    /// Opens the directory at `path`, relative to this directory.
+    ///
+    /// The new handle uses the same [`DirOptions`] as this one.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        self.inner.open_dir(path.as_ref()).map(|inner| Dir { inner })
+    }
+
+    /// Opens the file at `path`, relative to this directory, with the given
+    /// [`OpenOptions`].
+    ///
+    /// This is the directory-relative counterpart of [`OpenOptions::open`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open_file<P: AsRef<Path>>(&self, path: P, opts: &OpenOptions) -> io::Result<File> {
+        self.inner.open_file(path.as_ref(), &opts.0).map(|inner| File { inner })
+    }
+
+    /// Creates a new, empty directory at `path`, relative to this directory.
+    ///
+    /// This is the directory-relative counterpart of [`create_dir`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn create_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.create_dir(path.as_ref())
+    }
+
+    /// Removes the file at `path`, relative to this directory.
+    ///
+    /// This is the directory-relative counterpart of [`remove_file`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn remove_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_file(path.as_ref())
+    }
+
+    /// Removes the empty directory at `path`, relative to this directory.
+    ///
+    /// This is the directory-relative counterpart of [`remove_dir`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn remove_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_dir(path.as_ref())
+    }
+
+    /// Renames `from`, relative to this directory, to `to`, relative to
+    /// `to_dir`.
+    ///
+    /// This is the directory-relative counterpart of [`rename`], and the two
+    /// directories may be the same.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(dir_handle)]
+    /// use std::fs::Dir;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let incoming = Dir::open("incoming")?;
+    ///     let done = Dir::open("done")?;
+    ///     incoming.rename_to("job.txt", &done, "job.txt")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn rename_to<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        from: P,
+        to_dir: &Dir,
+        to: Q,
+    ) -> io::Result<()> {
+        self.inner.rename(from.as_ref(), &to_dir.inner, to.as_ref())
+    }
+
+    /// Queries metadata about the file at `path`, relative to this directory.
+    ///
+    /// Symbolic links are followed unless this directory was opened with
+    /// [`DirOptions::follow_symlinks`] set to `false` or with
+    /// [`DirOptions::beneath`], in which case a symbolic link is an error.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<Metadata> {
+        self.inner.metadata(path.as_ref()).map(Metadata)
+    }
+
+    /// Queries metadata about the file at `path`, relative to this directory,
+    /// without following a symbolic link in the last component.
+    ///
+    /// This is the directory-relative counterpart of [`symlink_metadata`].
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn symlink_metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<Metadata> {
+        self.inner.symlink_metadata(path.as_ref()).map(Metadata)
+    }
+
+    /// Returns an iterator over the entries within this directory.
+    ///
+    /// The paths of the returned entries are joined to the path this
+    /// directory was opened with.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        self.inner.read_dir().map(ReadDir)
+    }
+}
+
+#[unstable(feature = "dir_handle", issue = "none")]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl DirOptions {
+    /// Creates a blank set of options, which follow symbolic links and allow
+    /// paths to leave the directory.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    #[must_use]
+    pub fn new() -> DirOptions {
+        DirOptions(crate::sys_common::fs::DirOptions::new())
+    }
+
+    /// Sets whether symbolic links are followed, both when opening the
+    /// directory itself and when resolving paths relative to it.
+    ///
+    /// When set to `false`, only symbolic links in the last component of a
+    /// path are rejected, as with `O_NOFOLLOW`.
+    ///
+    /// This option defaults to `true`.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn follow_symlinks(&mut self, follow_symlinks: bool) -> &mut Self {
+        self.0.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Sets whether paths must resolve to a location beneath the directory.
+    ///
+    /// When set, absolute paths and paths containing `..` are rejected with
+    /// [`io::ErrorKind::PermissionDenied`], and symbolic links are not
+    /// followed in any component of the path, similar to `RESOLVE_BENEATH`
+    /// combined with `RESOLVE_NO_SYMLINKS` on Linux.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(dir_handle)]
+    /// use std::fs::DirOptions;
+    /// use std::io::ErrorKind;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let uploads = DirOptions::new().beneath(true).open("uploads")?;
+    ///     let err = uploads.metadata("../secrets").unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn beneath(&mut self, beneath: bool) -> &mut Self {
+        self.0.beneath = beneath;
+        self
+    }
+
+    /// Opens the directory at `path` with the options specified by `self`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `path` does not exist or is not
+    /// a directory, or if it is a symbolic link and
+    /// [`follow_symlinks`](DirOptions::follow_symlinks) is `false`.
+    #[unstable(feature = "dir_handle", issue = "none")]
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        fs_imp::Dir::open(path.as_ref(), &self.0).map(|inner| Dir { inner })
+    }
+}
+
+impl<'a> CopyOptions<'a> {
+    /// Creates a blank new set of options, which behave like [`copy`].
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    #[must_use]
+    pub fn new() -> Self {
+        CopyOptions(crate::sys_common::fs::CopyOptions::new())
+    }
+
+    /// Sets whether the copy must be a copy-on-write clone of the source,
+    /// sharing its data on disk, rather than falling back to copying the data
+    /// when cloning is not possible.
+    ///
+    /// When set, [`copy`](CopyOptions::copy) fails if the platform or
+    /// filesystem cannot clone the file, or if the two paths are on different
+    /// filesystems.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Clones are currently created with the `FICLONE` ioctl on Linux and
+    /// Android. On other platforms, setting this option makes every copy fail
+    /// with [`io::ErrorKind::Unsupported`].
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    pub fn reflink_only(&mut self, reflink_only: bool) -> &mut Self {
+        self.0.reflink_only = reflink_only;
+        self
+    }
+
+    /// Sets whether holes in a sparse source file are kept as holes in the
+    /// destination, instead of being written out as zeros.
+    ///
+    /// This is best-effort: when the platform or filesystem cannot report
+    /// holes, the file is copied in full.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Holes are currently found with `lseek` and `SEEK_DATA`/`SEEK_HOLE` on
+    /// Linux and Android, and this option has no effect elsewhere.
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    pub fn sparse(&mut self, sparse: bool) -> &mut Self {
+        self.0.sparse = sparse;
+        self
+    }
+
+    /// Sets whether the access and modification times of the source, and on
+    /// Unix its owner and group, are applied to the destination.
+    ///
+    /// If the process lacks the privileges to change the owner of the
+    /// destination, it is silently left unchanged, as `cp -p` does.
+    ///
+    /// Permissions are always copied. This option defaults to `false`.
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    pub fn preserve_metadata(&mut self, preserve_metadata: bool) -> &mut Self {
+        self.0.preserve_metadata = preserve_metadata;
+        self
+    }
+
+    /// Sets a callback that is called with the total number of bytes copied
+    /// so far as the copy makes progress.
+    ///
+    /// The callback is called at least once for a non-empty file. How often it
+    /// is called is unspecified.
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    pub fn progress<F: FnMut(u64) + 'a>(&mut self, progress: F) -> &mut Self {
+        self.0.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Copies the contents of `from` to `to` with the options specified by
+    /// `self`, returning the number of bytes copied.
+    ///
+    /// As with [`copy`], `to` is overwritten if it exists, and the permission
+    /// bits of `from` are copied to it. When the file is cloned, or when holes
+    /// are skipped by [`sparse`](CopyOptions::sparse), the returned count is
+    /// the number of bytes the destination shares with or received from the
+    /// source, which may be less than its length.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`copy`], and additionally
+    /// fails if [`reflink_only`](CopyOptions::reflink_only) is set and the
+    /// file cannot be cloned.
+    #[unstable(feature = "fs_copy_options", issue = "none")]
+    pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(&mut self, from: P, to: Q) -> io::Result<u64> {
+        if self.0.is_default() {
+            return fs_imp::copy(from.as_ref(), to.as_ref());
+        }
+        fs_imp::copy_with(from.as_ref(), to.as_ref(), &mut self.0)
+    }
+}
+
+#[unstable(feature = "fs_copy_options", issue = "none")]
+impl fmt::Debug for CopyOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyOptions")
+            .field("reflink_only", &self.0.reflink_only)
+            .field("sparse", &self.0.sparse)
+            .field("preserve_metadata", &self.0.preserve_metadata)
+            .field("progress", &self.0.progress.is_some())
+            .finish()
+    }
+}
+
+impl MapOptions {
+    /// Creates a blank new set of options, which map the file shared and do
+    /// not read it in ahead of time.
+    #[unstable(feature = "file_map", issue = "none")]
+    #[must_use]
+    pub fn new() -> MapOptions {
+        MapOptions(crate::sys_common::fs::MapOptions::new())
+    }
+
+    /// Sets whether the mapping is private to this process.
+    ///
+    /// Writes to a copy-on-write mapping are never written back to the file,
+    /// and the file only needs to be opened for reading to be mapped with
+    /// [`File::map_mut`]. Whether changes made to the file by others are
+    /// visible through such a mapping is unspecified.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "file_map", issue = "none")]
+    pub fn copy_on_write(&mut self, copy_on_write: bool) -> &mut Self {
+        self.0.copy_on_write = copy_on_write;
+        self
+    }
+
+    /// Sets whether the whole region is read into memory when it is mapped,
+    /// rather than page by page as it is accessed.
+    ///
+    /// This option defaults to `false`.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option currently corresponds to `MAP_POPULATE` on Linux and
+    /// Android, and has no effect elsewhere.
+    #[unstable(feature = "file_map", issue = "none")]
+    pub fn populate(&mut self, populate: bool) -> &mut Self {
+        self.0.populate = populate;
+        self
+    }
+}
+
+impl Mmap {
+    /// Advises the platform how the region will be accessed.
+    ///
+    /// This is only a hint. It currently corresponds to `madvise` on Unix, and
+    /// does nothing on platforms without an equivalent.
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "madvise")]
+    pub fn advise(&self, advice: MmapAdvice) -> io::Result<()> {
+        self.inner.advise(advice)
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl Deref for Mmap {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.inner.as_ptr(), self.inner.len()) }
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl AsRef<[u8]> for Mmap {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl fmt::Debug for Mmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mmap")
+            .field("ptr", &self.inner.as_ptr())
+            .field("len", &self.inner.len())
+            .finish()
+    }
+}
+
+impl MmapMut {
+    /// Writes modified pages of the region back to the file, and waits until
+    /// they have reached the storage device.
+    ///
+    /// This does nothing for copy-on-write mappings.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to `msync` with `MS_SYNC` on Unix,
+    /// and to `FlushViewOfFile` followed by `FlushFileBuffers` on Windows.
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "msync", alias = "FlushViewOfFile")]
+    pub fn flush(&self) -> io::Result<()> {
+        self.inner.flush(true)
+    }
+
+    /// Starts writing modified pages of the region back to the file, without
+    /// waiting for the writes to complete.
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "msync", alias = "FlushViewOfFile")]
+    pub fn flush_async(&self) -> io::Result<()> {
+        self.inner.flush(false)
+    }
+
+    /// Advises the platform how the region will be accessed.
+    ///
+    /// See [`Mmap::advise`].
+    #[unstable(feature = "file_map", issue = "none")]
+    #[doc(alias = "madvise")]
+    pub fn advise(&self, advice: MmapAdvice) -> io::Result<()> {
+        self.inner.advise(advice)
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl Deref for MmapMut {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.inner.as_ptr(), self.inner.len()) }
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl DerefMut for MmapMut {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.inner.as_ptr(), self.inner.len()) }
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl AsRef<[u8]> for MmapMut {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl AsMut<[u8]> for MmapMut {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[unstable(feature = "file_map", issue = "none")]
+impl fmt::Debug for MmapMut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapMut")
+            .field("ptr", &self.inner.as_ptr())
+            .field("len", &self.inner.len())
+            .finish()
+    }
+}
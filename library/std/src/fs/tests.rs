@@ -1803,3 +1803,191 @@ fn test_hidden_file_truncation() {
     let metadata = file.metadata().unwrap();
     assert_eq!(metadata.len(), 0);
 }
+
+#[test]
+fn dir_handle_relative_ops() {
+    let tmpdir = tmpdir();
+    let dir = fs::Dir::open(tmpdir.path()).unwrap();
+
+    dir.create_dir("sub").unwrap();
+    let mut file =
+        dir.open_file("sub/a.txt", OpenOptions::new().write(true).create_new(true)).unwrap();
+    file.write_all(b"hello").unwrap();
+    drop(file);
+    assert_eq!(fs::read(tmpdir.join("sub/a.txt")).unwrap(), b"hello");
+    assert_eq!(dir.metadata("sub/a.txt").unwrap().len(), 5);
+    assert!(dir.symlink_metadata("sub").unwrap().is_dir());
+
+    let sub = dir.open_dir("sub").unwrap();
+    let names: Vec<_> = sub.read_dir().unwrap().map(|e| e.unwrap().file_name()).collect();
+    assert_eq!(names, ["a.txt"]);
+
+    sub.rename_to("a.txt", &dir, "b.txt").unwrap();
+    assert!(!tmpdir.join("sub/a.txt").exists());
+    dir.remove_file("b.txt").unwrap();
+    dir.remove_dir("sub").unwrap();
+    assert_eq!(dir.read_dir().unwrap().count(), 0);
+    assert_eq!(dir.metadata("sub").unwrap_err().kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn dir_handle_beneath() {
+    let tmpdir = tmpdir();
+    check!(fs::create_dir(tmpdir.join("inner")));
+    check!(fs::write(tmpdir.join("outside.txt"), b"secret"));
+    let dir = fs::DirOptions::new().beneath(true).open(tmpdir.join("inner")).unwrap();
+
+    assert_eq!(dir.metadata("../outside.txt").unwrap_err().kind(), ErrorKind::PermissionDenied);
+    let abs = tmpdir.join("outside.txt");
+    assert_eq!(dir.metadata(&abs).unwrap_err().kind(), ErrorKind::PermissionDenied);
+
+    dir.create_dir("a").unwrap();
+    dir.create_dir("a/b").unwrap();
+    assert!(dir.metadata("./a/b").unwrap().is_dir());
+
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    }
+    check!(symlink_dir(tmpdir.path(), tmpdir.join("inner/escape")));
+    assert!(dir.open_file("escape/outside.txt", OpenOptions::new().read(true)).is_err());
+    assert!(dir.metadata("escape").is_err());
+    assert!(dir.symlink_metadata("escape").unwrap().is_symlink());
+}
+
+#[test]
+#[cfg(any(windows, target_os = "linux", target_os = "android", target_vendor = "apple"))]
+fn file_lock_multiple_handles() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("lock");
+    let f1 = check!(File::create(&path));
+    let f2 = check!(OpenOptions::new().write(true).open(&path));
+
+    check!(f1.lock());
+    assert!(!check!(f2.try_lock()));
+    assert!(!check!(f2.try_lock_shared()));
+    check!(f1.unlock());
+    assert!(check!(f2.try_lock()));
+    check!(f2.unlock());
+
+    check!(f1.lock_shared());
+    assert!(!check!(f2.try_lock()));
+    assert!(check!(f2.try_lock_shared()));
+    check!(f2.unlock());
+    check!(f1.unlock());
+
+    // Dropping the handle releases its lock.
+    check!(f1.lock());
+    drop(f1);
+    assert!(check!(f2.try_lock()));
+}
+
+#[test]
+fn copy_options_progress_and_metadata() {
+    let tmpdir = tmpdir();
+    let src = tmpdir.join("src");
+    let dst = tmpdir.join("dst");
+    let data = vec![7u8; 100_000];
+    check!(fs::write(&src, &data));
+    let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+    check!(check!(File::options().write(true).open(&src)).set_modified(mtime));
+
+    let mut last = 0;
+    let copied = check!(
+        fs::CopyOptions::new().preserve_metadata(true).progress(|n| last = n).copy(&src, &dst)
+    );
+    assert_eq!(copied, data.len() as u64);
+    assert_eq!(last, data.len() as u64);
+    assert_eq!(check!(fs::read(&dst)), data);
+    assert_eq!(check!(check!(fs::metadata(&dst)).modified()), mtime);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn copy_options_sparse() {
+    use crate::os::unix::fs::{FileExt, MetadataExt};
+
+    let tmpdir = tmpdir();
+    let src = tmpdir.join("src");
+    let dst = tmpdir.join("dst");
+    let len = 64 * 1024 * 1024;
+    let f = check!(File::create(&src));
+    check!(f.set_len(len));
+    check!(f.write_at(b"hello", len / 2));
+    drop(f);
+
+    check!(fs::CopyOptions::new().sparse(true).copy(&src, &dst));
+    let meta = check!(fs::metadata(&dst));
+    assert_eq!(meta.len(), len);
+    assert_eq!(check!(fs::read(&dst))[len as usize / 2..][..5], *b"hello");
+    // Only the blocks around the data are allocated in the destination.
+    assert!(meta.blocks() * 512 < len);
+}
+
+#[test]
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn copy_options_reflink_only_unsupported() {
+    let tmpdir = tmpdir();
+    let src = tmpdir.join("src");
+    check!(fs::write(&src, b"data"));
+    let err = fs::CopyOptions::new().reflink_only(true).copy(&src, tmpdir.join("dst")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn file_map_read_and_write() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("data");
+    // Longer than a page, so that an unaligned offset is rounded down.
+    let data: Vec<u8> = (0..20000u32).map(|i| i as u8).collect();
+    check!(fs::write(&path, &data));
+
+    let f = check!(File::open(&path));
+    let map = check!(unsafe { f.map(.., &fs::MapOptions::new()) });
+    assert_eq!(*map, *data);
+    let map = check!(unsafe { f.map(5000..=5009, &fs::MapOptions::new()) });
+    assert_eq!(*map, data[5000..=5009]);
+    check!(map.advise(fs::MmapAdvice::Sequential));
+    drop(map);
+
+    let f = check!(OpenOptions::new().read(true).write(true).open(&path));
+    let mut map = check!(unsafe { f.map_mut(4097.., &fs::MapOptions::new()) });
+    map[..5].copy_from_slice(b"hello");
+    check!(map.flush());
+    drop(map);
+    assert_eq!(check!(fs::read(&path))[4097..][..5], *b"hello");
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn file_map_copy_on_write() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("data");
+    check!(fs::write(&path, b"original"));
+
+    let f = check!(File::open(&path));
+    let mut map = check!(unsafe { f.map_mut(.., fs::MapOptions::new().copy_on_write(true)) });
+    map.copy_from_slice(b"modified");
+    check!(map.flush());
+    assert_eq!(*map, *b"modified");
+    drop(map);
+    assert_eq!(check!(fs::read(&path)), b"original");
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn file_map_bounds() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("data");
+    check!(fs::write(&path, b"data"));
+    let f = check!(File::open(&path));
+    let opts = fs::MapOptions::new();
+
+    let map = check!(unsafe { f.map(4.., &opts) });
+    assert!(map.is_empty());
+    let err = unsafe { f.map(2..5, &opts) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    #[allow(clippy::reversed_empty_ranges)]
+    let err = unsafe { f.map(3..2, &opts) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
@@ -89,3 +89,5 @@
 
 #[stable(feature = "std_hash_exports", since = "1.76.0")]
 pub use self::random::{DefaultHasher, RandomState};
+#[unstable(feature = "fast_hash", issue = "none")]
+pub use self::random::FastState;
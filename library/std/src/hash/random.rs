@@ -1,5 +1,5 @@
-//! This module exists to isolate [`RandomState`] and [`DefaultHasher`] outside of the
-//! [`collections`] module without actually publicly exporting them, so that parts of that
+//! This module exists to isolate [`RandomState`], [`FastState`] and [`DefaultHasher`] outside of
+//! the [`collections`] module without actually publicly exporting them, so that parts of that
 //! implementation can more easily be moved to the [`alloc`] crate.
 //!
 //! Although its items are public and contain stability attributes, they can't actually be accessed
@@ -8,7 +8,7 @@
 //! [`collections`]: crate::collections
 
 #[allow(deprecated)]
-use super::{BuildHasher, Hasher, SipHasher13};
+use super::{BuildHasher, FastHasher, Hasher, SipHasher13};
 use crate::cell::Cell;
 use crate::fmt;
 use crate::sys;
@@ -160,3 +160,71 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("RandomState").finish_non_exhaustive()
     }
 }
+
+/// A randomly seeded [`BuildHasher`] for the fast, non-cryptographic
+/// [`FastHasher`].
+///
+/// Like [`RandomState`], each `FastState` gets a different seed, so hash maps
+/// using it don't share an iteration order. Unlike [`RandomState`], this does
+/// **not** make it resistant to HashDoS attacks: the hash function is simple
+/// enough that an attacker can find colliding keys regardless of the seed.
+/// Only use it for keys that are not chosen by an untrusted party, such as
+/// integers or IDs generated by the program itself.
+///
+/// For hashes that are the same on every run, use [`FixedState`] instead.
+///
+/// [`FixedState`]: super::FixedState
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fast_hash)]
+/// use std::collections::HashMap;
+/// use std::hash::FastState;
+///
+/// let mut map = HashMap::with_hasher(FastState::new());
+/// map.insert(1, 2);
+/// ```
+#[unstable(feature = "fast_hash", issue = "none")]
+#[derive(Clone)]
+pub struct FastState {
+    seed: u64,
+}
+
+impl FastState {
+    /// Constructs a new `FastState` that is initialized with a random seed.
+    #[unstable(feature = "fast_hash", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn new() -> FastState {
+        // Reuse the cached per-thread keys, which also gives every instance
+        // a different seed.
+        let RandomState { k0, k1 } = RandomState::new();
+        FastState { seed: k0 ^ k1 }
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl BuildHasher for FastState {
+    type Hasher = FastHasher;
+    #[inline]
+    fn build_hasher(&self) -> FastHasher {
+        FastHasher::with_seed(self.seed)
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl Default for FastState {
+    /// Constructs a new `FastState`.
+    #[inline]
+    fn default() -> FastState {
+        FastState::new()
+    }
+}
+
+#[unstable(feature = "fast_hash", issue = "none")]
+impl fmt::Debug for FastState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FastState").finish_non_exhaustive()
+    }
+}
@@ -329,6 +329,8 @@
     util::{empty, repeat, sink, Empty, Repeat, Sink},
 };
 
+#[unstable(feature = "core_io_async", issue = "none")]
+pub use core::io::{AsyncRead, AsyncWrite};
 #[unstable(feature = "read_buf", issue = "78485")]
 pub use core::io::{BorrowedBuf, BorrowedCursor};
 pub(crate) use error::const_io_error;
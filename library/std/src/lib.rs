@@ -329,6 +329,7 @@
 #![feature(exclusive_wrapper)]
 #![feature(exposed_provenance)]
 #![feature(extend_one)]
+#![feature(fast_hash)]
 #![feature(float_gamma)]
 #![feature(float_minimum_maximum)]
 #![feature(float_next_up_down)]
@@ -31,10 +31,18 @@
 pub use self::tcp::IntoIncoming;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
+#[unstable(feature = "tcp_keepalive", issue = "none")]
+pub use self::tcp::TcpKeepalive;
+#[unstable(feature = "tcp_socket", issue = "none")]
+pub use self::tcp::TcpSocket;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::net::AddrParseError;
+#[unstable(feature = "ip_prefix", issue = "none")]
+pub use core::net::{
+    IpPrefix, Ipv4Hosts, Ipv4Prefix, Ipv4Subnets, Ipv6Hosts, Ipv6Prefix, Ipv6Subnets,
+};
 
 mod ip_addr;
 mod socket_addr;
@@ -8,7 +8,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::iter::FusedIterator;
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -503,6 +503,96 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.0.nodelay()
     }
 
+    /// Enables or disables TCP keepalive probes on this socket, using the
+    /// `SO_KEEPALIVE` option.
+    ///
+    /// With `Some`, keepalive is enabled and the parameters set in the
+    /// [`TcpKeepalive`] are applied; parameters that are not set keep the
+    /// system default. With `None`, keepalive is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Unsupported`] error if the platform cannot set one of
+    /// the parameters.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    /// use std::net::{TcpKeepalive, TcpStream};
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// let keepalive = TcpKeepalive::new().with_time(Duration::from_secs(60));
+    /// stream.set_keepalive(Some(keepalive)).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: Option<TcpKeepalive>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive.as_ref())
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive`].
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets the size of the kernel's receive buffer for this socket, using the
+    /// `SO_RCVBUF` option.
+    ///
+    /// The operating system may round, double or clamp the requested size;
+    /// use [`TcpStream::recv_buffer_size`] to find out the actual size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_recv_buffer_size(1 << 20).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's receive buffer for this socket, from the
+    /// `SO_RCVBUF` option.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_recv_buffer_size`].
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the kernel's send buffer for this socket, using the
+    /// `SO_SNDBUF` option.
+    ///
+    /// The operating system may round, double or clamp the requested size;
+    /// use [`TcpStream::send_buffer_size`] to find out the actual size.
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's send buffer for this socket, from the
+    /// `SO_SNDBUF` option.
+    ///
+    /// For more information about this option, see
+    /// [`TcpStream::set_send_buffer_size`].
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
     /// Sets the value for the `IP_TTL` option on this socket.
     ///
     /// This value sets the time-to-live field that is used in every packet sent
@@ -1069,3 +1159,312 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A TCP socket that has not yet been connected or turned into a listener.
+///
+/// A `TcpSocket` gives access to the socket options that have to be set
+/// before [`bind`], [`connect`] or [`listen`] to have an effect, such as
+/// `SO_REUSEADDR` and `IPV6_V6ONLY`, or that are commonly set on every socket
+/// of a server. Once configured, the socket is turned into a [`TcpStream`]
+/// with [`connect`] or into a [`TcpListener`] with [`listen`].
+///
+/// Unlike [`TcpListener::bind`], `TcpSocket` does not set `SO_REUSEADDR`
+/// on its own.
+///
+/// [`bind`]: TcpSocket::bind
+/// [`connect`]: TcpSocket::connect
+/// [`listen`]: TcpSocket::listen
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(tcp_socket, tcp_keepalive)]
+/// use std::net::{TcpKeepalive, TcpSocket};
+/// use std::time::Duration;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = TcpSocket::new_v6()?;
+///     socket.set_reuseaddr(true)?;
+///     socket.set_only_v6(false)?;
+///     socket.set_keepalive(Some(TcpKeepalive::new().with_time(Duration::from_secs(30))))?;
+///     socket.bind("[::]:8080".parse().unwrap())?;
+///
+///     let listener = socket.listen(1024)?;
+///     for stream in listener.incoming() {
+///         let _stream = stream?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "tcp_socket", issue = "none")]
+pub struct TcpSocket(net_imp::TcpSocket);
+
+impl TcpSocket {
+    /// Creates a new IPv4 TCP socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn new_v4() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0))).map(TcpSocket)
+    }
+
+    /// Creates a new IPv6 TCP socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn new_v6() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0))).map(TcpSocket)
+    }
+
+    /// Binds the socket to a local address.
+    ///
+    /// Binding with a port number of 0 will request that the OS assigns a port
+    /// to this socket; use [`TcpSocket::local_addr`] to find out which.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn bind(&self, addr: SocketAddr) -> io::Result<()> {
+        self.0.bind(&addr)
+    }
+
+    /// Opens a TCP connection to `addr`, turning the socket into a
+    /// [`TcpStream`].
+    ///
+    /// If the socket has not been bound, the OS picks a local address.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn connect(self, addr: SocketAddr) -> io::Result<TcpStream> {
+        self.0.connect(&addr).map(TcpStream)
+    }
+
+    /// Starts listening for connections, turning the socket into a
+    /// [`TcpListener`].
+    ///
+    /// `backlog` is the maximum number of pending connections; the OS may
+    /// lower it.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        self.0.listen(backlog).map(TcpListener)
+    }
+
+    /// Returns the local address that this socket is bound to.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On Unix, this allows binding to an address whose previous socket is
+    /// still in the `TIME_WAIT` state. On Windows, it allows binding to an
+    /// address that another socket is actively using, which lets that
+    /// socket's traffic be taken over.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// When set on all of them, several sockets can be bound to the same
+    /// address, and incoming connections are distributed among them.
+    ///
+    /// Returns an [`Unsupported`] error on platforms without `SO_REUSEPORT`,
+    /// such as Windows.
+    ///
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// Always returns `false` on platforms without `SO_REUSEPORT`.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the size of the kernel's receive buffer, using the `SO_RCVBUF`
+    /// option.
+    ///
+    /// Setting this before [`listen`] or [`connect`] lets the OS take it into
+    /// account for the TCP window scale of the connection.
+    ///
+    /// [`listen`]: TcpSocket::listen
+    /// [`connect`]: TcpSocket::connect
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's receive buffer, from the `SO_RCVBUF`
+    /// option.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the kernel's send buffer, using the `SO_SNDBUF`
+    /// option.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's send buffer, from the `SO_SNDBUF`
+    /// option.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Enables or disables TCP keepalive probes on this socket.
+    ///
+    /// Connections accepted from a listener inherit this setting. For more
+    /// information, see [`TcpStream::set_keepalive`].
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: Option<TcpKeepalive>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive.as_ref())
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// For more information, see [`TcpStream::set_linger`].
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.0.set_linger(linger)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.0.linger()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information, see [`TcpStream::set_nodelay`].
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Sets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If this is set to `true` then the socket is restricted to sending and
+    /// receiving IPv6 packets only. An IPv6 socket bound to the unspecified
+    /// address can then not accept IPv4 connections. The default depends on
+    /// the platform. This option can only be set before binding.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    ///
+    /// This will retrieve the stored error in the underlying socket, clearing
+    /// the field in the process. This can be useful for checking errors between
+    /// calls.
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+impl AsInner<net_imp::TcpSocket> for TcpSocket {
+    #[inline]
+    fn as_inner(&self) -> &net_imp::TcpSocket {
+        &self.0
+    }
+}
+
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Parameters for TCP keepalive probes.
+///
+/// Used with [`TcpStream::set_keepalive`] and [`TcpSocket::set_keepalive`].
+/// Parameters that are not set keep the system default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(tcp_keepalive)]
+/// use std::net::TcpKeepalive;
+/// use std::time::Duration;
+///
+/// // Start probing after a minute of idleness, then every 10 seconds, and
+/// // drop the connection after 5 unanswered probes.
+/// let keepalive = TcpKeepalive::new()
+///     .with_time(Duration::from_secs(60))
+///     .with_interval(Duration::from_secs(10))
+///     .with_retries(5);
+/// ```
+#[unstable(feature = "tcp_keepalive", issue = "none")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TcpKeepalive {
+    pub(crate) time: Option<Duration>,
+    pub(crate) interval: Option<Duration>,
+    pub(crate) retries: Option<u32>,
+}
+
+impl TcpKeepalive {
+    /// Creates keepalive parameters that keep all system defaults.
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    #[must_use]
+    pub const fn new() -> TcpKeepalive {
+        TcpKeepalive { time: None, interval: None, retries: None }
+    }
+
+    /// Sets how long the connection must be idle before the first probe is
+    /// sent (`TCP_KEEPIDLE`).
+    ///
+    /// The time is rounded down to whole seconds, with a minimum of one
+    /// second.
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    #[must_use]
+    pub const fn with_time(self, time: Duration) -> TcpKeepalive {
+        TcpKeepalive { time: Some(time), ..self }
+    }
+
+    /// Sets the time between probes that go unanswered (`TCP_KEEPINTVL`).
+    ///
+    /// The interval is rounded down to whole seconds, with a minimum of one
+    /// second.
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    #[must_use]
+    pub const fn with_interval(self, interval: Duration) -> TcpKeepalive {
+        TcpKeepalive { interval: Some(interval), ..self }
+    }
+
+    /// Sets how many unanswered probes are sent before the connection is
+    /// dropped (`TCP_KEEPCNT`).
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    #[must_use]
+    pub const fn with_retries(self, retries: u32) -> TcpKeepalive {
+        TcpKeepalive { retries: Some(retries), ..self }
+    }
+}
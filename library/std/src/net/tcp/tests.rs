@@ -917,3 +917,49 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn tcp_socket_listen_and_connect() {
+    let socket = t!(TcpSocket::new_v4());
+    t!(socket.set_reuseaddr(true));
+    assert!(t!(socket.reuseaddr()));
+    t!(socket.set_recv_buffer_size(64 * 1024));
+    assert!(t!(socket.recv_buffer_size()) >= 64 * 1024 / 2);
+    t!(socket.bind(next_test_ip4()));
+    let addr = t!(socket.local_addr());
+    let listener = t!(socket.listen(16));
+
+    let socket = t!(TcpSocket::new_v4());
+    t!(socket.set_nodelay(true));
+    t!(socket.set_keepalive(Some(TcpKeepalive::new())));
+    let mut stream = t!(socket.connect(addr));
+    assert!(t!(stream.nodelay()));
+    assert!(t!(stream.keepalive()));
+    t!(stream.write_all(b"hello"));
+
+    let (mut accepted, _) = t!(listener.accept());
+    let mut buf = [0; 5];
+    t!(accepted.read_exact(&mut buf));
+    assert_eq!(&buf, b"hello");
+
+    t!(stream.set_keepalive(None));
+    assert!(!t!(stream.keepalive()));
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "macos"))]
+fn tcp_socket_keepalive_params_and_only_v6() {
+    let socket = t!(TcpSocket::new_v6());
+    t!(socket.set_only_v6(true));
+    assert!(t!(socket.only_v6()));
+    t!(socket.set_reuseport(true));
+    assert!(t!(socket.reuseport()));
+
+    let keepalive = TcpKeepalive::new()
+        .with_time(Duration::from_secs(30))
+        .with_interval(Duration::from_secs(5))
+        .with_retries(3);
+    t!(socket.set_keepalive(Some(keepalive)));
+    assert!(t!(socket.keepalive()));
+}
@@ -428,6 +428,56 @@ pub fn broadcast(&self) -> io::Result<bool> {
         self.0.broadcast()
     }
 
+    /// Sets the size of the kernel's receive buffer for this socket, using the
+    /// `SO_RCVBUF` option.
+    ///
+    /// The operating system may round, double or clamp the requested size;
+    /// use [`UdpSocket::recv_buffer_size`] to find out the actual size.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_recv_buffer_size(1 << 20).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's receive buffer for this socket, from the
+    /// `SO_RCVBUF` option.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_recv_buffer_size`].
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the kernel's send buffer for this socket, using the
+    /// `SO_SNDBUF` option.
+    ///
+    /// The operating system may round, double or clamp the requested size;
+    /// use [`UdpSocket::send_buffer_size`] to find out the actual size.
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's send buffer for this socket, from the
+    /// `SO_SNDBUF` option.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocket::set_send_buffer_size`].
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
     /// Sets the value of the `IP_MULTICAST_LOOP` option for this socket.
     ///
     /// If enabled, multicast packets will be looped back to the local socket.
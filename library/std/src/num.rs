@@ -35,6 +35,9 @@
 #[stable(feature = "int_error_matching", since = "1.55.0")]
 pub use core::num::IntErrorKind;
 
+#[unstable(feature = "bigint", issue = "none")]
+pub use core::num::{Int, Uint, I256, I512, U256, U512};
+
 #[cfg(test)]
 use crate::fmt;
 #[cfg(test)]
@@ -16,6 +16,14 @@ fn as_raw_fd(&self) -> RawFd {
 }
 impl_as_raw_fd! { TcpStream TcpListener UdpSocket }
 
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl AsRawFd for net::TcpSocket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().socket().as_raw_fd()
+    }
+}
+
 macro_rules! impl_from_raw_fd {
     ($($t:ident)*) => {$(
         #[stable(feature = "from_raw_os", since = "1.1.0")]
@@ -347,6 +347,14 @@ fn as_fd(&self) -> BorrowedFd<'_> {
     }
 }
 
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl AsFd for crate::net::TcpSocket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().socket().as_fd()
+    }
+}
+
 #[stable(feature = "io_safety", since = "1.63.0")]
 impl From<crate::net::TcpListener> for OwnedFd {
     /// Takes ownership of a [`TcpListener`](crate::net::TcpListener)'s socket file descriptor.
@@ -4,6 +4,7 @@
 
 #![unstable(feature = "linux_pidfd", issue = "82971")]
 
+use crate::ffi::OsStr;
 use crate::io::Result;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use crate::process::{self, ExitStatus};
@@ -205,6 +206,95 @@ pub trait CommandExt: Sealed {
     /// [`pidfd`]: fn@ChildExt::pidfd
     /// [`into_pidfd`]: ChildExt::into_pidfd
     fn create_pidfd(&mut self, val: bool) -> &mut process::Command;
+
+    /// Makes the child the leader of a new session, detached from the
+    /// controlling terminal of this process, by calling `setsid` before
+    /// `exec`.
+    ///
+    /// A session leader cannot be moved to another process group, so this
+    /// cannot be combined with [`process_group`].
+    ///
+    /// [`process_group`]: crate::os::unix::process::CommandExt::process_group
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
+
+    /// Sets the soft and hard limits of `resource` for the child with
+    /// `setrlimit` before `exec`. Can be called repeatedly to set several
+    /// limits.
+    ///
+    /// Raising a hard limit requires the `CAP_SYS_RESOURCE` capability.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_command_ext)]
+    /// use std::os::linux::process::{CommandExt, Resource};
+    /// use std::process::Command;
+    ///
+    /// let status = Command::new("./untrusted-test")
+    ///     .setrlimit(Resource::Cpu, 10, 10)
+    ///     .setrlimit(Resource::Core, 0, 0)
+    ///     .status()
+    ///     .expect("failed to run test worker");
+    /// ```
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn setrlimit(&mut self, resource: Resource, soft: u64, hard: u64) -> &mut process::Command;
+
+    /// Changes the root directory of the child to `dir` with `chroot` before
+    /// `exec`.
+    ///
+    /// The program is looked up, and [`current_dir`] resolved, inside the new
+    /// root. If no working directory is set, the child starts in `/`.
+    ///
+    /// This requires the `CAP_SYS_CHROOT` capability, which is usually only
+    /// available to root or inside of a new user namespace.
+    ///
+    /// [`current_dir`]: process::Command::current_dir
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn chroot<P: AsRef<OsStr>>(&mut self, dir: P) -> &mut process::Command;
+
+    /// Moves the child into a new namespace of the given kind with `unshare`
+    /// before `exec`. Can be called repeatedly to unshare several namespaces.
+    ///
+    /// Namespaces are unshared before the child switches to the user and
+    /// groups set with [`uid`] and [`gid`], so the privileges of this process
+    /// apply.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_command_ext)]
+    /// use std::os::linux::process::{CommandExt, Namespace};
+    /// use std::process::Command;
+    ///
+    /// // Run the worker without network access.
+    /// let status = Command::new("./test-worker")
+    ///     .unshare(Namespace::Network)
+    ///     .status()
+    ///     .expect("failed to run test worker");
+    /// ```
+    ///
+    /// [`uid`]: crate::os::unix::process::CommandExt::uid
+    /// [`gid`]: crate::os::unix::process::CommandExt::gid
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn unshare(&mut self, namespace: Namespace) -> &mut process::Command;
+
+    /// Sets the signal the child receives when this thread's process exits,
+    /// using `prctl(PR_SET_PDEATHSIG)`.
+    ///
+    /// Note that Linux sends the signal when the *thread* that spawned the
+    /// child exits, not the whole process. If this process has already exited
+    /// by the time the child is set up, the signal is sent right away.
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn parent_death_signal(&mut self, signal: i32) -> &mut process::Command;
+
+    /// Sets whether the child starts with an empty signal mask and the default
+    /// disposition for every signal, instead of inheriting them.
+    ///
+    /// By default the signal mask is inherited, as are ignored signals other
+    /// than `SIGPIPE`.
+    #[unstable(feature = "linux_command_ext", issue = "none")]
+    fn reset_signals(&mut self, reset: bool) -> &mut process::Command;
 }
 
 impl CommandExt for process::Command {
@@ -212,4 +302,120 @@ fn create_pidfd(&mut self, val: bool) -> &mut process::Command {
         self.as_inner_mut().create_pidfd(val);
         self
     }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().linux_options().setsid = setsid;
+        self
+    }
+
+    fn setrlimit(&mut self, resource: Resource, soft: u64, hard: u64) -> &mut process::Command {
+        self.as_inner_mut().linux_options().rlimits.push((resource.as_raw(), soft, hard));
+        self
+    }
+
+    fn chroot<P: AsRef<OsStr>>(&mut self, dir: P) -> &mut process::Command {
+        self.as_inner_mut().chroot(dir.as_ref());
+        self
+    }
+
+    fn unshare(&mut self, namespace: Namespace) -> &mut process::Command {
+        self.as_inner_mut().linux_options().unshare |= namespace.as_raw();
+        self
+    }
+
+    fn parent_death_signal(&mut self, signal: i32) -> &mut process::Command {
+        self.as_inner_mut().linux_options().parent_death_signal = Some(signal);
+        self
+    }
+
+    fn reset_signals(&mut self, reset: bool) -> &mut process::Command {
+        self.as_inner_mut().linux_options().reset_signals = reset;
+        self
+    }
+}
+
+/// A resource whose usage can be limited with [`CommandExt::setrlimit`].
+///
+/// See `setrlimit(2)` for the meaning of each resource.
+#[unstable(feature = "linux_command_ext", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Resource {
+    /// The maximum size of the address space, in bytes (`RLIMIT_AS`).
+    AddressSpace,
+    /// The maximum size of core dumps, in bytes (`RLIMIT_CORE`).
+    Core,
+    /// The maximum CPU time, in seconds (`RLIMIT_CPU`).
+    Cpu,
+    /// The maximum size of the data segment, in bytes (`RLIMIT_DATA`).
+    Data,
+    /// The maximum size of files that can be created, in bytes
+    /// (`RLIMIT_FSIZE`).
+    FileSize,
+    /// The maximum number of bytes that can be locked in memory
+    /// (`RLIMIT_MEMLOCK`).
+    MemLock,
+    /// One more than the largest file descriptor that can be opened
+    /// (`RLIMIT_NOFILE`).
+    NoFile,
+    /// The maximum number of threads of the user (`RLIMIT_NPROC`).
+    Processes,
+    /// The maximum size of the stack, in bytes (`RLIMIT_STACK`).
+    Stack,
+}
+
+impl Resource {
+    fn as_raw(self) -> i32 {
+        (match self {
+            Resource::AddressSpace => libc::RLIMIT_AS,
+            Resource::Core => libc::RLIMIT_CORE,
+            Resource::Cpu => libc::RLIMIT_CPU,
+            Resource::Data => libc::RLIMIT_DATA,
+            Resource::FileSize => libc::RLIMIT_FSIZE,
+            Resource::MemLock => libc::RLIMIT_MEMLOCK,
+            Resource::NoFile => libc::RLIMIT_NOFILE,
+            Resource::Processes => libc::RLIMIT_NPROC,
+            Resource::Stack => libc::RLIMIT_STACK,
+        }) as i32
+    }
+}
+
+/// A kind of namespace the child can be moved into with
+/// [`CommandExt::unshare`].
+///
+/// See `namespaces(7)` for what each namespace isolates.
+#[unstable(feature = "linux_command_ext", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Namespace {
+    /// Control groups (`CLONE_NEWCGROUP`).
+    Cgroup,
+    /// System V IPC and POSIX message queues (`CLONE_NEWIPC`).
+    Ipc,
+    /// Mount points (`CLONE_NEWNS`).
+    Mount,
+    /// Network devices, addresses, ports and firewall rules
+    /// (`CLONE_NEWNET`).
+    Network,
+    /// Process IDs (`CLONE_NEWPID`). Only children of the child are placed
+    /// in the new namespace, the child itself is not.
+    Pid,
+    /// User and group IDs (`CLONE_NEWUSER`).
+    User,
+    /// Host and domain names (`CLONE_NEWUTS`).
+    Uts,
+}
+
+impl Namespace {
+    fn as_raw(self) -> i32 {
+        match self {
+            Namespace::Cgroup => libc::CLONE_NEWCGROUP,
+            Namespace::Ipc => libc::CLONE_NEWIPC,
+            Namespace::Mount => libc::CLONE_NEWNS,
+            Namespace::Network => libc::CLONE_NEWNET,
+            Namespace::Pid => libc::CLONE_NEWPID,
+            Namespace::User => libc::CLONE_NEWUSER,
+            Namespace::Uts => libc::CLONE_NEWUTS,
+        }
+    }
 }
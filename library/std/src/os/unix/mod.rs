@@ -95,6 +95,7 @@ mod platform {
 pub mod net;
 pub mod process;
 pub mod raw;
+pub mod signal;
 pub mod thread;
 
 /// A prelude for conveniently writing platform-specific code.
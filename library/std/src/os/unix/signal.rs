@@ -0,0 +1,233 @@
+//! Unix-specific handling of asynchronous signals.
+//!
+//! A [`Signals`] handle registers interest in a set of signals, such as
+//! [`SIGTERM`] or [`SIGCHLD`]. Instead of running code in a signal handler,
+//! where almost nothing is allowed, each delivered signal is queued on the
+//! handle and can be received from ordinary code, either by blocking in
+//! [`Signals::wait`] or by polling the handle's file descriptor alongside other
+//! I/O.
+//!
+//! Any number of handles may exist at the same time, including several for the
+//! same signal; each of them receives every signal it registered for. While at
+//! least one handle is registered for a signal, its default action (such as
+//! terminating the process) is not taken. A handler function that was
+//! installed for the signal before is still called.
+//!
+//! Signals are coalesced: if a signal is delivered several times before it is
+//! received from a handle, it may be reported only once.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! #![feature(unix_signals)]
+//! use std::os::unix::signal::{Signals, SIGINT, SIGTERM};
+//!
+//! let signals = Signals::new(&[SIGINT, SIGTERM])?;
+//! for signal in &signals {
+//!     println!("received signal {}, shutting down", signal?);
+//!     break;
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+#![unstable(feature = "unix_signals", issue = "none")]
+
+use crate::fmt;
+use crate::io;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
+use crate::sys::signals as imp;
+
+#[cfg(all(
+    test,
+    not(any(
+        target_os = "espidf",
+        target_os = "horizon",
+        target_os = "l4re",
+        target_os = "vita",
+        target_os = "vxworks",
+    ))
+))]
+mod tests;
+
+/// Hangup detected on the controlling terminal, or death of the controlling
+/// process.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGHUP: i32 = libc::SIGHUP;
+/// Interrupt from the keyboard, usually Ctrl-C.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGINT: i32 = libc::SIGINT;
+/// Quit from the keyboard, usually Ctrl-\\.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGQUIT: i32 = libc::SIGQUIT;
+/// Write to a pipe with no readers.
+///
+/// The standard library ignores this signal by default, so writes return an
+/// [`io::ErrorKind::BrokenPipe`] error instead.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGPIPE: i32 = libc::SIGPIPE;
+/// Timer signal from `alarm`.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGALRM: i32 = libc::SIGALRM;
+/// Termination request.
+#[unstable(feature = "unix_signals", issue = "none")]
+pub const SIGTERM: i32 = libc::SIGTERM;
+/// User-defined signal 1.
+#[unstable(feature = "unix_signals", issue = "none")]
+#[cfg(not(any(target_os = "l4re", target_os = "vxworks")))]
+pub const SIGUSR1: i32 = libc::SIGUSR1;
+/// User-defined signal 2.
+#[unstable(feature = "unix_signals", issue = "none")]
+#[cfg(not(any(target_os = "l4re", target_os = "vxworks")))]
+pub const SIGUSR2: i32 = libc::SIGUSR2;
+/// A child process stopped or terminated.
+#[unstable(feature = "unix_signals", issue = "none")]
+#[cfg(not(target_os = "l4re"))]
+pub const SIGCHLD: i32 = libc::SIGCHLD;
+/// Continue if stopped.
+#[unstable(feature = "unix_signals", issue = "none")]
+#[cfg(not(target_os = "l4re"))]
+pub const SIGCONT: i32 = libc::SIGCONT;
+/// Terminal window size changed.
+#[unstable(feature = "unix_signals", issue = "none")]
+#[cfg(not(any(target_os = "l4re", target_os = "vxworks")))]
+pub const SIGWINCH: i32 = libc::SIGWINCH;
+
+/// A registration for a set of signals, through which they are received.
+///
+/// The handle owns a file descriptor that becomes readable whenever a signal
+/// is pending, so it can be waited on with `poll`, `epoll` or `kqueue`
+/// together with other file descriptors. Only [`wait`], [`try_wait`] and the
+/// iterators should be used to read from it.
+///
+/// Dropping the handle unregisters it. When the last handle for a signal is
+/// dropped, the signal's previous disposition is restored.
+///
+/// [`wait`]: Signals::wait
+/// [`try_wait`]: Signals::try_wait
+#[unstable(feature = "unix_signals", issue = "none")]
+pub struct Signals {
+    inner: imp::Signals,
+}
+
+impl Signals {
+    /// Registers interest in `signals`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidInput`] error for signals that cannot be caught
+    /// (`SIGKILL` and `SIGSTOP`), and for signals generated by faults
+    /// (`SIGSEGV`, `SIGBUS`, `SIGILL` and `SIGFPE`), which have to be handled
+    /// synchronously and are used by the standard library to detect stack
+    /// overflows.
+    ///
+    /// Returns an [`Unsupported`] error on platforms without signal handlers.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "unix_signals", issue = "none")]
+    pub fn new(signals: &[i32]) -> io::Result<Signals> {
+        imp::Signals::new(signals).map(|inner| Signals { inner })
+    }
+
+    /// Blocks until one of the registered signals is delivered, and returns
+    /// its number.
+    #[unstable(feature = "unix_signals", issue = "none")]
+    pub fn wait(&self) -> io::Result<i32> {
+        self.inner.wait()
+    }
+
+    /// Returns the number of a pending signal, or `None` if no registered
+    /// signal has been delivered since it was last received.
+    ///
+    /// This function never blocks.
+    #[unstable(feature = "unix_signals", issue = "none")]
+    pub fn try_wait(&self) -> io::Result<Option<i32>> {
+        self.inner.try_wait()
+    }
+
+    /// Returns an iterator over the signals that are currently pending.
+    ///
+    /// The iterator never blocks, and ends once there are no more pending
+    /// signals or reading them fails.
+    #[unstable(feature = "unix_signals", issue = "none")]
+    pub fn pending(&self) -> Pending<'_> {
+        Pending { signals: self }
+    }
+
+    /// Returns an iterator that blocks until each next signal is delivered.
+    ///
+    /// The iterator never returns [`None`].
+    #[unstable(feature = "unix_signals", issue = "none")]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { signals: self }
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl<'a> IntoIterator for &'a Signals {
+    type Item = io::Result<i32>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl AsFd for Signals {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.inner.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl AsRawFd for Signals {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_fd().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl fmt::Debug for Signals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signals").field("fd", &self.as_raw_fd()).finish_non_exhaustive()
+    }
+}
+
+/// An iterator over the pending signals of a [`Signals`] handle.
+///
+/// This struct is created by [`Signals::pending`].
+#[unstable(feature = "unix_signals", issue = "none")]
+#[derive(Debug)]
+pub struct Pending<'a> {
+    signals: &'a Signals,
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl Iterator for Pending<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        self.signals.try_wait().ok().flatten()
+    }
+}
+
+/// A blocking iterator over the signals of a [`Signals`] handle.
+///
+/// This struct is created by [`Signals::iter`].
+#[unstable(feature = "unix_signals", issue = "none")]
+#[derive(Debug)]
+pub struct Iter<'a> {
+    signals: &'a Signals,
+}
+
+#[unstable(feature = "unix_signals", issue = "none")]
+impl Iterator for Iter<'_> {
+    type Item = io::Result<i32>;
+
+    fn next(&mut self) -> Option<io::Result<i32>> {
+        Some(self.signals.wait())
+    }
+}
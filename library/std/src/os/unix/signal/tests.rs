@@ -0,0 +1,47 @@
+use super::*;
+use crate::{mem, ptr};
+
+fn handler(signal: i32) -> libc::sighandler_t {
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        libc::sigaction(signal, ptr::null(), &mut action);
+        action.sa_sigaction
+    }
+}
+
+#[test]
+fn receive_raised_signals() {
+    let first = Signals::new(&[SIGUSR2, SIGWINCH]).unwrap();
+    let second = Signals::new(&[SIGUSR2]).unwrap();
+    assert_eq!(first.try_wait().unwrap(), None);
+
+    unsafe { libc::raise(SIGUSR2) };
+    assert_eq!(first.wait().unwrap(), SIGUSR2);
+    assert_eq!(second.pending().collect::<Vec<_>>(), [SIGUSR2]);
+
+    unsafe { libc::raise(SIGWINCH) };
+    assert_eq!(first.pending().collect::<Vec<_>>(), [SIGWINCH]);
+    assert_eq!(second.try_wait().unwrap(), None);
+}
+
+#[test]
+fn restore_previous_disposition() {
+    unsafe { libc::signal(SIGUSR1, libc::SIG_IGN) };
+
+    let signals = Signals::new(&[SIGUSR1, SIGUSR1]).unwrap();
+    assert_ne!(handler(SIGUSR1), libc::SIG_IGN);
+    unsafe { libc::raise(SIGUSR1) };
+    assert_eq!(signals.try_wait().unwrap(), Some(SIGUSR1));
+    drop(signals);
+
+    assert_eq!(handler(SIGUSR1), libc::SIG_IGN);
+    unsafe { libc::signal(SIGUSR1, libc::SIG_DFL) };
+}
+
+#[test]
+fn reject_fault_signals() {
+    for signal in [libc::SIGSEGV, libc::SIGBUS, libc::SIGKILL, 0, -1] {
+        let err = Signals::new(&[signal]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -256,6 +256,14 @@ fn as_raw_socket(&self) -> RawSocket {
     }
 }
 
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl AsRawSocket for net::TcpSocket {
+    #[inline]
+    fn as_raw_socket(&self) -> RawSocket {
+        self.as_inner().socket().as_raw_socket()
+    }
+}
+
 #[stable(feature = "from_raw_os", since = "1.1.0")]
 impl FromRawSocket for net::TcpStream {
     #[inline]
@@ -359,6 +359,14 @@ fn from(owned: OwnedSocket) -> Self {
     }
 }
 
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl AsSocket for crate::net::TcpSocket {
+    #[inline]
+    fn as_socket(&self) -> BorrowedSocket<'_> {
+        unsafe { BorrowedSocket::borrow_raw(self.as_raw_socket()) }
+    }
+}
+
 #[stable(feature = "io_safety", since = "1.63.0")]
 impl AsSocket for crate::net::UdpSocket {
     #[inline]
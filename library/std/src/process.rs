@@ -166,6 +166,7 @@
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::{Duration, Instant};
 
 /// Representation of a running or exited child process.
 ///
@@ -2219,6 +2220,69 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit for at most `timeout`, returning the status
+    /// that it exited with, or `None` if it is still running when the timeout
+    /// elapses.
+    ///
+    /// Like [`wait`], this function closes the stdin handle to the child
+    /// process, if any, before waiting. If the child has exited, then on Unix
+    /// the process ID is reaped.
+    ///
+    /// See [`wait_deadline`] for details on how the wait is implemented.
+    ///
+    /// [`wait`]: Child::wait
+    /// [`wait_deadline`]: Child::wait_deadline
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("1000").spawn()?;
+    /// if child.wait_timeout(Duration::from_secs(5))?.is_none() {
+    ///     child.kill()?;
+    ///     child.wait()?;
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.wait_deadline(deadline),
+            None => self.wait().map(Some),
+        }
+    }
+
+    /// Waits for the child to exit until `deadline`, returning the status that
+    /// it exited with, or `None` if it is still running at the deadline.
+    ///
+    /// Like [`wait`], this function closes the stdin handle to the child
+    /// process, if any, before waiting. If the child has exited, then on Unix
+    /// the process ID is reaped.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux, this function waits on a pidfd for the child, opening one if
+    /// the child was not spawned with one. On macOS and the BSDs, it waits for
+    /// the child's `EVFILT_PROC` event on a kqueue. On Windows, it waits on the
+    /// process handle.
+    ///
+    /// On other Unix platforms, or if the above are not available, it installs
+    /// a `SIGCHLD` handler for the whole process while it is waiting, chaining
+    /// to any previously installed handler, and falls back to polling if that
+    /// is not possible. Changing the `SIGCHLD` disposition from another thread
+    /// during the wait is racy: the change may be undone when this function
+    /// restores the previous handler.
+    ///
+    /// [`wait`]: Child::wait
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        drop(self.stdin.take());
+        Ok(self.handle.wait_deadline(deadline)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
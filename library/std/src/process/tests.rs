@@ -55,6 +55,31 @@ fn exit_reported_right() {
     drop(p.wait());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn wait_timeout() {
+    use crate::time::{Duration, Instant};
+
+    let mut p = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "ping -n 1000 127.0.0.1 >nul"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("sleep 1000").spawn().unwrap()
+    };
+    assert!(p.wait_timeout(Duration::from_millis(10)).unwrap().is_none());
+    assert!(p.wait_deadline(Instant::now()).unwrap().is_none());
+    p.kill().unwrap();
+    assert!(!p.wait_timeout(Duration::MAX).unwrap().unwrap().success());
+
+    let mut p = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "exit 1"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("false").spawn().unwrap()
+    };
+    let status = p.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+    assert_eq!(status.code(), Some(1));
+    assert_eq!(p.wait_timeout(Duration::ZERO).unwrap(), Some(status));
+}
+
 #[test]
 #[cfg(unix)]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests;
+
+use crate::sealed::Sealed;
+use crate::sync::atomic::AtomicU32;
+use crate::sync::atomic::Ordering::Acquire;
+use crate::sys::sync::atomic_wait as imp;
+use crate::time::{Duration, Instant};
+
+/// Blocking until an atomic changes, and waking threads blocked that way.
+///
+/// This is the building block of lock-free data structures that need to put
+/// threads to sleep, similar to `std::atomic::wait` in C++20. A thread that
+/// finds the atomic in a state it cannot make progress in calls [`wait`] with
+/// the value it saw, and a thread that changes the value calls [`notify_one`]
+/// or [`notify_all`] afterwards.
+///
+/// Waiting only compares the value of the atomic: if it changes and changes
+/// back before the waiting thread observes it, the thread keeps waiting.
+///
+/// This trait is sealed and cannot be implemented outside the standard
+/// library.
+///
+/// # Platform-specific behavior
+///
+/// This is currently implemented with the same primitive as [`Mutex`]: a
+/// futex on Linux, Android, FreeBSD, OpenBSD, DragonFly, Fuchsia and Hermit,
+/// `WaitOnAddress` on Windows, and `memory.atomic.wait32` on WebAssembly with
+/// threads. Elsewhere, waiting threads block on one of a fixed set of
+/// condition variables chosen by the address of the atomic. Note that, this
+/// [may change in the future][changes].
+///
+/// [`wait`]: AtomicWait::wait
+/// [`notify_one`]: AtomicWait::notify_one
+/// [`notify_all`]: AtomicWait::notify_all
+/// [`Mutex`]: crate::sync::Mutex
+/// [changes]: crate::io#platform-specific-behavior
+///
+/// # Examples
+///
+/// ```
+/// #![feature(atomic_wait)]
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::sync::AtomicWait;
+/// use std::thread;
+///
+/// let ready = AtomicU32::new(0);
+/// thread::scope(|s| {
+///     s.spawn(|| {
+///         ready.store(1, Ordering::Release);
+///         ready.notify_all();
+///     });
+///     ready.wait(0);
+///     assert_eq!(ready.load(Ordering::Acquire), 1);
+/// });
+/// ```
+#[unstable(feature = "atomic_wait", issue = "none")]
+pub trait AtomicWait: Sealed {
+    /// The type of the value held by the atomic.
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    type Value;
+
+    /// Blocks the current thread for as long as the atomic holds `expected`.
+    ///
+    /// Returns immediately if the atomic holds another value. Otherwise, the
+    /// thread sleeps until it is woken by [`notify_one`] or [`notify_all`]
+    /// and finds that the value changed. The value is loaded with [`Acquire`]
+    /// ordering.
+    ///
+    /// [`notify_one`]: AtomicWait::notify_one
+    /// [`notify_all`]: AtomicWait::notify_all
+    /// [`Acquire`]: crate::sync::atomic::Ordering::Acquire
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn wait(&self, expected: Self::Value);
+
+    /// Blocks the current thread for as long as the atomic holds `expected`,
+    /// but for at most `timeout`.
+    ///
+    /// Returns `true` if the atomic holds another value, and `false` if the
+    /// timeout elapsed first. See [`wait`](AtomicWait::wait) for details.
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn wait_timeout(&self, expected: Self::Value, timeout: Duration) -> bool;
+
+    /// Wakes up at least one thread blocked waiting on this atomic, if any.
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn notify_one(&self);
+
+    /// Wakes up all threads blocked waiting on this atomic.
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn notify_all(&self);
+}
+
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl Sealed for AtomicU32 {}
+
+#[unstable(feature = "atomic_wait", issue = "none")]
+impl AtomicWait for AtomicU32 {
+    type Value = u32;
+
+    fn wait(&self, expected: u32) {
+        while self.load(Acquire) == expected {
+            imp::wait(self, expected, None);
+        }
+    }
+
+    fn wait_timeout(&self, expected: u32, timeout: Duration) -> bool {
+        // Overflows are rounded up to an infinite timeout.
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            self.wait(expected);
+            return true;
+        };
+        while self.load(Acquire) == expected {
+            let Some(timeout) = deadline.checked_duration_since(Instant::now()) else {
+                return false;
+            };
+            imp::wait(self, expected, Some(timeout));
+        }
+        true
+    }
+
+    fn notify_one(&self) {
+        imp::notify_one(self)
+    }
+
+    fn notify_all(&self) {
+        imp::notify_all(self)
+    }
+}
@@ -0,0 +1,44 @@
+use super::AtomicWait;
+use crate::sync::atomic::{AtomicU32, Ordering};
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn wait_returns_on_other_value() {
+    let atomic = AtomicU32::new(1);
+    atomic.wait(0);
+    assert!(atomic.wait_timeout(0, Duration::from_secs(60)));
+}
+
+#[test]
+fn wait_timeout_elapses() {
+    let atomic = AtomicU32::new(0);
+    let start = Instant::now();
+    assert!(!atomic.wait_timeout(0, Duration::from_millis(50)));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn notify_wakes_waiters() {
+    let atomic = AtomicU32::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                atomic.wait(0);
+                assert_eq!(atomic.load(Ordering::Relaxed), 1);
+            });
+        }
+        thread::sleep(Duration::from_millis(10));
+        atomic.store(1, Ordering::Release);
+        atomic.notify_all();
+    });
+
+    let woken = thread::scope(|s| {
+        let waiter = s.spawn(|| atomic.wait_timeout(1, Duration::from_secs(60)));
+        thread::sleep(Duration::from_millis(10));
+        atomic.store(2, Ordering::Release);
+        atomic.notify_one();
+        waiter.join()
+    });
+    assert!(woken.unwrap());
+}
@@ -165,6 +165,8 @@
 #[unstable(feature = "exclusive_wrapper", issue = "98407")]
 pub use core::sync::Exclusive;
 
+#[unstable(feature = "atomic_wait", issue = "none")]
+pub use self::atomic_wait::AtomicWait;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::barrier::{Barrier, BarrierWaitResult};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -193,6 +195,7 @@
 
 pub mod mpsc;
 
+mod atomic_wait;
 mod barrier;
 mod condvar;
 mod lazy_lock;
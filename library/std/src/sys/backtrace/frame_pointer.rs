@@ -0,0 +1,118 @@
+//! Stack walking through frame pointers.
+//!
+//! This is used in place of the `backtrace` crate's unwinder on targets where
+//! it cannot walk the stack, if the standard library was built with the
+//! `backtrace-frame-pointers` feature. Frames found this way are symbolized by
+//! address if the symbolizer supports the target, and are otherwise printed as
+//! raw addresses to be resolved offline. It only produces a useful trace if all
+//! code on the stack, including the standard library itself, was compiled with
+//! `-C force-frame-pointers=yes`.
+//!
+//! Every function then saves its caller's frame pointer next to its return
+//! address and points the frame pointer register at this record, so the
+//! records form a linked list through the stack. The list ends at a null frame
+//! pointer, which thread entry points are expected to set up. As a safeguard
+//! against corrupted or missing records, the walk also stops as soon as a
+//! record is misaligned or does not lie above the previous one on the stack,
+//! and after at most `MAX_DEPTH` frames.
+
+use crate::ffi::c_void;
+use crate::ptr;
+
+#[cfg(all(test, feature = "backtrace-frame-pointers"))]
+mod tests;
+
+/// Max number of frames to walk.
+const MAX_DEPTH: usize = 1024;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_arch = "x86",
+        all(target_arch = "x86_64", target_pointer_width = "64"),
+        all(target_arch = "aarch64", target_pointer_width = "64"),
+    ))] {
+        // The frame pointer points at the saved frame pointer, which is
+        // followed by the return address.
+        const PREV_FP: isize = 0;
+        const RETURN_ADDRESS: isize = 1;
+    } else if #[cfg(any(
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "loongarch64",
+    ))] {
+        // The frame pointer points just past the record, in which the return
+        // address is stored above the saved frame pointer.
+        const PREV_FP: isize = -2;
+        const RETURN_ADDRESS: isize = -1;
+    } else {
+        // Unused, `frame_address` always returns null.
+        const PREV_FP: isize = 0;
+        const RETURN_ADDRESS: isize = 1;
+    }
+}
+
+/// Returns the frame pointer of the calling function, or null if frame
+/// pointers cannot be walked on this architecture.
+#[inline(always)]
+fn frame_address() -> *const usize {
+    let fp: usize;
+    cfg_if::cfg_if! {
+        // The x32 and ILP32 ABIs save 64-bit frame pointers and return
+        // addresses, which the walk would read as two `usize`s each.
+        if #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))] {
+            unsafe { crate::arch::asm!("mov {}, rbp", out(reg) fp, options(nomem, nostack)) };
+        } else if #[cfg(target_arch = "x86")] {
+            unsafe { crate::arch::asm!("mov {}, ebp", out(reg) fp, options(nomem, nostack)) };
+        } else if #[cfg(all(target_arch = "aarch64", target_pointer_width = "64"))] {
+            unsafe { crate::arch::asm!("mov {}, x29", out(reg) fp, options(nomem, nostack)) };
+        } else if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
+            unsafe { crate::arch::asm!("mv {}, s0", out(reg) fp, options(nomem, nostack)) };
+        } else if #[cfg(target_arch = "loongarch64")] {
+            unsafe { crate::arch::asm!("move {}, $fp", out(reg) fp, options(nomem, nostack)) };
+        } else {
+            fp = 0;
+        }
+    }
+    ptr::with_exposed_provenance(fp)
+}
+
+/// Calls `cb` with the return address of each frame on the stack, starting
+/// with the one into the caller of `trace`, until `cb` returns `false` or the
+/// chain of frame records ends.
+///
+/// # Safety
+///
+/// All functions on the stack must maintain frame pointers, as described in
+/// the module documentation.
+#[inline(never)] // the walk starts at this function's own frame record
+pub unsafe fn trace(cb: impl FnMut(*mut c_void) -> bool) {
+    unsafe { walk(frame_address(), cb) }
+}
+
+/// Calls `cb` with the return address of each frame record in the chain
+/// starting at `fp`.
+///
+/// # Safety
+///
+/// Every aligned, non-null frame pointer in the chain must point to a valid
+/// frame record.
+unsafe fn walk(mut fp: *const usize, mut cb: impl FnMut(*mut c_void) -> bool) {
+    for _ in 0..MAX_DEPTH {
+        if fp.is_null() || !fp.is_aligned() {
+            break;
+        }
+
+        let ip = unsafe { *fp.offset(RETURN_ADDRESS) };
+        if ip == 0 || !cb(ptr::without_provenance_mut(ip)) {
+            break;
+        }
+
+        // The stack grows downwards, so the caller's record must be above
+        // this one.
+        let prev: *const usize = ptr::with_exposed_provenance(unsafe { *fp.offset(PREV_FP) });
+        if prev.addr() <= fp.addr() {
+            break;
+        }
+        fp = prev;
+    }
+}
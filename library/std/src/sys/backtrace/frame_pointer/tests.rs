@@ -0,0 +1,73 @@
+use super::*;
+
+/// Lays out a chain of frame records with the given return addresses, each
+/// linked to the one after it, and returns the stack and its frame pointers.
+fn chain(ips: &[usize]) -> (Vec<usize>, Vec<*mut usize>) {
+    // Both layouts store the saved frame pointer right below the return
+    // address.
+    assert_eq!(RETURN_ADDRESS, PREV_FP + 1);
+
+    let mut stack = vec![0; ips.len() * 2];
+    let base = stack.as_mut_ptr();
+    let fps: Vec<*mut usize> =
+        (0..ips.len()).map(|i| base.wrapping_add(i * 2).wrapping_offset(-PREV_FP)).collect();
+    for (i, &ip) in ips.iter().enumerate() {
+        let prev = fps.get(i + 1).map_or(0, |fp| fp.expose_provenance());
+        unsafe {
+            *fps[i].offset(PREV_FP) = prev;
+            *fps[i].offset(RETURN_ADDRESS) = ip;
+        }
+    }
+    (stack, fps)
+}
+
+fn walk_ips(fp: *mut usize) -> Vec<usize> {
+    let mut ips = Vec::new();
+    unsafe {
+        walk(fp, |ip| {
+            ips.push(ip.addr());
+            true
+        })
+    };
+    ips
+}
+
+#[test]
+fn walks_chain() {
+    let (_stack, fps) = chain(&[1, 2, 3]);
+    assert_eq!(walk_ips(fps[0]), [1, 2, 3]);
+}
+
+#[test]
+fn stops_when_callback_returns_false() {
+    let (_stack, fps) = chain(&[1, 2, 3]);
+    let mut ips = Vec::new();
+    unsafe {
+        walk(fps[0], |ip| {
+            ips.push(ip.addr());
+            ips.len() < 2
+        })
+    };
+    assert_eq!(ips, [1, 2]);
+}
+
+#[test]
+fn stops_at_max_depth() {
+    let ips: Vec<usize> = (1..=MAX_DEPTH + 10).collect();
+    let (_stack, fps) = chain(&ips);
+    assert_eq!(walk_ips(fps[0]), &ips[..MAX_DEPTH]);
+}
+
+#[test]
+fn stops_at_misaligned_record() {
+    let (_stack, fps) = chain(&[1, 2, 3]);
+    unsafe { *fps[1].offset(PREV_FP) = fps[2].expose_provenance() + 1 };
+    assert_eq!(walk_ips(fps[0]), [1, 2]);
+}
+
+#[test]
+fn stops_at_record_below_previous() {
+    let (_stack, fps) = chain(&[1, 2, 3]);
+    unsafe { *fps[1].offset(PREV_FP) = fps[0].expose_provenance() };
+    assert_eq!(walk_ips(fps[0]), [1, 2]);
+}
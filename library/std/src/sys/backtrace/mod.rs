@@ -1,14 +1,17 @@
 //! Common code for printing backtraces.
 
-use crate::backtrace_rs::{self, BacktraceFmt, BytesOrWideString, PrintFmt};
+use crate::backtrace_rs::{self, BacktraceFmt, BytesOrWideString, PrintFmt, Symbol};
 use crate::borrow::Cow;
 use crate::env;
+use crate::ffi::c_void;
 use crate::fmt;
 use crate::io;
 use crate::io::prelude::*;
 use crate::path::{self, Path, PathBuf};
 use crate::sync::{Mutex, PoisonError};
 
+pub mod frame_pointer;
+
 /// Max number of frames to print.
 const MAX_NB_FRAMES: usize = 100;
 
@@ -65,13 +68,15 @@ unsafe fn _print_fmt(fmt: &mut fmt::Formatter<'_>, print_fmt: PrintFmt) -> fmt::
     // Start immediately if we're not using a short backtrace.
     let mut start = print_fmt != PrintFmt::Short;
     set_image_base();
-    backtrace_rs::trace_unsynchronized(|frame| {
+    // Prints a frame, given its instruction pointer and a function that
+    // resolves it to symbols.
+    let mut print_frame = |ip: *mut c_void, resolve: &mut dyn FnMut(&mut dyn FnMut(&Symbol))| {
         if print_fmt == PrintFmt::Short && idx > MAX_NB_FRAMES {
             return false;
         }
 
         let mut hit = false;
-        backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
+        resolve(&mut |symbol| {
             hit = true;
 
             // Any frames between `__rust_begin_short_backtrace` and `__rust_end_short_backtrace`
@@ -109,15 +114,21 @@ unsafe fn _print_fmt(fmt: &mut fmt::Formatter<'_>, print_fmt: PrintFmt) -> fmt::
                     first_omit = false;
                     omitted_count = 0;
                 }
-                res = bt_fmt.frame().symbol(frame, symbol);
+                res = bt_fmt.frame().print_raw_with_column(
+                    ip,
+                    symbol.name(),
+                    symbol.filename_raw(),
+                    symbol.lineno(),
+                    symbol.colno(),
+                );
             }
         });
         #[cfg(target_os = "nto")]
-        if libc::__my_thread_exit as *mut libc::c_void == frame.ip() {
+        if libc::__my_thread_exit as *mut libc::c_void == ip {
             if !hit && start {
                 use crate::backtrace_rs::SymbolName;
                 res = bt_fmt.frame().print_raw(
-                    frame.ip(),
+                    ip,
                     Some(SymbolName::new("__my_thread_exit".as_bytes())),
                     None,
                     None,
@@ -126,12 +137,23 @@ unsafe fn _print_fmt(fmt: &mut fmt::Formatter<'_>, print_fmt: PrintFmt) -> fmt::
             return false;
         }
         if !hit && start {
-            res = bt_fmt.frame().print_raw(frame.ip(), None, None, None);
+            res = bt_fmt.frame().print_raw(ip, None, None, None);
         }
 
         idx += 1;
         res.is_ok()
+    };
+    let mut traced = false;
+    backtrace_rs::trace_unsynchronized(|frame| {
+        traced = true;
+        print_frame(frame.ip(), &mut |cb| backtrace_rs::resolve_frame_unsynchronized(frame, cb))
     });
+    // Fall back to frame pointers if the unwinder doesn't support this target.
+    if !traced && cfg!(feature = "backtrace-frame-pointers") {
+        frame_pointer::trace(|ip| {
+            print_frame(ip, &mut |cb| backtrace_rs::resolve_unsynchronized(ip, cb))
+        });
+    }
     res?;
     bt_fmt.finish()?;
     if print_fmt == PrintFmt::Short {
@@ -176,33 +198,39 @@ pub fn __rust_end_short_backtrace<F, T>(f: F) -> T
     result
 }
 
-/// Prints the filename of the backtrace frame.
-///
-/// See also `output`.
-pub fn output_filename(
-    fmt: &mut fmt::Formatter<'_>,
-    bows: BytesOrWideString<'_>,
-    print_fmt: PrintFmt,
-    cwd: Option<&PathBuf>,
-) -> fmt::Result {
-    let file: Cow<'_, Path> = match bows {
+/// Converts a file name reported by the symbolizer into a path, or returns
+/// `None` if it cannot be represented on this platform.
+pub fn filename_path(bows: BytesOrWideString<'_>) -> Option<Cow<'_, Path>> {
+    match bows {
         #[cfg(unix)]
         BytesOrWideString::Bytes(bytes) => {
             use crate::os::unix::prelude::*;
-            Path::new(crate::ffi::OsStr::from_bytes(bytes)).into()
+            Some(Path::new(crate::ffi::OsStr::from_bytes(bytes)).into())
         }
         #[cfg(not(unix))]
         BytesOrWideString::Bytes(bytes) => {
-            Path::new(crate::str::from_utf8(bytes).unwrap_or("<unknown>")).into()
+            crate::str::from_utf8(bytes).ok().map(|s| Path::new(s).into())
         }
         #[cfg(windows)]
         BytesOrWideString::Wide(wide) => {
             use crate::os::windows::prelude::*;
-            Cow::Owned(crate::ffi::OsString::from_wide(wide).into())
+            Some(Cow::Owned(crate::ffi::OsString::from_wide(wide).into()))
         }
         #[cfg(not(windows))]
-        BytesOrWideString::Wide(_wide) => Path::new("<unknown>").into(),
-    };
+        BytesOrWideString::Wide(_wide) => None,
+    }
+}
+
+/// Prints the filename of the backtrace frame.
+///
+/// See also `output`.
+pub fn output_filename(
+    fmt: &mut fmt::Formatter<'_>,
+    bows: BytesOrWideString<'_>,
+    print_fmt: PrintFmt,
+    cwd: Option<&PathBuf>,
+) -> fmt::Result {
+    let file = filename_path(bows).unwrap_or_else(|| Path::new("<unknown>").into());
     if print_fmt == PrintFmt::Short && file.is_absolute() {
         if let Some(cwd) = cwd {
             if let Ok(stripped) = file.strip_prefix(&cwd) {
@@ -18,7 +18,8 @@
 use crate::sys::unsupported;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 
-pub use crate::sys_common::fs::{copy, exists};
+pub use crate::sys_common::fs::{copy, copy_with, exists, Dir, Mmap};
+use crate::sys_common::fs::MapOptions;
 
 #[derive(Debug)]
 pub struct File(FileDesc);
@@ -372,6 +373,36 @@ pub fn truncate(&self, _size: u64) -> io::Result<()> {
         Err(Error::from_raw_os_error(22))
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn map(
+        &self,
+        _offset: u64,
+        _len: usize,
+        _writable: bool,
+        _opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        unsupported()
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -4,9 +4,10 @@
 use crate::cmp;
 use crate::io::{self, BorrowedBuf, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Shutdown, SocketAddr};
+use crate::net::{Shutdown, SocketAddr, TcpKeepalive};
 use crate::os::hermit::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, RawFd};
 use crate::sys::time::Instant;
+use crate::sys::unsupported;
 use crate::sys_common::net::{getsockopt, setsockopt, sockaddr_to_addr};
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -293,6 +294,33 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        match keepalive {
+            None => setsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE, 0 as i32),
+            Some(keepalive)
+                if keepalive.time.is_none()
+                    && keepalive.interval.is_none()
+                    && keepalive.retries.is_none() =>
+            {
+                setsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE, 1 as i32)
+            }
+            Some(_) => unsupported(),
+        }
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: i32 = getsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Ok(false)
+    }
+
     pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
         let mut nonblocking: i32 = if nonblocking { 1 } else { 0 };
         cvt(unsafe {
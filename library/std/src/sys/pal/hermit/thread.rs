@@ -70,6 +70,25 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        // Hermit pins a thread to its core when it is spawned and cannot
+        // migrate it afterwards.
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "changing the affinity of a running thread is not supported",
+        ))
+    }
+
+    pub fn set_priority(priority: i32) -> io::Result<()> {
+        let priority = u8::try_from(priority).map_err(|_| {
+            io::const_io_error!(io::ErrorKind::InvalidInput, "thread priority out of range")
+        })?;
+        unsafe {
+            hermit_abi::set_priority(hermit_abi::getpid(), hermit_abi::Priority::from(priority));
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn sleep(dur: Duration) {
         unsafe {
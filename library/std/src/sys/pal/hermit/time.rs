@@ -2,8 +2,10 @@
 
 use super::hermit_abi::{self, timespec, CLOCK_MONOTONIC, CLOCK_REALTIME};
 use crate::cmp::Ordering;
+use crate::io;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
-use crate::time::Duration;
+use crate::sys::unsupported;
+use crate::time::{Clock, Duration};
 use core::hash::{Hash, Hasher};
 
 const NSEC_PER_SEC: i32 = 1_000_000_000;
@@ -196,6 +198,16 @@ fn sub(self, other: Instant) -> Duration {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        Clock::Monotonic => {
+            let t = Instant::now().0.t;
+            Ok(Duration::new(t.tv_sec as u64, t.tv_nsec as u32))
+        }
+        _ => unsupported(),
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct SystemTime(Timespec);
 
@@ -143,6 +143,8 @@ pub struct T_CTSK {
     pub fn ter_tsk(tskid: ID) -> ER;
     #[link_name = "__asp3_del_tsk"]
     pub fn del_tsk(tskid: ID) -> ER;
+    #[link_name = "__asp3_chg_pri"]
+    pub fn chg_pri(tskid: ID, tskpri: PRI) -> ER;
     #[link_name = "__asp3_get_pri"]
     pub fn get_pri(tskid: ID, p_tskpri: *mut PRI) -> ER;
     #[link_name = "__asp3_rot_rdq"]
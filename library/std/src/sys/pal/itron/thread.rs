@@ -204,6 +204,16 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        super::unsupported()
+    }
+
+    pub fn set_priority(priority: i32) -> io::Result<()> {
+        ItronError::err_if_negative(unsafe { abi::chg_pri(abi::TSK_SELF, priority) })
+            .map_err(|e| e.as_io_error())?;
+        Ok(())
+    }
+
     pub fn sleep(dur: Duration) {
         for timeout in dur2reltims(dur) {
             expect_success(unsafe { abi::dly_tsk(timeout) }, &"dly_tsk");
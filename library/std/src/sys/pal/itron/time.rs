@@ -1,9 +1,21 @@
 use super::{abi, error::expect_success};
-use crate::{mem::MaybeUninit, time::Duration};
+use crate::{
+    io,
+    mem::MaybeUninit,
+    time::{Clock, Duration},
+};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Instant(abi::SYSTIM);
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        // `SYSTIM` is measured in microseconds
+        Clock::Monotonic => Ok(Duration::from_micros(Instant::now().0)),
+        _ => crate::sys::unsupported(),
+    }
+}
+
 impl Instant {
     pub fn now() -> Instant {
         // Safety: The provided pointer is valid
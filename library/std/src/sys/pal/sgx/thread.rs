@@ -127,6 +127,14 @@ pub fn set_name(_name: &CStr) {
         // which succeeds as-is with the SGX target.
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         usercalls::wait_timeout(0, dur, || true);
     }
@@ -1,5 +1,7 @@
 use super::abi::usercalls;
-use crate::time::Duration;
+use super::unsupported;
+use crate::io;
+use crate::time::{Clock, Duration};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Instant(Duration);
@@ -27,6 +29,13 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        Clock::Monotonic => Ok(Instant::now().0),
+        _ => unsupported(),
+    }
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         SystemTime(usercalls::insecure_time())
@@ -26,11 +26,17 @@
 pub const SOL_SOCKET: i32 = 4095;
 pub const SO_BROADCAST: i32 = 32;
 pub const SO_ERROR: i32 = 4103;
+pub const SO_KEEPALIVE: i32 = 8;
+pub const SO_RCVBUF: i32 = 4098;
 pub const SO_RCVTIMEO: i32 = 4102;
 pub const SO_REUSEADDR: i32 = 4;
+pub const SO_SNDBUF: i32 = 4097;
 pub const SO_SNDTIMEO: i32 = 4101;
 pub const SO_LINGER: i32 = 128;
 pub const TCP_NODELAY: i32 = 1;
+pub const TCP_KEEPIDLE: i32 = 3;
+pub const TCP_KEEPINTVL: i32 = 4;
+pub const TCP_KEEPCNT: i32 = 5;
 pub const MSG_PEEK: c_int = 1;
 pub const FIONBIO: c_long = 0x8008667eu32 as c_long;
 pub const EAI_NONAME: i32 = -2200;
@@ -12,7 +12,8 @@
     sys::unsupported,
 };
 
-pub use crate::sys_common::fs::exists;
+pub use crate::sys_common::fs::{copy_with, exists, Dir, Mmap};
+use crate::sys_common::fs::MapOptions;
 
 /// A file descriptor.
 #[derive(Clone, Copy)]
@@ -356,6 +357,36 @@ pub fn truncate(&self, _size: u64) -> io::Result<()> {
         unsupported()
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn map(
+        &self,
+        _offset: u64,
+        _len: usize,
+        _writable: bool,
+        _opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        unsupported()
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         unsafe {
             let mut out_num_bytes = MaybeUninit::uninit();
@@ -4,9 +4,10 @@
     ffi::CStr,
     io::{self, BorrowedBuf, BorrowedCursor, ErrorKind, IoSlice, IoSliceMut},
     mem,
-    net::{Shutdown, SocketAddr},
+    net::{Shutdown, SocketAddr, TcpKeepalive},
     os::solid::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd},
     ptr, str,
+    sys::unsupported,
     sys_common::net::{getsockopt, setsockopt, sockaddr_to_addr},
     sys_common::{FromInner, IntoInner},
     time::Duration,
@@ -367,6 +368,40 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        let Some(keepalive) = keepalive else {
+            return setsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE, 0 as c_int);
+        };
+
+        // The timeouts are in whole seconds, and must not be zero.
+        let secs = |dur: Duration| cmp::max(dur.as_secs(), 1).min(c_int::MAX as u64) as c_int;
+        if let Some(time) = keepalive.time {
+            setsockopt(self, netc::IPPROTO_TCP, netc::TCP_KEEPIDLE, secs(time))?;
+        }
+        if let Some(interval) = keepalive.interval {
+            setsockopt(self, netc::IPPROTO_TCP, netc::TCP_KEEPINTVL, secs(interval))?;
+        }
+        if let Some(retries) = keepalive.retries {
+            let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+            setsockopt(self, netc::IPPROTO_TCP, netc::TCP_KEEPCNT, retries)?;
+        }
+
+        setsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE, 1 as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, netc::SOL_SOCKET, netc::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Ok(false)
+    }
+
     pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
         let mut nonblocking = nonblocking as c_int;
         cvt(unsafe {
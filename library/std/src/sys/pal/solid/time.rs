@@ -1,7 +1,7 @@
 use super::{abi, error::expect_success};
 use crate::{mem::MaybeUninit, time::Duration};
 
-pub use super::itron::time::Instant;
+pub use super::itron::time::{clock_now, Instant};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct SystemTime(abi::time_t);
@@ -6,7 +6,7 @@
 use crate::mem;
 use crate::num::NonZero;
 use crate::ptr;
-use crate::sys::os;
+use crate::sys::{os, unsupported};
 use crate::time::Duration;
 
 pub const DEFAULT_MIN_STACK_SIZE: usize = 8 * 1024;
@@ -101,6 +101,14 @@ pub fn set_name(_name: &CStr) {
         // contact the teeos rustzone team.
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     /// only main thread could wait for sometime in teeos
     pub fn sleep(dur: Duration) {
         let sleep_millis = dur.as_millis();
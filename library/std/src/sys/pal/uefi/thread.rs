@@ -23,6 +23,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         let boot_services: NonNull<r_efi::efi::BootServices> =
             crate::os::uefi::env::boot_services().expect("can't sleep").cast();
@@ -1,4 +1,6 @@
-use crate::time::Duration;
+use crate::io;
+use crate::sys::unsupported;
+use crate::time::{Clock, Duration};
 
 const SECS_IN_MINUTE: u64 = 60;
 const SECS_IN_HOUR: u64 = SECS_IN_MINUTE * 60;
@@ -39,6 +41,13 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        Clock::Monotonic => Ok(Instant::now().0),
+        _ => unsupported(),
+    }
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         system_time_internal::now()
@@ -18,6 +18,7 @@
 use crate::sys::fd::FileDesc;
 use crate::sys::time::SystemTime;
 use crate::sys::{cvt, cvt_r};
+use crate::sys_common::fs::{CopyOptions, MapOptions};
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
@@ -97,6 +98,28 @@
 ))]
 use libc::{dirent64, fstat64, ftruncate64, lseek64, lstat64, off64_t, open64, stat64};
 
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_vendor = "apple",
+    ))] {
+        use libc::{LOCK_EX, LOCK_NB, LOCK_SH, LOCK_UN};
+    } else {
+        // Not every libc without `flock` defines its operations, but
+        // `File::flock` rejects all of them there anyway.
+        const LOCK_SH: c_int = 1;
+        const LOCK_EX: c_int = 2;
+        const LOCK_NB: c_int = 4;
+        const LOCK_UN: c_int = 8;
+    }
+}
+
 pub use crate::sys_common::fs::exists;
 
 pub struct File(FileDesc);
@@ -251,7 +274,7 @@ pub struct FileAttr {
 
 // all DirEntry's will have a reference to this struct
 struct InnerReadDir {
-    dirp: Dir,
+    dirp: DirStream,
     root: PathBuf,
 }
 
@@ -266,10 +289,10 @@ fn new(inner: InnerReadDir) -> Self {
     }
 }
 
-struct Dir(*mut libc::DIR);
+struct DirStream(*mut libc::DIR);
 
-unsafe impl Send for Dir {}
-unsafe impl Sync for Dir {}
+unsafe impl Send for DirStream {}
+unsafe impl Sync for DirStream {}
 
 #[cfg(any(
     target_os = "android",
@@ -845,7 +868,7 @@ pub(crate) fn debug_assert_fd_is_open(fd: RawFd) {
     }
 }
 
-impl Drop for Dir {
+impl Drop for DirStream {
     fn drop(&mut self) {
         // dirfd isn't supported everywhere
         #[cfg(not(any(
@@ -1240,6 +1263,73 @@ pub fn truncate(&self, size: u64) -> io::Result<()> {
         cvt_r(|| unsafe { ftruncate64(self.as_raw_fd(), size) }).map(drop)
     }
 
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_vendor = "apple",
+    ))]
+    fn flock(&self, operation: c_int) -> io::Result<bool> {
+        match cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation) }) {
+            Ok(_) => Ok(true),
+            Err(e) if operation & LOCK_NB != 0 && e.kind() == io::ErrorKind::WouldBlock => {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_vendor = "apple",
+    )))]
+    fn flock(&self, _operation: c_int) -> io::Result<bool> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "file locking is not supported on this platform",
+        ))
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.flock(LOCK_EX).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(LOCK_SH).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.flock(LOCK_EX | LOCK_NB)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.flock(LOCK_SH | LOCK_NB)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(LOCK_UN).map(drop)
+    }
+
+    pub fn map(
+        &self,
+        offset: u64,
+        len: usize,
+        writable: bool,
+        opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        Mmap::map(self.as_raw_fd(), offset, len, writable, opts)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -1676,7 +1766,7 @@ pub fn readdir(path: &Path) -> io::Result<ReadDir> {
         Err(Error::last_os_error())
     } else {
         let root = path.to_path_buf();
-        let inner = InnerReadDir { dirp: Dir(ptr), root };
+        let inner = InnerReadDir { dirp: DirStream(ptr), root };
         Ok(ReadDir::new(inner))
     }
 }
@@ -1863,6 +1953,78 @@ fn open_to_and_set_permissions(
     Ok((writer, writer_metadata))
 }
 
+/// Applies the `preserve_metadata` option of `copy_with` once the data has
+/// been copied.
+fn finish_copy(
+    writer: &crate::fs::File,
+    writer_metadata: &crate::fs::Metadata,
+    reader_metadata: &crate::fs::Metadata,
+    opts: &CopyOptions<'_>,
+) -> io::Result<()> {
+    use crate::os::unix::fs::MetadataExt;
+
+    if !opts.preserve_metadata || !writer_metadata.is_file() {
+        return Ok(());
+    }
+    // Like `cp -p`, keep the current owner if we lack the privileges to
+    // change it. Changing the owner may clear the setuid and setgid bits, so
+    // the permissions are applied again afterwards.
+    match fchown(writer.as_raw_fd(), reader_metadata.uid(), reader_metadata.gid()) {
+        Ok(()) => writer.set_permissions(reader_metadata.permissions())?,
+        Err(e) if e.raw_os_error() == Some(libc::EPERM) => {}
+        Err(e) => return Err(e),
+    }
+    crate::sys_common::fs::copy_times(writer, reader_metadata)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn copy_with(from: &Path, to: &Path, opts: &mut CopyOptions<'_>) -> io::Result<u64> {
+    use crate::sys_common::fs::{copy_data, REFLINK_ERROR};
+
+    if opts.reflink_only {
+        return Err(REFLINK_ERROR);
+    }
+    let (mut reader, reader_metadata) = open_from(from)?;
+    let (mut writer, writer_metadata) =
+        open_to_and_set_permissions(to, reader_metadata.clone())?;
+
+    let written = copy_data(&mut reader, &mut writer, opts)?;
+    finish_copy(&writer, &writer_metadata, &reader_metadata, opts)?;
+    Ok(written)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn copy_with(from: &Path, to: &Path, opts: &mut CopyOptions<'_>) -> io::Result<u64> {
+    use super::kernel_copy::{clone_file, copy_file_data, regular_file_pair};
+
+    let (reader, reader_metadata) = open_from(from)?;
+    let (writer, writer_metadata) = open_to_and_set_permissions(to, reader_metadata.clone())?;
+    let len = reader_metadata.len();
+
+    // Try to share the data extents first. `FICLONE` either clones the whole
+    // file or fails without writing anything, so falling back is safe.
+    let cloned = if opts.reflink_only || regular_file_pair(&reader, &writer) {
+        match clone_file(reader.as_raw_fd(), writer.as_raw_fd()) {
+            Ok(()) => true,
+            Err(e) if opts.reflink_only => return Err(e),
+            Err(_) => false,
+        }
+    } else {
+        false
+    };
+
+    let written = if cloned {
+        if let Some(progress) = &mut opts.progress {
+            progress(len);
+        }
+        len
+    } else {
+        copy_file_data(&reader, &writer, opts)?
+    };
+    finish_copy(&writer, &writer_metadata, &reader_metadata, opts)?;
+    Ok(written)
+}
+
 #[cfg(not(any(target_os = "linux", target_os = "android", target_vendor = "apple")))]
 pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     let (mut reader, reader_metadata) = open_from(from)?;
@@ -1974,6 +2136,8 @@ pub fn chroot(dir: &Path) -> io::Result<()> {
     run_path_with_cstr(dir, &|dir| cvt(unsafe { libc::chroot(dir.as_ptr()) }).map(|_| ()))
 }
 
+pub use dir_impl::Dir;
+pub use mmap_impl::Mmap;
 pub use remove_dir_impl::remove_dir_all;
 
 // Fallback for REDOX, ESP-ID, Horizon, Vita, Vxworks and Miri
@@ -2001,7 +2165,7 @@ mod remove_dir_impl {
     miri
 )))]
 mod remove_dir_impl {
-    use super::{lstat, Dir, DirEntry, InnerReadDir, ReadDir};
+    use super::{lstat, DirEntry, DirStream, InnerReadDir, ReadDir};
     use crate::ffi::CStr;
     use crate::io;
     use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd};
@@ -2077,7 +2241,7 @@ fn fdreaddir(dir_fd: OwnedFd) -> io::Result<(ReadDir, RawFd)> {
         if ptr.is_null() {
             return Err(io::Error::last_os_error());
         }
-        let dirp = Dir(ptr);
+        let dirp = DirStream(ptr);
         // file descriptor is automatically closed by libc::closedir() now, so give up ownership
         let new_parent_fd = dir_fd.into_raw_fd();
         // a valid root is not needed because we do not call any functions involving the full path
@@ -2188,3 +2352,384 @@ pub fn remove_dir_all(p: &Path) -> io::Result<()> {
         }
     }
 }
+
+// Fallback for targets without openat() and friends, matching the list above
+#[cfg(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    target_os = "vxworks",
+    miri
+))]
+mod dir_impl {
+    pub use crate::sys_common::fs::Dir;
+}
+
+// Directory handles resolving paths with openat(), mkdirat(), unlinkat() etc.
+#[cfg(not(any(
+    target_os = "redox",
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "vita",
+    target_os = "nto",
+    target_os = "vxworks",
+    miri
+)))]
+mod dir_impl {
+    use super::{stat64, DirStream, File, FileAttr, InnerReadDir, OpenOptions, ReadDir};
+    use crate::ffi::{CStr, OsStr};
+    use crate::fmt;
+    use crate::io;
+    use crate::mem;
+    use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+    use crate::path::{Component, Path, PathBuf};
+    use crate::sys::common::small_c_string::run_path_with_cstr;
+    use crate::sys::fd::FileDesc;
+    use crate::sys::{cvt, cvt_r};
+    use crate::sys_common::fs::{check_beneath, DirOptions, SYMLINK_ERROR};
+    use libc::c_int;
+
+    #[cfg(any(
+        all(target_os = "linux", not(target_env = "musl")),
+        target_os = "emscripten",
+        target_os = "android",
+        target_os = "hurd"
+    ))]
+    use super::fstatat64;
+    #[cfg(not(any(
+        all(target_os = "linux", not(target_env = "musl")),
+        target_os = "emscripten",
+        target_os = "android",
+        target_os = "hurd"
+    )))]
+    use libc::fstatat as fstatat64;
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    use libc::openat;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    use libc::openat64 as openat;
+
+    pub struct Dir {
+        fd: OwnedFd,
+        // Only used to build the paths of `DirEntry`s returned by `read_dir`.
+        root: PathBuf,
+        opts: DirOptions,
+    }
+
+    fn open_dir_fd(parent_fd: RawFd, path: &CStr, follow_symlinks: bool) -> io::Result<OwnedFd> {
+        let mut flags = libc::O_CLOEXEC | libc::O_RDONLY | libc::O_DIRECTORY;
+        if !follow_symlinks {
+            flags |= libc::O_NOFOLLOW;
+        }
+        let fd = cvt_r(|| unsafe { openat(parent_fd, path.as_ptr(), flags) })?;
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+
+    impl Dir {
+        pub fn open(path: &Path, opts: &DirOptions) -> io::Result<Dir> {
+            let fd = run_path_with_cstr(path, &|p| {
+                open_dir_fd(libc::AT_FDCWD, p, opts.follow_symlinks)
+            })?;
+            Ok(Dir { fd, root: path.to_path_buf(), opts: *opts })
+        }
+
+        /// Resolves `path` against this directory, and calls `f` with a
+        /// descriptor of the directory containing the last component and the
+        /// name of that component.
+        fn at<T>(&self, path: &Path, f: &dyn Fn(RawFd, &CStr) -> io::Result<T>) -> io::Result<T> {
+            if !self.opts.beneath {
+                return run_path_with_cstr(path, &|p| f(self.fd.as_raw_fd(), p));
+            }
+
+            // Walk the path one component at a time, refusing to follow
+            // symbolic links, so that nothing outside of this directory can be
+            // reached. `check_beneath` already rejected `..` and absolute paths.
+            check_beneath(path)?;
+            let mut names = path.components().filter_map(|c| match c {
+                Component::Normal(name) => Some(name),
+                _ => None,
+            });
+            let mut last = names.next().unwrap_or(OsStr::new("."));
+            let mut parent: Option<OwnedFd> = None;
+            for name in names {
+                let parent_fd = parent.as_ref().map_or(self.fd.as_raw_fd(), |fd| fd.as_raw_fd());
+                parent = Some(run_path_with_cstr(Path::new(last), &|p| {
+                    open_dir_fd(parent_fd, p, false)
+                })?);
+                last = name;
+            }
+            let parent_fd = parent.as_ref().map_or(self.fd.as_raw_fd(), |fd| fd.as_raw_fd());
+            run_path_with_cstr(Path::new(last), &|p| f(parent_fd, p))
+        }
+
+        pub fn open_dir(&self, path: &Path) -> io::Result<Dir> {
+            let fd = self.at(path, &|dirfd, name| {
+                open_dir_fd(dirfd, name, self.opts.follows_symlinks())
+            })?;
+            Ok(Dir { fd, root: self.root.join(path), opts: self.opts })
+        }
+
+        pub fn open_file(&self, path: &Path, opts: &OpenOptions) -> io::Result<File> {
+            let mut flags = libc::O_CLOEXEC
+                | opts.get_access_mode()?
+                | opts.get_creation_mode()?
+                | (opts.custom_flags as c_int & !libc::O_ACCMODE);
+            if !self.opts.follows_symlinks() {
+                flags |= libc::O_NOFOLLOW;
+            }
+            // See `File::open_c` for why `mode` is passed as a `c_int`.
+            let fd = self.at(path, &|dirfd, name| {
+                cvt_r(|| unsafe { openat(dirfd, name.as_ptr(), flags, opts.mode as c_int) })
+            })?;
+            Ok(File(unsafe { FileDesc::from_raw_fd(fd) }))
+        }
+
+        pub fn create_dir(&self, path: &Path) -> io::Result<()> {
+            self.at(path, &|dirfd, name| {
+                cvt(unsafe { libc::mkdirat(dirfd, name.as_ptr(), 0o777) }).map(drop)
+            })
+        }
+
+        pub fn remove_file(&self, path: &Path) -> io::Result<()> {
+            self.at(path, &|dirfd, name| {
+                cvt(unsafe { libc::unlinkat(dirfd, name.as_ptr(), 0) }).map(drop)
+            })
+        }
+
+        pub fn remove_dir(&self, path: &Path) -> io::Result<()> {
+            self.at(path, &|dirfd, name| {
+                cvt(unsafe { libc::unlinkat(dirfd, name.as_ptr(), libc::AT_REMOVEDIR) }).map(drop)
+            })
+        }
+
+        pub fn rename(&self, from: &Path, to_dir: &Dir, to: &Path) -> io::Result<()> {
+            self.at(from, &|from_fd, from_name| {
+                to_dir.at(to, &|to_fd, to_name| {
+                    cvt(unsafe {
+                        libc::renameat(from_fd, from_name.as_ptr(), to_fd, to_name.as_ptr())
+                    })
+                    .map(drop)
+                })
+            })
+        }
+
+        fn stat_at(&self, path: &Path, flags: c_int) -> io::Result<FileAttr> {
+            self.at(path, &|dirfd, name| {
+                let mut stat: stat64 = unsafe { mem::zeroed() };
+                cvt(unsafe { fstatat64(dirfd, name.as_ptr(), &mut stat, flags) })?;
+                Ok(FileAttr::from_stat64(stat))
+            })
+        }
+
+        pub fn metadata(&self, path: &Path) -> io::Result<FileAttr> {
+            if self.opts.follows_symlinks() {
+                return self.stat_at(path, 0);
+            }
+            let attr = self.stat_at(path, libc::AT_SYMLINK_NOFOLLOW)?;
+            if attr.file_type().is_symlink() {
+                return Err(SYMLINK_ERROR);
+            }
+            Ok(attr)
+        }
+
+        pub fn symlink_metadata(&self, path: &Path) -> io::Result<FileAttr> {
+            self.stat_at(path, libc::AT_SYMLINK_NOFOLLOW)
+        }
+
+        pub fn read_dir(&self) -> io::Result<ReadDir> {
+            // A duplicated descriptor would share its offset with `self.fd`,
+            // so open the directory again to get an independent stream.
+            let fd = open_dir_fd(self.fd.as_raw_fd(), c".", true)?;
+            let ptr = unsafe { libc::fdopendir(fd.as_raw_fd()) };
+            if ptr.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let dirp = DirStream(ptr);
+            // file descriptor is automatically closed by libc::closedir() now, so give up ownership
+            let _ = fd.into_raw_fd();
+            Ok(ReadDir::new(InnerReadDir { dirp, root: self.root.clone() }))
+        }
+    }
+
+    impl fmt::Debug for Dir {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Dir")
+                .field("fd", &self.fd.as_raw_fd())
+                .field("root", &self.root)
+                .field("opts", &self.opts)
+                .finish()
+        }
+    }
+}
+
+#[cfg(any(target_os = "espidf", target_os = "horizon", target_os = "vita"))]
+mod mmap_impl {
+    use crate::io;
+    use crate::os::unix::io::RawFd;
+    use crate::sys::pal::unix::unsupported::unsupported;
+    use crate::sys_common::fs::MapOptions;
+
+    pub use crate::sys_common::fs::Mmap;
+
+    impl Mmap {
+        pub fn map(
+            _fd: RawFd,
+            _offset: u64,
+            _len: usize,
+            _writable: bool,
+            _opts: &MapOptions,
+        ) -> io::Result<Mmap> {
+            unsupported()
+        }
+    }
+}
+
+// Memory mappings created with mmap()
+#[cfg(not(any(target_os = "espidf", target_os = "horizon", target_os = "vita")))]
+mod mmap_impl {
+    use crate::ffi::c_void;
+    use crate::fs::MmapAdvice;
+    use crate::io;
+    use crate::os::unix::io::RawFd;
+    use crate::ptr::{self, NonNull};
+    use crate::sys::os;
+    use crate::sys_common::fs::MapOptions;
+
+    #[cfg(not(target_os = "vxworks"))]
+    use crate::sys::cvt;
+    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    use libc::{mmap64, off64_t};
+    #[cfg(not(all(target_os = "linux", not(target_env = "musl"))))]
+    use libc::{mmap as mmap64, off_t as off64_t};
+
+    pub struct Mmap {
+        // The start of the requested region, which `mmap` only maps from the
+        // beginning of its page.
+        ptr: NonNull<u8>,
+        len: usize,
+    }
+
+    // Like `&[u8]`, the mapping can be shared and sent between threads.
+    unsafe impl Send for Mmap {}
+    unsafe impl Sync for Mmap {}
+
+    impl Mmap {
+        pub fn map(
+            fd: RawFd,
+            offset: u64,
+            len: usize,
+            writable: bool,
+            opts: &MapOptions,
+        ) -> io::Result<Mmap> {
+            if len == 0 {
+                // `mmap` rejects empty mappings, so don't create one.
+                return Ok(Mmap { ptr: NonNull::dangling(), len: 0 });
+            }
+
+            let align = (offset % os::page_size() as u64) as usize;
+            let map_len = len.checked_add(align).ok_or(TOO_LARGE)?;
+            let map_offset = off64_t::try_from(offset - align as u64).map_err(|_| TOO_LARGE)?;
+            let prot = if writable { libc::PROT_READ | libc::PROT_WRITE } else { libc::PROT_READ };
+            #[allow(unused_mut)]
+            let mut flags = if opts.copy_on_write { libc::MAP_PRIVATE } else { libc::MAP_SHARED };
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            if opts.populate {
+                flags |= libc::MAP_POPULATE;
+            }
+
+            let ptr = unsafe { mmap64(ptr::null_mut(), map_len, prot, flags, fd, map_offset) };
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            let ptr = unsafe { NonNull::new_unchecked(ptr.cast::<u8>().add(align)) };
+            Ok(Mmap { ptr, len })
+        }
+
+        pub fn as_ptr(&self) -> *mut u8 {
+            self.ptr.as_ptr()
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns the address and length of the pages spanned by the region.
+        fn pages(&self) -> (*mut c_void, usize) {
+            let align = self.ptr.as_ptr().addr() % os::page_size();
+            (self.ptr.as_ptr().wrapping_sub(align).cast(), self.len + align)
+        }
+
+        #[cfg(not(target_os = "vxworks"))]
+        pub fn flush(&self, sync: bool) -> io::Result<()> {
+            if self.len == 0 {
+                return Ok(());
+            }
+            let (addr, len) = self.pages();
+            let flags = if sync { libc::MS_SYNC } else { libc::MS_ASYNC };
+            cvt(unsafe { libc::msync(addr, len, flags) }).map(drop)
+        }
+
+        // VxWorks has no `msync`.
+        #[cfg(target_os = "vxworks")]
+        pub fn flush(&self, _sync: bool) -> io::Result<()> {
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "flushing memory maps is not supported on this platform",
+            ))
+        }
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        pub fn advise(&self, advice: MmapAdvice) -> io::Result<()> {
+            if self.len == 0 {
+                return Ok(());
+            }
+            let advice = match advice {
+                MmapAdvice::Normal => libc::MADV_NORMAL,
+                MmapAdvice::Sequential => libc::MADV_SEQUENTIAL,
+                MmapAdvice::Random => libc::MADV_RANDOM,
+                MmapAdvice::WillNeed => libc::MADV_WILLNEED,
+            };
+            let (addr, len) = self.pages();
+            cvt(unsafe { libc::madvise(addr, len, advice) }).map(drop)
+        }
+
+        // Advice is only a hint, so it is fine to ignore it.
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_os = "illumos",
+            target_os = "solaris",
+        )))]
+        pub fn advise(&self, _advice: MmapAdvice) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Drop for Mmap {
+        fn drop(&mut self) {
+            if self.len != 0 {
+                let (addr, len) = self.pages();
+                unsafe { libc::munmap(addr, len) };
+            }
+        }
+    }
+
+    const TOO_LARGE: io::Error =
+        io::const_io_error!(io::ErrorKind::InvalidInput, "mapped region is too large");
+}
@@ -59,10 +59,15 @@
 use crate::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use crate::sys::cvt;
 use crate::sys::weak::syscall;
+use crate::sys_common::fs::CopyOptions;
 #[cfg(not(any(all(target_os = "linux", target_env = "gnu"), target_os = "hurd")))]
 use libc::sendfile as sendfile64;
 #[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "hurd"))]
 use libc::sendfile64;
+#[cfg(not(any(all(target_os = "linux", target_env = "gnu"), target_os = "android")))]
+use libc::{lseek as lseek64, off_t as off64_t};
+#[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "android"))]
+use libc::{lseek64, off64_t};
 use libc::{EBADF, EINVAL, ENOSYS, EOPNOTSUPP, EOVERFLOW, EPERM, EXDEV};
 
 #[cfg(test)]
@@ -674,6 +679,135 @@ fn probe_copy_file_range_support() -> u8 {
     CopyResult::Ended(written)
 }
 
+// _IOW(0x94, 9, int), the direction bits differ between architectures.
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+))]
+const FICLONE: u32 = 0x8004_9409;
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+const FICLONE: u32 = 0x4004_9409;
+
+/// Makes `writer` a copy-on-write clone of `reader` with the `FICLONE` ioctl,
+/// sharing the data extents of the source instead of copying them.
+///
+/// Unlike `copy_regular_files` this never copies partially: it either clones
+/// the whole file or fails, e.g. with `EOPNOTSUPP` if the filesystem cannot
+/// share extents or `EXDEV` if the files are on different filesystems.
+pub(super) fn clone_file(reader: RawFd, writer: RawFd) -> Result<()> {
+    cvt(unsafe { libc::ioctl(writer, FICLONE as _, reader) }).map(drop)
+}
+
+/// Returns true if both files are candidates for the file-only syscalls,
+/// using the same hints as the `io::copy` specialization.
+pub(super) fn regular_file_pair(reader: &File, writer: &File) -> bool {
+    let CopyParams(input_meta, _) = CopyRead::properties(reader);
+    let CopyParams(output_meta, _) = CopyWrite::properties(writer);
+    input_meta.copy_file_range_candidate(FdHandle::Input)
+        && output_meta.copy_file_range_candidate(FdHandle::Output)
+}
+
+/// Copies the remaining data of `reader` into `writer` for `fs::CopyOptions`,
+/// honoring its `sparse` and `progress` options.
+pub(super) fn copy_file_data(
+    reader: &File,
+    writer: &File,
+    opts: &mut CopyOptions<'_>,
+) -> Result<u64> {
+    if opts.sparse && regular_file_pair(reader, writer) {
+        if let Some(written) = copy_sparse(reader, writer, opts)? {
+            return Ok(written);
+        }
+    }
+    copy_chunks(reader, writer, u64::MAX, 0, opts)
+}
+
+/// Copies the data regions of `reader` to the same offsets in `writer`, as
+/// reported by `lseek` with `SEEK_DATA` and `SEEK_HOLE`, so that holes in the
+/// source stay holes in the destination.
+///
+/// Returns `None` without copying anything if the filesystem of `reader` does
+/// not support `SEEK_DATA`.
+fn copy_sparse(reader: &File, writer: &File, opts: &mut CopyOptions<'_>) -> Result<Option<u64>> {
+    let (readfd, writefd) = (reader.as_raw_fd(), writer.as_raw_fd());
+    let len = reader.metadata()?.len();
+    let seek = |fd, offset: u64, whence| {
+        cvt(unsafe { lseek64(fd, offset as off64_t, whence) }).map(|pos| pos as u64)
+    };
+
+    let mut offset = 0;
+    let mut written = 0;
+    while offset < len {
+        let data = match seek(readfd, offset, libc::SEEK_DATA) {
+            Ok(data) => data,
+            // there is no data after `offset`, the rest of the file is a hole
+            Err(e) if e.raw_os_error() == Some(libc::ENXIO) => break,
+            Err(e) if offset == 0 && e.raw_os_error() == Some(EINVAL) => {
+                seek(readfd, 0, libc::SEEK_SET)?;
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+        let hole = seek(readfd, data, libc::SEEK_HOLE)?;
+        seek(readfd, data, libc::SEEK_SET)?;
+        seek(writefd, data, libc::SEEK_SET)?;
+        written += copy_chunks(reader, writer, hole - data, written, opts)?;
+        offset = hole;
+    }
+    // extend the destination over a trailing hole
+    writer.set_len(len)?;
+    Ok(Some(written))
+}
+
+/// Copies up to `max_len` bytes, stopping early at the end of `reader`. If a
+/// progress callback is set, the copy is split into chunks and the callback
+/// is called with `done` plus the bytes copied so far after each of them.
+fn copy_chunks(
+    reader: &File,
+    writer: &File,
+    max_len: u64,
+    done: u64,
+    opts: &mut CopyOptions<'_>,
+) -> Result<u64> {
+    const PROGRESS_CHUNK: u64 = 8 * 1024 * 1024;
+
+    let chunk = if opts.progress.is_some() { PROGRESS_CHUNK } else { u64::MAX };
+    let mut copied = 0;
+    while copied < max_len {
+        let limit = min(chunk, max_len - copied);
+        let bytes = match copy_regular_files(reader.as_raw_fd(), writer.as_raw_fd(), limit) {
+            CopyResult::Ended(bytes) => bytes,
+            CopyResult::Error(e, _) => return Err(e),
+            CopyResult::Fallback(bytes) => {
+                bytes + generic_copy(&mut reader.take(limit - bytes), &mut &*writer)?
+            }
+        };
+        copied += bytes;
+        if let Some(progress) = &mut opts.progress {
+            progress(done + copied);
+        }
+        if bytes < limit {
+            break;
+        }
+    }
+    Ok(copied)
+}
+
 #[derive(PartialEq)]
 enum SpliceMode {
     Sendfile,
@@ -12,6 +12,14 @@
 pub(crate) struct PidFd(FileDesc);
 
 impl PidFd {
+    /// Opens a pidfd for `pid`, which must be a child of this process that
+    /// has not been reaped yet for the pidfd to refer to it reliably.
+    pub fn open(pid: libc::pid_t) -> io::Result<PidFd> {
+        let fd = cvt(unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) })?;
+        // pidfd_open sets CLOEXEC by default
+        Ok(unsafe { PidFd::from_raw_fd(fd as RawFd) })
+    }
+
     pub fn kill(&self) -> io::Result<()> {
         return cvt(unsafe {
             libc::syscall(
@@ -30,6 +30,7 @@
 pub mod pipe;
 pub mod process;
 pub mod rand;
+pub mod signals;
 pub mod stack_overflow;
 pub mod stdio;
 pub mod thread;
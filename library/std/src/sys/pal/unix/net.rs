@@ -2,7 +2,7 @@
 use crate::ffi::CStr;
 use crate::io::{self, BorrowedBuf, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Shutdown, SocketAddr};
+use crate::net::{Shutdown, SocketAddr, TcpKeepalive};
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
 use crate::str;
 use crate::sys::fd::FileDesc;
@@ -431,6 +431,104 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        let Some(keepalive) = keepalive else {
+            return setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, 0 as c_int);
+        };
+
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "fuchsia",
+                target_os = "illumos",
+                target_os = "linux",
+                target_os = "netbsd",
+                target_vendor = "apple",
+            ))] {
+                #[cfg(not(target_vendor = "apple"))]
+                use libc::TCP_KEEPIDLE;
+                #[cfg(target_vendor = "apple")]
+                use libc::TCP_KEEPALIVE as TCP_KEEPIDLE;
+
+                // The timeouts are in whole seconds, and must not be zero.
+                let secs = |dur: Duration| cmp::max(dur.as_secs(), 1).min(c_int::MAX as u64);
+                if let Some(time) = keepalive.time {
+                    setsockopt(self, libc::IPPROTO_TCP, TCP_KEEPIDLE, secs(time) as c_int)?;
+                }
+                if let Some(interval) = keepalive.interval {
+                    let interval = secs(interval) as c_int;
+                    setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL, interval)?;
+                }
+                if let Some(retries) = keepalive.retries {
+                    let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+                    setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPCNT, retries)?;
+                }
+            } else {
+                if keepalive.time.is_some()
+                    || keepalive.interval.is_some()
+                    || keepalive.retries.is_some()
+                {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::Unsupported,
+                        "TCP keepalive parameters are not supported on this platform",
+                    ));
+                }
+            }
+        }
+
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, 1 as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "fuchsia",
+                target_os = "linux",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_vendor = "apple",
+            ))] {
+                setsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT, reuseport as c_int)
+            } else {
+                let _ = reuseport;
+                Err(io::const_io_error!(
+                    io::ErrorKind::Unsupported,
+                    "SO_REUSEPORT is not supported on this platform",
+                ))
+            }
+        }
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "android",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "fuchsia",
+                target_os = "linux",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_vendor = "apple",
+            ))] {
+                let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT)?;
+                Ok(raw != 0)
+            } else {
+                Ok(false)
+            }
+        }
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux",))]
     pub fn set_quickack(&self, quickack: bool) -> io::Result<()> {
         setsockopt(self, libc::IPPROTO_TCP, libc::TCP_QUICKACK, quickack as c_int)
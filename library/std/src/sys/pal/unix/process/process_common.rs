@@ -107,9 +107,41 @@ pub struct Command {
     stderr: Option<Stdio>,
     #[cfg(target_os = "linux")]
     create_pidfd: bool,
+    #[cfg(target_os = "linux")]
+    linux: LinuxOptions,
     pgroup: Option<pid_t>,
 }
 
+/// Options set through `os::linux::process::CommandExt` that are applied in
+/// the child before `exec`.
+#[cfg(target_os = "linux")]
+#[derive(Default, Debug, PartialEq)]
+pub struct LinuxOptions {
+    pub setsid: bool,
+    /// `(resource, soft, hard)` triples for `setrlimit`.
+    pub rlimits: Vec<(c_int, u64, u64)>,
+    pub chroot: Option<CString>,
+    /// `CLONE_NEW*` flags for `unshare`.
+    pub unshare: c_int,
+    pub parent_death_signal: Option<c_int>,
+    pub reset_signals: bool,
+    /// The pid of the process that forked the child, only set in the child
+    /// to detect a parent that exited before `parent_death_signal` was armed.
+    pub forked_by: Option<pid_t>,
+}
+
+#[cfg(target_os = "linux")]
+impl LinuxOptions {
+    /// Whether any option needs the fork/exec path because `posix_spawn`
+    /// has no equivalent for it.
+    pub fn needs_fork(&self) -> bool {
+        !self.rlimits.is_empty()
+            || self.chroot.is_some()
+            || self.unshare != 0
+            || self.parent_death_signal.is_some()
+    }
+}
+
 // Create a new type for argv, so that we can make it `Send` and `Sync`
 struct Argv(Vec<*const c_char>);
 
@@ -223,6 +255,7 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             create_pidfd: false,
+            linux: LinuxOptions::default(),
             pgroup: None,
         }
     }
@@ -279,6 +312,43 @@ pub fn get_create_pidfd(&self) -> bool {
         self.create_pidfd
     }
 
+    #[cfg(target_os = "linux")]
+    pub fn linux_options(&mut self) -> &mut LinuxOptions {
+        &mut self.linux
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn get_linux_options(&self) -> &LinuxOptions {
+        &self.linux
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn chroot(&mut self, dir: &OsStr) {
+        self.linux.chroot = Some(os2c(dir, &mut self.saw_nul));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[allow(dead_code)]
+    pub fn get_setsid(&self) -> bool {
+        false
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn get_setsid(&self) -> bool {
+        self.linux.setsid
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[allow(dead_code)]
+    pub fn get_reset_signals(&self) -> bool {
+        false
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn get_reset_signals(&self) -> bool {
+        self.linux.reset_signals
+    }
+
     pub fn saw_nul(&self) -> bool {
         self.saw_nul
     }
@@ -579,6 +649,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             #[cfg(target_os = "linux")]
             {
                 debug_command.field("create_pidfd", &self.create_pidfd);
+                if self.linux != LinuxOptions::default() {
+                    debug_command.field("linux", &self.linux);
+                }
             }
 
             debug_command.finish()
@@ -192,3 +192,64 @@ fn unix_exit_statuses() {
         }
     }
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_linux_rlimit_and_setsid() {
+    use crate::os::linux::process::{CommandExt, Resource};
+    use crate::process::Command;
+
+    // Setting a resource limit forces the fork + exec path.
+    let output = t!(Command::new("sh")
+        .arg("-c")
+        .arg("ulimit -n; ps -o sid= -p $$; echo $$")
+        .setrlimit(Resource::NoFile, 64, 64)
+        .setsid(true)
+        .output());
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines().map(str::trim);
+    assert_eq!(lines.next(), Some("64"));
+    // A session leader's session id is its own pid. `ps` may be missing on
+    // minimal systems, in which case only the limit is checked.
+    if let (Some(sid), Some(pid)) = (lines.next(), lines.next()) {
+        assert_eq!(sid, pid);
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_linux_reset_signals() {
+    use crate::os::linux::process::CommandExt;
+    use crate::process::Command;
+
+    // Returns the signal mask of a child spawned while this thread blocks
+    // SIGINT. Only this thread's mask is changed, which the child inherits, so
+    // other tests running in parallel are not affected.
+    fn blocked_in_child(reset: bool) -> u64 {
+        unsafe {
+            let mut set = mem::MaybeUninit::<libc::sigset_t>::uninit();
+            let mut old_set = mem::MaybeUninit::<libc::sigset_t>::uninit();
+            t!(cvt(sigemptyset(set.as_mut_ptr())));
+            t!(cvt(sigaddset(set.as_mut_ptr(), libc::SIGINT)));
+            t!(cvt_nz(libc::pthread_sigmask(libc::SIG_BLOCK, set.as_ptr(), old_set.as_mut_ptr())));
+
+            // Run grep directly: a shell may change its signal mask on startup.
+            let output = Command::new("grep")
+                .arg("^SigBlk")
+                .arg("/proc/self/status")
+                .reset_signals(reset)
+                .output();
+
+            t!(cvt_nz(libc::pthread_sigmask(libc::SIG_SETMASK, old_set.as_ptr(), ptr::null_mut())));
+
+            let output = t!(output);
+            let stdout = String::from_utf8(output.stdout).unwrap();
+            t!(u64::from_str_radix(stdout.trim_start_matches("SigBlk:").trim(), 16))
+        }
+    }
+
+    let sigint = 1 << (libc::SIGINT - 1);
+    assert_eq!(blocked_in_child(false) & sigint, sigint);
+    assert_eq!(blocked_in_child(true) & sigint, 0);
+}
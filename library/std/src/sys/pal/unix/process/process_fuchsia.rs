@@ -3,6 +3,7 @@
 use crate::mem;
 use crate::num::NonZero;
 use crate::ptr;
+use crate::time::Instant;
 
 use crate::sys::process::process_common::*;
 use crate::sys::process::zircon::{zx_handle_t, Handle};
@@ -233,6 +234,32 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
         Ok(Some(ExitStatus(proc_info.return_code)))
     }
+
+    pub fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        use crate::sys::process::zircon::*;
+
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let timeout = i64::try_from(timeout.as_nanos()).unwrap_or(i64::MAX);
+        unsafe {
+            let deadline = zx_clock_get_monotonic().saturating_add(timeout);
+            let status = zx_object_wait_one(
+                self.handle.raw(),
+                ZX_TASK_TERMINATED,
+                deadline,
+                ptr::null_mut(),
+            );
+            match status {
+                0 => {} // Success
+                x if x == ERR_TIMED_OUT => {
+                    return Ok(None);
+                }
+                _ => {
+                    panic!("Failed to wait on process handle: {status}");
+                }
+            }
+        }
+        self.try_wait()
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
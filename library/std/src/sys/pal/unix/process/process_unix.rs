@@ -5,6 +5,7 @@
 use crate::sys;
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
+use crate::time::Instant;
 
 #[cfg(target_os = "linux")]
 use crate::sys::pal::unix::linux::pidfd::PidFd;
@@ -94,6 +95,8 @@ pub fn spawn(
         // The child calls `mem::forget` to leak the lock, which is crucial because
         // releasing a lock is not async-signal-safe.
         let env_lock = sys::os::env_read_lock();
+        #[cfg(target_os = "linux")]
+        let parent_pid = unsafe { libc::getpid() };
         let pid = unsafe { self.do_fork()? };
 
         if pid == 0 {
@@ -104,6 +107,10 @@ pub fn spawn(
             if self.get_create_pidfd() {
                 self.send_pidfd(&output);
             }
+            #[cfg(target_os = "linux")]
+            {
+                self.linux_options().forked_by = Some(parent_pid);
+            }
             let Err(err) = unsafe { self.do_exec(theirs, envp.as_ref()) };
             let errno = err.raw_os_error().unwrap_or(libc::EINVAL) as u32;
             let errno = errno.to_be_bytes();
@@ -304,6 +311,11 @@ unsafe fn do_exec(
             cvt_r(|| libc::dup2(fd, libc::STDERR_FILENO))?;
         }
 
+        // These need the privileges of the parent, so they are applied before
+        // switching to another user.
+        #[cfg(target_os = "linux")]
+        self.setup_linux_isolation()?;
+
         #[cfg(not(target_os = "l4re"))]
         {
             if let Some(_g) = self.get_groups() {
@@ -341,6 +353,26 @@ unsafe fn do_exec(
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
+        #[cfg(target_os = "linux")]
+        {
+            let linux = self.get_linux_options();
+            if linux.setsid {
+                cvt(libc::setsid())?;
+            }
+            // Changing the credentials above clears the parent death signal,
+            // so it is armed afterwards.
+            if let Some(signal) = linux.parent_death_signal {
+                cvt(libc::prctl(libc::PR_SET_PDEATHSIG, signal as libc::c_ulong))?;
+                // If the parent already exited, the signal will never be
+                // delivered, so deliver it now.
+                if let Some(parent) = linux.forked_by {
+                    if libc::getppid() != parent {
+                        cvt(libc::kill(libc::getpid(), signal))?;
+                    }
+                }
+            }
+        }
+
         if let Some(pgroup) = self.get_pgroup() {
             cvt(libc::setpgid(0, pgroup))?;
         }
@@ -348,8 +380,25 @@ unsafe fn do_exec(
         // emscripten has no signal support.
         #[cfg(not(target_os = "emscripten"))]
         {
-            // Inherit the signal mask from the parent rather than resetting it (i.e. do not call
-            // pthread_sigmask).
+            // Unless asked to, inherit the signal mask from the parent rather than resetting it
+            // (i.e. do not call pthread_sigmask).
+            #[cfg(target_os = "linux")]
+            if self.get_reset_signals() {
+                let mut set = mem::MaybeUninit::<libc::sigset_t>::uninit();
+                cvt(libc::sigemptyset(set.as_mut_ptr()))?;
+                sys::cvt_nz(libc::pthread_sigmask(
+                    libc::SIG_SETMASK,
+                    set.as_ptr(),
+                    crate::ptr::null_mut(),
+                ))?;
+                // Errors are expected for signals whose disposition cannot be changed (SIGKILL,
+                // SIGSTOP, and those reserved by libc).
+                let mut action: libc::sigaction = mem::zeroed();
+                action.sa_sigaction = libc::SIG_DFL;
+                for signal in 1..=libc::SIGRTMAX() {
+                    libc::sigaction(signal, &action, crate::ptr::null_mut());
+                }
+            }
 
             // If -Zon-broken-pipe is used, don't reset SIGPIPE to SIG_DFL.
             // If -Zon-broken-pipe is not used, reset SIGPIPE to SIG_DFL for backward compatibility.
@@ -408,6 +457,33 @@ fn drop(&mut self) {
         Err(io::Error::last_os_error())
     }
 
+    /// Applies the isolation options of `os::linux::process::CommandExt`.
+    #[cfg(target_os = "linux")]
+    unsafe fn setup_linux_isolation(&self) -> io::Result<()> {
+        #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+        use libc::{rlimit as rlimit64, setrlimit as setrlimit64};
+        #[cfg(all(target_os = "linux", target_env = "gnu"))]
+        use libc::{rlimit64, setrlimit64};
+
+        let linux = self.get_linux_options();
+        if linux.unshare != 0 {
+            cvt(libc::unshare(linux.unshare))?;
+        }
+        for &(resource, soft, hard) in &linux.rlimits {
+            let limit = rlimit64 { rlim_cur: soft as _, rlim_max: hard as _ };
+            cvt(setrlimit64(resource as _, &limit))?;
+        }
+        if let Some(ref root) = linux.chroot {
+            cvt(libc::chroot(root.as_ptr()))?;
+            // Don't leave the working directory outside of the new root. An
+            // explicit working directory is resolved inside of it.
+            if self.get_cwd().is_none() {
+                cvt(libc::chdir(c"/".as_ptr()))?;
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(any(target_os = "tvos", target_os = "watchos"))]
     unsafe fn do_exec(
         &mut self,
@@ -460,11 +536,17 @@ fn posix_spawn(
             return Ok(None);
         }
 
+        #[cfg(target_os = "linux")]
+        if self.get_linux_options().needs_fork() {
+            return Ok(None);
+        }
+
         // Only glibc 2.24+ posix_spawn() supports returning ENOENT directly.
         #[cfg(all(target_os = "linux", target_env = "gnu"))]
         {
             if let Some(version) = sys::os::glibc_version() {
-                if version < (2, 24) {
+                // POSIX_SPAWN_SETSID was added in glibc 2.26.
+                if version < (2, 24) || (self.get_setsid() && version < (2, 26)) {
                     return Ok(None);
                 }
             } else {
@@ -602,19 +684,32 @@ fn drop(&mut self) {
                 cvt_nz(f(file_actions.0.as_mut_ptr(), cwd.as_ptr()))?;
             }
 
+            #[cfg(target_os = "linux")]
+            if self.get_setsid() {
+                flags |= libc::POSIX_SPAWN_SETSID;
+            }
+
             if let Some(pgroup) = pgroup {
                 flags |= libc::POSIX_SPAWN_SETPGROUP;
                 cvt_nz(libc::posix_spawnattr_setpgroup(attrs.0.as_mut_ptr(), pgroup))?;
             }
 
-            // Inherit the signal mask from this process rather than resetting it (i.e. do not call
-            // posix_spawnattr_setsigmask).
-
-            // If -Zon-broken-pipe is used, don't reset SIGPIPE to SIG_DFL.
-            // If -Zon-broken-pipe is not used, reset SIGPIPE to SIG_DFL for backward compatibility.
-            //
-            // -Zon-broken-pipe is an opportunity to change the default here.
-            if !on_broken_pipe_flag_used() {
+            if self.get_reset_signals() {
+                let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+                cvt(sigemptyset(set.as_mut_ptr()))?;
+                cvt_nz(libc::posix_spawnattr_setsigmask(attrs.0.as_mut_ptr(), set.as_ptr()))?;
+                cvt(libc::sigfillset(set.as_mut_ptr()))?;
+                cvt_nz(libc::posix_spawnattr_setsigdefault(attrs.0.as_mut_ptr(), set.as_ptr()))?;
+                flags |= libc::POSIX_SPAWN_SETSIGMASK | libc::POSIX_SPAWN_SETSIGDEF;
+            } else if !on_broken_pipe_flag_used() {
+                // Unless asked to, inherit the signal mask from this process rather than
+                // resetting it (i.e. do not call posix_spawnattr_setsigmask).
+
+                // If -Zon-broken-pipe is used, don't reset SIGPIPE to SIG_DFL.
+                // If -Zon-broken-pipe is not used, reset SIGPIPE to SIG_DFL for backward
+                // compatibility.
+                //
+                // -Zon-broken-pipe is an opportunity to change the default here.
                 let mut default_set = MaybeUninit::<libc::sigset_t>::uninit();
                 cvt(sigemptyset(default_set.as_mut_ptr()))?;
                 cvt(sigaddset(default_set.as_mut_ptr(), libc::SIGPIPE))?;
@@ -856,6 +951,151 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        use crate::os::unix::io::AsFd;
+        use crate::sys::signals::Signals;
+        use crate::thread;
+        use crate::time::Duration;
+
+        if let Some(status) = self.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        // A pidfd becomes readable once the process has exited. If the child
+        // was spawned without one, open one now; its pid cannot have been
+        // reused since we haven't reaped it.
+        #[cfg(target_os = "linux")]
+        {
+            use crate::sys_common::AsInner;
+
+            let opened;
+            let pidfd = match self.pidfd.as_ref() {
+                Some(pidfd) => Some(pidfd),
+                None => {
+                    opened = PidFd::open(self.pid).ok();
+                    opened.as_ref()
+                }
+            };
+            if let Some(pidfd) = pidfd {
+                poll_readable(pidfd.as_inner().as_fd(), deadline)?;
+                return self.try_wait();
+            }
+        }
+
+        // A kqueue reports the exit of this child only, without changing any
+        // process-wide state.
+        #[cfg(any(
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        ))]
+        match wait_exit_kqueue(self.pid, deadline) {
+            // The exit is reported just before the child can be reaped.
+            Ok(true) => return self.wait().map(Some),
+            Ok(false) => return self.try_wait(),
+            Err(_) => {}
+        }
+
+        // Otherwise wake up whenever any child changes state. Registering for
+        // `SIGCHLD` before checking the child again ensures its exit is not
+        // missed. If that fails, fall back to polling.
+        #[cfg(not(target_os = "l4re"))]
+        let signals = Signals::new(&[libc::SIGCHLD]);
+        // L4Re has no `SIGCHLD` (nor signal handling), so always poll there.
+        #[cfg(target_os = "l4re")]
+        let signals = Signals::new(&[]);
+        let mut backoff = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status));
+            }
+            match &signals {
+                Ok(signals) => {
+                    if !poll_readable(signals.as_fd(), deadline)? {
+                        return self.try_wait();
+                    }
+                    while signals.try_wait()?.is_some() {}
+                }
+                Err(_) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(None);
+                    }
+                    thread::sleep(backoff.min(deadline - now));
+                    backoff = (backoff * 2).min(Duration::from_millis(50));
+                }
+            }
+        }
+    }
+}
+
+/// Waits until the process `pid` has exited or `deadline` has passed, and
+/// returns whether it has exited.
+#[cfg(any(
+    target_vendor = "apple",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+fn wait_exit_kqueue(pid: pid_t, deadline: Instant) -> io::Result<bool> {
+    use crate::os::unix::io::{AsRawFd, FromRawFd};
+    use crate::ptr;
+    use crate::sys::fd::FileDesc;
+
+    let kq = unsafe { FileDesc::from_raw_fd(cvt(libc::kqueue())?) };
+    let mut change: libc::kevent = unsafe { mem::zeroed() };
+    change.ident = pid as _;
+    change.filter = libc::EVFILT_PROC as _;
+    change.flags = (libc::EV_ADD | libc::EV_ONESHOT) as _;
+    change.fflags = libc::NOTE_EXIT as _;
+    // Registering fails with `ESRCH` if the child has already exited.
+    match cvt(unsafe { libc::kevent(kq.as_raw_fd(), &change, 1, ptr::null_mut(), 0, ptr::null()) })
+    {
+        Ok(_) => {}
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => return Ok(true),
+        Err(e) => return Err(e),
+    }
+
+    loop {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let timeout = libc::timespec {
+            tv_sec: timeout.as_secs().min(libc::time_t::MAX as u64) as libc::time_t,
+            tv_nsec: timeout.subsec_nanos() as _,
+        };
+        let mut event: libc::kevent = unsafe { mem::zeroed() };
+        match cvt(unsafe { libc::kevent(kq.as_raw_fd(), ptr::null(), 0, &mut event, 1, &timeout) })
+        {
+            Ok(0) if Instant::now() >= deadline => return Ok(false),
+            Ok(0) => {}
+            Ok(_) => return Ok(true),
+            Err(e) if e.is_interrupted() => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Waits until `fd` is readable or `deadline` has passed, and returns whether
+/// it is readable.
+fn poll_readable(fd: crate::os::unix::io::BorrowedFd<'_>, deadline: Instant) -> io::Result<bool> {
+    use crate::os::unix::io::AsRawFd;
+
+    loop {
+        // Round up, so that we don't wake up just before the deadline.
+        let timeout = deadline.saturating_duration_since(Instant::now()).as_nanos();
+        let timeout = timeout.div_ceil(1_000_000).min(c_int::MAX as u128) as c_int;
+        let mut pollfd = libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout) }) {
+            Ok(0) if Instant::now() >= deadline => return Ok(false),
+            Ok(0) => {}
+            Ok(_) => return Ok(true),
+            Err(e) if e.is_interrupted() => {}
+            Err(e) => return Err(e),
+        }
+    }
 }
 
 /// Unix exit statuses
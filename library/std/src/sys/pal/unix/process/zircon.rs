@@ -76,6 +76,8 @@ pub struct zx_info_process_t {
 }
 
 extern "C" {
+    pub fn zx_clock_get_monotonic() -> zx_time_t;
+
     pub fn zx_job_default() -> zx_handle_t;
 
     pub fn zx_task_kill(handle: zx_handle_t) -> zx_status_t;
@@ -0,0 +1,306 @@
+//! Delivery of asynchronous signals through a self-pipe.
+//!
+//! Every `Signals` owns a non-blocking pipe. The process-wide signal handler
+//! installed here looks up all registrations for the signal it received and
+//! writes the signal number into their pipes, which is async-signal-safe. A
+//! full pipe means the signal is already pending there, so the write may fail
+//! silently: like the kernel itself, we coalesce signals.
+//!
+//! Registrations live in a fixed table of atomics that the handler can read
+//! without locking. Changes to the table and the installed handlers are
+//! serialized by a mutex. The previous disposition of each signal is saved
+//! when the first registration for it is made and restored when the last one
+//! is dropped; if it was a handler function, it is chained to.
+
+#![cfg_attr(test, allow(dead_code))]
+
+pub use self::imp::Signals;
+
+#[cfg(not(any(
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "l4re",
+    target_os = "vita",
+    target_os = "vxworks",
+)))]
+mod imp {
+    use crate::io;
+    use crate::mem;
+    use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd};
+    use crate::ptr;
+    use crate::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+    use crate::sync::Mutex;
+    use crate::sys::fd::FileDesc;
+    use crate::sys::pipe::anon_pipe;
+    use crate::sys::{cvt, cvt_r, os};
+    use crate::sys_common::IntoInner;
+
+    /// Signals that are delivered synchronously on a fault, or that cannot be
+    /// caught at all. Returning from a handler for the former re-executes the
+    /// faulting instruction, and `SIGSEGV`/`SIGBUS` are used to detect stack
+    /// overflows.
+    const FORBIDDEN: [libc::c_int; 6] =
+        [libc::SIGKILL, libc::SIGSTOP, libc::SIGSEGV, libc::SIGBUS, libc::SIGILL, libc::SIGFPE];
+    /// One more than the highest signal number we accept: MIPS has 127
+    /// signals, other architectures 64.
+    const NSIG: usize = if cfg!(any(
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6",
+    )) {
+        128
+    } else {
+        65
+    };
+    /// The maximum number of (signal, pipe) registrations in the process.
+    const MAX_SLOTS: usize = 128;
+
+    struct Slot {
+        signal: AtomicI32,
+        fd: AtomicI32,
+    }
+
+    static SLOTS: [Slot; MAX_SLOTS] =
+        [const { Slot { signal: AtomicI32::new(0), fd: AtomicI32::new(-1) } }; MAX_SLOTS];
+    /// The number of handler invocations currently scanning `SLOTS`.
+    static IN_HANDLER: AtomicUsize = AtomicUsize::new(0);
+    /// The handler function that was installed before ours, or 0.
+    static PREV_HANDLER: [AtomicUsize; NSIG] = [const { AtomicUsize::new(0) }; NSIG];
+    /// The `sa_flags` that `PREV_HANDLER` was installed with.
+    static PREV_FLAGS: [AtomicI32; NSIG] = [const { AtomicI32::new(0) }; NSIG];
+
+    /// For each signal with registrations: the number of them, and the
+    /// disposition to restore once there are none left.
+    static INSTALLED: Mutex<Vec<(libc::c_int, usize, SavedAction)>> = Mutex::new(Vec::new());
+
+    /// A disposition saved by `install`, to be handed back to `sigaction`.
+    struct SavedAction(libc::sigaction);
+
+    // SAFETY: some platforms (e.g. Haiku) store raw pointers in `sigaction`.
+    // They are never dereferenced here, only passed back to `sigaction` from
+    // whichever thread drops the last registration, which is as valid as doing
+    // so from the thread that saved them.
+    unsafe impl Send for SavedAction {}
+
+    unsafe extern "C" fn signal_handler(
+        signum: libc::c_int,
+        info: *mut libc::siginfo_t,
+        data: *mut libc::c_void,
+    ) {
+        // `write` may clobber errno of the interrupted code.
+        #[cfg(not(target_os = "vxworks"))]
+        let errno = os::errno();
+
+        IN_HANDLER.fetch_add(1, Ordering::SeqCst);
+        for slot in &SLOTS {
+            if slot.signal.load(Ordering::SeqCst) == signum {
+                let fd = slot.fd.load(Ordering::Relaxed);
+                let byte = signum as u8;
+                libc::write(fd, ptr::addr_of!(byte).cast(), 1);
+            }
+        }
+        IN_HANDLER.fetch_sub(1, Ordering::SeqCst);
+
+        let mut prev = PREV_HANDLER[signum as usize].load(Ordering::Acquire);
+        let flags = PREV_FLAGS[signum as usize].load(Ordering::Relaxed);
+        if prev != 0 && flags & libc::SA_RESETHAND as i32 != 0 {
+            // The previous handler was to be called only once; if another
+            // thread got to it first, don't call it again.
+            prev = PREV_HANDLER[signum as usize].swap(0, Ordering::Acquire);
+        }
+        if prev != 0 {
+            if flags & libc::SA_SIGINFO as i32 != 0 {
+                let prev: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) =
+                    mem::transmute(prev);
+                prev(signum, info, data);
+            } else {
+                let prev: extern "C" fn(libc::c_int) = mem::transmute(prev);
+                prev(signum);
+            }
+        }
+
+        #[cfg(not(target_os = "vxworks"))]
+        os::set_errno(errno);
+    }
+
+    pub struct Signals {
+        read: FileDesc,
+        // Kept open for the handler; closed only after deregistration.
+        write: FileDesc,
+        slots: Vec<usize>,
+    }
+
+    impl Signals {
+        pub fn new(signals: &[libc::c_int]) -> io::Result<Signals> {
+            for &signal in signals {
+                if signal <= 0 || signal as usize >= NSIG {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "invalid signal number",
+                    ));
+                }
+                if FORBIDDEN.contains(&signal) {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "signal cannot be delivered through a `Signals` handle",
+                    ));
+                }
+            }
+
+            let (read, write) = anon_pipe()?;
+            let read: FileDesc = read.into_inner();
+            let write: FileDesc = write.into_inner();
+            read.set_nonblocking(true)?;
+            write.set_nonblocking(true)?;
+
+            let mut signals = signals.to_vec();
+            signals.sort_unstable();
+            signals.dedup();
+
+            let mut this = Signals { read, write, slots: Vec::with_capacity(signals.len()) };
+            let mut installed = INSTALLED.lock().unwrap_or_else(|e| e.into_inner());
+            for signal in signals {
+                // On failure, dropping `this` undoes the registrations so far.
+                let Some(slot) =
+                    SLOTS.iter().position(|slot| slot.signal.load(Ordering::Relaxed) == 0)
+                else {
+                    drop(installed);
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::OutOfMemory,
+                        "too many signal registrations",
+                    ));
+                };
+                if let Err(e) = unsafe { install(&mut installed, signal) } {
+                    drop(installed);
+                    return Err(e);
+                }
+                SLOTS[slot].fd.store(this.write.as_raw_fd(), Ordering::Relaxed);
+                SLOTS[slot].signal.store(signal, Ordering::SeqCst);
+                this.slots.push(slot);
+            }
+            drop(installed);
+            Ok(this)
+        }
+
+        /// Returns the next pending signal without blocking.
+        pub fn try_wait(&self) -> io::Result<Option<libc::c_int>> {
+            let mut byte = 0u8;
+            match self.read.read(crate::slice::from_mut(&mut byte)) {
+                Ok(0) => unreachable!("signal pipe closed"),
+                Ok(_) => Ok(Some(byte as libc::c_int)),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => Ok(None),
+                Err(e) if e.is_interrupted() => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
+
+        pub fn wait(&self) -> io::Result<libc::c_int> {
+            loop {
+                if let Some(signal) = self.try_wait()? {
+                    return Ok(signal);
+                }
+                let mut pollfd =
+                    libc::pollfd { fd: self.read.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+                cvt_r(|| unsafe { libc::poll(&mut pollfd, 1, -1) })?;
+            }
+        }
+    }
+
+    /// Installs our handler for `signal` if this is its first registration.
+    unsafe fn install(
+        installed: &mut Vec<(libc::c_int, usize, SavedAction)>,
+        signal: libc::c_int,
+    ) -> io::Result<()> {
+        if let Some(entry) = installed.iter_mut().find(|entry| entry.0 == signal) {
+            entry.1 += 1;
+            return Ok(());
+        }
+
+        let mut old: libc::sigaction = mem::zeroed();
+        cvt(libc::sigaction(signal, ptr::null(), &mut old))?;
+        let prev = old.sa_sigaction;
+        let prev = if prev == libc::SIG_DFL || prev == libc::SIG_IGN { 0 } else { prev };
+        PREV_FLAGS[signal as usize].store(old.sa_flags as i32, Ordering::Relaxed);
+        PREV_HANDLER[signal as usize].store(prev, Ordering::Release);
+
+        // The previous handler is called from ours, so run ours with the
+        // signal mask and the stack it expects.
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_flags = libc::SA_SIGINFO
+            | libc::SA_RESTART
+            | old.sa_flags & (libc::SA_NODEFER | libc::SA_ONSTACK);
+        action.sa_sigaction = signal_handler as libc::sighandler_t;
+        action.sa_mask = old.sa_mask;
+        cvt(libc::sigaction(signal, &action, ptr::null_mut()))?;
+
+        installed.push((signal, 1, SavedAction(old)));
+        Ok(())
+    }
+
+    impl Drop for Signals {
+        fn drop(&mut self) {
+            let mut installed = INSTALLED.lock().unwrap_or_else(|e| e.into_inner());
+            for &slot in &self.slots {
+                let signal = SLOTS[slot].signal.swap(0, Ordering::SeqCst);
+                let index = installed.iter().position(|entry| entry.0 == signal).unwrap();
+                installed[index].1 -= 1;
+                if installed[index].1 == 0 {
+                    let (_, _, SavedAction(old)) = installed.swap_remove(index);
+                    unsafe { libc::sigaction(signal, &old, ptr::null_mut()) };
+                    PREV_HANDLER[signal as usize].store(0, Ordering::Release);
+                }
+            }
+            // A handler that saw one of our slots before it was cleared may
+            // still be writing to our pipe; wait for it before closing it.
+            while IN_HANDLER.load(Ordering::SeqCst) != 0 {
+                crate::hint::spin_loop();
+            }
+        }
+    }
+
+    impl AsFd for Signals {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.read.as_fd()
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "espidf",
+    target_os = "horizon",
+    target_os = "l4re",
+    target_os = "vita",
+    target_os = "vxworks",
+))]
+mod imp {
+    use crate::io;
+    use crate::os::unix::io::{AsFd, BorrowedFd};
+
+    const UNSUPPORTED: io::Error = io::const_io_error!(
+        io::ErrorKind::Unsupported,
+        "signal handling is not supported on this platform",
+    );
+
+    pub struct Signals(!);
+
+    impl Signals {
+        pub fn new(_signals: &[libc::c_int]) -> io::Result<Signals> {
+            Err(UNSUPPORTED)
+        }
+
+        pub fn try_wait(&self) -> io::Result<Option<libc::c_int>> {
+            self.0
+        }
+
+        pub fn wait(&self) -> io::Result<libc::c_int> {
+            self.0
+        }
+    }
+
+    impl AsFd for Signals {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.0
+        }
+    }
+}
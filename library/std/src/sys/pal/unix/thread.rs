@@ -5,6 +5,8 @@
 use crate::num::NonZero;
 use crate::ptr;
 use crate::sys::{os, stack_overflow};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::sys::cvt;
 use crate::time::Duration;
 
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
@@ -228,6 +230,66 @@ pub fn set_name(_name: &CStr) {
         // Newlib, Emscripten, and VxWorks have no way to set a thread name.
     }
 
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            for &cpu in cpus {
+                if cpu >= libc::CPU_SETSIZE as usize {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "CPU index exceeds the size of the affinity mask",
+                    ));
+                }
+                libc::CPU_SET(cpu, &mut set);
+            }
+            cvt(libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set))?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "setting the thread affinity is not supported on this platform",
+        ))
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_vendor = "apple",
+    ))]
+    pub fn set_priority(priority: i32) -> io::Result<()> {
+        unsafe {
+            let mut param: libc::sched_param = mem::zeroed();
+            param.sched_priority = priority;
+            // Unlike most libc functions, this returns the error code
+            // directly instead of setting `errno`.
+            match libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param) {
+                0 => Ok(()),
+                err => Err(io::Error::from_raw_os_error(err)),
+            }
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_vendor = "apple",
+    )))]
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "setting the thread priority is not supported on this platform",
+        ))
+    }
+
     #[cfg(not(target_os = "espidf"))]
     pub fn sleep(dur: Duration) {
         let mut secs = dur.as_secs();
@@ -1,4 +1,4 @@
-use crate::time::Duration;
+use crate::time::{Clock, Duration};
 use crate::{fmt, io};
 
 const NSEC_PER_SEC: u64 = 1_000_000_000;
@@ -257,23 +257,24 @@ pub struct Instant {
 }
 
 impl Instant {
+    // https://www.manpagez.com/man/3/clock_gettime/
+    //
+    // CLOCK_UPTIME_RAW   clock that increments monotonically, in the same man-
+    //                    ner as CLOCK_MONOTONIC_RAW, but that does not incre-
+    //                    ment while the system is asleep.  The returned value
+    //                    is identical to the result of mach_absolute_time()
+    //                    after the appropriate mach_timebase conversion is
+    //                    applied.
+    //
+    // Instant on macos was historically implemented using mach_absolute_time;
+    // we preserve this value domain out of an abundance of caution.
+    #[cfg(target_vendor = "apple")]
+    const CLOCK_ID: libc::clockid_t = libc::CLOCK_UPTIME_RAW;
+    #[cfg(not(target_vendor = "apple"))]
+    const CLOCK_ID: libc::clockid_t = libc::CLOCK_MONOTONIC;
+
     pub fn now() -> Instant {
-        // https://www.manpagez.com/man/3/clock_gettime/
-        //
-        // CLOCK_UPTIME_RAW   clock that increments monotonically, in the same man-
-        //                    ner as CLOCK_MONOTONIC_RAW, but that does not incre-
-        //                    ment while the system is asleep.  The returned value
-        //                    is identical to the result of mach_absolute_time()
-        //                    after the appropriate mach_timebase conversion is
-        //                    applied.
-        //
-        // Instant on macos was historically implemented using mach_absolute_time;
-        // we preserve this value domain out of an abundance of caution.
-        #[cfg(target_vendor = "apple")]
-        const clock_id: libc::clockid_t = libc::CLOCK_UPTIME_RAW;
-        #[cfg(not(target_vendor = "apple"))]
-        const clock_id: libc::clockid_t = libc::CLOCK_MONOTONIC;
-        Instant { t: Timespec::now(clock_id) }
+        Instant { t: Timespec::now(Self::CLOCK_ID) }
     }
 
     pub fn checked_sub_instant(&self, other: &Instant) -> Option<Duration> {
@@ -289,6 +290,44 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    use crate::mem::MaybeUninit;
+    use crate::sys::cvt;
+
+    #[allow(unreachable_patterns)]
+    let clock_id = match clock {
+        Clock::Monotonic => Instant::CLOCK_ID,
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        Clock::Boottime => libc::CLOCK_BOOTTIME,
+        // Unlike on other Unixes, `CLOCK_MONOTONIC` keeps counting while the
+        // system is asleep.
+        #[cfg(target_vendor = "apple")]
+        Clock::Boottime => libc::CLOCK_MONOTONIC,
+        #[cfg(any(target_os = "linux", target_os = "android", target_vendor = "apple"))]
+        Clock::MonotonicRaw => libc::CLOCK_MONOTONIC_RAW,
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_vendor = "apple",
+        ))]
+        Clock::ThreadCpuTime => libc::CLOCK_THREAD_CPUTIME_ID,
+        _ => {
+            return Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "this clock is not available on this platform",
+            ));
+        }
+    };
+
+    let mut t = MaybeUninit::uninit();
+    cvt(unsafe { libc::clock_gettime(clock_id, t.as_mut_ptr()) })?;
+    let t = unsafe { t.assume_init() };
+    Ok(Duration::new(t.tv_sec as u64, t.tv_nsec as u32))
+}
+
 impl fmt::Debug for Instant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Instant")
@@ -6,6 +6,9 @@
 use crate::sys::time::SystemTime;
 use crate::sys::unsupported;
 
+pub use crate::sys_common::fs::{copy_with, Dir, Mmap};
+use crate::sys_common::fs::MapOptions;
+
 pub struct File(!);
 
 pub struct FileAttr(!);
@@ -202,6 +205,36 @@ pub fn truncate(&self, _size: u64) -> io::Result<()> {
         self.0
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn map(
+        &self,
+        _offset: u64,
+        _len: usize,
+        _writable: bool,
+        _opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        self.0
+    }
+
     pub fn read(&self, _buf: &mut [u8]) -> io::Result<usize> {
         self.0
     }
@@ -22,6 +22,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(_dur: Duration) {
         panic!("can't sleep");
     }
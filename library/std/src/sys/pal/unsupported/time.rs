@@ -1,4 +1,6 @@
-use crate::time::Duration;
+use crate::io;
+use crate::sys::unsupported;
+use crate::time::{Clock, Duration};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Instant(Duration);
@@ -26,6 +28,10 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(_clock: Clock) -> io::Result<Duration> {
+    unsupported()
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         panic!("time not implemented on this platform")
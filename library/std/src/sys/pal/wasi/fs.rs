@@ -17,7 +17,8 @@
 use crate::sys::unsupported;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
-pub use crate::sys_common::fs::exists;
+pub use crate::sys_common::fs::{copy_with, exists, Dir, Mmap};
+use crate::sys_common::fs::MapOptions;
 
 pub struct File {
     fd: WasiFd,
@@ -427,6 +428,36 @@ pub fn truncate(&self, size: u64) -> io::Result<()> {
         self.fd.filestat_set_size(size)
     }
 
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn map(
+        &self,
+        _offset: u64,
+        _len: usize,
+        _writable: bool,
+        _opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        unsupported()
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.read_vectored(&mut [IoSliceMut::new(buf)])
     }
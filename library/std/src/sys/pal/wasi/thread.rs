@@ -134,6 +134,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         let nanos = dur.as_nanos();
         assert!(nanos <= u64::MAX as u128);
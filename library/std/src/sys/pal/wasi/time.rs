@@ -1,6 +1,9 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
-use crate::time::Duration;
+use super::err2io;
+use crate::io;
+use crate::sys::unsupported;
+use crate::time::{Clock, Duration};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Instant(Duration);
@@ -38,6 +41,16 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    let clock = match clock {
+        Clock::Monotonic => wasi::CLOCKID_MONOTONIC,
+        Clock::ThreadCpuTime => wasi::CLOCKID_THREAD_CPUTIME_ID,
+        _ => return unsupported(),
+    };
+    let ts = unsafe { wasi::clock_time_get(clock, 1).map_err(err2io)? };
+    Ok(Duration::new((ts / 1_000_000_000) as u64, (ts % 1_000_000_000) as u32))
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         SystemTime(current_time(wasi::CLOCKID_REALTIME))
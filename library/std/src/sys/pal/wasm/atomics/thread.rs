@@ -18,6 +18,14 @@ pub fn yield_now() {}
 
     pub fn set_name(_name: &CStr) {}
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         #[cfg(target_arch = "wasm32")]
         use core::arch::wasm32 as wasm;
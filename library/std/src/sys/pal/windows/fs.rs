@@ -9,7 +9,7 @@
 use crate::mem::{self, MaybeUninit};
 use crate::os::windows::io::{AsHandle, BorrowedHandle};
 use crate::path::{Path, PathBuf};
-use crate::ptr;
+use crate::ptr::{self, NonNull};
 use crate::slice;
 use crate::sync::Arc;
 use crate::sys::handle::Handle;
@@ -22,6 +22,9 @@
 use super::{to_u16s, IoResult};
 use crate::sys::path::maybe_verbatim;
 
+pub use crate::sys_common::fs::{copy_with, Dir};
+use crate::sys_common::fs::MapOptions;
+
 pub struct File {
     handle: Handle,
 }
@@ -354,6 +357,72 @@ pub fn truncate(&self, size: u64) -> io::Result<()> {
         api::set_file_information_by_handle(self.handle.as_raw_handle(), &info).io_result()
     }
 
+    fn lock_file(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<()> {
+        // Lock the whole file, including any data appended to it later.
+        let handle = self.handle.as_raw_handle();
+        cvt(unsafe {
+            let mut overlapped: c::OVERLAPPED = mem::zeroed();
+            c::LockFileEx(handle, flags, 0, u32::MAX, u32::MAX, &mut overlapped)
+        })
+        .map(drop)
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0)
+    }
+
+    fn try_lock_file(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<bool> {
+        match self.lock_file(flags | c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(e)
+                if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32)
+                    || e.raw_os_error() == Some(c::ERROR_IO_PENDING as i32) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.try_lock_file(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_lock_file(0)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        let handle = self.handle.as_raw_handle();
+        // Unlocking a file that isn't locked fails with `ERROR_NOT_LOCKED`,
+        // which we report as success like `flock` does.
+        let unlock = || match cvt(unsafe { c::UnlockFile(handle, 0, 0, u32::MAX, u32::MAX) }) {
+            Ok(_) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(c::ERROR_NOT_LOCKED as i32) => Ok(false),
+            Err(e) => Err(e),
+        };
+        // A handle may hold both an exclusive and a shared lock on the file, in
+        // which case a second call is needed to release the latter.
+        if unlock()? {
+            unlock()?;
+        }
+        Ok(())
+    }
+
+    pub fn map(
+        &self,
+        offset: u64,
+        len: usize,
+        writable: bool,
+        opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        Mmap::map(self, offset, len, writable, opts)
+    }
+
     #[cfg(not(target_vendor = "uwp"))]
     pub fn file_attr(&self) -> io::Result<FileAttr> {
         unsafe {
@@ -858,6 +927,117 @@ fn open_link_no_reparse(parent: &File, name: &[u16], access: u32) -> io::Result<
     }
 }
 
+pub struct Mmap {
+    // The start of the view as returned by `MapViewOfFile`, which is needed to
+    // unmap it.
+    view: *mut c_void,
+    // The start of the requested region, which the view only starts at a
+    // multiple of the allocation granularity before.
+    ptr: NonNull<u8>,
+    len: usize,
+    // A handle to flush the file with, for views that write to it.
+    file: Option<Handle>,
+}
+
+// Like `&[u8]`, the mapping can be shared and sent between threads.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    fn map(
+        file: &File,
+        offset: u64,
+        len: usize,
+        writable: bool,
+        opts: &MapOptions,
+    ) -> io::Result<Mmap> {
+        if len == 0 {
+            // Views cannot be empty, so don't create one.
+            return Ok(Mmap { view: ptr::null_mut(), ptr: NonNull::dangling(), len: 0, file: None });
+        }
+
+        let granularity = unsafe {
+            let mut info: c::SYSTEM_INFO = mem::zeroed();
+            c::GetSystemInfo(&mut info);
+            info.dwAllocationGranularity
+        };
+        let align = (offset % granularity as u64) as usize;
+        let view_len = len.checked_add(align).ok_or(io::const_io_error!(
+            io::ErrorKind::InvalidInput,
+            "mapped region is too large",
+        ))?;
+        let view_offset = offset - align as u64;
+        let (protect, access) = match (writable, opts.copy_on_write) {
+            (false, _) => (c::PAGE_READONLY, c::FILE_MAP_READ),
+            (true, false) => (c::PAGE_READWRITE, c::FILE_MAP_WRITE),
+            (true, true) => (c::PAGE_WRITECOPY, c::FILE_MAP_COPY),
+        };
+        let flush_file = if writable && !opts.copy_on_write {
+            Some(file.handle.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?)
+        } else {
+            None
+        };
+
+        // The view keeps the mapping object alive, so its handle can be closed
+        // right away.
+        let handle = file.handle.as_raw_handle();
+        let mapping =
+            unsafe { c::CreateFileMappingW(handle, ptr::null(), protect, 0, 0, ptr::null()) };
+        if mapping.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let mapping = unsafe { Handle::from_raw_handle(mapping) };
+        let view = unsafe {
+            c::MapViewOfFile(
+                mapping.as_raw_handle(),
+                access,
+                (view_offset >> 32) as u32,
+                view_offset as u32,
+                view_len,
+            )
+        };
+        if view.Value.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let ptr = unsafe { NonNull::new_unchecked(view.Value.cast::<u8>().add(align)) };
+        Ok(Mmap { view: view.Value, ptr, len, file: flush_file })
+    }
+
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn flush(&self, sync: bool) -> io::Result<()> {
+        if self.len == 0 {
+            return Ok(());
+        }
+        cvt(unsafe { c::FlushViewOfFile(self.ptr.as_ptr().cast(), self.len) })?;
+        // `FlushViewOfFile` only starts writing the pages back to the file.
+        if let (true, Some(file)) = (sync, &self.file) {
+            cvt(unsafe { c::FlushFileBuffers(file.as_raw_handle()) })?;
+        }
+        Ok(())
+    }
+
+    // Advice is only a hint, so it is fine to ignore it.
+    pub fn advise(&self, _advice: crate::fs::MmapAdvice) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            let view = c::MEMORY_MAPPED_VIEW_ADDRESS { Value: self.view };
+            unsafe { c::UnmapViewOfFile(view) };
+        }
+    }
+}
+
 impl AsInner<Handle> for File {
     #[inline]
     fn as_inner(&self) -> &Handle {
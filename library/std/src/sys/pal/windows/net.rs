@@ -3,7 +3,7 @@
 use crate::cmp;
 use crate::io::{self, BorrowedBuf, BorrowedCursor, IoSlice, IoSliceMut, Read};
 use crate::mem;
-use crate::net::{Shutdown, SocketAddr};
+use crate::net::{Shutdown, SocketAddr, TcpKeepalive};
 use crate::os::windows::io::{
     AsRawSocket, AsSocket, BorrowedSocket, FromRawSocket, IntoRawSocket, OwnedSocket, RawSocket,
 };
@@ -422,6 +422,43 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        let Some(keepalive) = keepalive else {
+            return net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, c::FALSE);
+        };
+
+        // The timeouts are in whole seconds, and must not be zero. Setting
+        // them requires Windows 10, version 1709 or later.
+        let secs = |dur: Duration| cmp::max(dur.as_secs(), 1).min(c_int::MAX as u64) as u32;
+        if let Some(time) = keepalive.time {
+            net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPALIVE, secs(time))?;
+        }
+        if let Some(interval) = keepalive.interval {
+            net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPINTVL, secs(interval))?;
+        }
+        if let Some(retries) = keepalive.retries {
+            net::setsockopt(self, c::IPPROTO_TCP, c::TCP_KEEPCNT, retries)?;
+        }
+
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, c::TRUE)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c::BOOL = net::getsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "SO_REUSEPORT is not supported on Windows",
+        ))
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Ok(false)
+    }
+
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_ERROR)?;
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
@@ -28,6 +28,7 @@
 use crate::sys::stdio;
 use crate::sys_common::process::{CommandEnv, CommandEnvs};
 use crate::sys_common::IntoInner;
+use crate::time::Instant;
 
 use core::ffi::c_void;
 
@@ -693,6 +694,15 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
     }
 
+    pub fn wait_deadline(&mut self, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+        let timeout = super::dur2timeout(deadline.saturating_duration_since(Instant::now()));
+        match unsafe { c::WaitForSingleObject(self.handle.as_raw_handle(), timeout) } {
+            c::WAIT_OBJECT_0 => self.try_wait(),
+            c::WAIT_TIMEOUT => Ok(None),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
     pub fn handle(&self) -> &Handle {
         &self.handle
     }
@@ -72,6 +72,20 @@ pub unsafe fn set_name_wide(name: &[u16]) {
         c::SetThreadDescription(c::GetCurrentThread(), name.as_ptr());
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "setting the thread affinity is not supported on this platform",
+        ))
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "setting the thread priority is not supported on this platform",
+        ))
+    }
+
     pub fn join(self) {
         let rc = unsafe { c::WaitForSingleObject(self.handle.as_raw_handle(), c::INFINITE) };
         if rc == c::WAIT_FAILED {
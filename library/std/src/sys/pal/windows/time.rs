@@ -1,10 +1,11 @@
 use crate::cmp::Ordering;
 use crate::fmt;
+use crate::io;
 use crate::mem;
 use crate::ptr::null;
-use crate::sys::c;
+use crate::sys::{c, cvt};
 use crate::sys_common::IntoInner;
-use crate::time::Duration;
+use crate::time::{Clock, Duration};
 
 use core::hash::{Hash, Hasher};
 use core::ops::Neg;
@@ -64,6 +65,33 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        Clock::Monotonic => Ok(Instant::now().t),
+        Clock::ThreadCpuTime => unsafe {
+            let mut creation: c::FILETIME = mem::zeroed();
+            let mut exit: c::FILETIME = mem::zeroed();
+            let mut kernel: c::FILETIME = mem::zeroed();
+            let mut user: c::FILETIME = mem::zeroed();
+            cvt(c::GetThreadTimes(
+                c::GetCurrentThread(),
+                &mut creation,
+                &mut exit,
+                &mut kernel,
+                &mut user,
+            ))?;
+            let intervals = |t: c::FILETIME| {
+                (t.dwLowDateTime as u64) | ((t.dwHighDateTime as u64) << 32)
+            };
+            Ok(intervals2dur(intervals(kernel) + intervals(user)))
+        },
+        _ => Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "this clock is not available on this platform",
+        )),
+    }
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         unsafe {
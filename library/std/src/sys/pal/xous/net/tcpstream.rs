@@ -1,7 +1,9 @@
 use super::*;
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-use crate::net::{IpAddr, Ipv4Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    IpAddr, Ipv4Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6, TcpKeepalive,
+};
 use crate::os::xous::services;
 use crate::sync::Arc;
 use crate::time::Duration;
@@ -373,6 +375,30 @@ pub fn nodelay(&self) -> io::Result<bool> {
         .map(|res| res[0] != 0)?)
     }
 
+    pub fn set_keepalive(&self, _: Option<&TcpKeepalive>) -> io::Result<()> {
+        unimpl!();
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        unimpl!();
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        unimpl!();
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        unimpl!();
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        unimpl!();
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        unimpl!();
+    }
+
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         if ttl > 255 {
             return Err(io::Error::new(io::ErrorKind::InvalidInput, "TTL must be less than 256"));
@@ -404,6 +404,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         unimpl!();
     }
 
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        unimpl!();
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        unimpl!();
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        unimpl!();
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        unimpl!();
+    }
+
     pub fn set_multicast_loop_v4(&self, _: bool) -> io::Result<()> {
         unimpl!();
     }
@@ -6,6 +6,7 @@
     MemoryFlags, Syscall, ThreadId,
 };
 use crate::os::xous::services::{ticktimer_server, TicktimerScalar};
+use crate::sys::unsupported;
 use crate::time::Duration;
 use core::arch::asm;
 
@@ -113,6 +114,14 @@ pub fn set_name(_name: &CStr) {
         // nope
     }
 
+    pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn set_priority(_priority: i32) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn sleep(dur: Duration) {
         // Because the sleep server works on units of `usized milliseconds`, split
         // the messages up into these chunks. This means we may run into issues
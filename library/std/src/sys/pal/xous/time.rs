@@ -2,7 +2,9 @@
 use crate::os::xous::services::{
     systime_server, ticktimer_server, SystimeScalar::GetUtcTimeMs, TicktimerScalar::ElapsedMs,
 };
-use crate::time::Duration;
+use crate::io;
+use crate::sys::unsupported;
+use crate::time::{Clock, Duration};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Instant(Duration);
@@ -34,6 +36,13 @@ pub fn checked_sub_duration(&self, other: &Duration) -> Option<Instant> {
     }
 }
 
+pub fn clock_now(clock: Clock) -> io::Result<Duration> {
+    match clock {
+        Clock::Monotonic => Ok(Instant::now().0),
+        _ => unsupported(),
+    }
+}
+
 impl SystemTime {
     pub fn now() -> SystemTime {
         let result = blocking_scalar(systime_server(), GetUtcTimeMs.into())
@@ -0,0 +1,18 @@
+use crate::sync::atomic::AtomicU32;
+use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::time::Duration;
+
+/// Waits for a notification on `atomic` if it holds `expected`.
+///
+/// Returns false on timeout. May also return spuriously.
+pub fn wait(atomic: &AtomicU32, expected: u32, timeout: Option<Duration>) -> bool {
+    futex_wait(atomic, expected, timeout)
+}
+
+pub fn notify_one(atomic: &AtomicU32) {
+    futex_wake(atomic);
+}
+
+pub fn notify_all(atomic: &AtomicU32) {
+    futex_wake_all(atomic);
+}
@@ -0,0 +1,68 @@
+//! Waiting on atomics without a futex.
+//!
+//! Waiters block on one of a fixed number of condition variables, chosen by
+//! the address of the atomic. Several atomics share each of them, so a
+//! notification wakes every waiter of the bucket, and the ones waiting on
+//! another atomic return spuriously.
+//!
+//! A notifier locks the bucket's mutex before notifying. Waiters check the
+//! value while holding that mutex, so they either see a change made before the
+//! notification, or are already blocked on the condition variable when it is
+//! notified.
+
+use crate::ptr;
+use crate::sync::atomic::AtomicU32;
+use crate::sync::atomic::Ordering::Relaxed;
+use crate::sync::{Condvar, Mutex, PoisonError};
+use crate::time::Duration;
+
+const BUCKETS: usize = 64;
+
+struct Bucket {
+    mutex: Mutex<()>,
+    condvar: Condvar,
+}
+
+static TABLE: [Bucket; BUCKETS] =
+    [const { Bucket { mutex: Mutex::new(()), condvar: Condvar::new() } }; BUCKETS];
+
+fn bucket(atomic: &AtomicU32) -> &'static Bucket {
+    // Fibonacci hashing, so that neighbouring atomics end up in different
+    // buckets.
+    let hash = ptr::from_ref(atomic).addr().wrapping_mul(0x9E37_79B9_7F4A_7C15_u64 as usize);
+    &TABLE[hash >> (usize::BITS - BUCKETS.ilog2())]
+}
+
+/// Waits for a notification on `atomic` if it holds `expected`.
+///
+/// Returns false on timeout. May also return spuriously.
+pub fn wait(atomic: &AtomicU32, expected: u32, timeout: Option<Duration>) -> bool {
+    let bucket = bucket(atomic);
+    let guard = bucket.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+    if atomic.load(Relaxed) != expected {
+        return true;
+    }
+    match timeout {
+        None => {
+            drop(bucket.condvar.wait(guard));
+            true
+        }
+        Some(timeout) => {
+            let (_guard, result) =
+                bucket.condvar.wait_timeout(guard, timeout).unwrap_or_else(PoisonError::into_inner);
+            !result.timed_out()
+        }
+    }
+}
+
+pub fn notify_one(atomic: &AtomicU32) {
+    // The waiter we would wake may share its bucket with waiters on other
+    // atomics, so all of them have to be woken.
+    notify_all(atomic)
+}
+
+pub fn notify_all(atomic: &AtomicU32) {
+    let bucket = bucket(atomic);
+    drop(bucket.mutex.lock());
+    bucket.condvar.notify_all();
+}
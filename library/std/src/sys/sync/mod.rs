@@ -1,3 +1,4 @@
+pub mod atomic_wait;
 mod condvar;
 mod mutex;
 mod once;
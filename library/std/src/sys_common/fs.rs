@@ -1,8 +1,10 @@
 #![allow(dead_code)] // not used on all platforms
 
+use crate::fmt;
 use crate::fs;
 use crate::io::{self, Error, ErrorKind};
-use crate::path::Path;
+use crate::path::{Component, Path, PathBuf};
+use crate::sys::fs as fs_imp;
 
 pub(crate) const NOT_FILE_ERROR: Error = io::const_io_error!(
     ErrorKind::InvalidInput,
@@ -25,6 +27,96 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     Ok(ret)
 }
 
+pub(crate) const REFLINK_ERROR: Error = io::const_io_error!(
+    ErrorKind::Unsupported,
+    "copy-on-write clones are not supported on this platform",
+);
+
+/// Options for `copy_with`, shared by all platforms.
+pub struct CopyOptions<'a> {
+    pub reflink_only: bool,
+    pub sparse: bool,
+    pub preserve_metadata: bool,
+    pub progress: Option<Box<dyn FnMut(u64) + 'a>>,
+}
+
+impl CopyOptions<'_> {
+    pub fn new() -> Self {
+        CopyOptions { reflink_only: false, sparse: false, preserve_metadata: false, progress: None }
+    }
+
+    /// Whether no option is set, in which case the platform's `copy` is used.
+    pub fn is_default(&self) -> bool {
+        !self.reflink_only && !self.sparse && !self.preserve_metadata && self.progress.is_none()
+    }
+}
+
+/// A writer that reports the total number of bytes written after every write.
+struct ProgressWriter<'p, W, F: ?Sized> {
+    inner: W,
+    written: u64,
+    progress: &'p mut F,
+}
+
+impl<W: io::Write, F: ?Sized + FnMut(u64)> io::Write for ProgressWriter<'_, W, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n as u64;
+        (self.progress)(self.written);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Copies the contents of `reader` to `writer` with `io::copy`, calling the
+/// progress callback of `opts`, if any, as data is written.
+pub fn copy_data(
+    reader: &mut fs::File,
+    writer: &mut fs::File,
+    opts: &mut CopyOptions<'_>,
+) -> io::Result<u64> {
+    match &mut opts.progress {
+        None => io::copy(reader, writer),
+        Some(progress) => {
+            let progress = &mut **progress;
+            io::copy(reader, &mut ProgressWriter { inner: writer, written: 0, progress })
+        }
+    }
+}
+
+/// Sets the access and modification times of `writer` to those in `metadata`.
+pub fn copy_times(writer: &fs::File, metadata: &fs::Metadata) -> io::Result<()> {
+    let times =
+        fs::FileTimes::new().set_accessed(metadata.accessed()?).set_modified(metadata.modified()?);
+    writer.set_times(times)
+}
+
+pub fn copy_with(from: &Path, to: &Path, opts: &mut CopyOptions<'_>) -> io::Result<u64> {
+    if opts.reflink_only {
+        return Err(REFLINK_ERROR);
+    }
+
+    let mut reader = fs::File::open(from)?;
+    let metadata = reader.metadata()?;
+
+    if !metadata.is_file() {
+        return Err(NOT_FILE_ERROR);
+    }
+
+    let mut writer = fs::File::create(to)?;
+    let perm = metadata.permissions();
+
+    let ret = copy_data(&mut reader, &mut writer, opts)?;
+    writer.set_permissions(perm)?;
+    if opts.preserve_metadata {
+        copy_times(&writer, &metadata)?;
+    }
+    Ok(ret)
+}
+
 pub fn remove_dir_all(path: &Path) -> io::Result<()> {
     let filetype = fs::symlink_metadata(path)?.file_type();
     if filetype.is_symlink() { fs::remove_file(path) } else { remove_dir_all_recursive(path) }
@@ -49,3 +141,180 @@ pub fn exists(path: &Path) -> io::Result<bool> {
         Err(error) => Err(error),
     }
 }
+
+/// Options for opening a directory handle, shared by all platforms.
+#[derive(Clone, Copy, Debug)]
+pub struct DirOptions {
+    pub follow_symlinks: bool,
+    pub beneath: bool,
+}
+
+impl DirOptions {
+    pub fn new() -> DirOptions {
+        DirOptions { follow_symlinks: true, beneath: false }
+    }
+
+    /// Whether symbolic links are followed when resolving paths relative to a
+    /// directory. Resolving beneath a directory never follows them, as a link
+    /// could point anywhere.
+    pub fn follows_symlinks(&self) -> bool {
+        self.follow_symlinks && !self.beneath
+    }
+}
+
+pub(crate) const ESCAPE_ERROR: Error = io::const_io_error!(
+    ErrorKind::PermissionDenied,
+    "path escapes the directory it is resolved beneath",
+);
+
+pub(crate) const SYMLINK_ERROR: Error = io::const_io_error!(
+    ErrorKind::FilesystemLoop,
+    "symbolic link found where symbolic links are not followed",
+);
+
+/// Checks that `path` is relative and contains no `..` components, so that
+/// resolving it cannot leave the directory it is resolved against through
+/// anything but a symbolic link.
+pub fn check_beneath(path: &Path) -> io::Result<()> {
+    for component in path.components() {
+        match component {
+            Component::Normal(_) | Component::CurDir => {}
+            Component::Prefix(_) | Component::RootDir | Component::ParentDir => {
+                return Err(ESCAPE_ERROR);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Path-based directory handle for platforms without `openat` and friends.
+///
+/// Relative paths are joined to the absolute path of the directory, so unlike
+/// a real handle this does not follow the directory if it is moved, and the
+/// symbolic link checks below are subject to races.
+pub struct Dir {
+    root: PathBuf,
+    opts: DirOptions,
+}
+
+impl Dir {
+    pub fn open(path: &Path, opts: &DirOptions) -> io::Result<Dir> {
+        let root = crate::path::absolute(path)?;
+        let attr = if opts.follow_symlinks { fs_imp::stat(&root)? } else { fs_imp::lstat(&root)? };
+        if attr.file_type().is_symlink() {
+            return Err(SYMLINK_ERROR);
+        }
+        if !attr.file_type().is_dir() {
+            return Err(io::const_io_error!(ErrorKind::NotADirectory, "not a directory"));
+        }
+        Ok(Dir { root, opts: *opts })
+    }
+
+    fn resolve(&self, path: &Path) -> io::Result<PathBuf> {
+        if !self.opts.beneath {
+            return Ok(self.root.join(path));
+        }
+
+        check_beneath(path)?;
+        let mut resolved = self.root.clone();
+        let mut components = path.components().filter(|c| *c != Component::CurDir).peekable();
+        while let Some(component) = components.next() {
+            resolved.push(component);
+            // Only the last component may be a symbolic link, and only if the
+            // operation does not follow it.
+            if components.peek().is_some() && fs_imp::lstat(&resolved)?.file_type().is_symlink() {
+                return Err(SYMLINK_ERROR);
+            }
+        }
+        Ok(resolved)
+    }
+
+    fn resolve_nofollow(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = self.resolve(path)?;
+        if !self.opts.follows_symlinks() {
+            if let Ok(attr) = fs_imp::lstat(&path) {
+                if attr.file_type().is_symlink() {
+                    return Err(SYMLINK_ERROR);
+                }
+            }
+        }
+        Ok(path)
+    }
+
+    pub fn open_dir(&self, path: &Path) -> io::Result<Dir> {
+        Dir::open(&self.resolve_nofollow(path)?, &self.opts)
+    }
+
+    pub fn open_file(&self, path: &Path, opts: &fs_imp::OpenOptions) -> io::Result<fs_imp::File> {
+        fs_imp::File::open(&self.resolve_nofollow(path)?, opts)
+    }
+
+    pub fn create_dir(&self, path: &Path) -> io::Result<()> {
+        fs_imp::DirBuilder::new().mkdir(&self.resolve(path)?)
+    }
+
+    pub fn remove_file(&self, path: &Path) -> io::Result<()> {
+        fs_imp::unlink(&self.resolve(path)?)
+    }
+
+    pub fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        fs_imp::rmdir(&self.resolve(path)?)
+    }
+
+    pub fn rename(&self, from: &Path, to_dir: &Dir, to: &Path) -> io::Result<()> {
+        fs_imp::rename(&self.resolve(from)?, &to_dir.resolve(to)?)
+    }
+
+    pub fn metadata(&self, path: &Path) -> io::Result<fs_imp::FileAttr> {
+        fs_imp::stat(&self.resolve_nofollow(path)?)
+    }
+
+    pub fn symlink_metadata(&self, path: &Path) -> io::Result<fs_imp::FileAttr> {
+        fs_imp::lstat(&self.resolve(path)?)
+    }
+
+    pub fn read_dir(&self) -> io::Result<fs_imp::ReadDir> {
+        fs_imp::readdir(&self.root)
+    }
+}
+
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("root", &self.root).field("opts", &self.opts).finish()
+    }
+}
+
+/// Options for `File::map`, shared by all platforms.
+#[derive(Clone, Copy, Debug)]
+pub struct MapOptions {
+    pub copy_on_write: bool,
+    pub populate: bool,
+}
+
+impl MapOptions {
+    pub fn new() -> MapOptions {
+        MapOptions { copy_on_write: false, populate: false }
+    }
+}
+
+/// The memory mapping of platforms that cannot map files, on which `File::map`
+/// always fails.
+pub struct Mmap(!);
+
+impl Mmap {
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0
+    }
+
+    pub fn flush(&self, _sync: bool) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn advise(&self, _advice: fs::MmapAdvice) -> io::Result<()> {
+        self.0
+    }
+}
@@ -5,7 +5,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, ErrorKind, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpKeepalive};
 use crate::ptr;
 use crate::sys::common::small_c_string::run_with_cstr;
 use crate::sys::net::netc as c;
@@ -99,6 +99,35 @@ pub fn getsockopt<T: Copy>(sock: &Socket, level: c_int, option_name: c_int) -> i
     }
 }
 
+fn set_buffer_size(sock: &Socket, option_name: c_int, size: usize) -> io::Result<()> {
+    let size = cmp::min(size, c_int::MAX as usize) as c_int;
+    setsockopt(sock, c::SOL_SOCKET, option_name, size)
+}
+
+fn buffer_size(sock: &Socket, option_name: c_int) -> io::Result<usize> {
+    let raw: c_int = getsockopt(sock, c::SOL_SOCKET, option_name)?;
+    Ok(raw as usize)
+}
+
+/// Clamps a `listen` backlog to what the platform supports.
+fn backlog(backlog: u32) -> c_int {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "horizon")] {
+            // The 3DS doesn't support a big connection backlog. Sometimes
+            // it allows up to about 37, but other times it doesn't even
+            // accept 32. There may be a global limitation causing this.
+            let max = 20;
+        } else if #[cfg(target_os = "haiku")] {
+            // Haiku does not support a queue length > 32
+            // https://github.com/haiku/haiku/blob/979a0bc487864675517fb2fab28f87dc8bf43041/headers/posix/sys/socket.h#L81
+            let max = 32;
+        } else {
+            let max = c_int::MAX as u32;
+        }
+    }
+    cmp::min(backlog, max) as c_int
+}
+
 fn sockname<F>(f: F) -> io::Result<SocketAddr>
 where
     F: FnOnce(*mut c::sockaddr, *mut c::socklen_t) -> c_int,
@@ -340,6 +369,30 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.inner.nodelay()
     }
 
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        self.inner.set_keepalive(keepalive)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.inner.keepalive()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
     pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL, ttl as c_int)
     }
@@ -418,24 +471,8 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         let (addr, len) = addr.into_inner();
         cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
 
-        cfg_if::cfg_if! {
-            if #[cfg(target_os = "horizon")] {
-                // The 3DS doesn't support a big connection backlog. Sometimes
-                // it allows up to about 37, but other times it doesn't even
-                // accept 32. There may be a global limitation causing this.
-                let backlog = 20;
-            } else if #[cfg(target_os = "haiku")] {
-                // Haiku does not support a queue length > 32
-                // https://github.com/haiku/haiku/blob/979a0bc487864675517fb2fab28f87dc8bf43041/headers/posix/sys/socket.h#L81
-                let backlog = 32;
-            } else {
-                // The default for all other platforms
-                let backlog = 128;
-            }
-        }
-
         // Start listening
-        cvt(unsafe { c::listen(sock.as_raw(), backlog) })?;
+        cvt(unsafe { c::listen(sock.as_raw(), backlog(128)) })?;
         Ok(TcpListener { inner: sock })
     }
 
@@ -510,6 +547,131 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Unconnected TCP sockets
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct TcpSocket {
+    inner: Socket,
+}
+
+impl TcpSocket {
+    pub fn new(addr: &SocketAddr) -> io::Result<TcpSocket> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_STREAM)?;
+        Ok(TcpSocket { inner: sock })
+    }
+
+    #[inline]
+    pub fn socket(&self) -> &Socket {
+        &self.inner
+    }
+
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(self.inner.as_raw(), addr.as_ptr(), len as _) })?;
+        Ok(())
+    }
+
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        self.inner.connect(addr)?;
+        Ok(TcpStream { inner: self.inner })
+    }
+
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        cvt(unsafe { c::listen(self.inner.as_raw(), self::backlog(backlog)) })?;
+        Ok(TcpListener { inner: self.inner })
+    }
+
+    pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+        sockname(|buf, len| unsafe { c::getsockname(self.inner.as_raw(), buf, len) })
+    }
+
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR, reuseaddr as c_int)
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.inner.set_reuseport(reuseport)
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.inner.reuseport()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        self.inner.set_keepalive(keepalive)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.inner.keepalive()
+    }
+
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.inner.set_linger(linger)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.inner.linger()
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.inner.set_nodelay(nodelay)
+    }
+
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.inner.nodelay()
+    }
+
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::IPPROTO_IPV6, c::IPV6_V6ONLY, only_v6 as c_int)
+    }
+
+    pub fn only_v6(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_IPV6, c::IPV6_V6ONLY)?;
+        Ok(raw != 0)
+    }
+
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.inner.take_error()
+    }
+}
+
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("TcpSocket");
+
+        if let Ok(addr) = self.socket_addr() {
+            res.field("addr", &addr);
+        }
+
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        res.field(name, &self.inner.as_raw()).finish()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // UDP
 ////////////////////////////////////////////////////////////////////////////////
@@ -600,6 +762,22 @@ pub fn broadcast(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
     pub fn set_multicast_loop_v4(&self, multicast_loop_v4: bool) -> io::Result<()> {
         setsockopt(
             &self.inner,
@@ -163,21 +163,23 @@
 use crate::env;
 use crate::ffi::{CStr, CString};
 use crate::fmt;
+use crate::future::{Future, IntoFuture};
 use crate::io;
 use crate::marker::PhantomData;
 use crate::mem::{self, forget};
 use crate::num::NonZero;
 use crate::panic;
 use crate::panicking;
-use crate::pin::Pin;
+use crate::pin::{pin, Pin};
 use crate::ptr::addr_of_mut;
 use crate::str;
 use crate::sync::atomic::{AtomicUsize, Ordering};
-use crate::sync::Arc;
+use crate::sync::{mpsc, Arc};
 use crate::sys::sync::Parker;
 use crate::sys::thread as imp;
 use crate::sys_common::{AsInner, IntoInner};
-use crate::time::{Duration, Instant};
+use crate::task::{Context, Poll, Wake, Waker};
+use crate::time::{Clock, Duration, Instant};
 
 #[stable(feature = "scoped_threads", since = "1.63.0")]
 mod scoped;
@@ -219,10 +221,12 @@ pub mod local_impl {
 ///
 /// Methods can be chained on it in order to configure it.
 ///
-/// The two configurations available are:
+/// The configurations available are:
 ///
 /// - [`name`]: specifies an [associated name for the thread][naming-threads]
 /// - [`stack_size`]: specifies the [desired stack size for the thread][stack-size]
+/// - [`affinity`]: specifies the CPUs the thread may run on
+/// - [`priority`]: specifies the scheduling priority of the thread
 ///
 /// The [`spawn`] method will take ownership of the builder and create an
 /// [`io::Result`] to the thread handle with the given configuration.
@@ -250,6 +254,8 @@ pub mod local_impl {
 ///
 /// [`stack_size`]: Builder::stack_size
 /// [`name`]: Builder::name
+/// [`affinity`]: Builder::affinity
+/// [`priority`]: Builder::priority
 /// [`spawn`]: Builder::spawn
 /// [`thread::spawn`]: spawn
 /// [`io::Result`]: crate::io::Result
@@ -264,6 +270,10 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // The CPUs the spawned thread may run on
+    affinity: Option<Vec<usize>>,
+    // The platform-specific scheduling priority of the spawned thread
+    priority: Option<i32>,
 }
 
 impl Builder {
@@ -287,7 +297,7 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder { name: None, stack_size: None, affinity: None, priority: None }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -343,6 +353,78 @@ pub fn stack_size(mut self, size: usize) -> Builder {
         self
     }
 
+    /// Restricts the new thread to run only on the given CPUs.
+    ///
+    /// CPUs are identified by their index, starting at zero. The affinity is
+    /// applied by the new thread before it runs the spawned closure; if it
+    /// cannot be applied, [`spawn`] returns the error and the closure is
+    /// dropped without being run.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This currently corresponds to `sched_setaffinity` on Linux and
+    /// Android. Other platforms return an error of kind
+    /// [`io::ErrorKind::Unsupported`] from [`spawn`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_affinity)]
+    ///
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new().affinity(&[0]).spawn(|| {
+    ///     // thread code, running on CPU 0 only
+    /// }).unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    ///
+    /// [`spawn`]: Builder::spawn
+    #[unstable(feature = "thread_affinity", issue = "none")]
+    pub fn affinity(mut self, cpus: &[usize]) -> Builder {
+        self.affinity = Some(cpus.to_vec());
+        self
+    }
+
+    /// Sets the scheduling priority of the new thread.
+    ///
+    /// The meaning of `priority` is platform-specific. It is applied by the
+    /// new thread before it runs the spawned closure; if it cannot be
+    /// applied, [`spawn`] returns the error and the closure is dropped
+    /// without being run.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Unix, the thread is switched to the real-time `SCHED_FIFO` policy
+    /// with the given priority, which usually requires elevated privileges.
+    /// On Hermit, the priority must be in the range `0..=255`, and other
+    /// values make [`spawn`] return an error of kind
+    /// [`io::ErrorKind::InvalidInput`]. On SOLID, the priority is passed to
+    /// the kernel scheduler as is. Other platforms return an error of kind
+    /// [`io::ErrorKind::Unsupported`] from [`spawn`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_affinity)]
+    ///
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new().priority(10).spawn(|| {
+    ///     // latency-sensitive thread code
+    /// }).unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    ///
+    /// [`spawn`]: Builder::spawn
+    #[unstable(feature = "thread_affinity", issue = "none")]
+    pub fn priority(mut self, priority: i32) -> Builder {
+        self.priority = Some(priority);
+        self
+    }
+
     /// Spawns a new thread by taking ownership of the `Builder`, and returns an
     /// [`io::Result`] to its [`JoinHandle`].
     ///
@@ -467,7 +549,7 @@ unsafe fn spawn_unchecked_<'a, 'scope, F, T>(
         T: Send + 'a,
         'scope: 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, affinity, priority } = self;
 
         let stack_size = stack_size.unwrap_or_else(|| {
             static MIN: AtomicUsize = AtomicUsize::new(0);
@@ -528,12 +610,41 @@ fn drop(&mut self) {
             }
         }
 
+        fn apply_options(affinity: Option<&[usize]>, priority: Option<i32>) -> io::Result<()> {
+            if let Some(cpus) = affinity {
+                imp::Thread::set_affinity(cpus)?;
+            }
+            if let Some(priority) = priority {
+                imp::Thread::set_priority(priority)?;
+            }
+            Ok(())
+        }
+
+        // Scheduling options are applied by the new thread itself. It reports
+        // the outcome back before running `f`, so that failures surface as an
+        // error from `spawn`.
+        let (options_tx, options_rx) = if affinity.is_some() || priority.is_some() {
+            let (tx, rx) = mpsc::sync_channel(1);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         let f = MaybeDangling::new(f);
         let main = move || {
             if let Some(name) = their_thread.cname() {
                 imp::Thread::set_name(name);
             }
 
+            if let Some(options_tx) = options_tx {
+                let result = apply_options(affinity.as_deref(), priority);
+                let failed = result.is_err();
+                let _ = options_tx.send(result);
+                if failed {
+                    return;
+                }
+            }
+
             crate::io::set_output_capture(output_capture);
 
             let f = f.into_inner();
@@ -564,24 +675,33 @@ fn drop(&mut self) {
         let main =
             unsafe { Box::from_raw(Box::into_raw(main) as *mut (dyn FnOnce() + Send + 'static)) };
 
-        Ok(JoinInner {
-            // SAFETY:
-            //
-            // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
-            // through FFI or otherwise used with low-level threading primitives that have no
-            // notion of or way to enforce lifetimes.
-            //
-            // As mentioned in the `Safety` section of this function's documentation, the caller of
-            // this function needs to guarantee that the passed-in lifetime is sufficiently long
-            // for the lifetime of the thread.
-            //
-            // Similarly, the `sys` implementation must guarantee that no references to the closure
-            // exist after the thread has terminated, which is signaled by `Thread::join`
-            // returning.
-            native: unsafe { imp::Thread::new(stack_size, main)? },
-            thread: my_thread,
-            packet: my_packet,
-        })
+        // SAFETY:
+        //
+        // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
+        // through FFI or otherwise used with low-level threading primitives that have no
+        // notion of or way to enforce lifetimes.
+        //
+        // As mentioned in the `Safety` section of this function's documentation, the caller of
+        // this function needs to guarantee that the passed-in lifetime is sufficiently long
+        // for the lifetime of the thread.
+        //
+        // Similarly, the `sys` implementation must guarantee that no references to the closure
+        // exist after the thread has terminated, which is signaled by `Thread::join`
+        // returning.
+        let native = unsafe { imp::Thread::new(stack_size, main)? };
+
+        if let Some(options_rx) = options_rx {
+            // If the sender was dropped without reporting, the thread panicked
+            // and `join` will observe that instead.
+            if let Ok(Err(e)) = options_rx.recv() {
+                // The thread exits without running `f`. Wait for it so that
+                // `f` is dropped before returning.
+                native.join();
+                return Err(e);
+            }
+        }
+
+        Ok(JoinInner { native, thread: my_thread, packet: my_packet })
     }
 }
 
@@ -988,6 +1108,33 @@ pub fn sleep_until(deadline: Instant) {
     }
 }
 
+/// Returns the CPU time consumed by the current thread so far.
+///
+/// This reads [`Clock::ThreadCpuTime`]. Time spent sleeping, blocked or
+/// waiting to be scheduled is not counted.
+///
+/// # Errors
+///
+/// Returns an error of kind [`io::ErrorKind::Unsupported`] if the platform
+/// does not track per-thread CPU time.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(time_clocks)]
+///
+/// use std::thread;
+///
+/// let start = thread::cpu_time().unwrap();
+/// let sum: u64 = (0..1_000_000).sum();
+/// let spent = thread::cpu_time().unwrap() - start;
+/// println!("computed {sum} in {spent:?} of CPU time");
+/// ```
+#[unstable(feature = "time_clocks", issue = "none")]
+pub fn cpu_time() -> io::Result<Duration> {
+    Clock::ThreadCpuTime.now()
+}
+
 /// Used to ensure that `park` and `park_timeout` do not unwind, as that can
 /// cause undefined behaviour if not handled correctly (see #102398 for context).
 struct PanicGuard;
@@ -1174,6 +1321,63 @@ pub fn park_timeout(dur: Duration) {
     forget(guard);
 }
 
+/// Runs a future to completion on the current thread.
+///
+/// The future is polled with a [`Waker`] that [unparks](Thread::unpark) the
+/// current thread, and the thread is [parked](park) whenever the future
+/// returns [`Poll::Pending`]. Since a wake-up sets the thread's token, a
+/// wake-up that happens during a poll is never lost.
+///
+/// This is the thread-parking counterpart of [`future::block_on`], which
+/// takes a caller-supplied parker instead.
+///
+/// Note that because the current thread's token is shared with other users of
+/// [`park`], the future may be polled spuriously. This is permitted by the
+/// [`Future`] contract.
+///
+/// [`future::block_on`]: crate::future::block_on
+///
+/// # Examples
+///
+/// ```
+/// #![feature(block_on)]
+///
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// let (tx, rx) = mpsc::channel();
+/// let handle = thread::spawn(move || tx.send(42).unwrap());
+///
+/// let value = thread::block_on(async move { rx.recv().unwrap() });
+/// assert_eq!(value, 42);
+/// handle.join().unwrap();
+/// ```
+#[unstable(feature = "block_on", issue = "none")]
+pub fn block_on<F: IntoFuture>(future: F) -> F::Output {
+    /// A waker that unparks the thread it was created on.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = pin!(future.into_future());
+    let waker = Waker::from(Arc::new(ThreadWaker(current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => park(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // ThreadId
 ////////////////////////////////////////////////////////////////////////////////
@@ -1460,6 +1664,35 @@ pub fn name(&self) -> Option<&str> {
         self.cname().map(|s| unsafe { str::from_utf8_unchecked(s.to_bytes()) })
     }
 
+    /// Restricts this thread to run only on the given CPUs.
+    ///
+    /// CPUs are identified by their index, starting at zero. Only the
+    /// affinity of the calling thread can be changed: if `self` is not the
+    /// [current] thread, an error of kind [`io::ErrorKind::InvalidInput`] is
+    /// returned. See [`Builder::affinity`] for the platform-specific behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_affinity)]
+    ///
+    /// use std::thread;
+    ///
+    /// thread::current().set_affinity(&[0, 1]).expect("failed to set affinity");
+    /// ```
+    ///
+    /// [current]: current
+    #[unstable(feature = "thread_affinity", issue = "none")]
+    pub fn set_affinity(&self, cpus: &[usize]) -> io::Result<()> {
+        if self.id() != current().id() {
+            return Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "only the affinity of the current thread can be changed",
+            ));
+        }
+        imp::Thread::set_affinity(cpus)
+    }
+
     fn cname(&self) -> Option<&CStr> {
         match &self.inner.name {
             ThreadName::Main => Some(c"main"),
@@ -414,3 +414,67 @@ fn test_minimal_thread_stack() {
     assert_eq!(before, 0);
     assert_eq!(COUNT.load(Ordering::Relaxed), 1);
 }
+
+#[test]
+fn test_block_on() {
+    use crate::future::{self, Future};
+    use crate::pin::Pin;
+    use crate::task::{Context, Poll};
+
+    assert_eq!(thread::block_on(future::ready(7)), 7);
+
+    // A future that is woken from another thread, once.
+    struct WokenOnce {
+        spawned: bool,
+        done: Arc<AtomicBool>,
+    }
+
+    impl Future for WokenOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.done.load(Ordering::Acquire) {
+                return Poll::Ready(());
+            }
+            if !self.spawned {
+                self.spawned = true;
+                let done = self.done.clone();
+                let waker = cx.waker().clone();
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(10));
+                    done.store(true, Ordering::Release);
+                    waker.wake();
+                });
+            }
+            Poll::Pending
+        }
+    }
+
+    thread::block_on(WokenOnce { spawned: false, done: Arc::new(AtomicBool::new(false)) });
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_affinity() {
+    // The CPU we are running on is certainly part of the allowed set.
+    let cpu = unsafe { libc::sched_getcpu() } as usize;
+
+    let builder = thread::Builder::new().affinity(&[cpu]);
+    let ran_on = builder.spawn(|| unsafe { libc::sched_getcpu() } as usize).unwrap();
+    assert_eq!(ran_on.join().unwrap(), cpu);
+
+    thread::current().set_affinity(&[cpu]).unwrap();
+}
+
+#[test]
+fn test_affinity_error() {
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran2 = ran.clone();
+    let builder = thread::Builder::new().affinity(&[usize::MAX]);
+    assert!(builder.spawn(move || ran2.store(true, Ordering::Relaxed)).is_err());
+    assert!(!ran.load(Ordering::Relaxed));
+
+    let main = thread::current();
+    let err = thread::spawn(move || main.set_affinity(&[0])).join().unwrap().unwrap_err();
+    assert_eq!(err.kind(), crate::io::ErrorKind::InvalidInput);
+}
@@ -36,6 +36,7 @@
 
 use crate::error::Error;
 use crate::fmt;
+use crate::io;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
 use crate::sys::time;
 use crate::sys_common::{FromInner, IntoInner};
@@ -706,6 +707,96 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// A clock that can be read with [`Clock::now`].
+///
+/// [`Instant`] always uses the platform's default monotonic clock, and it is
+/// not specified whether that clock advances while the system is suspended.
+/// `Clock` gives access to specific clocks with well-defined behavior, for
+/// code that cares about the difference.
+///
+/// Not every platform provides every clock. Reading a clock that is not
+/// available returns an error of kind [`io::ErrorKind::Unsupported`].
+///
+/// # Underlying System calls
+///
+/// The following system calls are [currently] being used to read each clock:
+///
+/// - `Monotonic`: the same clock as [`Instant::now`].
+/// - `Boottime`: `CLOCK_BOOTTIME` on Linux and Android, and `CLOCK_MONOTONIC`
+///   on Darwin, where it keeps advancing during sleep.
+/// - `MonotonicRaw`: `CLOCK_MONOTONIC_RAW` on Linux, Android and Darwin.
+/// - `ThreadCpuTime`: `CLOCK_THREAD_CPUTIME_ID` on Unix,
+///   `CLOCKID_THREAD_CPUTIME_ID` on WASI, and [GetThreadTimes] on Windows.
+///
+/// **Disclaimer:** These system calls might change over time.
+///
+/// [currently]: crate::io#platform-specific-behavior
+/// [GetThreadTimes]: https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getthreadtimes
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(time_clocks)]
+///
+/// use std::time::Clock;
+///
+/// let start = Clock::Boottime.now().expect("no boot-time clock");
+/// // ... the system may be suspended here ...
+/// let elapsed = Clock::Boottime.now().unwrap() - start;
+/// println!("{elapsed:?} passed, including time spent suspended");
+/// ```
+#[unstable(feature = "time_clocks", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Clock {
+    /// The clock used by [`Instant`].
+    Monotonic,
+    /// A monotonic clock that keeps advancing while the system is suspended.
+    ///
+    /// This is the clock to use for timeouts that must account for
+    /// suspend, such as watchdogs.
+    Boottime,
+    /// A monotonic clock that is not subject to frequency adjustments, for
+    /// example by NTP.
+    ///
+    /// Its rate may drift from real time, but it is steady, which makes it
+    /// suitable for profiling.
+    MonotonicRaw,
+    /// The CPU time consumed by the calling thread.
+    ///
+    /// See also [`thread::cpu_time`](crate::thread::cpu_time).
+    ThreadCpuTime,
+}
+
+impl Clock {
+    /// Reads the current value of the clock.
+    ///
+    /// The value is measured from a clock-specific starting point, so only
+    /// differences between two readings of the same clock are meaningful.
+    /// Readings of different clocks must not be compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::Unsupported`] if the clock
+    /// is not available on this platform, or an OS error if reading it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(time_clocks)]
+    ///
+    /// use std::time::Clock;
+    ///
+    /// let a = Clock::Monotonic.now().unwrap();
+    /// let b = Clock::Monotonic.now().unwrap();
+    /// assert!(b >= a);
+    /// ```
+    #[unstable(feature = "time_clocks", issue = "none")]
+    pub fn now(self) -> io::Result<Duration> {
+        time::clock_now(self)
+    }
+}
+
 impl FromInner<time::SystemTime> for SystemTime {
     fn from_inner(time: time::SystemTime) -> SystemTime {
         SystemTime(time)
@@ -1,4 +1,4 @@
-use super::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use super::{Clock, Duration, Instant, SystemTime, UNIX_EPOCH};
 use core::fmt::Debug;
 #[cfg(not(target_arch = "wasm32"))]
 use test::{black_box, Bencher};
@@ -228,6 +228,30 @@ fn check<T: Eq + Copy + Debug>(start: Option<T>, op: impl Fn(&T, Duration) -> Op
     check(instant.checked_add(Duration::from_secs(i64::MAX as _)), Instant::checked_sub);
 }
 
+#[test]
+fn clocks_are_monotonic() {
+    for clock in [Clock::Monotonic, Clock::Boottime, Clock::MonotonicRaw, Clock::ThreadCpuTime] {
+        let a = match clock.now() {
+            Ok(a) => a,
+            Err(e) => {
+                assert_eq!(e.kind(), crate::io::ErrorKind::Unsupported, "{clock:?}");
+                continue;
+            }
+        };
+        let b = clock.now().unwrap();
+        assert!(b >= a, "{clock:?} went backwards");
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", windows))]
+#[test]
+fn thread_cpu_time_excludes_sleep() {
+    let a = crate::thread::cpu_time().unwrap();
+    crate::thread::sleep(Duration::from_millis(100));
+    let b = crate::thread::cpu_time().unwrap();
+    assert!(b - a < Duration::from_millis(100));
+}
+
 macro_rules! bench_instant_threaded {
     ($bench_name:ident, $thread_count:expr) => {
         #[bench]
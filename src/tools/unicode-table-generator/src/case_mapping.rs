@@ -59,7 +59,7 @@ fn generate_tables(case: &str, data: &BTreeMap<u32, (u32, u32, u32)>) -> String
     tables
 }
 
-struct CharEscape(char);
+pub(crate) struct CharEscape(pub(crate) char);
 
 impl fmt::Debug for CharEscape {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,123 @@
+use crate::raw_emitter::RawEmitter;
+use crate::UnicodeData;
+use std::collections::BTreeMap;
+
+/// The values of Grapheme_Cluster_Break, in the order of the constants in
+/// `HEADER`. Extended_Pictographic is folded in as one more value, since all
+/// of its characters are otherwise `Other`.
+static CATEGORIES: &[&str] = &[
+    "Other",
+    "CR",
+    "LF",
+    "Control",
+    "Extend",
+    "ZWJ",
+    "Regional_Indicator",
+    "Prepend",
+    "SpacingMark",
+    "L",
+    "V",
+    "T",
+    "LV",
+    "LVT",
+    "Extended_Pictographic",
+];
+
+/// The values of Indic_Conjunct_Break, stored in bits 4 and 5.
+static INDIC_CONJUNCT_BREAK: &[&str] = &["None", "Consonant", "Extend", "Linker"];
+
+const HANGUL_SYLLABLES: std::ops::RangeInclusive<u32> = 0xAC00..=0xD7A3;
+
+/// Reads the Indic_Conjunct_Break property from DerivedCoreProperties.txt.
+/// `ucd_parse` only reads the first field after the code points, which is
+/// "InCB" for all of them.
+pub(crate) fn parse_indic_conjunct_break(contents: &str) -> BTreeMap<u32, String> {
+    let mut values = BTreeMap::new();
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap();
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        let &[codepoints, "InCB", value] = &fields[..] else { continue };
+        let (start, end) = match codepoints.split_once("..") {
+            Some((start, end)) => (start, end),
+            None => (codepoints, codepoints),
+        };
+        let start = u32::from_str_radix(start, 16).unwrap();
+        let end = u32::from_str_radix(end, 16).unwrap();
+        for codepoint in start..=end {
+            values.insert(codepoint, value.to_string());
+        }
+    }
+    values
+}
+
+pub(crate) fn generate_grapheme_break(data: &UnicodeData) -> String {
+    let mut values = BTreeMap::new();
+    for (&codepoint, category) in &data.grapheme_cluster_break {
+        let mut value = CATEGORIES.iter().position(|&c| c == *category).unwrap() as u8;
+        if HANGUL_SYLLABLES.contains(&codepoint) {
+            // Hangul syllables alternate between LV and LVT, which would take
+            // a run each. Only store LVT, and let `lookup` tell them apart.
+            let expected = if (codepoint - 0xAC00) % 28 == 0 { "LV" } else { "LVT" };
+            assert_eq!(category, expected, "U+{codepoint:04X}");
+            value = CATEGORIES.iter().position(|&c| c == "LVT").unwrap() as u8;
+        } else {
+            assert!(!["LV", "LVT"].contains(&&**category), "U+{codepoint:04X}");
+        }
+        values.insert(codepoint, value);
+    }
+    for &codepoint in &data.extended_pictographic {
+        assert!(!values.contains_key(&codepoint), "U+{codepoint:04X} is Extended_Pictographic");
+        values.insert(codepoint, CATEGORIES.len() as u8 - 1);
+    }
+    for (&codepoint, incb) in &data.indic_conjunct_break {
+        let incb = INDIC_CONJUNCT_BREAK.iter().position(|&i| i == *incb).unwrap() as u8;
+        *values.entry(codepoint).or_insert(0) |= incb << 4;
+    }
+
+    let mut emitter = RawEmitter::new();
+    emitter.emit_run_table("RUNS", &values);
+
+    let mut file = String::new();
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&emitter.file);
+    file.push('\n');
+    file.push_str(LOOKUP.trim_start());
+    file
+}
+
+static HEADER: &str = r"
+pub const OTHER: u8 = 0;
+pub const CR: u8 = 1;
+pub const LF: u8 = 2;
+pub const CONTROL: u8 = 3;
+pub const EXTEND: u8 = 4;
+pub const ZWJ: u8 = 5;
+pub const REGIONAL_INDICATOR: u8 = 6;
+pub const PREPEND: u8 = 7;
+pub const SPACING_MARK: u8 = 8;
+pub const L: u8 = 9;
+pub const V: u8 = 10;
+pub const T: u8 = 11;
+pub const LV: u8 = 12;
+pub const LVT: u8 = 13;
+pub const EXTENDED_PICTOGRAPHIC: u8 = 14;
+pub const CATEGORY_MASK: u8 = 0x0f;
+
+pub const INCB_CONSONANT: u8 = 0x10;
+pub const INCB_EXTEND: u8 = 0x20;
+pub const INCB_LINKER: u8 = 0x30;
+pub const INCB_MASK: u8 = 0x30;
+";
+
+static LOOKUP: &str = r"
+/// Returns the Grapheme_Cluster_Break category of `c` in the low bits, with
+/// Extended_Pictographic as a category of its own, and its
+/// Indic_Conjunct_Break value in the `INCB_MASK` bits.
+#[inline]
+pub fn lookup(c: char) -> u8 {
+    let value = super::run_search(c as u32, &RUNS);
+    // Hangul syllables alternate between LV and LVT; only LVT is stored.
+    if value == LVT && (c as u32 - 0xAC00) % 28 == 0 { LV } else { value }
+}
+";
@@ -71,13 +71,17 @@
 //! index of that offset is utilized as the answer to whether we're in the set
 //! or not.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
+use std::path::Path;
 use ucd_parse::Codepoints;
 
 mod cascading_map;
 mod case_mapping;
+mod grapheme_break;
+mod normalization;
 mod raw_emitter;
+mod run_table;
 mod skiplist;
 mod unicode_download;
 
@@ -99,6 +103,16 @@ struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    /// Only the code points with a non-zero canonical combining class.
+    combining_class: BTreeMap<u32, u8>,
+    /// Single-level canonical decompositions, without Hangul syllables.
+    decomposition: BTreeMap<u32, Vec<u32>>,
+    composition_exclusions: BTreeSet<u32>,
+    /// Only the code points with a Grapheme_Cluster_Break other than `Other`.
+    grapheme_cluster_break: BTreeMap<u32, String>,
+    extended_pictographic: BTreeSet<u32>,
+    /// Only the code points with an Indic_Conjunct_Break other than `None`.
+    indic_conjunct_break: BTreeMap<u32, String>,
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -142,11 +156,29 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    let rows = ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap();
+
+    // The code points in a range all copy the row of its first code point
+    // when expanded, but none of them have a combining class or decompose, so
+    // these are read from the rows before expanding them.
+    let mut combining_class = BTreeMap::new();
+    let mut decomposition = BTreeMap::new();
+    for row in &rows {
+        if row.canonical_combining_class != 0 {
+            combining_class.insert(row.codepoint.value(), row.canonical_combining_class);
+        }
+        // Characters without a decomposition are listed as decomposing to
+        // themselves.
+        let mapping = row.decomposition.mapping();
+        if row.decomposition.is_canonical() && mapping != [row.codepoint] {
+            decomposition
+                .insert(row.codepoint.value(), mapping.iter().map(|c| c.value()).collect());
+        }
+    }
+
     let mut to_lower = BTreeMap::new();
     let mut to_upper = BTreeMap::new();
-    for row in ucd_parse::UnicodeDataExpander::new(
-        ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap(),
-    ) {
+    for row in ucd_parse::UnicodeDataExpander::new(rows) {
         let general_category = if ["Nd", "Nl", "No"].contains(&row.general_category.as_str()) {
             "N"
         } else {
@@ -171,6 +203,29 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    let composition_exclusions = normalization::parse_composition_exclusions(
+        &std::fs::read_to_string(Path::new(UNICODE_DIRECTORY).join("CompositionExclusions.txt"))
+            .unwrap(),
+    );
+
+    let mut grapheme_cluster_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::GraphemeClusterBreak>(&UNICODE_DIRECTORY).unwrap()
+    {
+        for codepoint in row.codepoints {
+            grapheme_cluster_break.insert(codepoint.value(), row.value.clone());
+        }
+    }
+    let mut extended_pictographic = BTreeSet::new();
+    for row in ucd_parse::parse::<_, ucd_parse::EmojiProperty>(&UNICODE_DIRECTORY).unwrap() {
+        if row.property == "Extended_Pictographic" {
+            extended_pictographic.extend(row.codepoints.into_iter().map(|c| c.value()));
+        }
+    }
+    let indic_conjunct_break = grapheme_break::parse_indic_conjunct_break(
+        &std::fs::read_to_string(Path::new(UNICODE_DIRECTORY).join("DerivedCoreProperties.txt"))
+            .unwrap(),
+    );
+
     for row in ucd_parse::parse::<_, ucd_parse::SpecialCaseMapping>(&UNICODE_DIRECTORY).unwrap() {
         if !row.conditions.is_empty() {
             // Skip conditional case mappings
@@ -214,7 +269,17 @@ fn load_data() -> UnicodeData {
 
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData {
+        ranges: properties,
+        to_lower,
+        to_upper,
+        combining_class,
+        decomposition,
+        composition_exclusions,
+        grapheme_cluster_break,
+        extended_pictographic,
+        indic_conjunct_break,
+    }
 }
 
 fn main() {
@@ -280,6 +345,14 @@ fn main() {
     table_file.push('\n');
 
     modules.push((String::from("conversions"), case_mapping::generate_case_mapping(&unicode_data)));
+    modules.push((
+        String::from("grapheme_cluster_break"),
+        grapheme_break::generate_grapheme_break(&unicode_data),
+    ));
+    modules.push((
+        String::from("normalization"),
+        normalization::generate_normalization(&unicode_data),
+    ));
 
     for (name, contents) in modules {
         table_file.push_str("#[rustfmt::skip]\n");
@@ -0,0 +1,114 @@
+use crate::case_mapping::CharEscape;
+use crate::raw_emitter::RawEmitter;
+use crate::{fmt_list, UnicodeData};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+/// Reads the code points listed in CompositionExclusions.txt, which has no
+/// parser in `ucd_parse`.
+pub(crate) fn parse_composition_exclusions(contents: &str) -> BTreeSet<u32> {
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| u32::from_str_radix(line, 16).unwrap())
+        .collect()
+}
+
+pub(crate) fn generate_normalization(data: &UnicodeData) -> String {
+    let mut emitter = RawEmitter::new();
+    emitter.emit_run_table("COMBINING_CLASS_RUNS", &data.combining_class);
+    let mut file = emitter.file;
+    file.push('\n');
+
+    // Decompositions are applied recursively here, so that a single lookup
+    // gives the full canonical decomposition of a character.
+    let mut decompositions = Vec::with_capacity(data.decomposition.len());
+    let mut decomposed_chars = Vec::new();
+    for &c in data.decomposition.keys() {
+        let start = u32::try_from(decomposed_chars.len()).unwrap();
+        decompose(data, c, &mut decomposed_chars);
+        let len = u32::try_from(decomposed_chars.len()).unwrap() - start;
+        assert!(start < 1 << 24 && len < 1 << 8);
+        decompositions.push((CharEscape(char::from_u32(c).unwrap()), start << 8 | len));
+    }
+    write!(
+        file,
+        "static DECOMPOSITION_TABLE: &[(char, u32)] = &[{}];\n\n",
+        fmt_list(decompositions)
+    )
+    .unwrap();
+    write!(
+        file,
+        "static DECOMPOSITION_CHARS: &[char] = &[{}];\n\n",
+        fmt_list(decomposed_chars.into_iter().map(CharEscape))
+    )
+    .unwrap();
+
+    // Only primary composites compose: the characters that decompose into a
+    // pair, unless they are excluded from composition, either explicitly or
+    // because their decomposition starts with a non-starter.
+    let mut compositions = BTreeMap::new();
+    for (&c, mapping) in &data.decomposition {
+        let &[first, second] = &mapping[..] else { continue };
+        if data.composition_exclusions.contains(&c)
+            || data.combining_class.contains_key(&c)
+            || data.combining_class.contains_key(&first)
+        {
+            continue;
+        }
+        let pair = (char::from_u32(first).unwrap(), char::from_u32(second).unwrap());
+        assert!(compositions.insert(pair, char::from_u32(c).unwrap()).is_none());
+    }
+    let compositions = compositions
+        .into_iter()
+        .map(|((first, second), c)| ((CharEscape(first), CharEscape(second)), CharEscape(c)));
+    write!(
+        file,
+        "static COMPOSITION_TABLE: &[((char, char), char)] = &[{}];\n\n",
+        fmt_list(compositions)
+    )
+    .unwrap();
+
+    let first_combining = *data.combining_class.keys().next().unwrap();
+    let header = HEADER.trim_start().replace("FIRST_COMBINING", &format!("{first_combining:#x}"));
+    file.push_str(&header);
+    file
+}
+
+fn decompose(data: &UnicodeData, c: u32, out: &mut Vec<char>) {
+    match data.decomposition.get(&c) {
+        Some(mapping) => mapping.iter().for_each(|&c| decompose(data, c, out)),
+        None => out.push(char::from_u32(c).unwrap()),
+    }
+}
+
+static HEADER: &str = r"
+/// Returns the canonical combining class of `c`.
+#[inline]
+pub fn canonical_combining_class(c: char) -> u8 {
+    if (c as u32) < FIRST_COMBINING {
+        0
+    } else {
+        super::run_search(c as u32, &COMBINING_CLASS_RUNS)
+    }
+}
+
+/// Returns the full canonical decomposition of `c`, or `None` if it does not
+/// decompose. Hangul syllables are not included, since they decompose
+/// algorithmically.
+pub fn canonical_decomposition(c: char) -> Option<&'static [char]> {
+    let i = DECOMPOSITION_TABLE.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+    let packed = DECOMPOSITION_TABLE[i].1;
+    let (start, len) = ((packed >> 8) as usize, (packed & 0xff) as usize);
+    // SAFETY: Indices come from statically generated table
+    Some(unsafe { DECOMPOSITION_CHARS.get_unchecked(start..start + len) })
+}
+
+/// Returns the primary composite of `a` followed by `b`, if there is one.
+/// Hangul syllables are not included, since they compose algorithmically.
+pub fn compose(a: char, b: char) -> Option<char> {
+    let i = COMPOSITION_TABLE.binary_search_by(|&(pair, _)| pair.cmp(&(a, b))).ok()?;
+    Some(COMPOSITION_TABLE[i].1)
+}
+";
@@ -96,3 +96,16 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
     }
     offset_idx % 2 == 1
 }
+
+/// Looks up the value of the run containing `needle`, in a table of runs
+/// that each pack their first code point and their value as `start << 8 | value`.
+#[inline(always)]
+fn run_search<const N: usize>(needle: u32, runs: &[u32; N]) -> u8 {
+    // The first run starts at 0, so there is always a run at or before the
+    // needle, even though no packed run can be equal to it.
+    let idx = match runs.binary_search(&(needle << 8 | 0xff)) {
+        Ok(idx) => idx,
+        Err(idx) => idx - 1,
+    };
+    runs[idx] as u8
+}
@@ -0,0 +1,30 @@
+use crate::fmt_list;
+use crate::raw_emitter::RawEmitter;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+impl RawEmitter {
+    /// Emits a table for a property with small integer values, as the list of
+    /// runs of code points with the same value. Code points missing from
+    /// `values` have the value 0.
+    ///
+    /// Each run is packed into a single u32 as `start << 8 | value`, which
+    /// keeps the table sorted by start, so that it can be binary searched.
+    pub fn emit_run_table(&mut self, name: &str, values: &BTreeMap<u32, u8>) {
+        let mut runs = Vec::new();
+        let mut current = None;
+        for codepoint in 0..=(char::MAX as u32) {
+            let value = values.get(&codepoint).copied().unwrap_or(0);
+            // `run_search` relies on no run being packed as `_ << 8 | 0xff`.
+            assert!(value < u8::MAX, "value {value} of U+{codepoint:04X} is too large");
+            if current != Some(value) {
+                runs.push(codepoint << 8 | value as u32);
+                current = Some(value);
+            }
+        }
+
+        writeln!(&mut self.file, "static {name}: [u32; {}] = [{}];", runs.len(), fmt_list(&runs))
+            .unwrap();
+        self.bytes_used += 4 * runs.len();
+    }
+}
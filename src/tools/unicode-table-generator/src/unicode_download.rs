@@ -6,8 +6,15 @@
 
 static README: &str = "ReadMe.txt";
 
-static RESOURCES: &[&str] =
-    &["DerivedCoreProperties.txt", "PropList.txt", "UnicodeData.txt", "SpecialCasing.txt"];
+static RESOURCES: &[&str] = &[
+    "DerivedCoreProperties.txt",
+    "PropList.txt",
+    "UnicodeData.txt",
+    "SpecialCasing.txt",
+    "CompositionExclusions.txt",
+    "auxiliary/GraphemeBreakProperty.txt",
+    "emoji/emoji-data.txt",
+];
 
 #[track_caller]
 fn fetch(url: &str) -> Output {
@@ -40,6 +47,8 @@ pub fn fetch_latest() {
 
     for resource in RESOURCES {
         let output = fetch(resource);
-        std::fs::write(directory.join(resource), output.stdout).unwrap();
+        let path = directory.join(resource);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, output.stdout).unwrap();
     }
 }